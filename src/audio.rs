@@ -1,4 +1,5 @@
-use rodio::{Decoder, OutputStream, Sink};
+use rodio::{Decoder, OutputStream, Sink, Source};
+use std::cell::Cell;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
@@ -6,13 +7,14 @@ use std::path::PathBuf;
 pub struct AudioPlayer {
     _stream: OutputStream,
     sink: Sink,
+    crowd_volume: Cell<f32>, // Cheers get louder as the batting side builds momentum
 }
 
 impl AudioPlayer {
     pub fn new() -> Option<Self> {
         if let Ok((_stream, stream_handle)) = OutputStream::try_default() {
             if let Ok(sink) = Sink::try_new(&stream_handle) {
-                return Some(AudioPlayer { _stream, sink });
+                return Some(AudioPlayer { _stream, sink, crowd_volume: Cell::new(1.0) });
             }
         }
         None
@@ -23,15 +25,23 @@ impl AudioPlayer {
     }
 
     fn play_sound(&self, filename: &str) {
+        self.play_sound_at(filename, 1.0);
+    }
+
+    fn play_sound_at(&self, filename: &str, volume: f32) {
         let path = Self::get_audio_path(filename);
         if let Ok(file) = File::open(&path) {
             let source = BufReader::new(file);
             if let Ok(decoder) = Decoder::new(source) {
-                self.sink.append(decoder);
+                self.sink.append(decoder.amplify(volume));
             }
         }
     }
 
+    pub fn set_crowd_intensity(&self, volume: f32) {
+        self.crowd_volume.set(volume);
+    }
+
     pub fn play_bat_contact(&self) {
         self.play_sound("bat.wav");
     }
@@ -52,14 +62,14 @@ impl AudioPlayer {
     }
 
     pub fn play_cheer_single(&self) {
-        self.play_sound("cheer_single.wav");
+        self.play_sound_at("cheer_single.wav", self.crowd_volume.get());
     }
 
     pub fn play_cheer_double(&self) {
-        self.play_sound("cheer_double.wav");
+        self.play_sound_at("cheer_double.wav", self.crowd_volume.get());
     }
 
     pub fn play_cheer_triple_and_homer(&self) {
-        self.play_sound("cheer_triple_and_homer.wav");
+        self.play_sound_at("cheer_triple_and_homer.wav", self.crowd_volume.get());
     }
 }
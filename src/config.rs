@@ -0,0 +1,380 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::game::constants::{
+    AIM_REPEAT_WINDOW_MS, BATTER_AGGRESSIVENESS_STEP, DEFAULT_BATTER_AGGRESSIVENESS, MAX_INNINGS_CHOICES, DEFAULT_DATA_DIR, DEFAULT_SEASON, FAST_FORWARD_CHOICES,
+    FAST_FORWARD_MAX, FAST_FORWARD_MULTIPLIER, GAME_SPEED_FAST_PERCENT,
+    GAME_SPEED_NORMAL_PERCENT, GAME_SPEED_SLOW_PERCENT, PITCHER_CLOCK_FRAMES, PITCH_CLOCK_FRAMES,
+};
+
+// The config, the career stats and the end-of-game exports all go in the working directory
+pub const CONFIG_FILE: &str = "bitbatter_config.json";
+pub const CAREER_STATS_FILE: &str = "bitbatter_career.json"; // Every finished game's box score, added up
+pub const CONFIG_VERSION: u32 = 17; // Bump when a field is added or dropped so old files get rewritten
+
+/// Which colors the UI uses for good and bad news
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorScheme {
+    #[default]
+    Standard,   // Green for good, red for bad
+    ColorBlind, // Blue for good, orange for bad
+}
+
+/// How fast the game moves between plays
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+    Instant, // No dead time at all - straight on to the next pitch
+}
+
+impl GameSpeed {
+    pub fn from_flag(flag: &str) -> Option<GameSpeed> {
+        match flag {
+            "slow" => Some(GameSpeed::Slow),
+            "normal" => Some(GameSpeed::Normal),
+            "fast" => Some(GameSpeed::Fast),
+            "instant" => Some(GameSpeed::Instant),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameSpeed::Slow => "slow",
+            GameSpeed::Normal => "normal",
+            GameSpeed::Fast => "fast",
+            GameSpeed::Instant => "instant",
+        }
+    }
+
+    /// One step down, stopping at slow
+    pub fn slower(&self) -> GameSpeed {
+        match self {
+            GameSpeed::Slow | GameSpeed::Normal => GameSpeed::Slow,
+            GameSpeed::Fast => GameSpeed::Normal,
+            GameSpeed::Instant => GameSpeed::Fast,
+        }
+    }
+
+    /// One step up, stopping at instant
+    pub fn faster(&self) -> GameSpeed {
+        match self {
+            GameSpeed::Slow => GameSpeed::Normal,
+            GameSpeed::Normal => GameSpeed::Fast,
+            GameSpeed::Fast | GameSpeed::Instant => GameSpeed::Instant,
+        }
+    }
+
+    /// Percent of real time the dead time between plays runs at, `None` to skip it
+    pub fn percent(&self) -> Option<u16> {
+        match self {
+            GameSpeed::Slow => Some(GAME_SPEED_SLOW_PERCENT),
+            GameSpeed::Normal => Some(GAME_SPEED_NORMAL_PERCENT),
+            GameSpeed::Fast => Some(GAME_SPEED_FAST_PERCENT),
+            GameSpeed::Instant => None,
+        }
+    }
+}
+
+/// What the settings screen (F2) can change, in the order it lists them: game options, then
+/// display settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    MaxInnings,
+    CpuAggressiveness,
+    PitchPreview,
+    BattersEye,
+    ColorScheme,
+    Sound,
+    GameSpeed,
+    FastForward,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 8] = [
+        Setting::MaxInnings,
+        Setting::CpuAggressiveness,
+        Setting::PitchPreview,
+        Setting::BattersEye,
+        Setting::ColorScheme,
+        Setting::Sound,
+        Setting::GameSpeed,
+        Setting::FastForward,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Setting::MaxInnings => "Max innings",
+            Setting::CpuAggressiveness => "CPU batter aggressiveness",
+            Setting::PitchPreview => "Pitch preview",
+            Setting::BattersEye => "Batter's eye",
+            Setting::ColorScheme => "Colors",
+            Setting::Sound => "Sound",
+            Setting::GameSpeed => "Game speed",
+            Setting::FastForward => "Fast-forward (F)",
+        }
+    }
+
+    /// Display settings change how the game looks, sounds and moves, not how it plays
+    pub fn is_display(&self) -> bool {
+        matches!(self, Setting::ColorScheme | Setting::Sound | Setting::GameSpeed | Setting::FastForward)
+    }
+}
+
+/// User-adjustable settings, stored as JSON next to the executable's working directory.
+/// Missing fields fall back to their defaults, so older files keep loading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    #[serde(default)] // Files written before versioning read as version 0
+    pub version: u32,
+    pub batter_aggressiveness: f32,   // CPU batter swing tendency, 0.0 - 1.0
+    pub demo_mode: bool,              // CPU drives both the pitcher and the batter
+    pub data_dir: String,             // Where the statcast CSVs live
+    pub season: u16,                  // Season for downloads, templates and league files
+    pub league_file: Option<String>,  // League JSON that replaces the data directory's teams
+    pub pitch_clock_frames: u16,      // Countdown for the batter to get set once the pitch is locked in
+    pub pitcher_clock_frames: u16,    // Frames the pitcher gets to pick and aim before a ball is called, 0 for no limit
+    pub allow_same_team: bool,        // Let one team be picked for both home and away
+    pub max_innings: u8,              // A tie after this inning (9 or later) ends the game, 0 plays extras until someone wins
+    pub momentum_effects: bool,       // Extreme momentum nudges contact quality; the meter and crowd react either way
+    pub color_scheme: ColorScheme,    // "standard" or "color_blind"
+    pub tutorial_done: bool,          // Finished or skipped the tutorial - it's only offered until then
+    pub game_speed: GameSpeed,        // "slow", "normal", "fast" or "instant" between plays
+    pub pitch_preview: bool,          // Show the odds of the aimed pitch while aiming - off for the challenge
+    pub batters_eye: bool,            // Spin cue on the approaching ball and calling the pitch with 1-4
+    pub aim_repeat_ms: u64,           // Same arrow again within this many ms is a held key repeating, 0 takes every press
+    pub pitchers_bat: bool,           // No DH - the pitcher hits ninth
+    pub fast_forward: u16,            // How many times faster the dead time runs while F is held
+    pub sound: bool,                  // Bat, glove and crowd sounds
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            batter_aggressiveness: DEFAULT_BATTER_AGGRESSIVENESS,
+            demo_mode: false,
+            data_dir: DEFAULT_DATA_DIR.to_string(),
+            season: DEFAULT_SEASON,
+            league_file: None,
+            pitch_clock_frames: PITCH_CLOCK_FRAMES,
+            pitcher_clock_frames: PITCHER_CLOCK_FRAMES,
+            allow_same_team: false,
+            max_innings: 0,
+            momentum_effects: true,
+            color_scheme: ColorScheme::Standard,
+            tutorial_done: false,
+            game_speed: GameSpeed::Normal,
+            pitch_preview: false,
+            batters_eye: true,
+            aim_repeat_ms: AIM_REPEAT_WINDOW_MS,
+            pitchers_bat: false,
+            fast_forward: FAST_FORWARD_MULTIPLIER,
+            sound: true,
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Apply command-line overrides: `--demo`, `--data-dir <path>`, `--season <year>`,
+    /// `--league <file>`, `--max-innings <n>` and `--speed <slow|normal|fast|instant>`
+    pub fn apply_args<I: IntoIterator<Item = String>>(&mut self, args: I) -> Result<(), String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--demo" => self.demo_mode = true,
+                "--data-dir" => {
+                    self.data_dir = args.next().ok_or("--data-dir needs a path")?;
+                }
+                "--season" => {
+                    let season = args.next().ok_or("--season needs a year")?;
+                    self.season = season
+                        .parse()
+                        .map_err(|_| format!("--season expects a year, got '{}'", season))?;
+                }
+                "--league" => {
+                    self.league_file = Some(args.next().ok_or("--league needs a file")?);
+                }
+                "--max-innings" => {
+                    let innings = args.next().ok_or("--max-innings needs a number")?;
+                    self.max_innings = innings
+                        .parse()
+                        .map_err(|_| format!("--max-innings expects a number of innings, got '{}'", innings))?;
+                }
+                "--speed" => {
+                    let speed = args.next().ok_or("--speed needs slow, normal, fast or instant")?;
+                    self.game_speed = GameSpeed::from_flag(&speed)
+                        .ok_or_else(|| format!("--speed expects slow, normal, fast or instant, got '{}'", speed))?;
+                }
+                other => return Err(format!("Unknown argument '{}'", other)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Make sure the configured team data exists, falling back to the built-in data
+    /// directory when it doesn't. Returns a warning for the status line if anything was reset.
+    pub fn check_data_source(&mut self) -> Option<String> {
+        let mut warnings = Vec::new();
+        if let Some(league) = &self.league_file {
+            if !Path::new(league).is_file() {
+                warnings.push(format!("League file {} not found", league));
+                self.league_file = None;
+            }
+        }
+        if self.data_dir != DEFAULT_DATA_DIR && !Path::new(&self.data_dir).is_dir() {
+            warnings.push(format!("Data directory {} not found", self.data_dir));
+            self.data_dir = DEFAULT_DATA_DIR.to_string();
+        }
+
+        if warnings.is_empty() {
+            None
+        } else {
+            Some(format!("{}, using the built-in teams", warnings.join("; ")))
+        }
+    }
+
+    /// Record in the config file that the tutorial has been seen, leaving the rest of the
+    /// file - and this session's command line overrides - as they were
+    pub fn save_tutorial_done<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let mut config = if path.exists() { Self::load_from_file(path)? } else { Self::default() };
+        config.migrate();
+        config.tutorial_done = true;
+        config.save_to_file(path)
+    }
+
+    /// How `setting` reads on the settings screen
+    pub fn setting_value(&self, setting: Setting) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        match setting {
+            Setting::MaxInnings if self.max_innings == 0 => "extras until someone wins".to_string(),
+            Setting::MaxInnings => format!("tie ends after {}", self.max_innings),
+            Setting::CpuAggressiveness => format!("{:.1}", self.batter_aggressiveness),
+            Setting::PitchPreview => on_off(self.pitch_preview),
+            Setting::BattersEye => on_off(self.batters_eye),
+            Setting::ColorScheme => match self.color_scheme {
+                ColorScheme::Standard => "standard".to_string(),
+                ColorScheme::ColorBlind => "color blind".to_string(),
+            },
+            Setting::Sound => on_off(self.sound),
+            Setting::GameSpeed => self.game_speed.name().to_string(),
+            Setting::FastForward => format!("{}x", self.fast_forward),
+        }
+    }
+
+    /// Move `setting` to its next value (`forward`) or its previous one. Choices wrap around,
+    /// numbers stop at the ends of their range.
+    pub fn step_setting(&mut self, setting: Setting, forward: bool) {
+        match setting {
+            Setting::MaxInnings => {
+                let choices = MAX_INNINGS_CHOICES.len();
+                let current = MAX_INNINGS_CHOICES.iter().position(|&n| n == self.max_innings).unwrap_or(0);
+                let next = (if forward { current + 1 } else { current + choices - 1 }) % choices;
+                self.max_innings = MAX_INNINGS_CHOICES[next];
+            }
+            Setting::CpuAggressiveness => {
+                let step = if forward { BATTER_AGGRESSIVENESS_STEP } else { -BATTER_AGGRESSIVENESS_STEP };
+                // Rounded to the step so repeated presses don't drift
+                self.batter_aggressiveness = ((self.batter_aggressiveness + step) * 10.0).round().clamp(0.0, 10.0) / 10.0;
+            }
+            Setting::PitchPreview => self.pitch_preview = !self.pitch_preview,
+            Setting::BattersEye => self.batters_eye = !self.batters_eye,
+            Setting::ColorScheme => {
+                self.color_scheme = match self.color_scheme {
+                    ColorScheme::Standard => ColorScheme::ColorBlind,
+                    ColorScheme::ColorBlind => ColorScheme::Standard,
+                }
+            }
+            Setting::Sound => self.sound = !self.sound,
+            Setting::GameSpeed => self.game_speed = if forward { self.game_speed.faster() } else { self.game_speed.slower() },
+            Setting::FastForward => {
+                let choices = FAST_FORWARD_CHOICES.len();
+                let current = FAST_FORWARD_CHOICES.iter().position(|&n| n == self.fast_forward).unwrap_or(0);
+                let next = (if forward { current + 1 } else { current + choices - 1 }) % choices;
+                self.fast_forward = FAST_FORWARD_CHOICES[next];
+            }
+        }
+    }
+
+    /// Copy every setting the settings screen covers from `other`
+    pub fn copy_settings_from(&mut self, other: &GameConfig) {
+        self.max_innings = other.max_innings;
+        self.batter_aggressiveness = other.batter_aggressiveness;
+        self.pitch_preview = other.pitch_preview;
+        self.batters_eye = other.batters_eye;
+        self.color_scheme = other.color_scheme;
+        self.sound = other.sound;
+        self.game_speed = other.game_speed;
+        self.fast_forward = other.fast_forward;
+    }
+
+    /// Write the settings screen's settings to the config file, leaving the rest of the file -
+    /// and this session's command line overrides - as they were
+    pub fn save_settings<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let mut config = if path.exists() { Self::load_from_file(path)? } else { Self::default() };
+        config.migrate();
+        config.copy_settings_from(self);
+        config.save_to_file(path)
+    }
+
+    /// Bring an older config up to the current version. Returns true if anything changed.
+    pub fn migrate(&mut self) -> bool {
+        if self.version >= CONFIG_VERSION {
+            return false;
+        }
+        // Defaults for new fields were already filled in by serde
+        self.version = CONFIG_VERSION;
+        true
+    }
+
+    /// Load the config file if present, falling back to defaults when it's missing or unreadable.
+    /// Never fails - problems come back as a warning for the status line.
+    pub fn load_or_default() -> (Self, Option<String>) {
+        Self::load_or_default_from(CONFIG_FILE)
+    }
+
+    pub fn load_or_default_from<P: AsRef<Path>>(path: P) -> (Self, Option<String>) {
+        let path = path.as_ref();
+        if !path.exists() {
+            return (Self::default(), None);
+        }
+
+        let mut config = match Self::load_from_file(path) {
+            Ok(config) => config,
+            Err(e) => {
+                let warning = format!("Ignoring unreadable {} ({}), using defaults", path.display(), e);
+                return (Self::default(), Some(warning));
+            }
+        };
+        config.fast_forward = config.fast_forward.clamp(1, FAST_FORWARD_MAX);
+
+        if !config.migrate() {
+            return (config, None);
+        }
+
+        let warning = match config.save_to_file(path) {
+            Ok(()) => format!("Updated {} to config version {}", path.display(), CONFIG_VERSION),
+            Err(e) => format!("Config is from an older version and couldn't be rewritten: {}", e),
+        };
+        (config, Some(warning))
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{ColorScheme, GameConfig, GameSpeed, Setting, CONFIG_VERSION};
+    use crate::game::constants::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_config(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("bitbatter_{}_{}.json", name, std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_legacy_config_gets_defaults_and_is_migrated() {
+        let path = temp_config("legacy", r#"{ "season": 2023 }"#);
+
+        let (config, warning) = GameConfig::load_or_default_from(&path);
+
+        assert_eq!(config.season, 2023);
+        assert_eq!(config.batter_aggressiveness, DEFAULT_BATTER_AGGRESSIVENESS);
+        assert!(!config.demo_mode);
+        assert_eq!(config.data_dir, DEFAULT_DATA_DIR);
+        assert_eq!(config.pitch_clock_frames, PITCH_CLOCK_FRAMES);
+        assert_eq!(config.pitcher_clock_frames, PITCHER_CLOCK_FRAMES);
+        assert_eq!(config.max_innings, 0);
+        assert_eq!(config.color_scheme, ColorScheme::Standard);
+        assert!(!config.tutorial_done);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(warning.is_some());
+
+        // File was rewritten, so the next load is quiet
+        let (reloaded, warning) = GameConfig::load_or_default_from(&path);
+        assert_eq!(reloaded, config);
+        assert!(warning.is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_finishing_the_tutorial_only_touches_its_own_field() {
+        let path = temp_config("tutorial", r#"{ "version": 8, "season": 2023 }"#);
+
+        GameConfig::save_tutorial_done(&path).unwrap();
+
+        let (config, warning) = GameConfig::load_or_default_from(&path);
+        assert!(config.tutorial_done);
+        assert_eq!(config.season, 2023);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(warning.is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_config_falls_back_to_defaults() {
+        let path = temp_config("malformed", "{ not json");
+
+        let (config, warning) = GameConfig::load_or_default_from(&path);
+
+        assert_eq!(config, GameConfig::default());
+        assert!(warning.is_some());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_config_is_silent() {
+        let path = std::env::temp_dir().join("bitbatter_does_not_exist.json");
+        let (config, warning) = GameConfig::load_or_default_from(path);
+        assert_eq!(config, GameConfig::default());
+        assert!(warning.is_none());
+    }
+
+    #[test]
+    fn test_current_config_round_trips() {
+        let config = GameConfig { demo_mode: true, ..GameConfig::default() };
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed: GameConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_cli_args_override_config() {
+        let mut config = GameConfig::default();
+        let args = ["--data-dir", "/tmp/stats", "--season", "2024", "--demo", "--max-innings", "12", "--speed", "fast"];
+        config.apply_args(args.iter().map(|a| a.to_string())).unwrap();
+
+        assert_eq!(config.data_dir, "/tmp/stats");
+        assert_eq!(config.season, 2024);
+        assert!(config.demo_mode);
+        assert_eq!(config.max_innings, 12);
+        assert_eq!(config.game_speed, GameSpeed::Fast);
+    }
+
+    #[test]
+    fn test_bad_cli_args_are_rejected() {
+        let mut config = GameConfig::default();
+        assert!(config.apply_args(["--season".to_string(), "next".to_string()]).is_err());
+        assert!(config.apply_args(["--data-dir".to_string()]).is_err());
+        assert!(config.apply_args(["--bogus".to_string()]).is_err());
+        assert!(config.apply_args(["--max-innings".to_string(), "lots".to_string()]).is_err());
+        assert!(config.apply_args(["--speed".to_string(), "ludicrous".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_missing_data_source_falls_back_with_warning() {
+        let mut config = GameConfig::default();
+        let args = ["--data-dir", "/nonexistent/stats", "--league", "/nonexistent/league.json"];
+        config.apply_args(args.iter().map(|a| a.to_string())).unwrap();
+        assert_eq!(config.league_file.as_deref(), Some("/nonexistent/league.json"));
+
+        let warning = config.check_data_source().unwrap();
+        assert!(warning.contains("league.json"), "unexpected warning: {}", warning);
+        assert_eq!(config.data_dir, DEFAULT_DATA_DIR);
+        assert_eq!(config.league_file, None);
+
+        // Nothing to report once it points at real data
+        config.league_file = Some(format!("{}/fixtures/league.json", env!("CARGO_MANIFEST_DIR")));
+        assert_eq!(config.check_data_source(), None);
+    }
+
+    #[test]
+    fn test_out_of_range_fast_forward_is_clamped_on_load() {
+        let path = temp_config("fast_forward", r#"{ "fast_forward": 1000 }"#);
+        let (config, _) = GameConfig::load_or_default_from(&path);
+        assert_eq!(config.fast_forward, FAST_FORWARD_MAX);
+        fs::remove_file(&path).unwrap();
+
+        let path = temp_config("fast_forward_zero", r#"{ "fast_forward": 0 }"#);
+        let (config, _) = GameConfig::load_or_default_from(&path);
+        assert_eq!(config.fast_forward, 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_color_scheme_reads_by_name() {
+        let path = temp_config("color_blind", r#"{ "color_scheme": "color_blind" }"#);
+        let (config, _) = GameConfig::load_or_default_from(&path);
+        assert_eq!(config.color_scheme, ColorScheme::ColorBlind);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_stepping_settings_wraps_choices_and_stops_numbers_at_the_ends() {
+        let mut config = GameConfig::default();
+        config.step_setting(Setting::MaxInnings, false);
+        assert_eq!(config.max_innings, *MAX_INNINGS_CHOICES.last().unwrap());
+        config.step_setting(Setting::MaxInnings, true);
+        assert_eq!(config.max_innings, 0);
+        assert_eq!(config.setting_value(Setting::MaxInnings), "extras until someone wins");
+
+        for _ in 0..20 {
+            config.step_setting(Setting::CpuAggressiveness, true);
+        }
+        assert_eq!(config.batter_aggressiveness, 1.0);
+        config.step_setting(Setting::CpuAggressiveness, false);
+        assert_eq!(config.setting_value(Setting::CpuAggressiveness), "0.9");
+
+        config.step_setting(Setting::GameSpeed, false);
+        config.step_setting(Setting::GameSpeed, false);
+        assert_eq!(config.game_speed, GameSpeed::Slow);
+        assert_eq!(config.setting_value(Setting::FastForward), "4x");
+        config.step_setting(Setting::FastForward, true);
+        config.step_setting(Setting::FastForward, true);
+        assert_eq!(config.fast_forward, FAST_FORWARD_CHOICES[0]);
+        // Game options come first, then the display settings
+        let display = Setting::ALL.iter().position(|s| s.is_display()).unwrap();
+        assert!(Setting::ALL[display..].iter().all(|s| s.is_display()));
+    }
+
+    #[test]
+    fn test_applying_a_display_setting_saves_it_and_leaves_the_rest_of_the_file() {
+        let path = temp_config("settings", r#"{ "data_dir": "my_data", "color_scheme": "standard" }"#);
+        let (mut config, _) = GameConfig::load_or_default_from(&path);
+        // A command line override this session shouldn't end up in the file
+        config.data_dir = "from_the_command_line".to_string();
+
+        config.step_setting(Setting::ColorScheme, true);
+        assert_eq!(config.color_scheme, ColorScheme::ColorBlind);
+        assert_eq!(config.setting_value(Setting::ColorScheme), "color blind");
+        config.save_settings(&path).unwrap();
+
+        let (saved, warning) = GameConfig::load_or_default_from(&path);
+        assert!(warning.is_none());
+        assert_eq!(saved.color_scheme, ColorScheme::ColorBlind);
+        assert_eq!(saved.data_dir, "my_data");
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use crate::config::GameConfig;
+use crate::team::team_key;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+const SAVANT_LEADERBOARD_URL: &str = "https://baseballsavant.mlb.com/leaderboard/statcast";
+const MLB_TEAMS: [&str; 30] = [
+    "ARI", "ATL", "BAL", "BOS", "CHC", "CIN", "CLE", "COL", "CWS", "DET",
+    "HOU", "KC", "LAA", "LAD", "MIA", "MIL", "MIN", "NYM", "NYY", "OAK",
+    "PHI", "PIT", "SD", "SEA", "SF", "STL", "TB", "TEX", "TOR", "WSH"
+];
+const PLAYER_TYPES: [&str; 2] = ["batter", "pitcher"];
+const REQUEST_DELAY_MS: u64 = 1500; // Be polite to Baseball Savant between requests
+const REQUIRED_COLUMNS: [&str; 4] = ["last_name, first_name", "player_id", "attempts", "brl_percent"];
+
+/// Leaderboard CSV export for one team and player type
+pub fn leaderboard_url(player_type: &str, abbr: &str, season: u16) -> String {
+    format!(
+        "{}?type={}&year={}&position=&team={}&min=q&csv=true",
+        SAVANT_LEADERBOARD_URL, player_type, season, abbr
+    )
+}
+
+/// Make sure we got a statcast CSV and not an error page before it overwrites anything
+pub fn validate_header(body: &str) -> Result<(), String> {
+    let mut rdr = csv::Reader::from_reader(body.as_bytes());
+    let headers = rdr.headers().map_err(|e| format!("unreadable header: {}", e))?;
+    let missing: Vec<&str> = REQUIRED_COLUMNS
+        .iter()
+        .copied()
+        .filter(|column| !headers.iter().any(|h| h.trim_start_matches('\u{feff}') == *column))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("missing columns: {}", missing.join(", ")))
+    }
+}
+
+/// Savant leaves blank cells for small samples; the loader expects numbers
+pub fn fill_empty_cells(body: &str) -> Result<String, csv::Error> {
+    let mut rdr = csv::ReaderBuilder::new().has_headers(false).from_reader(body.as_bytes());
+    let mut wtr = csv::Writer::from_writer(Vec::new());
+    for (idx, record) in rdr.records().enumerate() {
+        let record = record?;
+        if idx == 0 {
+            wtr.write_record(&record)?;
+        } else {
+            wtr.write_record(record.iter().map(|cell| if cell.trim().is_empty() { "0" } else { cell }))?;
+        }
+    }
+    let bytes = wtr.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn download_file(player_type: &str, abbr: &str, season: u16, data_dir: &Path) -> Result<(), String> {
+    let body = ureq::get(&leaderboard_url(player_type, abbr, season))
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_string()
+        .map_err(|e| e.to_string())?;
+    validate_header(&body)?;
+    let cleaned = fill_empty_cells(&body).map_err(|e| e.to_string())?;
+
+    let path = data_dir.join(format!("{}_{}.csv", player_type, team_key(abbr, season)));
+    fs::write(&path, cleaned).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Fetch every MLB team's batter and pitcher CSVs for the configured season.
+/// Individual failures are reported at the end instead of stopping the run.
+pub fn download_all(config: &GameConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = Path::new(&config.data_dir);
+    fs::create_dir_all(data_dir)?;
+
+    let teams = MLB_TEAMS;
+    let total = teams.len() * PLAYER_TYPES.len();
+    let mut failures = Vec::new();
+    let mut done = 0;
+
+    for abbr in &teams {
+        for player_type in PLAYER_TYPES {
+            done += 1;
+            println!("[{}/{}] {} {} {}...", done, total, config.season, abbr, player_type);
+            if let Err(e) = download_file(player_type, abbr, config.season, data_dir) {
+                println!("    failed: {}", e);
+                failures.push(format!("{} {}: {}", abbr, player_type, e));
+            }
+            thread::sleep(Duration::from_millis(REQUEST_DELAY_MS));
+        }
+    }
+
+    println!("\nDownloaded {} of {} files into {}", total - failures.len(), total, data_dir.display());
+    for failure in &failures {
+        println!("  FAILED {}", failure);
+    }
+    Ok(())
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::download::{fill_empty_cells, leaderboard_url, validate_header};
+
+    #[test]
+    fn test_leaderboard_url() {
+        assert_eq!(
+            leaderboard_url("pitcher", "LAD", 2024),
+            "https://baseballsavant.mlb.com/leaderboard/statcast?type=pitcher&year=2024&position=&team=LAD&min=q&csv=true"
+        );
+    }
+
+    #[test]
+    fn test_validate_header_rejects_html() {
+        assert!(validate_header("<html><body>Too many requests</body></html>").is_err());
+        let csv = "\"last_name, first_name\",player_id,attempts,brl_percent\n\"A, B\",1,100,5.0\n";
+        assert!(validate_header(csv).is_ok());
+    }
+
+    #[test]
+    fn test_fill_empty_cells() {
+        let filled = fill_empty_cells("a,b,c\n1,,3\n").unwrap();
+        assert_eq!(filled, "a,b,c\n1,0,3\n");
+    }
+}
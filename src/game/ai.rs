@@ -0,0 +1,89 @@
+use crate::game::{constants::*, state::PitchLocation};
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+/// A CPU batter's plan for the pitch currently approaching
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwingDecision {
+    pub location: PitchLocation,
+    pub frames_left: u8, // Swing once the ball is this many frames from the plate
+}
+
+/// CPU batter whose swing decisions scale with aggressiveness
+#[derive(Debug, Clone, Copy)]
+pub struct BatterAi {
+    pub aggressiveness: f32, // 0.0 (takes everything it can) to 1.0 (free swinger)
+}
+
+impl Default for BatterAi {
+    fn default() -> Self {
+        Self::new(DEFAULT_BATTER_AGGRESSIVENESS)
+    }
+}
+
+impl BatterAi {
+    pub fn new(aggressiveness: f32) -> Self {
+        Self {
+            aggressiveness: aggressiveness.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Chance of offering at a pitch, depending on whether it's in the zone
+    pub fn swing_probability(&self, pitch_location: PitchLocation) -> f64 {
+        let aggressiveness = self.aggressiveness as f64;
+        if pitch_location.is_strike() {
+            AI_SWING_AT_STRIKE_MIN + (AI_SWING_AT_STRIKE_MAX - AI_SWING_AT_STRIKE_MIN) * aggressiveness
+        } else {
+            AI_CHASE_MIN + (AI_CHASE_MAX - AI_CHASE_MIN) * aggressiveness
+        }
+    }
+
+    pub fn wants_to_swing<R: Rng>(&self, pitch_location: PitchLocation, rng: &mut R) -> bool {
+        rng.gen_bool(self.swing_probability(pitch_location))
+    }
+
+    /// Where the batter aims the swing - usually the real location, sometimes a guess
+    pub fn read_location<R: Rng>(&self, pitch_location: PitchLocation, rng: &mut R) -> PitchLocation {
+        if rng.gen_bool(AI_LOCATION_READ_RATE) {
+            pitch_location
+        } else {
+            PitchLocation::from_numpad(rng.gen_range(1..=9))
+        }
+    }
+
+    /// Decide how to handle an approaching pitch. `None` means take it.
+    pub fn decide<R: Rng>(&self, pitch_location: PitchLocation, rng: &mut R) -> Option<SwingDecision> {
+        if !self.wants_to_swing(pitch_location, rng) {
+            return None;
+        }
+
+        let location = self.read_location(pitch_location, rng);
+        let frames_left = rng.gen_range(swing_frames()).max(1);
+        Some(SwingDecision { location, frames_left })
+    }
+}
+
+/// Frames from the plate a CPU batter starts its swing, each equally likely. A roll of 0 swings at 1.
+pub fn swing_frames() -> RangeInclusive<u8> {
+    let target = PERFECT_TIMING_WINDOW_FRAMES;
+    target.saturating_sub(AI_SWING_TIMING_SPREAD_FRAMES)..=target + AI_SWING_TIMING_SPREAD_FRAMES
+}
+
+/// CPU pitcher's pitch selection
+pub fn choose_pitch_type<R: Rng>(pitch_count: usize, rng: &mut R) -> usize {
+    if pitch_count == 0 {
+        0
+    } else {
+        rng.gen_range(0..pitch_count)
+    }
+}
+
+/// CPU pitcher's aim - works the zone most of the time and nibbles at the corners otherwise
+pub fn choose_pitch_location<R: Rng>(rng: &mut R) -> PitchLocation {
+    use PitchLocation::*;
+    if rng.gen_bool(AI_PITCHER_ZONE_RATE) {
+        [Up, Inside, Middle, Outside, Down][rng.gen_range(0..5)]
+    } else {
+        [UpInside, UpOutside, DownInside, DownOutside][rng.gen_range(0..4)]
+    }
+}
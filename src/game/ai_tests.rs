@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameConfig;
+    use crate::game::ai::{self, BatterAi};
+    use crate::game::{constants::*, GameEngine, GameMode, GameState, PitchLocation, PitchState};
+    use crate::input::InputState;
+    use crate::logger::GameLogger;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const TRIALS: u32 = 10_000;
+
+    fn swings_at(batter_ai: BatterAi, location: PitchLocation, seed: u64) -> u32 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..TRIALS)
+            .filter(|_| batter_ai.decide(location, &mut rng).is_some())
+            .count() as u32
+    }
+
+    fn tick(state: &mut GameState, engine: &GameEngine) {
+        let mut input_state = InputState::new();
+        let log_path = std::env::temp_dir().join("bitbatter_ai_tests.log");
+        let logger = GameLogger::with_path(log_path.to_string_lossy());
+        crate::game::update::update_game_state(
+            state,
+            engine,
+            &mut input_state,
+            None,
+            &logger,
+        );
+    }
+
+    #[test]
+    fn test_aggressive_batter_swings_at_strikes_more_often() {
+        let aggressive = swings_at(BatterAi::new(0.9), PitchLocation::Middle, 1566);
+        let passive = swings_at(BatterAi::new(0.1), PitchLocation::Middle, 1566);
+        assert!(
+            aggressive > passive,
+            "aggressive swung {} times, passive {} times",
+            aggressive,
+            passive
+        );
+    }
+
+    #[test]
+    fn test_batter_chases_less_than_it_swings_at_strikes() {
+        let batter_ai = BatterAi::default();
+        let strikes = swings_at(batter_ai, PitchLocation::Middle, 7);
+        let chases = swings_at(batter_ai, PitchLocation::DownOutside, 7);
+        assert!(strikes > chases);
+    }
+
+    #[test]
+    fn test_aggressiveness_is_clamped() {
+        assert_eq!(BatterAi::new(3.0).aggressiveness, 1.0);
+        assert_eq!(BatterAi::new(-1.0).aggressiveness, 0.0);
+    }
+
+    #[test]
+    fn test_swing_plan_lands_near_perfect_window() {
+        let batter_ai = BatterAi::new(1.0);
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..1_000 {
+            if let Some(plan) = batter_ai.decide(PitchLocation::Middle, &mut rng) {
+                assert!(plan.frames_left >= 1);
+                assert!(plan.frames_left <= PERFECT_TIMING_WINDOW_FRAMES + AI_SWING_TIMING_SPREAD_FRAMES);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpu_pitch_type_stays_in_range() {
+        let mut rng = StdRng::seed_from_u64(11);
+        assert_eq!(ai::choose_pitch_type(0, &mut rng), 0);
+        for _ in 0..100 {
+            assert!(ai::choose_pitch_type(4, &mut rng) < 4);
+        }
+    }
+
+    #[test]
+    fn test_demo_mode_cpu_pitcher_starts_pitch_clock() {
+        let engine = GameEngine::with_seed(2);
+        let config = GameConfig {
+            demo_mode: true,
+            ..GameConfig::default()
+        };
+        let mut state = GameState::with_config(config);
+        state.mode = GameMode::Playing;
+
+        tick(&mut state, &engine);
+
+        assert!(matches!(state.pitch_state, PitchState::PitchClock { .. }));
+        assert!(state.pitch_location.is_some());
+    }
+}
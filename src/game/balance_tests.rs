@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::ballpark::Ballpark;
+    use crate::game::{constants::*, GameEngine};
+    use crate::team::{Player, PlayerStats, Position};
+
+    const SIMULATED_PLATE_APPEARANCES: u32 = 100_000;
+    const BALANCE_SEED: u64 = 1565;
+
+    fn average_stats(name: &str, barrel_percent: f32) -> PlayerStats {
+        PlayerStats {
+            name: name.to_string(),
+            id: "0".to_string(),
+            attempts: 300,
+            avg_hit_angle: 13.0,
+            sweet_spot_percent: 33.0,
+            max_hit_speed: 112.0,
+            avg_hit_speed: 89.0,
+            ev50: 100.0,
+            fbld: 93.0,
+            gb: 86.5,
+            max_distance: 430,
+            avg_distance: 170,
+            avg_hr_distance: 396,
+            ev95plus: 130,
+            ev95_percent: 42.0,
+            barrels: 35,
+            barrel_percent,
+            barrel_pa: 7.0,
+        }
+    }
+
+    fn average_batter() -> Player {
+        Player {
+            stats: average_stats("Average, Batter", 12.6),
+            is_pitcher: false,
+            position: Position::CenterField,
+            arsenal: Vec::new(),
+            pitching: None,
+            sprint_speed: LEAGUE_AVG_SPRINT_SPEED,
+            bats: None,
+            throws: None,
+            outs_above_average: 0,
+        }
+    }
+
+    fn average_pitcher() -> Player {
+        Player {
+            stats: average_stats("Average, Pitcher", 9.2),
+            is_pitcher: true,
+            position: Position::Pitcher,
+            arsenal: Vec::new(),
+            pitching: None,
+            sprint_speed: LEAGUE_AVG_SPRINT_SPEED,
+            bats: None,
+            throws: None,
+            outs_above_average: 0,
+        }
+    }
+
+    #[test]
+    fn test_average_matchup_produces_plausible_rates() {
+        let engine = GameEngine::with_seed(BALANCE_SEED);
+        let batter = average_batter();
+        let pitcher = average_pitcher();
+
+        let summary = engine.simulate_plate_appearances(
+            SIMULATED_PLATE_APPEARANCES,
+            Some(&batter),
+            Some(&pitcher),
+            FATIGUE_PENALTY_FRESH,
+        );
+
+        let avg = summary.batting_average();
+        let hr_rate = summary.home_runs_per_contact();
+        let k_rate = summary.strikeout_rate();
+        let bb_rate = summary.walk_rate();
+        let report = format!(
+            "AVG {:.3}, HR/contact {:.3}, K% {:.3}, BB% {:.3}",
+            avg, hr_rate, k_rate, bb_rate
+        );
+
+        assert!((0.230..=0.320).contains(&avg), "batting average out of range: {}", report);
+        assert!((0.02..=0.06).contains(&hr_rate), "home run rate out of range: {}", report);
+        assert!((0.15..=0.30).contains(&k_rate), "strikeout rate out of range: {}", report);
+        assert!((0.06..=0.12).contains(&bb_rate), "walk rate out of range: {}", report);
+    }
+
+    #[test]
+    fn test_seeded_simulation_is_deterministic() {
+        let batter = average_batter();
+        let pitcher = average_pitcher();
+
+        let first = GameEngine::with_seed(BALANCE_SEED)
+            .simulate_plate_appearances(1_000, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH);
+        let second = GameEngine::with_seed(BALANCE_SEED)
+            .simulate_plate_appearances(1_000, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH);
+
+        assert_eq!(first.hits, second.hits);
+        assert_eq!(first.home_runs, second.home_runs);
+        assert_eq!(first.strikeouts, second.strikeouts);
+        assert_eq!(first.walks, second.walks);
+    }
+
+    #[test]
+    fn test_simulated_home_runs_come_from_the_ball_in_play() {
+        // Only the fielding path knows the park, so a hitter's park shows up in the tally
+        // only if that's where the simulation's home runs are decided
+        let home_runs = |ballpark: Ballpark| {
+            let engine = GameEngine::with_seed(BALANCE_SEED);
+            engine.set_ballpark(ballpark);
+            engine
+                .simulate_plate_appearances(20_000, Some(&average_batter()), Some(&average_pitcher()), FATIGUE_PENALTY_FRESH)
+                .home_runs
+        };
+        let neutral = home_runs(Ballpark::NEUTRAL);
+        let coors = home_runs(Ballpark::lookup("Coors Field"));
+        assert!(coors > neutral, "Coors {} vs neutral {}", coors, neutral);
+    }
+
+    #[test]
+    fn test_triples_are_the_rarest_hit_short_of_a_home_run() {
+        let engine = GameEngine::with_seed(BALANCE_SEED);
+        let summary = engine.simulate_plate_appearances(
+            SIMULATED_PLATE_APPEARANCES,
+            Some(&average_batter()),
+            Some(&average_pitcher()),
+            FATIGUE_PENALTY_FRESH,
+        );
+
+        let singles = summary.hits - summary.doubles - summary.triples - summary.home_runs;
+        let triple_share = summary.triples as f32 / summary.hits as f32;
+        let report = format!(
+            "1B {}, 2B {}, 3B {}, HR {} ({:.3} of hits are triples)",
+            singles, summary.doubles, summary.triples, summary.home_runs, triple_share
+        );
+
+        assert!(summary.triples > 0, "no triples at all: {}", report);
+        assert!(summary.triples < summary.doubles && summary.triples < singles, "{}", report);
+        assert!(triple_share < 0.05, "too many triples: {}", report);
+    }
+}
+
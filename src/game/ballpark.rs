@@ -0,0 +1,117 @@
+/// Fence distances and home run factor for a stadium
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ballpark {
+    pub name: &'static str,
+    pub left_field: u16,   // Feet down the left field line
+    pub center_field: u16, // Feet to straightaway center
+    pub right_field: u16,  // Feet down the right field line
+    pub park_factor: f32,  // Home run multiplier, 1.0 = neutral
+}
+
+impl Ballpark {
+    /// Used for custom teams and any park missing from the table
+    pub const NEUTRAL: Ballpark = Ballpark::new("Neutral Park", 330, 400, 330, 1.0);
+
+    const fn new(name: &'static str, left_field: u16, center_field: u16, right_field: u16, park_factor: f32) -> Self {
+        Self { name, left_field, center_field, right_field, park_factor }
+    }
+
+    /// Look up a park by name, falling back to neutral dimensions
+    pub fn lookup(name: &str) -> Ballpark {
+        BALLPARKS
+            .iter()
+            .find(|park| park.name == name)
+            .copied()
+            .unwrap_or(Ballpark::NEUTRAL)
+    }
+
+    /// Domes and retractable roofs - the weather never gets a say
+    pub fn has_roof(&self) -> bool {
+        ROOFED_PARKS.contains(&self.name)
+    }
+}
+
+const ROOFED_PARKS: [&str; 8] = [
+    "Chase Field",
+    "Daikin Park",
+    "loanDepot park",
+    "American Family Field",
+    "T-Mobile Park",
+    "Tropicana Field",
+    "Globe Life Field",
+    "Rogers Centre",
+];
+
+const BALLPARKS: [Ballpark; 30] = [
+    Ballpark::new("Chase Field", 330, 407, 334, 1.0),
+    Ballpark::new("Truist Park", 335, 400, 325, 1.0),
+    Ballpark::new("Oriole Park at Camden Yards", 363, 410, 318, 1.0),
+    Ballpark::new("Fenway Park", 310, 390, 302, 0.95),
+    Ballpark::new("Wrigley Field", 355, 400, 353, 1.0),
+    Ballpark::new("Great American Ball Park", 328, 404, 325, 1.25),
+    Ballpark::new("Progressive Field", 325, 400, 325, 0.95),
+    Ballpark::new("Coors Field", 347, 415, 350, 1.15),
+    Ballpark::new("Rate Field", 330, 400, 335, 1.1),
+    Ballpark::new("Comerica Park", 345, 412, 330, 0.9),
+    Ballpark::new("Daikin Park", 315, 409, 326, 1.05),
+    Ballpark::new("Kauffman Stadium", 330, 410, 330, 0.85),
+    Ballpark::new("Angel Stadium", 347, 396, 350, 1.05),
+    Ballpark::new("Dodger Stadium", 330, 395, 330, 1.15),
+    Ballpark::new("loanDepot park", 344, 400, 335, 0.9),
+    Ballpark::new("American Family Field", 344, 400, 345, 1.1),
+    Ballpark::new("Target Field", 339, 404, 328, 0.95),
+    Ballpark::new("Citi Field", 335, 408, 330, 0.95),
+    Ballpark::new("Yankee Stadium", 318, 408, 314, 1.2),
+    Ballpark::new("Sutter Health Park", 330, 403, 325, 1.0),
+    Ballpark::new("Citizens Bank Park", 329, 401, 330, 1.15),
+    Ballpark::new("PNC Park", 325, 399, 320, 0.85),
+    Ballpark::new("Petco Park", 336, 396, 322, 0.95),
+    Ballpark::new("T-Mobile Park", 331, 401, 326, 0.9),
+    Ballpark::new("Oracle Park", 339, 391, 309, 0.8),
+    Ballpark::new("Busch Stadium", 336, 400, 335, 0.85),
+    Ballpark::new("Tropicana Field", 315, 404, 322, 0.95),
+    Ballpark::new("Globe Life Field", 329, 407, 326, 0.95),
+    Ballpark::new("Rogers Centre", 328, 400, 328, 1.05),
+    Ballpark::new("Nationals Park", 337, 402, 335, 1.0),
+];
+
+/// Home stadium for an MLB team abbreviation
+pub fn home_ballpark_name(abbr: &str) -> &'static str {
+    let parks = [
+        ("ARI", "Chase Field"),
+        ("ATL", "Truist Park"),
+        ("BAL", "Oriole Park at Camden Yards"),
+        ("BOS", "Fenway Park"),
+        ("CHC", "Wrigley Field"),
+        ("CIN", "Great American Ball Park"),
+        ("CLE", "Progressive Field"),
+        ("COL", "Coors Field"),
+        ("CWS", "Rate Field"),
+        ("DET", "Comerica Park"),
+        ("HOU", "Daikin Park"),
+        ("KC", "Kauffman Stadium"),
+        ("LAA", "Angel Stadium"),
+        ("LAD", "Dodger Stadium"),
+        ("MIA", "loanDepot park"),
+        ("MIL", "American Family Field"),
+        ("MIN", "Target Field"),
+        ("NYM", "Citi Field"),
+        ("NYY", "Yankee Stadium"),
+        ("OAK", "Sutter Health Park"),
+        ("PHI", "Citizens Bank Park"),
+        ("PIT", "PNC Park"),
+        ("SD", "Petco Park"),
+        ("SEA", "T-Mobile Park"),
+        ("SF", "Oracle Park"),
+        ("STL", "Busch Stadium"),
+        ("TB", "Tropicana Field"),
+        ("TEX", "Globe Life Field"),
+        ("TOR", "Rogers Centre"),
+        ("WSH", "Nationals Park"),
+    ];
+
+    parks.iter()
+        .find(|(a, _)| *a == abbr)
+        .map(|(_, park)| *park)
+        .unwrap_or(Ballpark::NEUTRAL.name)
+}
@@ -0,0 +1,63 @@
+use crate::game::constants::*;
+
+/// Runner ids on 1st, 2nd and 3rd
+pub type Bases = [Option<String>; BASES_COUNT];
+
+/// How the batter reached base
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatterAdvance {
+    Walk,     // Only runners forced by the batter move
+    Hit(u8),  // Bases the batter takes - everyone moves up as many, 4 is a home run
+}
+
+/// Put the batter on and move the runners up. Returns the new bases and the runners who
+/// scored, in the order they crossed the plate (the batter last on a home run).
+/// `takes_extra_base` is asked about the runner who starts on second on a single - whether
+/// they keep going and score rather than holding at third.
+pub fn advance_runners(
+    bases: &Bases,
+    batter: &str,
+    advance: BatterAdvance,
+    mut takes_extra_base: impl FnMut(&str) -> bool,
+) -> (Bases, Vec<String>) {
+    let mut bases = bases.clone();
+    let mut scored = Vec::new();
+
+    match advance {
+        BatterAdvance::Walk => {
+            // A runner only moves when every base behind them is taken
+            if bases[0].is_some() {
+                if bases[1].is_some() {
+                    scored.extend(bases[2].take());
+                    bases[2] = bases[1].take();
+                }
+                bases[1] = bases[0].take();
+            }
+            bases[0] = Some(batter.to_string());
+        }
+        BatterAdvance::Hit(batter_bases) => {
+            // Lead runner first so nobody is overwritten; everyone takes as many bases as the
+            // batter, except that a runner on first comes all the way around on a double
+            for base in (0..BASES_COUNT).rev() {
+                if let Some(runner) = bases[base].take() {
+                    let extra = match (base, batter_bases) {
+                        (0, 2) => 1,
+                        (1, 1) => usize::from(takes_extra_base(&runner)),
+                        _ => 0,
+                    };
+                    match base + batter_bases as usize + extra {
+                        target if target >= BASES_COUNT => scored.push(runner),
+                        target => bases[target] = Some(runner),
+                    }
+                }
+            }
+
+            match batter_bases as usize {
+                advance if advance > BASES_COUNT => scored.push(batter.to_string()), // Home run
+                advance => bases[advance.max(1) - 1] = Some(batter.to_string()),
+            }
+        }
+    }
+
+    (bases, scored)
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::baserunning::{advance_runners, Bases, BatterAdvance};
+
+    fn bases(first: Option<&str>, second: Option<&str>, third: Option<&str>) -> Bases {
+        [first.map(String::from), second.map(String::from), third.map(String::from)]
+    }
+
+    #[test]
+    fn test_bases_loaded_walk_forces_in_a_run() {
+        let loaded = bases(Some("R1"), Some("R2"), Some("R3"));
+        let (after, scored) = advance_runners(&loaded, "B", BatterAdvance::Walk, |_| true);
+        assert_eq!(after, bases(Some("B"), Some("R1"), Some("R2")));
+        assert_eq!(scored, vec!["R3"]);
+    }
+
+    #[test]
+    fn test_walk_only_moves_forced_runners() {
+        let corners = bases(Some("R1"), None, Some("R3"));
+        let (after, scored) = advance_runners(&corners, "B", BatterAdvance::Walk, |_| true);
+        assert_eq!(after, bases(Some("B"), Some("R1"), Some("R3")));
+        assert!(scored.is_empty());
+
+        let second_only = bases(None, Some("R2"), None);
+        let (after, _) = advance_runners(&second_only, "B", BatterAdvance::Walk, |_| true);
+        assert_eq!(after, bases(Some("B"), Some("R2"), None));
+    }
+
+    #[test]
+    fn test_single_with_runners_on_first_and_second() {
+        let on = bases(Some("R1"), Some("R2"), None);
+        let (after, scored) = advance_runners(&on, "B", BatterAdvance::Hit(1), |_| false);
+        assert_eq!(after, bases(Some("B"), Some("R1"), Some("R2")));
+        assert!(scored.is_empty());
+    }
+
+    #[test]
+    fn test_double_clears_the_bases() {
+        let loaded = bases(Some("R1"), Some("R2"), Some("R3"));
+        let (after, scored) = advance_runners(&loaded, "B", BatterAdvance::Hit(2), |_| false);
+        assert_eq!(after, bases(None, Some("B"), None));
+        assert_eq!(scored, vec!["R3", "R2", "R1"]);
+    }
+
+    #[test]
+    fn test_home_run_with_two_on() {
+        let on = bases(Some("R1"), None, Some("R3"));
+        let (after, scored) = advance_runners(&on, "B", BatterAdvance::Hit(4), |_| false);
+        assert_eq!(after, bases(None, None, None));
+        assert_eq!(scored, vec!["R3", "R1", "B"]);
+    }
+
+    #[test]
+    fn test_runner_on_second_scores_on_a_single_only_when_sent() {
+        let on = bases(None, Some("FAST"), None);
+        let (after, scored) = advance_runners(&on, "B", BatterAdvance::Hit(1), |runner| runner == "FAST");
+        assert_eq!(after, bases(Some("B"), None, None));
+        assert_eq!(scored, vec!["FAST"]);
+
+        let on = bases(Some("R1"), Some("SLOW"), None);
+        let (after, scored) = advance_runners(&on, "B", BatterAdvance::Hit(1), |runner| runner == "FAST");
+        assert_eq!(after, bases(Some("B"), Some("R1"), Some("SLOW")));
+        assert!(scored.is_empty());
+    }
+}
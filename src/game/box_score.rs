@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+/// One batter's line for the current game
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameBatterLine {
+    pub at_bats: u8,
+    pub runs: u8, // Times this player crossed the plate
+    pub hits: u8,
+    pub home_runs: u8,
+    pub walks: u8,
+    pub strikeouts: u8,
+    pub rbi: u8, // Runs driven in - never includes runs that score on errors
+}
+
+/// One pitcher's line for the current game
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamePitcherLine {
+    pub outs_recorded: u16, // Innings pitched in thirds
+    pub strikeouts: u8,
+    pub walks: u8,
+    pub hits: u8,
+    pub runs: u8, // Charged to the pitcher who put the runner on, even after a pitching change
+    pub earned_runs: u8, // Runs that didn't score after an error in the same half inning
+    pub pitches: u16,
+    pub save: bool, // Finished off a win they entered with a lead of SAVE_MAX_LEAD or fewer
+}
+
+impl GamePitcherLine {
+    /// Innings pitched the way a box score writes them - 5.2 is five and two thirds
+    pub fn innings_pitched(&self) -> String {
+        format!("{}.{}", self.outs_recorded / 3, self.outs_recorded % 3)
+    }
+}
+
+/// Per-game batting and pitching lines, keyed by `GameState::current_batter_key` and
+/// `GameState::current_pitcher_key`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoxScore {
+    pub batting: HashMap<String, GameBatterLine>,
+    pub pitching: HashMap<String, GamePitcherLine>,
+    responsible_pitchers: HashMap<String, String>, // Runner on base -> pitcher who put them there
+}
+
+impl BoxScore {
+    pub fn batter_mut(&mut self, key: &str) -> &mut GameBatterLine {
+        self.batting.entry(key.to_string()).or_default()
+    }
+
+    pub fn batter(&self, key: &str) -> Option<&GameBatterLine> {
+        self.batting.get(key)
+    }
+
+    pub fn pitcher_mut(&mut self, key: &str) -> &mut GamePitcherLine {
+        self.pitching.entry(key.to_string()).or_default()
+    }
+
+    pub fn pitcher(&self, key: &str) -> Option<&GamePitcherLine> {
+        self.pitching.get(key)
+    }
+
+    /// A runner reached base off `pitcher` - if they score, it's that pitcher's run
+    pub fn runner_reached(&mut self, runner: &str, pitcher: &str) {
+        self.responsible_pitchers.insert(runner.to_string(), pitcher.to_string());
+    }
+
+    /// A pinch runner inherits whoever was responsible for the runner they replace
+    pub fn runner_replaced(&mut self, out: &str, replacement: &str) {
+        if let Some(pitcher) = self.responsible_pitchers.remove(out) {
+            self.responsible_pitchers.insert(replacement.to_string(), pitcher);
+        }
+    }
+
+    /// Charge a run scored by `runner`, falling back to `current_pitcher` for a batter who
+    /// never stopped on base (a home run)
+    pub fn charge_run(&mut self, runner: &str, current_pitcher: &str, earned: bool) {
+        let pitcher = self
+            .responsible_pitchers
+            .remove(runner)
+            .unwrap_or_else(|| current_pitcher.to_string());
+        let line = self.pitcher_mut(&pitcher);
+        line.runs += 1;
+        if earned {
+            line.earned_runs += 1;
+        }
+    }
+
+    /// The bases are empty again - nobody left on is anyone's responsibility
+    pub fn clear_runners(&mut self) {
+        self.responsible_pitchers.clear();
+    }
+}
@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::game::box_score::{BoxScore, GameBatterLine, GamePitcherLine};
+use crate::game::constants::CAREER_AVG_MIN_AT_BATS;
+
+/// A player's batting across every game they've played in
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CareerBatting {
+    pub name: String,
+    pub games: u32,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub home_runs: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+    pub rbi: u32,
+}
+
+impl CareerBatting {
+    fn add(&mut self, line: &GameBatterLine) {
+        self.games += 1;
+        self.at_bats += line.at_bats as u32;
+        self.runs += line.runs as u32;
+        self.hits += line.hits as u32;
+        self.home_runs += line.home_runs as u32;
+        self.walks += line.walks as u32;
+        self.strikeouts += line.strikeouts as u32;
+        self.rbi += line.rbi as u32;
+    }
+
+    pub fn average(&self) -> f32 {
+        if self.at_bats == 0 {
+            return 0.0;
+        }
+        self.hits as f32 / self.at_bats as f32
+    }
+
+    /// One line for the batter's card, e.g. "12 G, .312, 4 HR, 9 RBI"
+    pub fn summary(&self) -> String {
+        let average = format!("{:.3}", self.average());
+        format!(
+            "{} G, {}, {} HR, {} RBI",
+            self.games,
+            average.trim_start_matches('0'),
+            self.home_runs,
+            self.rbi
+        )
+    }
+}
+
+/// A player's pitching across every game they've appeared in
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CareerPitching {
+    pub name: String,
+    pub games: u32,
+    pub outs_recorded: u32,
+    pub strikeouts: u32,
+    pub walks: u32,
+    pub hits: u32,
+    pub runs: u32,
+    pub earned_runs: u32,
+    pub pitches: u32,
+    pub saves: u32,
+}
+
+impl CareerPitching {
+    fn add(&mut self, line: &GamePitcherLine) {
+        self.games += 1;
+        self.outs_recorded += line.outs_recorded as u32;
+        self.strikeouts += line.strikeouts as u32;
+        self.walks += line.walks as u32;
+        self.hits += line.hits as u32;
+        self.runs += line.runs as u32;
+        self.earned_runs += line.earned_runs as u32;
+        self.pitches += line.pitches as u32;
+        self.saves += line.save as u32;
+    }
+
+    /// Earned runs per nine innings
+    pub fn era(&self) -> f32 {
+        if self.outs_recorded == 0 {
+            return 0.0;
+        }
+        self.earned_runs as f32 * 27.0 / self.outs_recorded as f32
+    }
+
+    pub fn innings_pitched(&self) -> String {
+        format!("{}.{}", self.outs_recorded / 3, self.outs_recorded % 3)
+    }
+}
+
+/// What the career leaders screen ranks players by, one tab each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CareerCategory {
+    HomeRuns,
+    Average,
+    Rbi,
+    Strikeouts, // Pitchers
+}
+
+impl CareerCategory {
+    pub const ALL: [CareerCategory; 4] = [CareerCategory::HomeRuns, CareerCategory::Average, CareerCategory::Rbi, CareerCategory::Strikeouts];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CareerCategory::HomeRuns => "Home Runs",
+            CareerCategory::Average => "Batting Average",
+            CareerCategory::Rbi => "RBI",
+            CareerCategory::Strikeouts => "Strikeouts",
+        }
+    }
+
+    /// How a leader's value reads on screen - ".312" for an average, a whole number otherwise
+    pub fn format(&self, value: f32) -> String {
+        match self {
+            CareerCategory::Average => format!("{:.3}", value).trim_start_matches('0').to_string(),
+            _ => format!("{}", value as u32),
+        }
+    }
+}
+
+/// One row of a career leaders list
+#[derive(Debug, Clone, PartialEq)]
+pub struct CareerLeader {
+    pub name: String,
+    pub value: f32,
+}
+
+/// Every finished game's box score lines added up by player id, kept as JSON next to the config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CareerStats {
+    pub batting: BTreeMap<String, CareerBatting>,
+    pub pitching: BTreeMap<String, CareerPitching>,
+}
+
+impl CareerStats {
+    /// Read the career file - a missing one is an empty career, not an error
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Read the career file for display, falling back to an empty career (and saying why) when
+    /// it can't be read. The file itself is left alone.
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> (Self, Option<String>) {
+        let path = path.as_ref();
+        match Self::load(path) {
+            Ok(career) => (career, None),
+            Err(e) => (Self::default(), Some(format!("Ignoring unreadable {} ({})", path.display(), e))),
+        }
+    }
+
+    /// Written to a temporary file and renamed over the old one, so a crash - or another copy
+    /// of the game saving at the same moment - never leaves it half written
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let temp = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&temp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    /// The top `n` in `category`, best first. Batting average needs CAREER_AVG_MIN_AT_BATS to
+    /// qualify, and a counting stat needs at least one. Ties go by name and then player id so
+    /// the list comes out the same every time.
+    pub fn leaders(&self, category: CareerCategory, n: usize) -> Vec<CareerLeader> {
+        let mut rows: Vec<(&String, &String, f32)> = match category {
+            CareerCategory::Strikeouts => self
+                .pitching
+                .iter()
+                .map(|(id, line)| (id, &line.name, line.strikeouts as f32))
+                .filter(|(_, _, value)| *value > 0.0)
+                .collect(),
+            CareerCategory::Average => self
+                .batting
+                .iter()
+                .filter(|(_, line)| line.at_bats >= CAREER_AVG_MIN_AT_BATS)
+                .map(|(id, line)| (id, &line.name, line.average()))
+                .collect(),
+            _ => self
+                .batting
+                .iter()
+                .map(|(id, line)| {
+                    let value = if category == CareerCategory::HomeRuns { line.home_runs } else { line.rbi };
+                    (id, &line.name, value as f32)
+                })
+                .filter(|(_, _, value)| *value > 0.0)
+                .collect(),
+        };
+        rows.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.1.cmp(b.1)).then_with(|| a.0.cmp(b.0)));
+        rows.into_iter()
+            .take(n)
+            .map(|(_, name, value)| CareerLeader { name: name.clone(), value })
+            .collect()
+    }
+
+    /// Add one game's lines. Placeholder keys - a lineup slot with nobody in it, like
+    /// "NYY_2024#3", or a replacement player, like "NYY#R1" - have a `#`. They aren't real
+    /// players, so they're left out rather than lumped in with every other team's.
+    pub fn add_game(&mut self, box_score: &BoxScore, name_of: impl Fn(&str) -> String) {
+        for (key, line) in box_score.batting.iter().filter(|(key, _)| !key.contains('#')) {
+            let career = self.batting.entry(key.clone()).or_default();
+            career.name = name_of(key);
+            career.add(line);
+        }
+        for (key, line) in box_score.pitching.iter().filter(|(key, _)| !key.contains('#')) {
+            let career = self.pitching.entry(key.clone()).or_default();
+            career.name = name_of(key);
+            career.add(line);
+        }
+    }
+
+    /// Merge a finished game into the file at `path`, reading the whole file fresh right
+    /// before writing it back so games saved since this one started aren't lost. A file that
+    /// can't be read is renamed aside to `.bad` rather than written over, and the career starts
+    /// again from this game - the returned warning says so.
+    pub fn record_game<P: AsRef<Path>>(path: P, box_score: &BoxScore, name_of: impl Fn(&str) -> String) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let (mut career, warning) = match Self::load(path) {
+            Ok(career) => (career, None),
+            Err(e) => {
+                let aside = path.with_extension("bad");
+                fs::rename(path, &aside)?;
+                let warning = format!("Moved unreadable {} to {} ({}) - career stats start over", path.display(), aside.display(), e);
+                (Self::default(), Some(warning))
+            }
+        };
+        career.add_game(box_score, name_of);
+        career.save(path)?;
+        Ok(warning)
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::box_score::{BoxScore, GameBatterLine, GamePitcherLine};
+    use crate::game::career::{CareerCategory, CareerStats};
+    use crate::game::constants::CAREER_AVG_MIN_AT_BATS;
+    use std::fs;
+
+    fn finished_game() -> BoxScore {
+        let mut box_score = BoxScore::default();
+        *box_score.batter_mut("660271") = GameBatterLine { at_bats: 4, hits: 2, home_runs: 1, rbi: 3, ..Default::default() };
+        *box_score.batter_mut("NYY_2024#9") = GameBatterLine { at_bats: 3, ..Default::default() };
+        *box_score.pitcher_mut("543037") = GamePitcherLine { outs_recorded: 20, strikeouts: 8, earned_runs: 2, save: false, ..Default::default() };
+        box_score
+    }
+
+    #[test]
+    fn test_two_games_double_a_players_career_line() {
+        let path = std::env::temp_dir().join(format!("bitbatter_career_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        for _ in 0..2 {
+            CareerStats::record_game(&path, &finished_game(), |key| format!("Player {}", key)).unwrap();
+        }
+
+        let career = CareerStats::load(&path).unwrap();
+        let batter = &career.batting["660271"];
+        assert_eq!((batter.games, batter.at_bats, batter.home_runs, batter.rbi), (2, 8, 2, 6));
+        assert_eq!(batter.name, "Player 660271");
+        assert_eq!(batter.average(), 0.5);
+        let pitcher = &career.pitching["543037"];
+        assert_eq!((pitcher.games, pitcher.outs_recorded, pitcher.strikeouts), (2, 40, 16));
+        assert_eq!(pitcher.innings_pitched(), "13.1");
+        // Empty lineup slots aren't anyone's career
+        assert_eq!(career.batting.len(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_merging_different_games_adds_every_field() {
+        let mut first = BoxScore::default();
+        *first.batter_mut("660271") = GameBatterLine { at_bats: 4, runs: 1, hits: 1, walks: 1, strikeouts: 2, ..Default::default() };
+        *first.pitcher_mut("543037") = GamePitcherLine { outs_recorded: 2, hits: 1, earned_runs: 1, pitches: 14, save: true, ..Default::default() };
+        let mut second = BoxScore::default();
+        *second.batter_mut("660271") = GameBatterLine { at_bats: 3, runs: 2, hits: 3, home_runs: 2, rbi: 4, ..Default::default() };
+        *second.pitcher_mut("543037") = GamePitcherLine { outs_recorded: 25, walks: 3, runs: 2, earned_runs: 2, pitches: 101, ..Default::default() };
+
+        let mut career = CareerStats::default();
+        career.add_game(&first, |key| key.to_string());
+        career.add_game(&second, |key| key.to_string());
+
+        let batter = &career.batting["660271"];
+        assert_eq!((batter.games, batter.at_bats, batter.runs, batter.hits), (2, 7, 3, 4));
+        assert_eq!((batter.home_runs, batter.rbi, batter.walks, batter.strikeouts), (2, 4, 1, 2));
+        assert_eq!(batter.summary(), "2 G, .571, 2 HR, 4 RBI");
+        let pitcher = &career.pitching["543037"];
+        assert_eq!((pitcher.games, pitcher.outs_recorded, pitcher.hits, pitcher.walks), (2, 27, 1, 3));
+        assert_eq!((pitcher.runs, pitcher.earned_runs, pitcher.pitches, pitcher.saves), (2, 3, 115, 1));
+        assert_eq!(pitcher.innings_pitched(), "9.0");
+        assert_eq!(pitcher.era(), 3.0);
+    }
+
+    #[test]
+    fn test_unreadable_career_file_is_set_aside_not_overwritten() {
+        let path = std::env::temp_dir().join(format!("bitbatter_career_bad_{}.json", std::process::id()));
+        let aside = path.with_extension("bad");
+        let _ = fs::remove_file(&aside);
+        fs::write(&path, "{ \"batting\": { truncated").unwrap();
+
+        let (career, warning) = CareerStats::load_or_default(&path);
+        assert_eq!(career, CareerStats::default());
+        assert!(warning.is_some());
+
+        let warning = CareerStats::record_game(&path, &finished_game(), |key| key.to_string()).unwrap();
+        assert!(warning.unwrap().contains("start over"));
+        assert_eq!(fs::read_to_string(&aside).unwrap(), "{ \"batting\": { truncated");
+        let career = CareerStats::load(&path).unwrap();
+        assert_eq!(career.batting["660271"].games, 1);
+
+        // A good file records without a warning
+        assert_eq!(CareerStats::record_game(&path, &finished_game(), |key| key.to_string()).unwrap(), None);
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&aside).unwrap();
+    }
+
+    #[test]
+    fn test_leaders_rank_qualify_and_break_ties_by_name() {
+        let path = std::env::temp_dir().join(format!("bitbatter_career_leaders_{}.json", std::process::id()));
+        fs::write(
+            &path,
+            format!(
+                r#"{{
+                    "batting": {{
+                        "1": {{ "name": "Slugger, Sam", "at_bats": 40, "hits": 10, "home_runs": 6, "rbi": 12 }},
+                        "2": {{ "name": "Contact, Cal", "at_bats": {}, "hits": 15, "home_runs": 1, "rbi": 12 }},
+                        "3": {{ "name": "Hot Start, Hal", "at_bats": 4, "hits": 4, "home_runs": 0, "rbi": 0 }},
+                        "4": {{ "name": "Another, Al", "at_bats": 30, "hits": 9, "home_runs": 1, "rbi": 3 }}
+                    }},
+                    "pitching": {{
+                        "10": {{ "name": "Ace, Abe", "strikeouts": 31 }},
+                        "11": {{ "name": "Reliever, Ray", "strikeouts": 0 }}
+                    }}
+                }}"#,
+                CAREER_AVG_MIN_AT_BATS
+            ),
+        )
+        .unwrap();
+        let career = CareerStats::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let names = |category| -> Vec<String> { career.leaders(category, 10).into_iter().map(|l| l.name).collect() };
+        // Tied home runs go by name; nobody without one is listed
+        assert_eq!(names(CareerCategory::HomeRuns), ["Slugger, Sam", "Another, Al", "Contact, Cal"]);
+        assert_eq!(names(CareerCategory::Rbi), ["Contact, Cal", "Slugger, Sam", "Another, Al"]);
+        // 4-for-4 is short of the at-bats to qualify
+        assert_eq!(names(CareerCategory::Average), ["Contact, Cal", "Another, Al", "Slugger, Sam"]);
+        assert_eq!(names(CareerCategory::Strikeouts), ["Ace, Abe"]);
+        assert_eq!(career.leaders(CareerCategory::HomeRuns, 2).len(), 2);
+
+        assert_eq!(CareerCategory::Average.format(0.75), ".750");
+        assert_eq!(CareerCategory::Strikeouts.format(31.0), "31");
+        assert!(CareerStats::default().leaders(CareerCategory::HomeRuns, 10).is_empty());
+    }
+
+    #[test]
+    fn test_missing_career_file_is_an_empty_career() {
+        let path = std::env::temp_dir().join("bitbatter_career_does_not_exist.json");
+        assert_eq!(CareerStats::load(path).unwrap(), CareerStats::default());
+    }
+}
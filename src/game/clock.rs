@@ -0,0 +1,40 @@
+use crate::game::constants::{MAX_CATCH_UP_TICKS, TARGET_FPS};
+use std::time::Duration;
+
+/// Turns real elapsed time into fixed game ticks. Every timer in the game counts ticks of
+/// 1/`TARGET_FPS` seconds, so running however many ticks the wall clock says have passed keeps
+/// the pitch clock and swing windows the same length on a slow terminal as on a fast one.
+#[derive(Debug, Clone)]
+pub struct TickClock {
+    tick: Duration,
+    pending: Duration, // Real time not yet turned into a whole tick
+}
+
+impl Default for TickClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TickClock {
+    pub fn new() -> Self {
+        Self {
+            tick: Duration::from_secs(1) / TARGET_FPS as u32,
+            pending: Duration::ZERO,
+        }
+    }
+
+    /// Ticks to run for `elapsed` real time. After a long stall (a suspended process, a
+    /// dropped SSH link) at most `MAX_CATCH_UP_TICKS` run and the rest of the gap is dropped,
+    /// so the game doesn't fast-forward through a whole pitch in one frame.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        self.pending += elapsed;
+        let ticks = (self.pending.as_nanos() / self.tick.as_nanos()) as u32;
+        if ticks > MAX_CATCH_UP_TICKS {
+            self.pending = Duration::ZERO;
+            return MAX_CATCH_UP_TICKS;
+        }
+        self.pending -= self.tick * ticks;
+        ticks
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::clock::TickClock;
+    use crate::game::input_handler::handle_input;
+    use crate::game::{constants::*, GameEngine, GameMode, GameState, PitchLocation, PitchState, SwingTiming};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::team::TeamManager;
+    use std::time::Duration;
+
+    #[test]
+    fn test_clock_carries_partial_ticks_over() {
+        let mut clock = TickClock::new();
+        assert_eq!(clock.advance(Duration::from_millis(20)), 0);
+        assert_eq!(clock.advance(Duration::from_millis(20)), 1); // 40ms in, one 33.3ms tick
+        assert_eq!(clock.advance(Duration::from_millis(60)), 2); // 100ms in, three ticks total
+        assert_eq!(clock.advance(Duration::from_millis(400)), 12);
+    }
+
+    #[test]
+    fn test_durations_convert_to_ticks_at_the_tick_rate() {
+        assert_eq!(ms_to_frames(1_000), TARGET_FPS);
+        assert_eq!(BALL_APPROACH_FRAMES as u64, ms_to_frames(3_000));
+        assert_eq!(ms_to_frames(16), 0); // Rounds to the nearest tick
+        assert_eq!(ms_to_frames(17), 1);
+        assert_eq!(frames_to_seconds(0), 0);
+        assert_eq!(frames_to_seconds(1), 1); // A countdown shows the second it's in
+        assert_eq!(frames_to_seconds(PITCH_CLOCK_FRAMES as u64), 3);
+    }
+
+    #[test]
+    fn test_clock_skips_long_stalls() {
+        let mut clock = TickClock::new();
+        assert_eq!(clock.advance(Duration::from_secs(5)), MAX_CATCH_UP_TICKS);
+        assert_eq!(clock.advance(Duration::from_millis(10)), 0); // The rest of the stall is gone
+    }
+
+    /// Play `elapsed` real time through the clock in chunks cycling through `intervals`
+    fn run_for(state: &mut GameState, engine: &GameEngine, elapsed: Duration, intervals: &[u64]) {
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_clock_tests.log").to_string_lossy());
+        let mut clock = TickClock::new();
+        let mut remaining = elapsed;
+        for &ms in intervals.iter().cycle() {
+            let step = Duration::from_millis(ms).min(remaining);
+            for _ in 0..clock.advance(step) {
+                crate::game::update_game_state(state, engine, &mut InputState::new(), None, &logger);
+            }
+            remaining -= step;
+            if remaining.is_zero() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_mid_window_swing_is_perfect_at_any_frame_rate() {
+        // The perfect window is the 4th to 9th tick before the plate; this lands on the 6th
+        let approach = Duration::from_secs(1) * BALL_APPROACH_FRAMES as u32 / TARGET_FPS as u32;
+        let swing_at = approach - Duration::from_millis(190);
+        let patterns: [&[u64]; 4] = [&[66], &[16], &[33], &[5, 90, 41, 12, 70, 3]];
+
+        for intervals in patterns {
+            let engine = GameEngine::with_seed(8);
+            let mut state = GameState::new();
+            state.mode = GameMode::Playing;
+            state.pitch_location = Some(PitchLocation::Middle);
+            state.pitch_state = PitchState::BallApproaching {
+                frames_left: BALL_APPROACH_FRAMES,
+                approach_frames: BALL_APPROACH_FRAMES,
+                ball_position: 0.0,
+                pitch_type: 0,
+                can_swing: false,
+            };
+
+            run_for(&mut state, &engine, swing_at, intervals);
+            let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_clock_tests.log").to_string_lossy());
+            let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+            handle_input(&mut state, &mut teams, &engine, &mut InputState::new(), GameInput::Action, None, &logger);
+
+            assert_eq!(state.swing_timing, SwingTiming::Perfect, "ticks of {:?}ms", intervals);
+        }
+    }
+}
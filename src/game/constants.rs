@@ -1,25 +1,71 @@
-// Game timing constants (in frames)
+// Game timing - timers count game ticks (frames) of 1/TARGET_FPS seconds, but every duration
+// is set in milliseconds and turned into ticks by `ms_to_frames`, so the tick rate can change
+// without stretching or squeezing any of them
 pub const TARGET_FPS: u64 = 30;
 pub const FRAME_TIME_MS: u64 = 1000 / TARGET_FPS;
+pub const MAX_CATCH_UP_TICKS: u32 = ms_to_frames(500) as u32; // Game time run per frame at most - longer stalls are skipped
+
+/// Game ticks in `ms` milliseconds of real time, to the nearest tick
+pub const fn ms_to_frames(ms: u64) -> u64 {
+    (ms * TARGET_FPS + 500) / 1000
+}
+
+/// Whole seconds on a countdown with `frames` ticks to go, rounded up
+pub const fn frames_to_seconds(frames: u64) -> u64 {
+    frames.div_ceil(TARGET_FPS)
+}
 
 // Animation frame durations
-pub const PITCHING_ANIMATION_FRAMES: u8 = 20;
-pub const SWINGING_ANIMATION_FRAMES: u8 = 10;
-pub const RESULT_DISPLAY_FRAMES: u8 = 90;
-pub const GAME_OVER_DELAY_SECONDS: u64 = 3;
+pub const SWINGING_ANIMATION_FRAMES: u8 = ms_to_frames(333) as u8;
+pub const UMPIRE_CALL_FRAMES: u8 = ms_to_frames(800) as u8; // A taken pitch hangs this long before the call
+pub const UMPIRE_CALL_FLASH_FRAMES: u8 = ms_to_frames(200) as u8; // Where it crossed flashes on and off this often
+pub const RESULT_DISPLAY_FRAMES: u8 = ms_to_frames(3_000) as u8;
+pub const ATTRACT_IDLE_FRAMES: u32 = ms_to_frames(60_000) as u32; // A minute untouched on team selection starts the demo
+
+// Game speed - how fast the dead time between plays (the pitch clock, the swing animation and
+// the result on screen) runs down, in percent of real time. The swing and fielding windows
+// always run at real time.
+pub const GAME_SPEED_SLOW_PERCENT: u16 = 50;
+pub const GAME_SPEED_NORMAL_PERCENT: u16 = 100;
+pub const GAME_SPEED_FAST_PERCENT: u16 = 200;
+pub const FAST_FORWARD_MULTIPLIER: u16 = 4; // While F is held, unless the config picks another
+pub const FAST_FORWARD_CHOICES: [u16; 3] = [2, 4, 8]; // Offered on the settings screen
+pub const FAST_FORWARD_MAX: u16 = FAST_FORWARD_CHOICES[FAST_FORWARD_CHOICES.len() - 1]; // A config file asking for more gets this
+pub const FAST_FORWARD_HOLD_FRAMES: u8 = ms_to_frames(500) as u8; // Each F press lasts this long - key repeat bridges the gaps while it's held
 
 // Timing system constants
-pub const PITCH_CLOCK_FRAMES: u16 = 90; // 10 seconds at 30fps
-pub const BALL_APPROACH_FRAMES: u8 = 90; // 3 seconds for ball to reach plate
-pub const SWING_TIMING_WINDOW_FRAMES: u8 = 30; // 1 second timing window
-pub const PERFECT_TIMING_WINDOW_FRAMES: u8 = 6; // 0.2 second perfect window
-pub const EARLY_LATE_WINDOW_FRAMES: u8 = 12; // 0.4 second early/late windows each side
+pub const PITCH_CLOCK_FRAMES: u16 = ms_to_frames(3_000) as u16; // For the batter to get set once the pitch is locked in
+pub const PITCHER_CLOCK_FRAMES: u16 = ms_to_frames(15_000) as u16; // To pick and aim a pitch before a ball is called
+pub const BALL_APPROACH_FRAMES: u8 = ms_to_frames(3_000) as u8; // For a pitch at the reference speed to reach the plate
+pub const APPROACH_REFERENCE_SPEED_MPH: u8 = 90; // Faster pitches get there sooner, slower ones later
+pub const MIN_BALL_APPROACH_FRAMES: u8 = SWING_TIMING_WINDOW_FRAMES * 2; // Always time to pick up the ball before the window opens
+pub const SWING_TIMING_WINDOW_FRAMES: u8 = ms_to_frames(1_000) as u8;
+pub const PERFECT_TIMING_WINDOW_FRAMES: u8 = ms_to_frames(200) as u8;
+pub const EARLY_LATE_WINDOW_FRAMES: u8 = ms_to_frames(400) as u8; // Each side
+pub const TOO_LATE_WINDOW_FRAMES: u8 = ms_to_frames(67) as u8; // Just before the plate - the bat can't get around
+
+// Swing timing bands by frames left when the swing starts, each covering up to and including its end
+pub const TOO_LATE_END_FRAMES: u8 = TOO_LATE_WINDOW_FRAMES - 1;
+pub const LATE_END_FRAMES: u8 = PERFECT_TIMING_WINDOW_FRAMES / 2;
+pub const PERFECT_END_FRAMES: u8 = LATE_END_FRAMES + PERFECT_TIMING_WINDOW_FRAMES;
+pub const EARLY_END_FRAMES: u8 = PERFECT_END_FRAMES + EARLY_LATE_WINDOW_FRAMES;
+pub const PERFECT_TIMING_CENTER_FRAMES: u8 = LATE_END_FRAMES + PERFECT_TIMING_WINDOW_FRAMES / 2; // Dead-on timing, for practice's average
+
+// Batter's eye - the ball shows its spin for the first frames of the approach, and a batter who
+// calls the pitch before swinging gets a wider perfect window (a wrong call narrows it)
+pub const PITCH_CUE_FRAMES: u8 = ms_to_frames(200) as u8;
+pub const PITCH_READ_BONUS_FRAMES: i8 = ms_to_frames(33) as i8; // Added to each side of the perfect window
+pub const PITCH_MISREAD_PENALTY_FRAMES: i8 = ms_to_frames(33) as i8; // Taken off each side
 
-// Batter auto-take timing
-pub const BATTER_AUTO_TAKE_FRAMES: u8 = 60; // ~2 seconds at 30fps
 
 // Input polling
 pub const INPUT_POLL_TIMEOUT_MS: u64 = 16; // ~60fps polling
+// A second press of the same arrow this soon after the last is the key repeating, not a step -
+// shorter than a keyboard's delay before it starts repeating, longer than the repeats themselves
+pub const AIM_REPEAT_WINDOW_MS: u64 = 120;
+// Arrows on different axes pressed this close together are one diagonal press - about as far
+// apart as two fingers land when they mean to hit the keys at once
+pub const AIM_CHORD_WINDOW_MS: u64 = 50;
 
 // Pitcher stamina
 pub const STARTING_STAMINA: f32 = 100.0;
@@ -37,34 +83,135 @@ pub const FATIGUE_PENALTY_GOOD: f32 = 0.95;
 pub const FATIGUE_PENALTY_TIRED: f32 = 0.85;
 pub const FATIGUE_PENALTY_VERY_TIRED: f32 = 0.70;
 pub const FATIGUE_PENALTY_EXHAUSTED: f32 = 0.50;
+pub const BALK_CHANCE_PER_FATIGUE: f32 = 0.02; // Exhausted pitchers balk 1% of pitches with runners on
 
 // Game rules
 pub const MAX_STRIKES: u8 = 3;
 pub const MAX_BALLS: u8 = 4;
 pub const MAX_OUTS: u8 = 3;
 pub const INNINGS_PER_GAME: u8 = 9;
+pub const SAVE_MAX_LEAD: i16 = 3; // Biggest lead that still brings in the closer
 pub const BASES_COUNT: usize = 3;
+pub const AT_THE_PLATE: usize = BASES_COUNT; // The pinch menu's stop past third, for a pinch hitter
 pub const BATTING_ORDER_SIZE: usize = 9;
 
+// Statcast data location
+pub const DEFAULT_DATA_DIR: &str = "data_down/statcast_downloads";
+pub const DEFAULT_SEASON: u16 = 2025;
+
 // Player stats thresholds
 pub const MIN_PLAYER_ATTEMPTS: u32 = 50;
+pub const LEAGUE_LEADERS_SHOWN: usize = 10; // Rows printed by --leaders and on the career leaders screen
+pub const CAREER_AVG_MIN_AT_BATS: u32 = 20; // At-bats to qualify for the career batting average lead
+
+// Replacement-level hitters fill out a lineup that loaded short - below average contact and
+// power, the kind of player freely available off the waiver wire
+pub const REPLACEMENT_BARREL_PERCENT: f32 = 4.0;
+pub const REPLACEMENT_AVG_HIT_SPEED: f32 = 86.0;
+pub const REPLACEMENT_SWEET_SPOT_PERCENT: f32 = 30.0;
+pub const REPLACEMENT_PITCHER_BARREL_PERCENT: f32 = 10.0; // Allowed - hit hard more than most
+pub const REPLACEMENT_PITCHER_FASTBALL_MPH: f32 = 91.0;
+pub const REPLACEMENT_PITCHER_WHIFF_PERCENT: f32 = 20.0;
+
+// Pitchers at the plate when there's no DH - well below even a replacement hitter
+pub const PITCHER_BATTING_BARREL_PERCENT: f32 = 0.5;
+pub const PITCHER_BATTING_AVG_HIT_SPEED: f32 = 78.0;
+pub const PITCHER_BATTING_SWEET_SPOT_PERCENT: f32 = 20.0;
+
+// Pitcher arsenals synthesized from batted-ball stats
+pub const MAX_ARSENAL_SIZE: usize = 4; // Pitch select keys 1-4
+pub const POWER_ARM_MAX_EV95_PERCENT: f32 = 36.0; // Rarely squared up - throws hard
+pub const SINKERBALLER_MAX_LAUNCH_ANGLE: f32 = 10.0; // Keeps the ball on the ground
+pub const SWING_AND_MISS_MAX_BARREL_PERCENT: f32 = 7.0; // Elite breaking stuff
+pub const COMMAND_MAX_SWEET_SPOT_PERCENT: f32 = 29.0; // Avoids the middle of the bat
+pub const COMMAND_CONTROL_BONUS: f32 = 0.05;
+pub const MIN_PITCH_SPEED_MPH: f32 = 60.0; // Measured fastball velocities are kept in this range
+pub const MAX_PITCH_SPEED_MPH: f32 = 100.0;
+pub const LEAGUE_AVG_BB_PERCENT: f32 = 8.5;
+pub const BB_PERCENT_CONTROL_SCALE: f32 = 0.01; // Control lost per walk% point above average
+pub const MIN_PITCH_CONTROL: f32 = 0.6;
 
 // Fielding timing
-pub const FIELDING_TIMING_WINDOW: f32 = 15.0; // frames
-pub const MAX_FIELDING_AUTO_RESOLVE_MULTIPLIER: u8 = 1; // multiplier of hang_time
+pub const FIELDING_TIMING_WINDOW: f32 = ms_to_frames(500) as f32;
+pub const FIELDING_MIN_WAIT_FRAMES: u8 = ms_to_frames(1_500) as u8; // Even a quick liner waits this long for the player to field it
 
 // Contact quality ranges
 pub const CONTACT_EXCELLENT_MIN: i32 = 85;
-pub const CONTACT_GREAT_MIN: i32 = 75;
 pub const CONTACT_GOOD_MIN: i32 = 55;
 pub const CONTACT_WEAK_MIN: i32 = 35;
 
+// Chasing out of the zone - the closer the swing to where the ball really is, the better the
+// chance of getting a piece of it. Indexed by grid distance: on the ball, a spot off, further.
+pub const CHASE_MISS_CHANCE: [f64; 3] = [0.8, 0.9, 0.95];
+pub const CHASE_CONTACT_MAX: [i32; 3] = [40, 30, 20]; // Best contact quality before timing - weak at best
+
+// Count leverage - each ball more than strikes helps the batter's contact, each strike more
+// than balls takes it away and adds to the pitcher's chance of a whiff
+pub const COUNT_CONTACT_PER_PITCH: i32 = 4;
+pub const COUNT_WHIFF_PER_STRIKE: f64 = 0.06;
+
 // Skill adjustments
 pub const BATTER_SKILL_BONUS_MULTIPLIER: f32 = 1.5;
 pub const PITCHER_SKILL_PENALTY_MULTIPLIER: f32 = 2.0;
 pub const ADJACENT_BATTER_SKILL_MULTIPLIER: f32 = 1.0;
 pub const ADJACENT_PITCHER_SKILL_MULTIPLIER: f32 = 1.0;
 
+// Home runs on excellent contact - a park-neutral rate per batter, scaled by the ballpark
+pub const HR_CHANCE_LEAGUE_AVG: f32 = 0.25; // Also used when there's no batter
+pub const HR_CHANCE_MIN: f32 = 0.10;
+pub const HR_CHANCE_MAX: f32 = 0.60;
+pub const HR_CHANCE_PER_BARREL_POINT: f32 = 0.025;
+pub const LEAGUE_AVG_HR_DISTANCE: f32 = 395.0; // Feet
+pub const HR_CHANCE_PER_FOOT: f32 = 0.005;
+pub const HR_CHANCE_NO_HOMERS_PENALTY: f32 = 0.05; // Batters with no home runs have no avg_hr_distance
+
+// Spray direction on excellent contact - where an on-time swing sends the ball, and how the
+// home run chance shifts with it. Weighted together the factors come out close to 1.0.
+pub const SPRAY_PULL_SHARE: f32 = 0.45;
+pub const SPRAY_CENTER_SHARE: f32 = 0.30; // The rest goes the other way
+pub const PULL_HR_FACTOR: f32 = 1.25;
+pub const CENTER_HR_FACTOR: f32 = 1.0;
+pub const OPPOSITE_FIELD_HR_FACTOR: f32 = 0.5;
+
+// Triples - the chance an extra-base hit turns into a triple, before direction and speed
+pub const TRIPLE_BASE_CHANCE: f32 = 0.04;
+pub const TRIPLE_GAP_FACTOR: f32 = 2.0;       // Gaps and right field, where the ball rolls deepest
+pub const TRIPLE_OUTFIELD_FACTOR: f32 = 0.75; // Left and center field
+pub const TRIPLE_INFIELD_FACTOR: f32 = 0.25;  // Down the line past an infielder
+pub const TRIPLE_HARD_HIT_FACTOR: f32 = 1.25; // Balls past FIELDING_SPEED_THRESHOLD reach the wall
+
+// Extra bases on solid contact that gets through - a ball split between outfielders rolls to
+// the wall, one that sneaks past an infielder is usually held to a single
+pub const EXTRA_BASE_HIT_CHANCE: f64 = 0.5;
+pub const EXTRA_BASE_GAP_FACTOR: f64 = 1.4;     // Left-center and right-center
+pub const EXTRA_BASE_INFIELD_FACTOR: f64 = 0.3; // Through the infield
+
+// Runner speed - statcast sprint speed in feet per second. Without a sprint speed file it's
+// estimated from the batted-ball profile: short, low-angle contact reads as a fast slap hitter.
+pub const LEAGUE_AVG_SPRINT_SPEED: f32 = 27.0;
+pub const SPRINT_SPEED_MIN: f32 = 23.0;
+pub const SPRINT_SPEED_MAX: f32 = 31.0;
+pub const LEAGUE_AVG_HIT_ANGLE: f32 = 12.5;     // Degrees
+pub const LEAGUE_AVG_BATTED_DISTANCE: f32 = 185.0; // Feet
+pub const SPRINT_SPEED_PER_DEGREE: f32 = 0.15;
+pub const SPRINT_SPEED_PER_FOOT: f32 = 0.08;
+pub const RUNNER_SPEED_FACTOR_PER_FTPS: f32 = 0.25; // Each ft/s over average is +25% on extra-base tries
+pub const RUNNER_SPEED_FACTOR_MIN: f32 = 0.5;
+pub const RUNNER_SPEED_FACTOR_MAX: f32 = 2.0;
+
+// Extra bases on the bases, each scaled by the runner's speed factor
+pub const STRETCH_SINGLE_CHANCE: f32 = 0.15;     // Single into a gap becomes a double
+pub const INFIELD_HIT_CHANCE: f32 = 0.06;        // Fielded grounder is beaten out for a single
+pub const SCORE_FROM_SECOND_CHANCE: f32 = 0.45;  // Runner on second scores on a single
+
+// Pitcher stuff - only applied when the pitcher CSV carries these columns
+pub const LEAGUE_AVG_WHIFF_PERCENT: f32 = 25.0;
+pub const WHIFF_PERCENT_SCALE: f32 = 0.5; // Extra miss chance per whiff% point above average
+pub const MAX_EXTRA_WHIFF_CHANCE: f32 = 0.2;
+pub const VELOCITY_BASELINE_MPH: f32 = 93.0; // Fastballs at or below this leave the window alone
+pub const VELOCITY_MPH_PER_FRAME: f32 = 2.0; // Each step above the baseline costs a perfect frame
+pub const MIN_PERFECT_WINDOW_FRAMES: u8 = ms_to_frames(67) as u8;
+
 // Ball-in-play generation
 pub const SPEED_EXCELLENT_MIN: f32 = 80.0;
 pub const SPEED_EXCELLENT_MAX: f32 = 100.0;
@@ -73,12 +220,17 @@ pub const SPEED_GOOD_MAX: f32 = 90.0;
 pub const SPEED_WEAK_MIN: f32 = 40.0;
 pub const SPEED_WEAK_MAX: f32 = 60.0;
 
-pub const HANG_TIME_FLYBALL_MIN: u8 = 60;
-pub const HANG_TIME_FLYBALL_MAX: u8 = 90;
-pub const HANG_TIME_LINEDRIVE_MIN: u8 = 20;
-pub const HANG_TIME_LINEDRIVE_MAX: u8 = 40;
-pub const HANG_TIME_POPFLY_MIN: u8 = 40;
-pub const HANG_TIME_POPFLY_MAX: u8 = 60;
+pub const HANG_TIME_FLYBALL_MIN: u8 = ms_to_frames(2_000) as u8;
+pub const HANG_TIME_FLYBALL_MAX: u8 = ms_to_frames(3_000) as u8;
+pub const HANG_TIME_LINEDRIVE_MIN: u8 = ms_to_frames(667) as u8;
+pub const HANG_TIME_LINEDRIVE_MAX: u8 = ms_to_frames(1_333) as u8;
+pub const HANG_TIME_POPFLY_MIN: u8 = ms_to_frames(1_333) as u8;
+pub const HANG_TIME_POPFLY_MAX: u8 = ms_to_frames(2_000) as u8;
+
+// Launch angle - a batter's average launch angle tilts every batted ball toward the air (above
+// league average) or the ground (below), up to LAUNCH_ANGLE_SPREAD degrees either way
+pub const LAUNCH_ANGLE_SPREAD: f32 = 8.0;
+pub const LAUNCH_ANGLE_SHARE: f64 = 0.2; // Most of the mix that moves between grounders and fly balls
 
 // Fielding success base rates
 pub const FIELDING_SUCCESS_POPFLY: f32 = 0.98;
@@ -88,7 +240,108 @@ pub const FIELDING_SUCCESS_GROUNDER: f32 = 0.85;
 
 pub const FIELDING_SPEED_THRESHOLD: f32 = 95.0;
 pub const FIELDING_SPEED_PENALTY_DIVISOR: f32 = 300.0;
+pub const FIELDERS_CHOICE_CHANCE: f64 = 0.35; // Groundouts with a force at second that go for the lead runner
+pub const FIELDING_SUCCESS_PER_OAA: f32 = 0.005; // Catch chance per out above average of the fielder at the spot
+pub const FIELDING_ERROR_CHANCE: f64 = 0.015; // A fielder who gets to a fair ball still boots this many
+pub const OUTS_ABOVE_AVERAGE_MAX: i8 = 25; // Defense file values are clamped to +/- this
+
+// Foul pop-ups - the weaker the contact on a foul, the likelier it stays up long enough to catch
+pub const FOUL_POP_CHANCE_MAX: f64 = 0.15; // chance at the weakest contact, falling to 0 at perfect contact
 
 pub const FIELDING_TIMING_GOOD_THRESHOLD: f32 = 0.6;
 pub const FIELDING_TIMING_POOR_MULTIPLIER: f32 = 0.5;
 pub const FIELDING_MIN_SUCCESS_RATE: f32 = 0.1;
+
+// CPU decision-making (used by demo mode and the headless simulation)
+pub const DEFAULT_BATTER_AGGRESSIVENESS: f32 = 0.5;
+pub const BATTER_AGGRESSIVENESS_STEP: f32 = 0.1; // Per press on the settings screen
+pub const AI_SWING_AT_STRIKE_MIN: f64 = 0.5;
+pub const AI_SWING_AT_STRIKE_MAX: f64 = 0.9;
+pub const AI_CHASE_MIN: f64 = 0.1;
+pub const AI_CHASE_MAX: f64 = 0.4;
+pub const AI_LOCATION_READ_RATE: f64 = 0.8; // chance the batter aims at the exact pitch location
+pub const AI_SWING_TIMING_SPREAD_FRAMES: u8 = ms_to_frames(200) as u8; // Either side of perfect timing
+pub const AI_PITCHER_ZONE_RATE: f64 = 0.5;
+
+// Headless simulation - average-player timing and fielding model (the fielding half also
+// plays any ball the player doesn't field in time)
+pub const SIM_TIMING_TOO_EARLY_RATE: f64 = 0.05;
+pub const SIM_TIMING_EARLY_RATE: f64 = 0.1;
+pub const SIM_TIMING_LATE_RATE: f64 = 0.1;
+pub const SIM_TIMING_TOO_LATE_RATE: f64 = 0.05;
+pub const SIM_FIELDER_ATTEMPT_RATE: f64 = 0.9; // chance the fielder reacts before the ball gets through
+pub const SIM_FIELDING_TIMING_SPREAD: i32 = ms_to_frames(500) as i32; // Either side of perfect
+
+// Quick sim - guards so a half inning can't run forever
+pub const SIM_MAX_PITCHES_PER_PLATE_APPEARANCE: u32 = 40;
+pub const SIM_MAX_PLATE_APPEARANCES_PER_INNING: usize = 40;
+
+// Season simulation
+pub const SEASON_MAX_INNINGS: u8 = 20; // A season game still tied after this many goes in the books as a tie
+
+// Random matchups on the team selection screen
+pub const MATCHUP_ROLL_FRAMES: u8 = ms_to_frames(1_500) as u8; // Names cycle this long before locking in
+pub const BALANCED_MATCHUP_MAX_BARREL_GAP: f32 = 1.0; // Barrel% points between the two lineups
+pub const MATCHUP_MAX_REROLLS: usize = 5;
+
+// Team ratings on the 20-80 scouting scale - one spread from average is 10 points
+pub const LEAGUE_AVG_BARREL_PERCENT: f32 = 8.0;
+pub const LEAGUE_AVG_HIT_SPEED: f32 = 89.0; // mph
+pub const LINEUP_BARREL_PERCENT_SPREAD: f32 = 2.0;
+pub const LINEUP_HIT_SPEED_SPREAD: f32 = 1.5;
+pub const STAFF_BARREL_PERCENT_SPREAD: f32 = 1.5;
+
+// Batter heat map - danger out of 100 for an average hitter middle-middle, then scaled
+pub const HEAT_BASELINE: f32 = 60.0;
+pub const HEAT_POINTS_PER_SPREAD: f32 = 15.0; // Per spread of barrel% and exit velocity above average
+pub const HEAT_EDGE_WEIGHT: f32 = 0.75; // In the zone but off the heart of the plate
+pub const HEAT_CORNER_WEIGHT: f32 = 0.4; // Off the plate
+pub const HEAT_NEUTRAL_LAUNCH_ANGLE: f32 = 12.5; // Degrees - no lean toward the top or bottom
+pub const HEAT_LAUNCH_ANGLE_SPREAD: f32 = 5.0;
+pub const HEAT_TILT_POINTS: f32 = 10.0; // Most a launch angle moves the top or bottom row
+pub const HEAT_HOT_MIN: u8 = 60;
+pub const HEAT_WARM_MIN: u8 = 40;
+
+// Scouting reports - thresholds that sort a batter into a category, checked in order
+pub const SCOUT_DANGEROUS_BARREL_PERCENT: f32 = 12.0;
+pub const SCOUT_GROUND_BALL_LAUNCH_ANGLE: f32 = 7.0; // Degrees - at or below
+pub const SCOUT_FLY_BALL_LAUNCH_ANGLE: f32 = 18.0; // Degrees - at or above
+pub const SCOUT_HARD_CONTACT_HIT_SPEED: f32 = 91.0; // mph
+pub const SCOUT_LIGHT_BAT_BARREL_PERCENT: f32 = 4.0;
+
+// Weather, rolled when a game starts - multipliers on batted balls, 1.0 = no effect
+pub const WEATHER_WIND_OUT_CHANCE: f64 = 0.2;
+pub const WEATHER_WIND_IN_CHANCE: f64 = 0.2;
+pub const WEATHER_RAIN_CHANCE: f64 = 0.1; // Whatever's left is a clear day
+pub const WIND_OUT_HR_FACTOR: f32 = 1.25;
+pub const WIND_IN_HR_FACTOR: f32 = 0.8;
+pub const RAIN_HR_FACTOR: f32 = 0.9; // Heavy air
+pub const WIND_OUT_FLY_SPEED_FACTOR: f32 = 1.05; // Fly balls carry further
+pub const WIND_IN_FLY_SPEED_FACTOR: f32 = 0.95;
+pub const RAIN_GROUNDER_SPEED_FACTOR: f32 = 0.85; // Wet grass slows grounders down
+
+// Momentum - each team's run of form, from -MOMENTUM_MAX (ice cold) to MOMENTUM_MAX (rolling)
+pub const MOMENTUM_MAX: i8 = 10;
+pub const MOMENTUM_HIT: i8 = 3;
+pub const MOMENTUM_RUN: i8 = 2; // Per run, on top of the hit that drove it in
+pub const MOMENTUM_STRIKEOUT: i8 = 3;
+pub const MOMENTUM_DECAY: i8 = 1; // Drift back toward even with every new batter
+pub const MOMENTUM_EXTREME: i8 = 8; // Where the crowd is at its loudest (or quietest) and contact shifts
+pub const MOMENTUM_CONTACT_BONUS: i32 = 3; // Contact quality points at the extremes, with momentum effects on
+pub const MOMENTUM_CROWD_SWING: f32 = 0.5; // Cheer volume runs from 1.0 minus this to 1.0 plus this
+pub const MOMENTUM_METER_CELLS: i8 = 5;
+
+// Settings screen - the max innings choices it steps through, 0 playing extras until someone wins
+pub const MAX_INNINGS_CHOICES: [u8; 5] = [0, 9, 10, 12, 15];
+
+// Player of the game - points from the box score, best total wins
+pub const MVP_POINTS_PER_HIT: i32 = 2;
+pub const MVP_POINTS_PER_HOME_RUN: i32 = 3; // On top of the hit
+pub const MVP_POINTS_PER_RBI: i32 = 2;
+pub const MVP_POINTS_PER_RUN: i32 = 1;
+pub const MVP_POINTS_PER_WALK: i32 = 1;
+pub const MVP_POINTS_PER_OUT: i32 = 1;
+pub const MVP_POINTS_PER_STRIKEOUT: i32 = 1;
+pub const MVP_POINTS_PER_EARNED_RUN: i32 = 2;
+pub const HIGHLIGHTS_SHOWN: usize = 3;
+pub const BOX_SCORE_NAME_WIDTH: usize = 20; // Exported box score names are cut or padded to this
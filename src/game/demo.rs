@@ -0,0 +1,28 @@
+use crate::game::constants::*;
+use crate::game::{matchup, GameEngine, GameMode, GameState, PitchState};
+use crate::team::TeamManager;
+
+/// Team selection has sat untouched long enough to start the attract loop
+pub fn idle_long_enough(state: &GameState) -> bool {
+    matches!(state.mode, GameMode::TeamSelection { .. }) && state.idle_frames >= ATTRACT_IDLE_FRAMES
+}
+
+/// Start a random matchup with the CPU pitching and batting for both sides, the same way
+/// `--demo` plays. Nothing from the game is logged or saved.
+pub fn start_demo(state: &mut GameState, team_manager: &mut TeamManager, engine: &GameEngine) -> Result<(), String> {
+    // Don't try again every frame when there aren't two teams to play
+    state.idle_frames = 0;
+    let (away, home) = matchup::roll_matchup(team_manager, false, &mut *engine.rng())?;
+    state.config.demo_mode = true;
+    state.attract_mode = true;
+    state.start_game(team_manager, home, away);
+    state.roll_weather(&mut *engine.rng());
+    engine.set_ballpark(state.home_ballpark());
+    engine.set_weather(state.weather);
+    Ok(())
+}
+
+/// The demo game is over and the final play has had its time on screen
+pub fn demo_finished(state: &GameState) -> bool {
+    state.attract_mode && state.game_over && !matches!(state.pitch_state, PitchState::ShowResult { .. })
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::demo::{demo_finished, idle_long_enough, start_demo};
+    use crate::game::input_handler::handle_input;
+    use crate::game::test_support::league_manager;
+    use crate::game::update::update_game_state;
+    use crate::config::GameSpeed;
+    use crate::game::{GameEngine, GameMode, GameState, PitchState};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+
+    const MAX_DEMO_FRAMES: u32 = 1_000_000;
+
+    #[test]
+    fn test_idle_team_selection_is_ready_for_a_demo_until_a_key_is_pressed() {
+        let engine = GameEngine::with_seed(1597);
+        let mut manager = league_manager();
+        let mut state = GameState::new();
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        for _ in 0..ATTRACT_IDLE_FRAMES {
+            assert!(!idle_long_enough(&state));
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+        }
+        assert!(idle_long_enough(&state));
+
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Down, None, &logger);
+        assert!(!idle_long_enough(&state));
+    }
+
+    #[test]
+    fn test_demo_plays_a_full_game_without_input() {
+        let engine = GameEngine::with_seed(1597);
+        let mut manager = league_manager();
+        let mut state = GameState::new();
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        start_demo(&mut state, &mut manager, &engine).unwrap();
+        assert_eq!(state.mode, GameMode::Playing);
+        assert!(state.attract_mode && state.config.demo_mode);
+        assert_ne!(state.home_team, state.away_team);
+
+        let mut frames = 0;
+        while !demo_finished(&state) {
+            assert!(frames < MAX_DEMO_FRAMES, "demo stuck in inning {} at {:?}", state.inning, state.pitch_state);
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+            frames += 1;
+        }
+        assert!(state.game_over);
+        assert!(state.inning >= 9);
+        assert_ne!(state.home_score, state.away_score, "demo games play extras until someone wins");
+    }
+
+    #[test]
+    fn test_watch_starts_a_cpu_game_from_team_selection() {
+        let engine = GameEngine::with_seed(1603);
+        let mut manager = league_manager();
+        let mut state = GameState::new();
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        let teams = manager.get_team_list();
+        for input in [GameInput::SelectAwayTeam, GameInput::NumberInput('1'), GameInput::Action] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        for input in [GameInput::SelectHomeTeam, GameInput::NumberInput('2'), GameInput::Action, GameInput::Watch] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert_eq!(state.mode, GameMode::Playing);
+        assert!(state.config.demo_mode && !state.attract_mode);
+        assert_eq!((state.away_team.clone(), state.home_team.clone()), (Some(teams[0].clone()), Some(teams[1].clone())));
+
+        // Nothing but pause and speed gets through - the CPU throws every pitch itself
+        for input in [GameInput::SelectPitch(0), GameInput::SimInning, GameInput::PinchRun, GameInput::Up] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+        assert!(state.pinch_run_menu.is_none());
+        update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+        assert!(matches!(state.pitch_state, PitchState::PitchClock { .. }));
+    }
+
+    #[test]
+    fn test_watching_pauses_and_changes_speed() {
+        let engine = GameEngine::with_seed(1603);
+        let mut manager = league_manager();
+        let mut state = GameState::new();
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        // No teams picked - watch a surprise matchup
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Watch, None, &logger);
+        assert_eq!(state.mode, GameMode::Playing);
+        update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+        assert!(state.paused);
+        let frozen = state.pitch_state.clone();
+        for _ in 0..PITCH_CLOCK_FRAMES * 2 {
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+        }
+        assert_eq!(state.pitch_state, frozen);
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Pause, None, &logger);
+        assert!(!state.paused);
+
+        for _ in 0..3 {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Right, None, &logger);
+        }
+        assert_eq!(state.config.game_speed, GameSpeed::Instant);
+        for _ in 0..2 {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Left, None, &logger);
+        }
+        assert_eq!(state.config.game_speed, GameSpeed::Normal);
+        assert_eq!(state.message, "Game speed: normal");
+    }
+}
@@ -1,238 +1,191 @@
+use crate::game::ai::{self, BatterAi};
+use crate::game::ballpark::Ballpark;
+use crate::game::weather::Weather;
 use crate::game::{constants::*, state::{BallInPlay, BallType, FieldDirection, HitType, OutType, PitchLocation, PlayResult, SwingTiming}};
-use crate::team::Player;
-use rand::Rng;
+use crate::team::{Defense, Hand, Player};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::cell::{Cell, RefCell, RefMut};
 
 pub struct GameEngine {
     pub pitch_types: Vec<PitchType>,
+    rng: RefCell<StdRng>,
+    ballpark: Cell<Ballpark>,
+    weather: Cell<Weather>,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PitchType {
-    pub name: &'static str,
+    pub name: String,
     pub speed: u8,    // 60-100 mph
     pub break_amount: i8, // Movement
+    pub control: f32, // Chance the pitch hits its spot, 0.0 - 1.0
+    pub whiff: i32,   // Contact quality taken off when the batter connects
+}
+
+impl PitchType {
+    /// A pitch that always hits its spot and has no extra swing-and-miss
+    pub fn new(name: &str, speed: u8, break_amount: i8) -> Self {
+        Self {
+            name: name.to_string(),
+            speed,
+            break_amount,
+            control: 1.0,
+            whiff: 0,
+        }
+    }
+
+    /// Frames from release to the plate - `BALL_APPROACH_FRAMES` at the reference speed,
+    /// scaled inversely with the pitch's velocity
+    pub fn approach_frames(&self) -> u8 {
+        let frames = BALL_APPROACH_FRAMES as u32 * APPROACH_REFERENCE_SPEED_MPH as u32 / self.speed.max(1) as u32;
+        frames.clamp(MIN_BALL_APPROACH_FRAMES as u32, u8::MAX as u32) as u8
+    }
+
+    /// Generic four-pitch mix thrown when a pitcher has no arsenal of their own
+    pub fn default_arsenal() -> Vec<PitchType> {
+        vec![
+            PitchType::new("Fastball", 90, 0),
+            PitchType::new("Curveball", 75, 5),
+            PitchType::new("Slider", 82, 3),
+            PitchType::new("Changeup", 78, 1),
+        ]
+    }
+}
+
+impl Default for GameEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GameEngine {
     pub fn new() -> Self {
+        Self::from_rng(StdRng::from_entropy())
+    }
+
+    /// Create an engine whose random outcomes are reproducible from `seed`
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
         Self {
-            pitch_types: vec![
-                PitchType {
-                    name: "Fastball",
-                    speed: 90,
-                    break_amount: 0,
-                },
-                PitchType {
-                    name: "Curveball",
-                    speed: 75,
-                    break_amount: 5,
-                },
-                PitchType {
-                    name: "Slider",
-                    speed: 82,
-                    break_amount: 3,
-                },
-                PitchType {
-                    name: "Changeup",
-                    speed: 78,
-                    break_amount: 1,
-                },
-            ],
-        }
-    }
-
-    pub fn calculate_pitch_result(
-        &self,
-        pitch_location: PitchLocation,
-        swing_location: Option<PitchLocation>,
-        _pitch_type_idx: usize,
-        batter: Option<&Player>,
-        pitcher: Option<&Player>,
-        fatigue_penalty: f32,  // Multiplier from 0.5 to 1.0
-    ) -> (PlayResult, Option<i32>) {  // Returns (result, contact_quality)
-        let mut rng = rand::thread_rng();
+            pitch_types: PitchType::default_arsenal(),
+            rng: RefCell::new(rng),
+            ballpark: Cell::new(Ballpark::NEUTRAL),
+            weather: Cell::new(Weather::Clear),
+        }
+    }
 
-        // No swing
-        if swing_location.is_none() {
-            return if pitch_location.is_strike() {
-                (PlayResult::Strike, None)
-            } else {
-                (PlayResult::Ball, None)
-            };
+    /// Pitches available to `pitcher` - their own arsenal, or the generic mix
+    pub fn arsenal<'a>(&'a self, pitcher: Option<&'a Player>) -> &'a [PitchType] {
+        match pitcher {
+            Some(p) if !p.arsenal.is_empty() => &p.arsenal,
+            _ => &self.pitch_types,
         }
+    }
 
-        let swing_loc = swing_location.unwrap();
-        
-        // Calculate timing and location accuracy
-        let exact_match = std::mem::discriminant(&pitch_location) == std::mem::discriminant(&swing_loc);
-        let adjacent_match = !exact_match && self.locations_match(pitch_location, swing_loc);
-        let is_strike_zone = pitch_location.is_strike();
+    pub fn pitch_type<'a>(&'a self, pitcher: Option<&'a Player>, idx: usize) -> Option<&'a PitchType> {
+        self.arsenal(pitcher).get(idx)
+    }
 
-        // Perfect contact - ONLY on exact match in strike zone
-        if exact_match && is_strike_zone {
-            let mut contact_quality = rng.gen_range(1..=100);
-            
-            // Adjust contact quality based on batter's skills
-            if let Some(batter) = batter {
-                // Better batters (higher barrel %) get bonus to contact quality
-                let skill_bonus = (batter.stats.barrel_percent * BATTER_SKILL_BONUS_MULTIPLIER) as i32;
-                contact_quality = (contact_quality + skill_bonus).min(100);
-            }
+    /// How long the chosen pitch takes to reach the plate
+    pub fn approach_frames(&self, pitcher: Option<&Player>, idx: usize) -> u8 {
+        self.pitch_type(pitcher, idx).map(PitchType::approach_frames).unwrap_or(BALL_APPROACH_FRAMES)
+    }
 
-            // Adjust based on pitcher's ability to limit hard contact
-            if let Some(pitcher) = pitcher {
-                // Better pitchers (lower barrel % allowed) reduce contact quality
-                // Fatigue reduces pitcher effectiveness significantly
-                let pitcher_penalty = (pitcher.stats.barrel_percent * PITCHER_SKILL_PENALTY_MULTIPLIER * fatigue_penalty) as i32;
-                contact_quality = (contact_quality - pitcher_penalty).max(1);
+    /// Perfect-timing window against this pitcher's fastball, in frames
+    pub fn perfect_window_frames(&self, pitcher: Option<&Player>) -> u8 {
+        let velocity = pitcher
+            .and_then(|p| p.pitching.as_ref())
+            .and_then(|p| p.fastball_velocity);
+        match velocity {
+            Some(mph) if mph > VELOCITY_BASELINE_MPH => {
+                let lost_frames = ((mph - VELOCITY_BASELINE_MPH) / VELOCITY_MPH_PER_FRAME) as u8;
+                PERFECT_TIMING_WINDOW_FRAMES
+                    .saturating_sub(lost_frames)
+                    .max(MIN_PERFECT_WINDOW_FRAMES)
             }
-
-            // Even with perfect timing, outcomes are heavily weighted toward outs
-            // This reflects real baseball: ~.300 batting average means 70% outs!
-            let result = match contact_quality {
-                90..=100 => {
-                    // Exceptional contact - home run or extra bases
-                    let hr_chance = if let Some(batter) = batter {
-                        (batter.stats.max_distance as f32 / 500.0 * 100.0) as u32
-                    } else { 25 };
-                    
-                    if rng.gen_range(1..=100) <= hr_chance.min(25) {
-                        PlayResult::Hit(HitType::HomeRun)
-                    } else if rng.gen_bool(0.6) {
-                        PlayResult::Hit(HitType::Triple)
-                    } else {
-                        PlayResult::Hit(HitType::Double)
-                    }
-                }
-                75..=89 => {
-                    // Great contact - mostly doubles/singles, some outs
-                    let roll = rng.gen_range(1..=10);
-                    match roll {
-                        1 => PlayResult::Hit(HitType::Triple),
-                        2..=4 => PlayResult::Hit(HitType::Double),
-                        5..=7 => PlayResult::Hit(HitType::Single),
-                        _ => {
-                            // Even great contact can be caught
-                            if rng.gen_bool(0.6) {
-                                PlayResult::Out(OutType::Flyout)
-                            } else {
-                                PlayResult::Out(OutType::LineOut)
-                            }
-                        }
-                    }
-                }
-                55..=74 => {
-                    // Good contact - mix of hits and outs (realistic .300 avg)
-                    let roll = rng.gen_range(1..=10);
-                    match roll {
-                        1..=3 => PlayResult::Hit(HitType::Single),
-                        4 => PlayResult::Hit(HitType::Double),
-                        5..=6 => PlayResult::Foul,
-                        _ => {
-                            // Most outcomes are outs
-                            let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
-                            if rng.gen_range(0.0..100.0) < gb_tendency {
-                                PlayResult::Out(OutType::Groundout)
-                            } else {
-                                PlayResult::Out(OutType::Flyout)
-                            }
-                        }
-                    }
-                }
-                35..=54 => {
-                    // Weak contact - mostly outs and fouls
-                    let roll = rng.gen_range(1..=10);
-                    match roll {
-                        1..=2 => PlayResult::Foul,
-                        3 => PlayResult::Hit(HitType::Single),
-                        _ => {
-                            let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
-                            if rng.gen_range(0.0..100.0) < gb_tendency {
-                                PlayResult::Out(OutType::Groundout)
-                            } else {
-                                PlayResult::Out(OutType::Flyout)
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // Poor contact - mostly outs
-                    if rng.gen_bool(0.2) {
-                        PlayResult::Foul
-                    } else {
-                        let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
-                        if rng.gen_range(0.0..100.0) < gb_tendency {
-                            PlayResult::Out(OutType::Groundout)
-                        } else {
-                            PlayResult::Out(OutType::Flyout)
-                        }
-                    }
-                }
-            };
-            return (result, Some(contact_quality));
+            _ => PERFECT_TIMING_WINDOW_FRAMES,
         }
+    }
 
-        // Good contact - adjacent match in strike zone (weaker than perfect)
-        if adjacent_match && is_strike_zone {
-            let mut contact_quality = rand::thread_rng().gen_range(1..=100);
-            
-            // Adjust based on batter skill
-            if let Some(batter) = batter {
-                let skill_bonus = (batter.stats.barrel_percent * ADJACENT_BATTER_SKILL_MULTIPLIER) as i32;
-                contact_quality = (contact_quality + skill_bonus).min(100);
-            }
-            
-            // Adjust based on pitcher ability
-            if let Some(pitcher) = pitcher {
-                let pitcher_penalty = (pitcher.stats.barrel_percent * ADJACENT_PITCHER_SKILL_MULTIPLIER * fatigue_penalty) as i32;
-                contact_quality = (contact_quality - pitcher_penalty).max(1);
-            }
+    /// Additional swing-and-miss chance from an above-average whiff rate
+    pub fn extra_whiff_chance(&self, pitcher: Option<&Player>) -> f64 {
+        pitcher
+            .and_then(|p| p.pitching.as_ref())
+            .and_then(|p| p.whiff_percent)
+            .map(|whiff| ((whiff - LEAGUE_AVG_WHIFF_PERCENT) * WHIFF_PERCENT_SCALE / 100.0).clamp(0.0, MAX_EXTRA_WHIFF_CHANCE))
+            .unwrap_or(0.0) as f64
+    }
 
-            let result = match contact_quality {
-                75..=100 => PlayResult::Hit(HitType::Single),
-                50..=74 => {
-                    if rand::thread_rng().gen_bool(0.5) {
-                        PlayResult::Hit(HitType::Single)
-                    } else {
-                        PlayResult::Foul
-                    }
-                }
-                30..=49 => PlayResult::Foul,
-                _ => {
-                    let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
-                    if rand::thread_rng().gen_range(0.0..100.0) < gb_tendency {
-                        PlayResult::Out(OutType::Groundout)
-                    } else {
-                        PlayResult::Out(OutType::Flyout)
-                    }
-                }
-            };
-            return (result, Some(contact_quality));
-        }
+    /// A tired pitcher working with runners on can flinch in the set. Fresh pitchers never
+    /// balk; an exhausted one does about once every hundred pitches.
+    pub fn roll_balk(&self, runners_on: bool, fatigue_penalty: f32) -> bool {
+        let chance = (FATIGUE_PENALTY_FRESH - fatigue_penalty) * BALK_CHANCE_PER_FATIGUE;
+        runners_on && chance > 0.0 && self.rng.borrow_mut().gen_bool(chance as f64)
+    }
 
-        // Close contact - exact or adjacent match outside strike zone
-        if (exact_match || adjacent_match) && !is_strike_zone {
-            return if rng.gen_bool(0.7) {
-                (PlayResult::Foul, Some(20))
-            } else {
-                (PlayResult::Out(OutType::Flyout), Some(15))
-            };
+    /// Where a pitch actually ends up - off-target pitches drift to a neighbouring spot
+    pub fn apply_control(&self, intended: PitchLocation, pitch: &PitchType) -> PitchLocation {
+        let mut rng = self.rng.borrow_mut();
+        if pitch.control >= 1.0 || rng.gen_bool(pitch.control.max(0.0) as f64) {
+            return intended;
         }
 
-        // Swing and miss or weak contact
-        (if is_strike_zone {
-            if rng.gen_bool(0.6) {
-                PlayResult::Strike // Swing and miss
-            } else {
-                PlayResult::Foul // Weak contact
-            }
+        let neighbours = self.neighbours(intended);
+        neighbours[rng.gen_range(0..neighbours.len())]
+    }
+
+    /// The spots a pitch that misses `location` can drift to
+    fn neighbours(&self, location: PitchLocation) -> Vec<PitchLocation> {
+        use PitchLocation::*;
+        [UpInside, Up, UpOutside, Inside, Middle, Outside, DownInside, Down, DownOutside]
+            .into_iter()
+            .filter(|loc| self.locations_match(location, *loc))
+            .collect()
+    }
+
+    /// Park whose dimensions shape batted balls - the home team's
+    pub fn set_ballpark(&self, ballpark: Ballpark) {
+        self.ballpark.set(ballpark);
+    }
+
+    pub fn ballpark(&self) -> Ballpark {
+        self.ballpark.get()
+    }
+
+    pub fn set_weather(&self, weather: Weather) {
+        self.weather.set(weather);
+    }
+
+    pub fn weather(&self) -> Weather {
+        self.weather.get()
+    }
+
+    /// Contact quality points on top of the swing itself: the batter's skill against the
+    /// pitcher's (less for a tired arm), the pitch's bite, momentum and the count. Skills count
+    /// for more on an `exact` swing, right on the ball, than on one a spot off.
+    #[allow(clippy::too_many_arguments)]
+    fn contact_adjustment(&self, exact: bool, batter: Option<&Player>, pitcher: Option<&Player>, fatigue_penalty: f32, whiff: i32, count: (u8, u8), momentum_bonus: i32) -> i32 {
+        let (batter_multiplier, pitcher_multiplier) = if exact {
+            (BATTER_SKILL_BONUS_MULTIPLIER, PITCHER_SKILL_PENALTY_MULTIPLIER)
         } else {
-            if rng.gen_bool(0.8) {
-                PlayResult::Strike // Bad swing
-            } else {
-                PlayResult::Foul
-            }
-        }, Some(10))
+            (ADJACENT_BATTER_SKILL_MULTIPLIER, ADJACENT_PITCHER_SKILL_MULTIPLIER)
+        };
+        let skill_bonus = batter.map(|b| (b.stats.barrel_percent * batter_multiplier) as i32).unwrap_or(0);
+        let pitcher_penalty = pitcher.map(|p| (p.stats.barrel_percent * pitcher_multiplier * fatigue_penalty) as i32).unwrap_or(0);
+        skill_bonus - pitcher_penalty - whiff + momentum_bonus + count_contact_bonus(count)
+    }
+
+    /// Everything outside the batter's control that changes home run odds
+    fn home_run_factor(&self) -> f32 {
+        self.ballpark().park_factor * self.weather().hr_factor()
+    }
+
+    /// Shared random source, so CPU decisions follow the engine's seed
+    pub fn rng(&self) -> RefMut<'_, StdRng> {
+        self.rng.borrow_mut()
     }
 
     fn locations_match(&self, loc1: PitchLocation, loc2: PitchLocation) -> bool {
@@ -256,41 +209,47 @@ impl GameEngine {
         )
     }
 
-    pub fn get_pitch_name(&self, idx: usize) -> &str {
-        self.pitch_types.get(idx).map(|p| p.name).unwrap_or("Unknown")
+    pub fn get_pitch_name<'a>(&'a self, pitcher: Option<&'a Player>, idx: usize) -> &'a str {
+        self.pitch_type(pitcher, idx).map(|p| p.name.as_str()).unwrap_or("Unknown")
     }
 
-    /// Generate ball-in-play data from contact quality
+    /// Generate ball-in-play data from contact quality. The swing's timing decides whether it's
+    /// pulled or hit the other way.
     pub fn generate_ball_in_play(
         &self,
         contact_quality: i32,
         batter: Option<&Player>,
-        _pitcher: Option<&Player>,
-    ) -> Option<BallInPlay> {
-        let mut rng = rand::thread_rng();
+        pitcher: Option<&Player>,
+        swing_timing: &SwingTiming,
+    ) -> BallInPlay {
+        let lift = launch_angle_tilt(batter) * LAUNCH_ANGLE_SHARE;
+        let mut rng = self.rng.borrow_mut();
         
-        // Determine ball type based on contact quality
+        // Determine ball type based on contact quality, tilted by the batter's launch angle
         let (ball_type, speed, hang_time) = match contact_quality {
             CONTACT_EXCELLENT_MIN..=100 => {
                 // Excellent contact - likely fly ball or line drive
-                if rng.gen_bool(0.6) {
+                if rng.gen_bool(0.6 + lift) {
                     (BallType::FlyBall, rng.gen_range(SPEED_EXCELLENT_MIN..SPEED_EXCELLENT_MAX), rng.gen_range(HANG_TIME_FLYBALL_MIN..HANG_TIME_FLYBALL_MAX))
                 } else {
                     (BallType::LineDrive, rng.gen_range(90.0..110.0), rng.gen_range(HANG_TIME_LINEDRIVE_MIN..HANG_TIME_LINEDRIVE_MAX))
                 }
             }
             60..=84 => {
-                // Good contact - mix of outcomes
-                let roll = rng.gen_range(1..=10);
-                match roll {
-                    1..=3 => (BallType::FlyBall, rng.gen_range(SPEED_GOOD_MIN..SPEED_GOOD_MAX), rng.gen_range(50..70)),
-                    4..=6 => (BallType::LineDrive, rng.gen_range(80.0..100.0), rng.gen_range(25..45)),
-                    _ => (BallType::Grounder, rng.gen_range(60.0..90.0), 0),
+                // Good contact - mix of outcomes, line drives holding steady as the lift moves
+                // the rest between the air and the ground
+                let roll = rng.gen_range(0.0..1.0);
+                if roll < 0.3 + lift {
+                    (BallType::FlyBall, rng.gen_range(SPEED_GOOD_MIN..SPEED_GOOD_MAX), rng.gen_range(50..70))
+                } else if roll < 0.6 + lift {
+                    (BallType::LineDrive, rng.gen_range(80.0..100.0), rng.gen_range(25..45))
+                } else {
+                    (BallType::Grounder, rng.gen_range(60.0..90.0), 0)
                 }
             }
             40..=59 => {
                 // Weak contact - mostly grounders
-                if rng.gen_bool(0.7) {
+                if rng.gen_bool(0.7 - lift) {
                     (BallType::Grounder, rng.gen_range(50.0..75.0), 0)
                 } else {
                     (BallType::PopFly, rng.gen_range(SPEED_WEAK_MIN..SPEED_WEAK_MAX), rng.gen_range(HANG_TIME_POPFLY_MIN..HANG_TIME_POPFLY_MAX))
@@ -298,29 +257,71 @@ impl GameEngine {
             }
             _ => {
                 // Very weak contact - grounders and pop flies
-                let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
-                if rng.gen_range(0.0..100.0) < gb_tendency {
+                if rng.gen_bool(0.5 - lift) {
                     (BallType::Grounder, rng.gen_range(40.0..65.0), 0)
                 } else {
                     (BallType::PopFly, rng.gen_range(30.0..50.0), rng.gen_range(30..50))
                 }
             }
         };
+        let spray = SpraySide::from_timing(&mut *rng, swing_timing);
+        drop(rng);
 
-        // Determine field direction based on swing and random variation
-        let direction = self.generate_field_direction(&ball_type);
+        let weather = self.weather();
+        let speed = match ball_type {
+            BallType::FlyBall => speed * weather.fly_ball_speed_factor(),
+            BallType::Grounder => speed * weather.grounder_speed_factor(),
+            _ => speed,
+        };
 
-        Some(BallInPlay {
+        // Excellent contact goes where the swing sends it; the rest is scattered around the field
+        let direction = if contact_quality >= CONTACT_EXCELLENT_MIN {
+            spray.outfield(batting_side(batter.and_then(|b| b.bats), pitcher.and_then(|p| p.throws)))
+        } else {
+            self.field_direction(&ball_type)
+        };
+
+        BallInPlay {
             ball_type,
             direction,
             speed,
             hang_time,
             initial_contact_quality: contact_quality,
+            hr_chance: home_run_chance(batter),
+            speed_factor: batter_speed_factor(batter),
+            spray,
+        }
+    }
+
+    /// Some weakly hit fouls pop up where a fielder can get to them - behind the plate or just
+    /// over either line. `None` when the foul goes into the stands.
+    pub fn generate_foul_pop(&self, contact_quality: i32) -> Option<BallInPlay> {
+        let mut rng = self.rng.borrow_mut();
+        let chance = FOUL_POP_CHANCE_MAX * (1.0 - contact_quality.clamp(0, 100) as f64 / 100.0);
+        if !rng.gen_bool(chance) {
+            return None;
+        }
+
+        let direction = match rng.gen_range(1..=3) {
+            1 => FieldDirection::FoulLeft,
+            2 => FieldDirection::BehindPlate,
+            _ => FieldDirection::FoulRight,
+        };
+        Some(BallInPlay {
+            ball_type: BallType::PopFly,
+            direction,
+            speed: rng.gen_range(SPEED_WEAK_MIN..SPEED_WEAK_MAX),
+            hang_time: rng.gen_range(HANG_TIME_POPFLY_MIN..HANG_TIME_POPFLY_MAX),
+            initial_contact_quality: contact_quality,
+            hr_chance: 0.0,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
         })
     }
 
-    fn generate_field_direction(&self, ball_type: &BallType) -> FieldDirection {
-        let mut rng = rand::thread_rng();
+    /// Where a ball of this type goes - grounders to the infield, flies to the outfield
+    pub fn field_direction(&self, ball_type: &BallType) -> FieldDirection {
+        let mut rng = self.rng.borrow_mut();
         
         // Different ball types have different distribution
         match ball_type {
@@ -366,22 +367,25 @@ impl GameEngine {
         ball: &BallInPlay,
         catch_timing: u8,  // How many frames it took to position
         perfect_timing: u8, // Optimal timing window
+        infield_fly: bool,  // Infield fly rule in effect for this base/out state
+        defense: &Defense,  // The fielding team's outs above average by position
     ) -> (PlayResult, f32) {  // Returns (result, success_chance)
-        let mut rng = rand::thread_rng();
-        
         // Calculate timing accuracy (closer to perfect = higher accuracy)
         let timing_diff = (catch_timing as i32 - perfect_timing as i32).abs() as f32;
         // Much more forgiving timing window
         let timing_accuracy = 1.0 - (timing_diff / FIELDING_TIMING_WINDOW).min(1.0);
 
         // Base catch success rate - fielders catch MOST balls
-        // Since we only field hits now, success = preventing the hit (catching it for an out)
+        // Every batted ball is fielded, so success = turning it into an out
         let base_success = match ball.ball_type {
             BallType::PopFly => FIELDING_SUCCESS_POPFLY,
             BallType::FlyBall => FIELDING_SUCCESS_FLYBALL,
             BallType::LineDrive => FIELDING_SUCCESS_LINEDRIVE,
             BallType::Grounder => FIELDING_SUCCESS_GROUNDER,
         };
+        // A good glove at the spot turns a few more of them into outs
+        let glove = defense[ball.direction.fielder() as usize] as f32 * FIELDING_SUCCESS_PER_OAA;
+        let base_success = (base_success + glove).min(1.0);
 
         // Speed only slightly affects difficulty for very fast balls
         let speed_penalty = if ball.speed > FIELDING_SPEED_THRESHOLD {
@@ -400,17 +404,27 @@ impl GameEngine {
             ((base_success - speed_penalty) * (FIELDING_TIMING_POOR_MULTIPLIER + timing_accuracy * FIELDING_TIMING_POOR_MULTIPLIER)).max(FIELDING_MIN_SUCCESS_RATE)
         };
 
+        // Batter is out whether or not the pop fly is caught, so no roll
+        if Self::is_infield_fly(ball, infield_fly) {
+            return (PlayResult::Out(OutType::InfieldFly), success_chance);
+        }
+
         // Determine outcome
-        let result = if rng.gen_range(0.0..1.0) < success_chance {
-            // Successful catch/field
-            match ball.ball_type {
-                BallType::FlyBall | BallType::PopFly | BallType::LineDrive => {
-                    PlayResult::Out(OutType::Flyout)
-                }
-                BallType::Grounder => {
-                    PlayResult::Out(OutType::Groundout)
-                }
-            }
+        let mut rng = self.rng.borrow_mut();
+        let caught = rng.gen_range(0.0..1.0) < success_chance;
+        // Even a clean pick on a grounder can lose the race to first against a fast batter
+        let beaten_out = caught
+            && ball.ball_type == BallType::Grounder
+            && rng.gen_bool((INFIELD_HIT_CHANCE * ball.speed_factor).min(1.0) as f64);
+        // And a fielder who gets there can still boot it - a dropped foul pop is only a foul
+        let booted = caught && !beaten_out && !ball.direction.is_foul() && rng.gen_bool(FIELDING_ERROR_CHANCE);
+        drop(rng);
+        let result = if beaten_out {
+            PlayResult::Hit(HitType::Single)
+        } else if booted {
+            PlayResult::ReachedOnError
+        } else if caught {
+            PlayResult::Out(Self::caught_out_type(&ball.ball_type))
         } else {
             // Ball gets through - determine hit type
             self.ball_gets_through(ball)
@@ -419,50 +433,104 @@ impl GameEngine {
         (result, success_chance)
     }
 
+    /// The out a fielded ball makes - always the kind of ball that was hit
+    pub fn caught_out_type(ball_type: &BallType) -> OutType {
+        match ball_type {
+            BallType::Grounder => OutType::Groundout,
+            BallType::LineDrive => OutType::LineOut,
+            BallType::FlyBall | BallType::PopFly => OutType::Flyout,
+        }
+    }
+
+    pub fn is_infield_fly(ball: &BallInPlay, infield_fly: bool) -> bool {
+        infield_fly && ball.ball_type == BallType::PopFly && !ball.direction.is_foul()
+    }
+
+    /// Fielder never got to the ball - still an out under the infield fly rule
+    pub fn uncaught_result(&self, ball: &BallInPlay, infield_fly: bool) -> PlayResult {
+        if Self::is_infield_fly(ball, infield_fly) {
+            PlayResult::Out(OutType::InfieldFly)
+        } else {
+            self.ball_gets_through(ball)
+        }
+    }
+
+    /// Nobody fielded the ball in time - the fielder still gets a late jump on some of them
+    pub fn fielder_plays_ball(&self, ball: &BallInPlay, infield_fly: bool, defense: &Defense) -> (PlayResult, f32) {
+        if !self.rng.borrow_mut().gen_bool(SIM_FIELDER_ATTEMPT_RATE) {
+            return (self.uncaught_result(ball, infield_fly), 0.0);
+        }
+
+        let perfect_timing = ball.hang_time / 2;
+        let catch_timing = {
+            let mut rng = self.rng.borrow_mut();
+            let offset = rng.gen_range(-SIM_FIELDING_TIMING_SPREAD..=SIM_FIELDING_TIMING_SPREAD);
+            (perfect_timing as i32 + offset).max(0) as u8
+        };
+        self.calculate_fielding_result(ball, catch_timing, perfect_timing, infield_fly, defense)
+    }
+
+    /// With a force on at second, the defense sometimes takes the lead runner on a groundout
+    /// and leaves the batter safe at first
+    pub fn fielders_choice(&self, result: PlayResult, force_at_second: bool) -> PlayResult {
+        if force_at_second
+            && result == PlayResult::Out(OutType::Groundout)
+            && self.rng.borrow_mut().gen_bool(FIELDERS_CHOICE_CHANCE)
+        {
+            PlayResult::Out(OutType::FieldersChoice)
+        } else {
+            result
+        }
+    }
+
     pub fn ball_gets_through(&self, ball: &BallInPlay) -> PlayResult {
-        let mut rng = rand::thread_rng();
+        // A dropped foul pop is only a foul
+        if ball.direction.is_foul() {
+            return PlayResult::Foul;
+        }
+
+        let park_factor = self.home_run_factor();
+        let mut rng = self.rng.borrow_mut();
         
         // Use original contact quality to determine hit
         match ball.initial_contact_quality {
             CONTACT_EXCELLENT_MIN..=100 => {
                 // Great contact that got through
-                if ball.speed > FIELDING_SPEED_THRESHOLD {
-                    if rng.gen_bool(0.4) {
-                        PlayResult::Hit(HitType::HomeRun)
-                    } else {
-                        PlayResult::Hit(HitType::Triple)
-                    }
+                // Hard enough to leave the yard - far less often the other way
+                let hard_hit = ball.speed > FIELDING_SPEED_THRESHOLD;
+                let hr_chance = ball.hr_chance * ball.spray.home_run_factor() * park_factor;
+                if hard_hit && rng.gen_bool(hr_chance.min(1.0) as f64) {
+                    PlayResult::Hit(HitType::HomeRun)
                 } else {
-                    let roll = rng.gen_range(1..=3);
-                    match roll {
-                        1 => PlayResult::Hit(HitType::Triple),
-                        _ => PlayResult::Hit(HitType::Double),
-                    }
+                    PlayResult::Hit(double_or_triple(&mut *rng, Some(ball.direction), hard_hit, ball.speed_factor))
                 }
             }
             60..=84 => {
-                let roll = rng.gen_range(1..=10);
-                match roll {
-                    1..=2 => PlayResult::Hit(HitType::Triple),
-                    3..=5 => PlayResult::Hit(HitType::Double),
-                    _ => PlayResult::Hit(HitType::Single),
+                if rng.gen_bool(extra_base_chance(ball.direction)) {
+                    PlayResult::Hit(double_or_triple(&mut *rng, Some(ball.direction), false, ball.speed_factor))
+                } else {
+                    PlayResult::Hit(stretch_single(&mut *rng, ball.direction, ball.speed_factor))
                 }
             }
-            _ => PlayResult::Hit(HitType::Single),
+            _ => PlayResult::Hit(stretch_single(&mut *rng, ball.direction, ball.speed_factor)),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn calculate_pitch_result_with_timing(
         &self,
         pitch_location: PitchLocation,
         swing_location: Option<PitchLocation>,
-        _pitch_type_idx: usize,
+        pitch_type_idx: usize,
         batter: Option<&Player>,
         pitcher: Option<&Player>,
         fatigue_penalty: f32,
         swing_timing: &SwingTiming,
+        count: (u8, u8), // Balls and strikes before this pitch
+        momentum_bonus: i32, // Contact quality points for the batting side's momentum
     ) -> (PlayResult, Option<i32>) {
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng.borrow_mut();
+        let whiff = self.pitch_type(pitcher, pitch_type_idx).map(|p| p.whiff).unwrap_or(0);
 
         // No swing
         if swing_location.is_none() {
@@ -480,15 +548,18 @@ impl GameEngine {
         let adjacent_match = !exact_match && self.locations_match(pitch_location, swing_loc);
         let is_strike_zone = pitch_location.is_strike();
 
+        // Velocity shrinks the perfect window, so some "perfect" swings are really a tick late
+        let perfect_window = self.perfect_window_frames(pitcher);
+        let beaten_by_velocity = matches!(swing_timing, SwingTiming::Perfect)
+            && perfect_window < PERFECT_TIMING_WINDOW_FRAMES
+            && !rng.gen_bool(perfect_window as f64 / PERFECT_TIMING_WINDOW_FRAMES as f64);
+        let swing_timing = if beaten_by_velocity { &SwingTiming::Late } else { swing_timing };
+
+        if *swing_timing == SwingTiming::NoSwing {
+            return if is_strike_zone { (PlayResult::Strike, None) } else { (PlayResult::Ball, None) };
+        }
         // Apply timing penalties/bonuses to contact quality
-        let timing_multiplier = match swing_timing {
-            SwingTiming::TooEarly => 0.1,   // Almost impossible to make contact
-            SwingTiming::Early => 0.6,      // Reduced contact quality
-            SwingTiming::Perfect => 1.3,    // Bonus to contact quality
-            SwingTiming::Late => 0.6,       // Reduced contact quality  
-            SwingTiming::TooLate => 0.1,    // Almost impossible to make contact
-            SwingTiming::NoSwing => return if is_strike_zone { (PlayResult::Strike, None) } else { (PlayResult::Ball, None) },
-        };
+        let timing_multiplier = timing_multiplier(swing_timing);
 
         // Very early/late swings have high chance of complete miss
         if matches!(swing_timing, SwingTiming::TooEarly | SwingTiming::TooLate) {
@@ -499,44 +570,31 @@ impl GameEngine {
             };
         }
 
+        // Swing-and-miss pitchers get some whiffs no matter how the swing was timed, and more
+        // when they're ahead in the count
+        let extra_whiff = self.extra_whiff_chance(pitcher) + count_whiff_chance(count);
+        if extra_whiff > 0.0 && rng.gen_bool(extra_whiff) {
+            return (PlayResult::Strike, Some(5));
+        }
+
         // Perfect contact with good timing
         if exact_match && is_strike_zone {
             let mut contact_quality = rng.gen_range(1..=100);
             
             // Apply timing bonus/penalty
             contact_quality = ((contact_quality as f32 * timing_multiplier) as i32).clamp(1, 100);
-            
-            // Apply player skills
-            if let Some(batter) = batter {
-                let skill_bonus = (batter.stats.barrel_percent * BATTER_SKILL_BONUS_MULTIPLIER) as i32;
-                contact_quality = (contact_quality + skill_bonus).min(100);
-            }
-
-            if let Some(pitcher) = pitcher {
-                let pitcher_penalty = (pitcher.stats.barrel_percent * PITCHER_SKILL_PENALTY_MULTIPLIER * fatigue_penalty) as i32;
-                contact_quality = (contact_quality - pitcher_penalty).max(1);
-            }
+            // Then the players, the pitch, momentum and the count - clamped once they're all in
+            // so a strong batter's bonus isn't lost to the cap
+            contact_quality = (contact_quality + self.contact_adjustment(true, batter, pitcher, fatigue_penalty, whiff, count, momentum_bonus)).clamp(1, 100);
 
             // Same outcome logic as before, but with timing-adjusted contact quality
             let result = match contact_quality {
-                90..=100 => {
-                    let hr_chance = if let Some(batter) = batter {
-                        (batter.stats.max_distance as f32 / 500.0 * 100.0) as u32
-                    } else { 25 };
-                    
-                    if rng.gen_range(1..=100) <= hr_chance.min(25) {
-                        PlayResult::Hit(HitType::HomeRun)
-                    } else if rng.gen_bool(0.6) {
-                        PlayResult::Hit(HitType::Triple)
-                    } else {
-                        PlayResult::Hit(HitType::Double)
-                    }
-                }
+                // Whether it leaves the yard is up to the ball in play - see `ball_gets_through`
+                90..=100 => PlayResult::Hit(double_or_triple(&mut *rng, None, true, batter_speed_factor(batter))),
                 75..=89 => {
                     let roll = rng.gen_range(1..=10);
                     match roll {
-                        1 => PlayResult::Hit(HitType::Triple),
-                        2..=4 => PlayResult::Hit(HitType::Double),
+                        1..=4 => PlayResult::Hit(double_or_triple(&mut *rng, None, false, batter_speed_factor(batter))),
                         5..=7 => PlayResult::Hit(HitType::Single),
                         _ => {
                             if rng.gen_bool(0.6) {
@@ -598,16 +656,7 @@ impl GameEngine {
         if adjacent_match && is_strike_zone {
             let mut contact_quality = rng.gen_range(1..=100);
             contact_quality = ((contact_quality as f32 * timing_multiplier) as i32).clamp(1, 100);
-            
-            if let Some(batter) = batter {
-                let skill_bonus = (batter.stats.barrel_percent * ADJACENT_BATTER_SKILL_MULTIPLIER) as i32;
-                contact_quality = (contact_quality + skill_bonus).min(100);
-            }
-            
-            if let Some(pitcher) = pitcher {
-                let pitcher_penalty = (pitcher.stats.barrel_percent * ADJACENT_PITCHER_SKILL_MULTIPLIER * fatigue_penalty) as i32;
-                contact_quality = (contact_quality - pitcher_penalty).max(1);
-            }
+            contact_quality = (contact_quality + self.contact_adjustment(false, batter, pitcher, fatigue_penalty, whiff, count, momentum_bonus)).clamp(1, 100);
 
             let result = match contact_quality {
                 75..=100 => PlayResult::Hit(HitType::Single),
@@ -631,15 +680,31 @@ impl GameEngine {
             return (result, Some(contact_quality));
         }
 
-        // Poor location or timing - increased chance of swing and miss
-        let miss_chance = if is_strike_zone {
-            match swing_timing {
-                SwingTiming::Early | SwingTiming::Late => 0.8, // High miss chance on poor timing
-                SwingTiming::Perfect => 0.6,                   // Still can miss on bad location
-                _ => 0.9,
+        // A chase - the nearer the swing is to the ball, the likelier it's fouled off or even
+        // dumped into play, though never hit hard
+        if !is_strike_zone {
+            let distance = (pitch_location.distance(swing_loc) as usize).min(CHASE_MISS_CHANCE.len() - 1);
+            if rng.gen_bool(CHASE_MISS_CHANCE[distance]) {
+                return (PlayResult::Strike, Some(5)); // Swing and miss
             }
-        } else {
-            0.9 // Very high miss chance outside zone
+            let contact_quality = ((rng.gen_range(1..=CHASE_CONTACT_MAX[distance]) as f32 * timing_multiplier) as i32).clamp(1, 100);
+            if contact_quality < CONTACT_WEAK_MIN {
+                return (PlayResult::Foul, Some(contact_quality));
+            }
+            let gb_tendency = batter.map(|b| b.stats.gb).unwrap_or(50.0);
+            let result = if rng.gen_range(0.0..100.0) < gb_tendency {
+                PlayResult::Out(OutType::Groundout)
+            } else {
+                PlayResult::Out(OutType::Flyout)
+            };
+            return (result, Some(contact_quality));
+        }
+
+        // Poor location or timing in the zone - increased chance of swing and miss
+        let miss_chance = match swing_timing {
+            SwingTiming::Early | SwingTiming::Late => 0.8, // High miss chance on poor timing
+            SwingTiming::Perfect => 0.6,                   // Still can miss on bad location
+            _ => 0.9,
         };
 
         if rng.gen_bool(miss_chance) {
@@ -648,6 +713,497 @@ impl GameEngine {
             (PlayResult::Foul, Some(15))  // Weak contact
         }
     }
+}
+
+/// Rough odds for a pitch before it's thrown. Fouls count as strikes; contact is a ball put in play.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PitchPreview {
+    pub strike: f32,
+    pub ball: f32,
+    pub contact: f32,
+}
+
+// Pitch preview - the same formulas as `calculate_pitch_result_with_timing`, with every roll
+// replaced by its odds
+impl GameEngine {
+    /// What the CPU batter is likely to do with a pitch aimed at `pitch_loc`: where its control
+    /// may take it, whether the batter offers, where and when they swing, and what contact
+    /// comes of it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn preview_pitch(
+        &self,
+        pitch_loc: PitchLocation,
+        pitch_type_idx: usize,
+        batter: Option<&Player>,
+        pitcher: Option<&Player>,
+        fatigue_penalty: f32,
+        count: (u8, u8),
+        momentum_bonus: i32,
+    ) -> PitchPreview {
+        let ai = BatterAi::default();
+        let control = self.pitch_type(pitcher, pitch_type_idx).map(|p| p.control.clamp(0.0, 1.0)).unwrap_or(1.0) as f64;
+        let neighbours = self.neighbours(pitch_loc);
+        let mut landings = vec![(pitch_loc, control)];
+        landings.extend(neighbours.iter().map(|&loc| (loc, (1.0 - control) / neighbours.len() as f64)));
+
+        let whiff = self.pitch_type(pitcher, pitch_type_idx).map(|p| p.whiff).unwrap_or(0);
+        let adjustments = (
+            self.contact_adjustment(true, batter, pitcher, fatigue_penalty, whiff, count, momentum_bonus),
+            self.contact_adjustment(false, batter, pitcher, fatigue_penalty, whiff, count, momentum_bonus),
+        );
+        let no_extra_whiff = 1.0 - (self.extra_whiff_chance(pitcher) + count_whiff_chance(count)).min(1.0);
+        // A perfect swing is sometimes a tick late against velocity
+        let on_time = (self.perfect_window_frames(pitcher) as f64 / PERFECT_TIMING_WINDOW_FRAMES as f64).min(1.0);
+        let frames: Vec<u8> = ai::swing_frames().map(|f| f.max(1)).collect();
+
+        let mut preview = PitchPreview::default();
+        for (landing, landing_odds) in landings {
+            let swing_odds = ai.swing_probability(landing);
+            let taken = (landing_odds * (1.0 - swing_odds)) as f32;
+            if landing.is_strike() {
+                preview.strike += taken;
+            } else {
+                preview.ball += taken;
+            }
+
+            let mut in_play = 0.0;
+            for numpad in 1..=9 {
+                let swing_loc = PitchLocation::from_numpad(numpad);
+                let guess = (1.0 - AI_LOCATION_READ_RATE) / 9.0;
+                let read_odds = if swing_loc == landing { AI_LOCATION_READ_RATE + guess } else { guess };
+                for &frames_left in &frames {
+                    let chance = |timing| self.in_play_chance(landing, swing_loc, timing, adjustments);
+                    let fair = match crate::game::input_handler::classify_swing_timing(frames_left, true) {
+                        SwingTiming::Perfect => on_time * chance(SwingTiming::Perfect) + (1.0 - on_time) * chance(SwingTiming::Late),
+                        timing => chance(timing),
+                    };
+                    in_play += read_odds / frames.len() as f64 * no_extra_whiff * fair;
+                }
+            }
+            let swung = landing_odds * swing_odds;
+            preview.contact += (swung * in_play) as f32;
+            preview.strike += (swung * (1.0 - in_play)) as f32;
+        }
+        preview
+    }
+
+    /// Chance a swing with this timing puts the ball in play, given the contact quality
+    /// adjustments for a swing right on the ball and one a spot off
+    fn in_play_chance(&self, pitch_loc: PitchLocation, swing_loc: PitchLocation, timing: SwingTiming, adjustments: (i32, i32)) -> f64 {
+        if matches!(timing, SwingTiming::TooEarly | SwingTiming::TooLate | SwingTiming::NoSwing) {
+            return 0.0;
+        }
+        let multiplier = timing_multiplier(&timing);
+        let swung_quality = |roll: i32| ((roll as f32 * multiplier) as i32).clamp(1, 100);
+        if !pitch_loc.is_strike() {
+            let distance = (pitch_loc.distance(swing_loc) as usize).min(CHASE_MISS_CHANCE.len() - 1);
+            let best = CHASE_CONTACT_MAX[distance];
+            let fair = (1..=best).filter(|&roll| swung_quality(roll) >= CONTACT_WEAK_MIN).count();
+            return (1.0 - CHASE_MISS_CHANCE[distance]) * fair as f64 / best as f64;
+        }
+        let (adjustment, table): (i32, fn(i32) -> f64) = if swing_loc == pitch_loc {
+            (adjustments.0, exact_contact_in_play)
+        } else if self.locations_match(pitch_loc, swing_loc) {
+            (adjustments.1, adjacent_contact_in_play)
+        } else {
+            return 0.0; // Wrong spot in the zone - a whiff or a weak foul
+        };
+        (1..=100).map(|roll| table((swung_quality(roll) + adjustment).clamp(1, 100))).sum::<f64>() / 100.0
+    }
+}
+
+/// Share of swings right on the ball that stay fair at this contact quality
+fn exact_contact_in_play(contact_quality: i32) -> f64 {
+    if contact_quality >= 75 {
+        1.0
+    } else {
+        0.8 // Two in ten are fouled off
+    }
+}
+
+/// Share of swings a spot off the ball that stay fair at this contact quality
+fn adjacent_contact_in_play(contact_quality: i32) -> f64 {
+    match contact_quality {
+        75..=100 => 1.0,
+        50..=74 => 0.5,
+        30..=49 => 0.0,
+        _ => 1.0,
+    }
+}
+
+/// How much the swing's timing scales contact quality
+fn timing_multiplier(timing: &SwingTiming) -> f32 {
+    match timing {
+        SwingTiming::TooEarly => 0.1,   // Almost impossible to make contact
+        SwingTiming::Early => 0.6,      // Reduced contact quality
+        SwingTiming::Perfect => 1.3,    // Bonus to contact quality
+        SwingTiming::Late => 0.6,       // Reduced contact quality
+        SwingTiming::TooLate => 0.1,    // Almost impossible to make contact
+        SwingTiming::NoSwing => 0.0,
+    }
+}
+
+// Headless simulation - the balance tests and quick sim drive it
+impl GameEngine {
+    /// Simulate a full plate appearance without a terminal, using an
+    /// average-player decision model for both pitcher and batter.
+    /// Batted balls go through the same fielding resolution as the interactive game.
+    pub fn simulate_plate_appearance(
+        &self,
+        batter: Option<&Player>,
+        pitcher: Option<&Player>,
+        fatigue_penalty: f32,
+    ) -> PlateAppearanceResult {
+        // Without a pitch cap an endless foul streak is the only way this doesn't return
+        self.try_simulate_plate_appearance(batter, pitcher, fatigue_penalty, 0, &Defense::default(), u32::MAX)
+            .unwrap_or(PlateAppearanceResult::Out(OutType::Strikeout))
+    }
+
+    /// Like `simulate_plate_appearance`, but gives up with `None` if nothing has
+    /// happened after `max_pitches` pitches
+    pub fn try_simulate_plate_appearance(
+        &self,
+        batter: Option<&Player>,
+        pitcher: Option<&Player>,
+        fatigue_penalty: f32,
+        momentum_bonus: i32,
+        defense: &Defense,
+        max_pitches: u32,
+    ) -> Option<PlateAppearanceResult> {
+        let mut balls = 0;
+        let mut strikes = 0;
+
+        for _ in 0..max_pitches {
+            let pitch_location = self.sim_pitch_location();
+            let (swing_location, swing_timing) = self.sim_batter_swing(pitch_location);
+            let (result, contact_quality) = self.calculate_pitch_result_with_timing(
+                pitch_location,
+                swing_location,
+                0,
+                batter,
+                pitcher,
+                fatigue_penalty,
+                &swing_timing,
+                (balls, strikes),
+                momentum_bonus,
+            );
+
+            match result {
+                PlayResult::Strike => {
+                    strikes += 1;
+                    if strikes >= MAX_STRIKES {
+                        return Some(PlateAppearanceResult::Out(OutType::Strikeout));
+                    }
+                }
+                PlayResult::Ball => {
+                    balls += 1;
+                    if balls >= MAX_BALLS {
+                        return Some(PlateAppearanceResult::Walk);
+                    }
+                }
+                PlayResult::Foul => {
+                    if let Some(pop) = contact_quality.and_then(|cq| self.generate_foul_pop(cq)) {
+                        if let PlayResult::Out(out_type) = self.fielder_plays_ball(&pop, false, defense).0 {
+                            return Some(PlateAppearanceResult::Out(out_type));
+                        }
+                    }
+                    if strikes < MAX_STRIKES - 1 {
+                        strikes += 1;
+                    }
+                }
+                PlayResult::Hit(_) | PlayResult::Out(_) | PlayResult::ReachedOnError => {
+                    return Some(self.sim_fielding(contact_quality, batter, pitcher, defense, &swing_timing));
+                }
+            }
+        }
+        None
+    }
+
+    /// Run `count` simulated plate appearances and tally the outcomes
+    pub fn simulate_plate_appearances(
+        &self,
+        count: u32,
+        batter: Option<&Player>,
+        pitcher: Option<&Player>,
+        fatigue_penalty: f32,
+    ) -> SimulationSummary {
+        let mut summary = SimulationSummary::default();
+        for _ in 0..count {
+            let result = self.simulate_plate_appearance(batter, pitcher, fatigue_penalty);
+            summary.record(&result);
+        }
+        summary
+    }
+
+    fn sim_pitch_location(&self) -> PitchLocation {
+        ai::choose_pitch_location(&mut *self.rng.borrow_mut())
+    }
+
+    fn sim_batter_swing(&self, pitch_location: PitchLocation) -> (Option<PitchLocation>, SwingTiming) {
+        let mut rng = self.rng.borrow_mut();
+        let batter_ai = BatterAi::default();
+        if !batter_ai.wants_to_swing(pitch_location, &mut *rng) {
+            return (None, SwingTiming::NoSwing);
+        }
+
+        let swing_location = batter_ai.read_location(pitch_location, &mut *rng);
+
+        let timing = match rng.gen_range(0.0..1.0) {
+            r if r < SIM_TIMING_TOO_EARLY_RATE => SwingTiming::TooEarly,
+            r if r < SIM_TIMING_TOO_EARLY_RATE + SIM_TIMING_EARLY_RATE => SwingTiming::Early,
+            r if r < 1.0 - SIM_TIMING_TOO_LATE_RATE - SIM_TIMING_LATE_RATE => SwingTiming::Perfect,
+            r if r < 1.0 - SIM_TIMING_TOO_LATE_RATE => SwingTiming::Late,
+            _ => SwingTiming::TooLate,
+        };
+        (Some(swing_location), timing)
+    }
+
+    /// The ball in play decides the result, exactly as in the interactive game
+    fn sim_fielding(
+        &self,
+        contact_quality: Option<i32>,
+        batter: Option<&Player>,
+        pitcher: Option<&Player>,
+        defense: &Defense,
+        swing_timing: &SwingTiming,
+    ) -> PlateAppearanceResult {
+        let contact_quality = contact_quality.unwrap_or(CONTACT_WEAK_MIN);
+        let ball = self.generate_ball_in_play(contact_quality, batter, pitcher, swing_timing);
+
+        // Simulated plate appearances start with the bases empty
+        match self.fielder_plays_ball(&ball, false, defense).0 {
+            PlayResult::Hit(hit_type) => PlateAppearanceResult::Hit(hit_type),
+            PlayResult::Out(out_type) => PlateAppearanceResult::Out(out_type),
+            PlayResult::ReachedOnError => PlateAppearanceResult::ReachedOnError,
+            _ => PlateAppearanceResult::Hit(HitType::Single),
+        }
+    }
+}
+
+/// Final outcome of a simulated plate appearance
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlateAppearanceResult {
+    Walk,
+    Hit(HitType),
+    Out(OutType),
+    ReachedOnError,
+}
+
+/// Tally of simulated plate appearances, used for balance checks
+#[derive(Debug, Clone, Default)]
+pub struct SimulationSummary {
+    pub plate_appearances: u32,
+    pub at_bats: u32,
+    pub hits: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub strikeouts: u32,
+    pub walks: u32,
+}
+
+impl SimulationSummary {
+    pub fn record(&mut self, result: &PlateAppearanceResult) {
+        self.plate_appearances += 1;
+        match result {
+            PlateAppearanceResult::Walk => self.walks += 1,
+            PlateAppearanceResult::Hit(hit_type) => {
+                self.at_bats += 1;
+                self.hits += 1;
+                match hit_type {
+                    HitType::Single => {}
+                    HitType::Double => self.doubles += 1,
+                    HitType::Triple => self.triples += 1,
+                    HitType::HomeRun => self.home_runs += 1,
+                }
+            }
+            PlateAppearanceResult::Out(out_type) => {
+                self.at_bats += 1;
+                if *out_type == OutType::Strikeout {
+                    self.strikeouts += 1;
+                }
+            }
+            PlateAppearanceResult::ReachedOnError => self.at_bats += 1,
+        }
+    }
+
+    pub fn batting_average(&self) -> f32 {
+        ratio(self.hits, self.at_bats)
+    }
+
+    pub fn strikeout_rate(&self) -> f32 {
+        ratio(self.strikeouts, self.plate_appearances)
+    }
+
+    pub fn walk_rate(&self) -> f32 {
+        ratio(self.walks, self.plate_appearances)
+    }
 
-    // Keep original method for backward compatibility
+    /// Home runs per ball put in play (plate appearances minus strikeouts and walks)
+    pub fn home_runs_per_contact(&self) -> f32 {
+        ratio(self.home_runs, self.plate_appearances - self.strikeouts - self.walks)
     }
+}
+
+/// Contact quality points for the count, e.g. +8 at 2-0 and -8 at 0-2
+pub fn count_contact_bonus((balls, strikes): (u8, u8)) -> i32 {
+    (balls as i32 - strikes as i32) * COUNT_CONTACT_PER_PITCH
+}
+
+/// Extra swing-and-miss chance for a pitcher ahead in the count
+pub fn count_whiff_chance((balls, strikes): (u8, u8)) -> f64 {
+    strikes.saturating_sub(balls) as f64 * COUNT_WHIFF_PER_STRIKE
+}
+
+/// Park-neutral chance that excellent contact leaves the yard, from the batter's barrel rate
+/// and how far their home runs travel
+pub fn home_run_chance(batter: Option<&Player>) -> f32 {
+    let Some(batter) = batter else {
+        return HR_CHANCE_LEAGUE_AVG;
+    };
+    let barrels = (batter.stats.barrel_percent - LEAGUE_AVG_BARREL_PERCENT) * HR_CHANCE_PER_BARREL_POINT;
+    let distance = if batter.stats.avg_hr_distance == 0 {
+        -HR_CHANCE_NO_HOMERS_PENALTY
+    } else {
+        (batter.stats.avg_hr_distance as f32 - LEAGUE_AVG_HR_DISTANCE) * HR_CHANCE_PER_FOOT
+    };
+    (HR_CHANCE_LEAGUE_AVG + barrels + distance).clamp(HR_CHANCE_MIN, HR_CHANCE_MAX)
+}
+
+/// How far a batter's average launch angle leans from league average, from -1.0 (all the way
+/// toward the ground) to 1.0 (toward the air). Level without a batter.
+pub fn launch_angle_tilt(batter: Option<&Player>) -> f64 {
+    batter
+        .map(|b| ((b.stats.avg_hit_angle - LEAGUE_AVG_HIT_ANGLE) / LAUNCH_ANGLE_SPREAD).clamp(-1.0, 1.0) as f64)
+        .unwrap_or(0.0)
+}
+
+/// How well a batter runs, as a multiplier on extra-base tries. League average without a batter.
+pub fn batter_speed_factor(batter: Option<&Player>) -> f32 {
+    batter.map(|b| runner_speed_factor(b.sprint_speed)).unwrap_or(1.0)
+}
+
+/// Sprint speed as a multiplier on extra-base tries - 1.0 for a league-average runner
+pub fn runner_speed_factor(sprint_speed: f32) -> f32 {
+    (1.0 + (sprint_speed - LEAGUE_AVG_SPRINT_SPEED) * RUNNER_SPEED_FACTOR_PER_FTPS)
+        .clamp(RUNNER_SPEED_FACTOR_MIN, RUNNER_SPEED_FACTOR_MAX)
+}
+
+/// Which way a ball is hit relative to the batter - pulled, up the middle or the other way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpraySide {
+    Pull,
+    Center,
+    Opposite,
+}
+
+impl SpraySide {
+    /// Early swings get out in front and pull the ball and late ones push it the other way.
+    /// On-time swings are rolled, most often to the pull side.
+    pub fn from_timing(rng: &mut impl Rng, timing: &SwingTiming) -> SpraySide {
+        match timing {
+            SwingTiming::TooEarly | SwingTiming::Early => SpraySide::Pull,
+            SwingTiming::Late | SwingTiming::TooLate => SpraySide::Opposite,
+            SwingTiming::Perfect | SwingTiming::NoSwing => {
+                let roll = rng.gen_range(0.0..1.0);
+                if roll < SPRAY_PULL_SHARE {
+                    SpraySide::Pull
+                } else if roll < SPRAY_PULL_SHARE + SPRAY_CENTER_SHARE {
+                    SpraySide::Center
+                } else {
+                    SpraySide::Opposite
+                }
+            }
+        }
+    }
+
+    /// The outfield a batter hitting from `side` sends it to - a righty pulls to left
+    pub fn outfield(&self, side: Hand) -> FieldDirection {
+        match (self, side) {
+            (SpraySide::Center, _) => FieldDirection::CenterField,
+            (SpraySide::Pull, Hand::Left) | (SpraySide::Opposite, Hand::Right | Hand::Switch) => FieldDirection::RightField,
+            _ => FieldDirection::LeftField,
+        }
+    }
+
+    /// The pull side is where the power is
+    pub fn home_run_factor(&self) -> f32 {
+        match self {
+            SpraySide::Pull => PULL_HR_FACTOR,
+            SpraySide::Center => CENTER_HR_FACTOR,
+            SpraySide::Opposite => OPPOSITE_FIELD_HR_FACTOR,
+        }
+    }
+}
+
+/// The side of the plate a batter stands on. Switch hitters take the side opposite the
+/// pitcher's arm, and an unknown hand bats right.
+pub fn batting_side(bats: Option<Hand>, throws: Option<Hand>) -> Hand {
+    match bats {
+        Some(Hand::Switch) if throws == Some(Hand::Left) => Hand::Right,
+        Some(Hand::Switch) => Hand::Left,
+        Some(hand) => hand,
+        None => Hand::Right,
+    }
+}
+
+/// Frames added to each side of the perfect window for a batter who called the pitch before
+/// swinging - negative for the wrong call, nothing without one
+pub fn pitch_read_frames(guess: Option<usize>, pitch_type: usize) -> i8 {
+    match guess {
+        Some(guess) if guess == pitch_type => PITCH_READ_BONUS_FRAMES,
+        Some(_) => -PITCH_MISREAD_PENALTY_FRAMES,
+        None => 0,
+    }
+}
+
+/// A single into one of the gaps, where a fast batter may take second
+fn stretch_single(rng: &mut impl Rng, direction: FieldDirection, speed_factor: f32) -> HitType {
+    let in_the_gap = matches!(direction, FieldDirection::LeftCenter | FieldDirection::RightCenter);
+    if in_the_gap && rng.gen_bool((STRETCH_SINGLE_CHANCE * speed_factor).min(1.0) as f64) {
+        HitType::Double
+    } else {
+        HitType::Single
+    }
+}
+
+/// Chance solid contact that got through goes for extra bases - best in the gaps, worst when
+/// it only got past an infielder
+pub fn extra_base_chance(direction: FieldDirection) -> f64 {
+    let direction_factor = match direction {
+        FieldDirection::LeftCenter | FieldDirection::RightCenter => EXTRA_BASE_GAP_FACTOR,
+        FieldDirection::LeftField | FieldDirection::CenterField | FieldDirection::RightField => 1.0,
+        _ => EXTRA_BASE_INFIELD_FACTOR,
+    };
+    (EXTRA_BASE_HIT_CHANCE * direction_factor).min(1.0)
+}
+
+/// Chance an extra-base hit becomes a triple. Balls in the gaps and to right field roll the
+/// furthest from third base and hard-hit balls reach the wall. Without a direction the
+/// outfield average is used.
+pub fn triple_chance(direction: Option<FieldDirection>, hard_hit: bool, speed_factor: f32) -> f32 {
+    let direction_factor = match direction {
+        Some(FieldDirection::LeftCenter | FieldDirection::RightCenter | FieldDirection::RightField) => TRIPLE_GAP_FACTOR,
+        Some(FieldDirection::LeftField | FieldDirection::CenterField) => TRIPLE_OUTFIELD_FACTOR,
+        Some(_) => TRIPLE_INFIELD_FACTOR,
+        None => 1.0,
+    };
+    let hard_hit_factor = if hard_hit { TRIPLE_HARD_HIT_FACTOR } else { 1.0 };
+    TRIPLE_BASE_CHANCE * direction_factor * hard_hit_factor * speed_factor
+}
+
+fn double_or_triple(rng: &mut impl Rng, direction: Option<FieldDirection>, hard_hit: bool, speed_factor: f32) -> HitType {
+    if rng.gen_bool(triple_chance(direction, hard_hit, speed_factor).min(1.0) as f64) {
+        HitType::Triple
+    } else {
+        HitType::Double
+    }
+}
+
+fn ratio(numerator: u32, denominator: u32) -> f32 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f32 / denominator as f32
+    }
+}
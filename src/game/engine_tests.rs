@@ -1,6 +1,17 @@
 #[cfg(test)]
 mod tests {
-    use crate::game::{GameEngine, PitchLocation};
+    use crate::game::ballpark::Ballpark;
+    use crate::game::weather::Weather;
+    use crate::game::input_handler::handle_input;
+    use crate::game::state::{BallInPlay, BallType, FieldDirection};
+    use crate::game::{constants::*, engine::PitchType, GameEngine, GameState, HitType, OutType, PitchLocation, PitchState, PlayResult, SwingTiming};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::game::ai::BatterAi;
+    use crate::game::engine::{batting_side, PitchPreview, SpraySide};
+    use crate::game::input_handler::classify_swing_timing;
+    use crate::team::{synthesize_arsenal, Hand, PitcherStats, Player, PlayerStats, Position, Team, TeamManager};
+    use rand::{rngs::StdRng, SeedableRng};
 
     #[test]
     fn test_pitch_location_from_numpad() {
@@ -37,7 +48,7 @@ mod tests {
         let pitch_loc = PitchLocation::Middle; // Strike zone
         let swing_loc = None; // No swing
         
-        let (result, _) = engine.calculate_pitch_result(pitch_loc, swing_loc, 0, None, None, 1.0);
+        let (result, _) = engine.calculate_pitch_result_with_timing(pitch_loc, swing_loc, 0, None, None, 1.0, &SwingTiming::NoSwing, (0, 0), 0);
         
         assert!(matches!(result, crate::game::PlayResult::Strike));
     }
@@ -48,7 +59,7 @@ mod tests {
         let pitch_loc = PitchLocation::UpInside; // Outside strike zone
         let swing_loc = None; // No swing
         
-        let (result, _) = engine.calculate_pitch_result(pitch_loc, swing_loc, 0, None, None, 1.0);
+        let (result, _) = engine.calculate_pitch_result_with_timing(pitch_loc, swing_loc, 0, None, None, 1.0, &SwingTiming::NoSwing, (0, 0), 0);
         
         assert!(matches!(result, crate::game::PlayResult::Ball));
     }
@@ -57,9 +68,792 @@ mod tests {
     fn test_engine_has_pitch_types() {
         let engine = GameEngine::new();
         assert_eq!(engine.pitch_types.len(), 4);
-        assert_eq!(engine.get_pitch_name(0), "Fastball");
-        assert_eq!(engine.get_pitch_name(1), "Curveball");
-        assert_eq!(engine.get_pitch_name(2), "Slider");
-        assert_eq!(engine.get_pitch_name(3), "Changeup");
+        assert_eq!(engine.get_pitch_name(None, 0), "Fastball");
+        assert_eq!(engine.get_pitch_name(None, 1), "Curveball");
+        assert_eq!(engine.get_pitch_name(None, 2), "Slider");
+        assert_eq!(engine.get_pitch_name(None, 3), "Changeup");
+    }
+
+    fn pitcher_with_stats(ev95_percent: f32, avg_hit_angle: f32, barrel_percent: f32) -> Player {
+        Player {
+            stats: PlayerStats {
+                name: "Test, Pitcher".to_string(),
+                id: "1".to_string(),
+                attempts: 300,
+                avg_hit_angle,
+                sweet_spot_percent: 33.0,
+                max_hit_speed: 112.0,
+                avg_hit_speed: 89.0,
+                ev50: 100.0,
+                fbld: 93.0,
+                gb: 86.5,
+                max_distance: 430,
+                avg_distance: 170,
+                avg_hr_distance: 396,
+                ev95plus: 130,
+                ev95_percent,
+                barrels: 35,
+                barrel_percent,
+                barrel_pa: 7.0,
+            },
+            is_pitcher: true,
+            position: Position::Pitcher,
+            arsenal: Vec::new(),
+            pitching: None,
+            sprint_speed: LEAGUE_AVG_SPRINT_SPEED,
+            bats: None,
+            throws: None,
+            outs_above_average: 0,
+        }
+    }
+
+    #[test]
+    fn test_pitcher_without_arsenal_uses_default_pitches() {
+        let engine = GameEngine::new();
+        let pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        assert_eq!(engine.arsenal(Some(&pitcher)), PitchType::default_arsenal().as_slice());
+        assert_eq!(engine.get_pitch_name(Some(&pitcher), 0), "Fastball");
+    }
+
+    #[test]
+    fn test_pitcher_arsenal_replaces_default_pitches() {
+        let engine = GameEngine::new();
+        let mut pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        pitcher.arsenal = vec![PitchType::new("Knuckleball", 70, 6)];
+        assert_eq!(engine.arsenal(Some(&pitcher)).len(), 1);
+        assert_eq!(engine.get_pitch_name(Some(&pitcher), 0), "Knuckleball");
+        assert_eq!(engine.get_pitch_name(Some(&pitcher), 1), "Unknown");
+    }
+
+    #[test]
+    fn test_synthesized_arsenal_follows_stats() {
+        let power_sinkerballer = synthesize_arsenal(&pitcher_with_stats(30.0, 8.0, 9.0).stats);
+        assert_eq!(power_sinkerballer[0].speed, 97);
+        assert_eq!(power_sinkerballer[1].name, "Sinker");
+
+        let finesse = synthesize_arsenal(&pitcher_with_stats(45.0, 15.0, 12.0).stats);
+        assert!(finesse[0].speed < 97);
+        assert_eq!(finesse[1].name, "Curveball");
+        assert!(finesse.len() <= MAX_ARSENAL_SIZE);
+    }
+
+    #[test]
+    fn test_perfect_control_always_hits_the_spot() {
+        let engine = GameEngine::with_seed(1566);
+        let pitch = PitchType::new("Fastball", 90, 0);
+        for _ in 0..100 {
+            assert_eq!(engine.apply_control(PitchLocation::DownOutside, &pitch), PitchLocation::DownOutside);
+        }
+    }
+
+    #[test]
+    fn test_missed_spot_drifts_to_an_adjacent_location() {
+        let engine = GameEngine::with_seed(1566);
+        let wild = PitchType { control: 0.0, ..PitchType::new("Fastball", 90, 0) };
+        for _ in 0..100 {
+            let actual = engine.apply_control(PitchLocation::Middle, &wild);
+            assert!(matches!(actual, PitchLocation::Up | PitchLocation::Down | PitchLocation::Inside | PitchLocation::Outside));
+        }
+    }
+
+    #[test]
+    fn test_load_arsenals_from_csv() {
+        let path = std::env::temp_dir().join("bitbatter_arsenal_test.csv");
+        std::fs::write(
+            &path,
+            "player_id,pitch_name,speed,break_amount,control,whiff\n\
+             7,Cutter,91,2,0.9,3\n\
+             7,Splitter,86,4,1.5,5\n",
+        )
+        .unwrap();
+
+        let load = TeamManager::load_arsenals_from_csv(&path).unwrap();
+        assert_eq!(load.skipped_rows, 0);
+        let arsenal = &load.arsenals["7"];
+        assert_eq!(arsenal.len(), 2);
+        assert_eq!(arsenal[0].name, "Cutter");
+        assert_eq!(arsenal[1].control, 1.0);
+        assert_eq!(arsenal[1].whiff, 5);
+    }
+
+    #[test]
+    fn test_velocity_tightens_perfect_window() {
+        let engine = GameEngine::new();
+        let mut pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        assert_eq!(engine.perfect_window_frames(Some(&pitcher)), PERFECT_TIMING_WINDOW_FRAMES);
+
+        pitcher.pitching = Some(PitcherStats { fastball_velocity: Some(99.0), ..PitcherStats::default() });
+        assert!(engine.perfect_window_frames(Some(&pitcher)) < PERFECT_TIMING_WINDOW_FRAMES);
+
+        pitcher.pitching = Some(PitcherStats { fastball_velocity: Some(110.0), ..PitcherStats::default() });
+        assert_eq!(engine.perfect_window_frames(Some(&pitcher)), MIN_PERFECT_WINDOW_FRAMES);
+    }
+
+    #[test]
+    fn test_high_whiff_pitcher_misses_more_bats() {
+        let batter = pitcher_with_stats(40.0, 13.0, 9.0);
+        let average = pitcher_with_stats(40.0, 13.0, 9.0);
+        let mut nasty = pitcher_with_stats(40.0, 13.0, 9.0);
+        nasty.pitching = Some(PitcherStats { whiff_percent: Some(45.0), ..PitcherStats::default() });
+
+        let misses = |pitcher: &Player| {
+            let engine = GameEngine::with_seed(1567);
+            (0..2_000)
+                .filter(|_| {
+                    let (result, _) = engine.calculate_pitch_result_with_timing(
+                        PitchLocation::Middle,
+                        Some(PitchLocation::Middle),
+                        0,
+                        Some(&batter),
+                        Some(pitcher),
+                        1.0,
+                        &SwingTiming::Perfect,
+                        (0, 0),
+                        0,
+                    );
+                    matches!(result, PlayResult::Strike)
+                })
+                .count()
+        };
+
+        assert!(misses(&nasty) > misses(&average));
+    }
+
+    #[test]
+    fn test_chase_on_the_ball_finds_more_wood_than_a_wild_chase() {
+        let batter = pitcher_with_stats(40.0, 13.0, 9.0);
+        let pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        let contact = |swing: PitchLocation| {
+            let engine = GameEngine::with_seed(1600);
+            (0..4_000)
+                .filter(|_| {
+                    let (result, contact_quality) = engine.calculate_pitch_result_with_timing(
+                        PitchLocation::DownOutside,
+                        Some(swing),
+                        0,
+                        Some(&batter),
+                        Some(&pitcher),
+                        1.0,
+                        &SwingTiming::Perfect,
+                        (0, 0),
+                        0,
+                    );
+                    // Out of the zone nothing is ever squared up
+                    assert!(contact_quality.unwrap_or(0) < CONTACT_GOOD_MIN, "{:?} {:?}", result, contact_quality);
+                    result != PlayResult::Strike
+                })
+                .count()
+        };
+
+        let on_the_ball = contact(PitchLocation::DownOutside);
+        let one_off = contact(PitchLocation::Down);
+        let wild = contact(PitchLocation::UpInside);
+        assert!(on_the_ball > one_off && one_off > wild, "{} / {} / {}", on_the_ball, one_off, wild);
+    }
+
+    #[test]
+    fn test_only_a_chase_on_the_ball_is_put_in_play() {
+        let engine = GameEngine::with_seed(1600);
+        let batted = |swing: PitchLocation| {
+            (0..2_000)
+                .filter(|_| {
+                    engine
+                        .calculate_pitch_result_with_timing(PitchLocation::UpInside, Some(swing), 0, None, None, 1.0, &SwingTiming::Perfect, (0, 0), 0)
+                        .0
+                        .is_batted_ball()
+                })
+                .count()
+        };
+        assert!(batted(PitchLocation::UpInside) > 0);
+        assert_eq!(batted(PitchLocation::DownOutside), 0);
+    }
+
+    #[test]
+    fn test_pitch_location_distance_counts_rows_and_columns() {
+        assert_eq!(PitchLocation::Middle.distance(PitchLocation::Middle), 0);
+        assert_eq!(PitchLocation::DownOutside.distance(PitchLocation::Down), 1);
+        assert_eq!(PitchLocation::DownOutside.distance(PitchLocation::Middle), 2);
+        assert_eq!(PitchLocation::UpInside.distance(PitchLocation::DownOutside), 4);
+    }
+
+    fn state_with_pitcher(pitcher: Player) -> GameState {
+        let mut state = GameState::new();
+        let mut team = Team::new("Test Team".to_string(), "TST".to_string());
+        team.pitchers.push(pitcher);
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TST".to_string(), team);
+        state.start_game(&teams, "TST".to_string(), "TST".to_string());
+        state
+    }
+
+    #[test]
+    fn test_pitchers_present_different_pitch_menus() {
+        let engine = GameEngine::new();
+        let mut power = pitcher_with_stats(30.0, 8.0, 6.5);
+        power.arsenal = synthesize_arsenal(&power.stats);
+        let mut finesse = pitcher_with_stats(45.0, 15.0, 12.0);
+        finesse.arsenal = synthesize_arsenal(&finesse.stats);
+
+        let menu = |pitcher: &Player| -> Vec<String> {
+            engine.arsenal(Some(pitcher)).iter().map(|p| p.name.clone()).collect()
+        };
+        assert_eq!(menu(&power), vec!["Fastball", "Sinker", "Slider"]);
+        assert_eq!(menu(&finesse), vec!["Fastball", "Curveball", "Slider", "Changeup"]);
+    }
+
+    #[test]
+    fn test_pitch_outside_arsenal_cannot_be_selected() {
+        let engine = GameEngine::new();
+        let mut pitcher = pitcher_with_stats(30.0, 8.0, 6.5);
+        pitcher.arsenal = synthesize_arsenal(&pitcher.stats);
+        let mut state = state_with_pitcher(pitcher);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_engine_tests.log").to_string_lossy());
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+
+        // Power arm has no changeup, so the fourth slot does nothing
+        handle_input(&mut state, &mut teams, &engine, &mut input_state, GameInput::SelectPitch(3), None, &logger);
+        assert!(matches!(state.pitch_state, PitchState::ChoosePitch));
+
+        handle_input(&mut state, &mut teams, &engine, &mut input_state, GameInput::SelectPitch(2), None, &logger);
+        assert!(matches!(state.pitch_state, PitchState::Aiming { pitch_type: 2 }));
+    }
+
+    fn home_runs_in_park(ballpark: Ballpark) -> usize {
+        let engine = GameEngine::with_seed(11);
+        engine.set_ballpark(ballpark);
+        let ball = BallInPlay {
+            ball_type: BallType::FlyBall,
+            direction: FieldDirection::CenterField,
+            speed: 105.0,
+            hang_time: 60,
+            initial_contact_quality: 95,
+            hr_chance: HR_CHANCE_LEAGUE_AVG,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        (0..2000)
+            .filter(|_| engine.ball_gets_through(&ball) == PlayResult::Hit(HitType::HomeRun))
+            .count()
+    }
+
+    #[test]
+    fn test_hitters_park_yields_more_home_runs() {
+        let neutral = home_runs_in_park(Ballpark::NEUTRAL);
+        let coors = home_runs_in_park(Ballpark::lookup("Coors Field"));
+        let oracle = home_runs_in_park(Ballpark::lookup("Oracle Park"));
+        assert!(coors > neutral, "Coors {} vs neutral {}", coors, neutral);
+        assert!(oracle < neutral, "Oracle {} vs neutral {}", oracle, neutral);
+    }
+
+    /// Home runs from the same run of well-hit balls, start to finish, in `weather`
+    fn home_runs_in_weather(weather: Weather) -> usize {
+        let engine = GameEngine::with_seed(1595);
+        engine.set_weather(weather);
+        (0..2000)
+            .filter(|_| {
+                let ball = engine.generate_ball_in_play(95, None, None, &SwingTiming::Perfect);
+                engine.ball_gets_through(&ball) == PlayResult::Hit(HitType::HomeRun)
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_wind_blowing_out_yields_more_home_runs() {
+        let clear = home_runs_in_weather(Weather::Clear);
+        let wind_out = home_runs_in_weather(Weather::WindOut);
+        let wind_in = home_runs_in_weather(Weather::WindIn);
+        assert!(wind_out > clear, "wind out {} vs clear {}", wind_out, clear);
+        assert!(wind_in < clear, "wind in {} vs clear {}", wind_in, clear);
+        assert_eq!(home_runs_in_weather(Weather::Dome), clear);
+    }
+
+    #[test]
+    fn test_rain_slows_grounders() {
+        let grounder_speed = |weather: Weather| {
+            let engine = GameEngine::with_seed(1595);
+            engine.set_weather(weather);
+            (0..200)
+                .map(|_| engine.generate_ball_in_play(50, None, None, &SwingTiming::Perfect))
+                .filter(|ball| ball.ball_type == BallType::Grounder)
+                .map(|ball| ball.speed)
+                .sum::<f32>()
+        };
+        let clear = grounder_speed(Weather::Clear);
+        let rain = grounder_speed(Weather::Rain);
+        assert!((rain - clear * RAIN_GROUNDER_SPEED_FACTOR).abs() < 0.01, "rain {} vs clear {}", rain, clear);
+    }
+
+    #[test]
+    fn test_roofed_parks_always_play_as_a_dome() {
+        let mut state = GameState::new();
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TOR".to_string(), Team::new("Toronto Blue Jays".to_string(), "TOR".to_string()));
+        teams.teams.insert("CHC".to_string(), Team::new("Chicago Cubs".to_string(), "CHC".to_string()));
+        let engine = GameEngine::with_seed(1595);
+
+        state.start_game(&teams, "TOR".to_string(), "CHC".to_string());
+        for _ in 0..20 {
+            state.roll_weather(&mut *engine.rng());
+            assert_eq!(state.weather, Weather::Dome);
+        }
+
+        state.start_game(&teams, "CHC".to_string(), "TOR".to_string());
+        let rolls: Vec<Weather> = (0..200)
+            .map(|_| {
+                state.roll_weather(&mut *engine.rng());
+                state.weather
+            })
+            .collect();
+        for weather in [Weather::Clear, Weather::WindOut, Weather::WindIn, Weather::Rain] {
+            assert!(rolls.contains(&weather), "never rolled {:?}", weather);
+        }
+        assert!(!rolls.contains(&Weather::Dome));
+    }
+
+    #[test]
+    fn test_home_team_ballpark_governs() {
+        let mut state = GameState::new();
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("COL".to_string(), Team::new("Colorado Rockies".to_string(), "COL".to_string()));
+        teams.teams.insert("SF".to_string(), Team::new("San Francisco Giants".to_string(), "SF".to_string()));
+        state.start_game(&teams, "COL".to_string(), "SF".to_string());
+        assert_eq!(state.home_ballpark().name, "Coors Field");
+        assert_eq!(state.home_ballpark().center_field, 415);
+
+        // Custom and unknown teams play in a neutral park
+        assert_eq!(Ballpark::lookup("Storm Dragons Yard"), Ballpark::NEUTRAL);
+    }
+
+    fn hitter_with_power(barrel_percent: f32, avg_hr_distance: u32) -> Player {
+        let mut hitter = pitcher_with_stats(40.0, 12.0, barrel_percent);
+        hitter.stats.avg_hr_distance = avg_hr_distance;
+        hitter.is_pitcher = false;
+        hitter.position = Position::FirstBase;
+        hitter
+    }
+
+    #[test]
+    fn test_home_run_chance_follows_power_within_bounds() {
+        let slugger = hitter_with_power(17.0, 412);
+        let slap_hitter = hitter_with_power(2.0, 0);
+        assert!(crate::game::engine::home_run_chance(Some(&slugger)) > 0.45);
+        assert_eq!(crate::game::engine::home_run_chance(Some(&slap_hitter)), HR_CHANCE_MIN);
+        assert_eq!(crate::game::engine::home_run_chance(Some(&hitter_with_power(40.0, 480))), HR_CHANCE_MAX);
+        assert_eq!(crate::game::engine::home_run_chance(None), HR_CHANCE_LEAGUE_AVG);
+    }
+
+    fn home_runs_on_excellent_contact(batter: &Player) -> usize {
+        let engine = GameEngine::with_seed(1586);
+        (0..10_000)
+            .filter(|_| {
+                let ball = engine.generate_ball_in_play(95, Some(batter), None, &SwingTiming::Perfect);
+                engine.ball_gets_through(&ball) == PlayResult::Hit(HitType::HomeRun)
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_power_hitter_homers_far_more_than_a_slap_hitter() {
+        let slugger = home_runs_on_excellent_contact(&hitter_with_power(17.0, 412));
+        let slap_hitter = home_runs_on_excellent_contact(&hitter_with_power(2.0, 0));
+        assert!(slugger > slap_hitter * 3, "slugger {} vs slap hitter {}", slugger, slap_hitter);
+    }
+
+    /// Fly balls out of 2000 balls in play at `contact_quality` for a hitter who averages
+    /// `launch_angle` degrees
+    fn fly_balls_at_launch_angle(launch_angle: f32, contact_quality: i32) -> usize {
+        let engine = GameEngine::with_seed(1606);
+        let mut hitter = hitter_with_power(8.0, 400);
+        hitter.stats.avg_hit_angle = launch_angle;
+        (0..2000)
+            .map(|_| engine.generate_ball_in_play(contact_quality, Some(&hitter), None, &SwingTiming::Perfect).ball_type)
+            .filter(|ball_type| matches!(ball_type, BallType::FlyBall | BallType::PopFly))
+            .count()
+    }
+
+    #[test]
+    fn test_high_launch_angle_hitters_put_more_balls_in_the_air() {
+        for contact_quality in [95, 70, 50, 20] {
+            let uppercut = fly_balls_at_launch_angle(22.0, contact_quality);
+            let level = fly_balls_at_launch_angle(LEAGUE_AVG_HIT_ANGLE, contact_quality);
+            let chopper = fly_balls_at_launch_angle(3.0, contact_quality);
+            assert!(uppercut > level && level > chopper, "quality {}: {} / {} / {}", contact_quality, uppercut, level, chopper);
+        }
+    }
+
+    #[test]
+    fn test_launch_angle_tilt_is_bounded_and_level_without_a_batter() {
+        use crate::game::engine::launch_angle_tilt;
+        let mut hitter = hitter_with_power(8.0, 400);
+        hitter.stats.avg_hit_angle = LEAGUE_AVG_HIT_ANGLE;
+        assert_eq!(launch_angle_tilt(Some(&hitter)), 0.0);
+        hitter.stats.avg_hit_angle = 40.0;
+        assert_eq!(launch_angle_tilt(Some(&hitter)), 1.0);
+        hitter.stats.avg_hit_angle = -10.0;
+        assert_eq!(launch_angle_tilt(Some(&hitter)), -1.0);
+        assert_eq!(launch_angle_tilt(None), 0.0);
+    }
+
+    #[test]
+    fn test_triples_favor_the_gaps_hard_hit_balls_and_fast_batters() {
+        use crate::game::engine::{batter_speed_factor, triple_chance};
+        let gap = triple_chance(Some(FieldDirection::RightCenter), false, 1.0);
+        let left = triple_chance(Some(FieldDirection::LeftField), false, 1.0);
+        let infield = triple_chance(Some(FieldDirection::ThirdBase), false, 1.0);
+        assert!(gap > left && left > infield);
+        assert!(triple_chance(Some(FieldDirection::RightField), true, 1.0) > triple_chance(Some(FieldDirection::RightField), false, 1.0));
+
+        let mut burner = hitter_with_power(3.0, 380);
+        burner.sprint_speed = 30.0;
+        let mut plodder = hitter_with_power(15.0, 410);
+        plodder.sprint_speed = 24.0;
+        assert!(batter_speed_factor(Some(&burner)) > batter_speed_factor(Some(&plodder)));
+        assert_eq!(batter_speed_factor(None), 1.0);
+    }
+
+    #[test]
+    fn test_only_tired_pitchers_with_runners_on_balk() {
+        let engine = GameEngine::with_seed(1588);
+        let balks = |runners_on: bool, fatigue_penalty: f32| {
+            (0..20_000).filter(|_| engine.roll_balk(runners_on, fatigue_penalty)).count()
+        };
+        assert_eq!(balks(true, FATIGUE_PENALTY_FRESH), 0);
+        assert_eq!(balks(false, FATIGUE_PENALTY_EXHAUSTED), 0);
+        let exhausted = balks(true, FATIGUE_PENALTY_EXHAUSTED);
+        assert!((100..=300).contains(&exhausted), "{} balks in 20000 pitches", exhausted);
+        assert!(balks(true, FATIGUE_PENALTY_TIRED) < exhausted);
+    }
+
+    #[test]
+    fn test_fielded_outs_match_the_ball_type() {
+        let engine = GameEngine::with_seed(1588);
+        let expected = [
+            (BallType::Grounder, OutType::Groundout),
+            (BallType::LineDrive, OutType::LineOut),
+            (BallType::FlyBall, OutType::Flyout),
+            (BallType::PopFly, OutType::Flyout),
+        ];
+        for (ball_type, out_type) in expected {
+            let ball = BallInPlay {
+                ball_type: ball_type.clone(),
+                direction: FieldDirection::Shortstop,
+                speed: 80.0,
+                hang_time: 30,
+                initial_contact_quality: 70,
+                hr_chance: HR_CHANCE_LEAGUE_AVG,
+                speed_factor: 1.0,
+                spray: SpraySide::Center,
+            };
+            for _ in 0..200 {
+                let (result, _) = engine.fielder_plays_ball(&ball, false, &[0; 9]);
+                if let PlayResult::Out(made) = result {
+                    assert_eq!(made, out_type, "{:?} turned into a {:?}", ball_type, made);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_foul_pops_are_caught_for_outs_or_stay_fouls() {
+        let engine = GameEngine::with_seed(1589);
+        let pops = |contact_quality: i32| (0..5_000).filter_map(|_| engine.generate_foul_pop(contact_quality)).collect::<Vec<_>>();
+        let weak = pops(10);
+        assert!(weak.len() > pops(70).len(), "weak contact should pop up more often");
+        assert!(weak.len() < 1_000, "only a small share of fouls should be catchable");
+
+        for pop in &weak {
+            assert!(pop.direction.is_foul());
+            // The infield fly rule never applies in foul territory
+            let (result, _) = engine.calculate_fielding_result(pop, 0, pop.hang_time / 2, true, &[0; 9]);
+            assert!(
+                matches!(result, PlayResult::Out(OutType::Flyout) | PlayResult::Foul),
+                "foul pop became {:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_faster_pitches_reach_the_plate_in_fewer_frames() {
+        let heater = PitchType::new("Fastball", 100, 0);
+        let hook = PitchType::new("Curveball", 75, 5);
+        assert!(heater.approach_frames() < hook.approach_frames());
+        assert_eq!(PitchType::new("Fastball", APPROACH_REFERENCE_SPEED_MPH, 0).approach_frames(), BALL_APPROACH_FRAMES);
+        // Even an absurd velocity leaves the swing window intact
+        assert!(PitchType::new("Railgun", u8::MAX, 0).approach_frames() > SWING_TIMING_WINDOW_FRAMES);
+    }
+
+    #[test]
+    fn test_fast_batters_beat_out_grounders_and_stretch_singles() {
+        let ball = |ball_type: BallType, direction: FieldDirection, contact: i32, speed_factor: f32| BallInPlay {
+            ball_type,
+            direction,
+            speed: 70.0,
+            hang_time: 0,
+            initial_contact_quality: contact,
+            hr_chance: HR_CHANCE_LEAGUE_AVG,
+            speed_factor,
+            spray: SpraySide::Center,
+        };
+        let fast = crate::game::engine::runner_speed_factor(30.0);
+        let slow = crate::game::engine::runner_speed_factor(24.0);
+        let engine = GameEngine::with_seed(1590);
+
+        let infield_hits = |speed_factor: f32| {
+            let grounder = ball(BallType::Grounder, FieldDirection::Shortstop, 50, speed_factor);
+            (0..5_000)
+                .filter(|_| matches!(engine.calculate_fielding_result(&grounder, 0, 0, false, &[0; 9]).0, PlayResult::Hit(_)))
+                .count()
+        };
+        // Both miss the same share of grounders; only the fast one beats out the ones fielded
+        let (fast_hits, slow_hits) = (infield_hits(fast), infield_hits(slow));
+        assert!(fast_hits > slow_hits + 250, "fast batter {} hits, slow batter {}", fast_hits, slow_hits);
+
+        let doubles = |speed_factor: f32| {
+            let gapper = ball(BallType::LineDrive, FieldDirection::LeftCenter, 40, speed_factor);
+            (0..5_000).filter(|_| engine.ball_gets_through(&gapper) == PlayResult::Hit(HitType::Double)).count()
+        };
+        assert!(doubles(fast) > doubles(slow) * 2);
+        let down_the_line = ball(BallType::LineDrive, FieldDirection::LeftField, 40, fast);
+        assert_eq!(engine.ball_gets_through(&down_the_line), PlayResult::Hit(HitType::Single));
+    }
+
+    #[test]
+    fn test_solid_contact_in_the_gap_goes_for_extra_bases_more_than_through_the_infield() {
+        let ball = |ball_type: BallType, direction: FieldDirection| BallInPlay {
+            ball_type,
+            direction,
+            speed: 85.0,
+            hang_time: 30,
+            initial_contact_quality: 70,
+            hr_chance: HR_CHANCE_LEAGUE_AVG,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        let engine = GameEngine::with_seed(1611);
+        let extra_bases = |ball: &BallInPlay| {
+            (0..5_000)
+                .filter(|_| matches!(engine.ball_gets_through(ball), PlayResult::Hit(HitType::Double | HitType::Triple)))
+                .count()
+        };
+        let gapper = extra_bases(&ball(BallType::LineDrive, FieldDirection::LeftCenter));
+        let pulled_grounder = extra_bases(&ball(BallType::Grounder, FieldDirection::ThirdBase));
+        let down_the_line = extra_bases(&ball(BallType::LineDrive, FieldDirection::LeftField));
+        assert!(gapper > pulled_grounder * 3, "gap {} vs past third {}", gapper, pulled_grounder);
+        assert!(gapper > down_the_line && down_the_line > pulled_grounder, "{} / {} / {}", gapper, down_the_line, pulled_grounder);
+    }
+
+    #[test]
+    fn test_perfectly_timed_pop_fly_catches_replay_exactly_on_a_seed() {
+        let pop_fly = BallInPlay {
+            ball_type: BallType::PopFly,
+            direction: FieldDirection::Shortstop,
+            speed: 60.0,
+            hang_time: 40,
+            initial_contact_quality: 30,
+            hr_chance: 0.0,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        let perfect = pop_fly.hang_time / 2;
+        let attempts = |seed: u64| {
+            let engine = GameEngine::with_seed(seed);
+            (0..200).map(|_| engine.calculate_fielding_result(&pop_fly, perfect, perfect, false, &[0; 9])).collect::<Vec<_>>()
+        };
+        let first = attempts(1612);
+        assert_eq!(first, attempts(1612));
+        assert_eq!(first[0], (PlayResult::Out(OutType::Flyout), FIELDING_SUCCESS_POPFLY));
+        assert!(first.iter().all(|&(_, chance)| chance == FIELDING_SUCCESS_POPFLY));
+    }
+
+    #[test]
+    fn test_defensive_sub_raises_the_catch_chance_at_their_position() {
+        let mut team = crate::roster::CustomRoster::template("TST", "Test").into_team(DEFAULT_SEASON);
+        let mut glove = team.batters[0].clone();
+        glove.stats.id = "GLOVE".to_string();
+        glove.outs_above_average = 15;
+        team.batters.push(glove);
+        let mut state = GameState::new();
+        state.home_roster = Some(team); // In the field in the top half
+
+        let grounder = BallInPlay {
+            ball_type: BallType::Grounder,
+            direction: FieldDirection::Shortstop,
+            speed: 80.0,
+            hang_time: 30,
+            initial_contact_quality: 50,
+            hr_chance: 0.0,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        let engine = GameEngine::with_seed(1598);
+        let catch_chance = |state: &GameState| {
+            engine.calculate_fielding_result(&grounder, 15, 15, false, &state.defense()).1
+        };
+
+        let before = catch_chance(&state);
+        assert_eq!(state.get_current_pitching_team().unwrap().fielding_rating(Position::Shortstop), 0);
+        state.defensive_sub(Position::Shortstop, "GLOVE").unwrap();
+        assert_eq!(state.get_current_pitching_team().unwrap().fielding_rating(Position::Shortstop), 15);
+        let after = catch_chance(&state);
+        assert!((after - before - 15.0 * FIELDING_SUCCESS_PER_OAA).abs() < 1e-6, "{} -> {}", before, after);
+
+        // Balls hit elsewhere don't care who plays short
+        let to_first = BallInPlay { direction: FieldDirection::FirstBase, ..grounder.clone() };
+        assert_eq!(engine.calculate_fielding_result(&to_first, 15, 15, false, &state.defense()).1, before);
+    }
+
+    #[test]
+    fn test_identical_swings_do_worse_behind_in_the_count() {
+        let batter = pitcher_with_stats(40.0, 13.0, 9.0);
+        let pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        // Misses and outs, then hits, from the same seeded swings
+        let tally = |balls: u8, strikes: u8| {
+            let engine = GameEngine::with_seed(1602);
+            let mut misses_and_outs = (0, 0);
+            let mut hits = 0;
+            for _ in 0..4_000 {
+                let (result, _) = engine.calculate_pitch_result_with_timing(
+                    PitchLocation::Middle,
+                    Some(PitchLocation::Middle),
+                    0,
+                    Some(&batter),
+                    Some(&pitcher),
+                    1.0,
+                    &SwingTiming::Perfect,
+                    (balls, strikes),
+                    0,
+                );
+                match result {
+                    PlayResult::Strike => misses_and_outs.0 += 1,
+                    PlayResult::Out(_) => misses_and_outs.1 += 1,
+                    PlayResult::Hit(_) => hits += 1,
+                    _ => {}
+                }
+            }
+            (misses_and_outs, hits)
+        };
+
+        let ((ahead_misses, ahead_outs), ahead_hits) = tally(0, 2);
+        let ((even_misses, _), _) = tally(0, 0);
+        let ((behind_misses, behind_outs), behind_hits) = tally(2, 0);
+        assert!(ahead_misses > even_misses && even_misses >= behind_misses, "{} / {} / {}", ahead_misses, even_misses, behind_misses);
+        assert!(ahead_misses + ahead_outs > behind_misses + behind_outs);
+        assert!(behind_hits > ahead_hits, "2-0 {} hits, 0-2 {}", behind_hits, ahead_hits);
+    }
+
+    #[test]
+    fn test_pulled_excellent_contact_homers_more_than_the_other_way() {
+        const SAMPLES: u32 = 10_000;
+        let engine = GameEngine::with_seed(1604);
+        let mut batter = hitter_with_power(12.0, 400);
+        batter.bats = Some(Hand::Right);
+        // Early swings pull the ball and late ones push it the other way, then the fielders play it
+        let home_runs = |timing: SwingTiming, field: FieldDirection| {
+            (0..SAMPLES)
+                .filter(|_| {
+                    let ball = engine.generate_ball_in_play(95, Some(&batter), None, &timing);
+                    assert_eq!(ball.direction, field);
+                    engine.fielder_plays_ball(&ball, false, &[0; 9]).0 == PlayResult::Hit(HitType::HomeRun)
+                })
+                .count()
+        };
+        let pulled = home_runs(SwingTiming::Early, FieldDirection::LeftField);
+        let opposite = home_runs(SwingTiming::Late, FieldDirection::RightField);
+        assert!(pulled as f32 > opposite as f32 * 1.5, "pull {} vs oppo {}", pulled, opposite);
+    }
+
+    #[test]
+    fn test_spray_side_follows_timing_and_handedness() {
+        let mut rng = StdRng::seed_from_u64(1604);
+        assert_eq!(SpraySide::from_timing(&mut rng, &SwingTiming::Early), SpraySide::Pull);
+        assert_eq!(SpraySide::from_timing(&mut rng, &SwingTiming::Late), SpraySide::Opposite);
+        assert_eq!(SpraySide::Pull.outfield(Hand::Right), FieldDirection::LeftField);
+        assert_eq!(SpraySide::Pull.outfield(Hand::Left), FieldDirection::RightField);
+        assert_eq!(SpraySide::Opposite.outfield(Hand::Left), FieldDirection::LeftField);
+
+        // Switch hitters bat from the side opposite the pitcher
+        assert_eq!(batting_side(Some(Hand::Switch), Some(Hand::Right)), Hand::Left);
+        assert_eq!(batting_side(Some(Hand::Switch), Some(Hand::Left)), Hand::Right);
+        assert_eq!(batting_side(None, None), Hand::Right);
+
+        let shares = (PULL_HR_FACTOR * SPRAY_PULL_SHARE)
+            + (CENTER_HR_FACTOR * SPRAY_CENTER_SHARE)
+            + (OPPOSITE_FIELD_HR_FACTOR * (1.0 - SPRAY_PULL_SHARE - SPRAY_CENTER_SHARE));
+        assert!((shares - 1.0).abs() < 0.05, "on-time home run rate shifted by {}", shares);
+    }
+
+    fn preview_pitcher() -> Player {
+        let mut pitcher = pitcher_with_stats(40.0, 13.0, 9.0);
+        pitcher.arsenal = synthesize_arsenal(&pitcher.stats);
+        pitcher.pitching = Some(PitcherStats { fastball_velocity: Some(97.0), whiff_percent: Some(30.0), ..PitcherStats::default() });
+        pitcher
+    }
+
+    fn preview_total(preview: &PitchPreview) -> f32 {
+        preview.strike + preview.ball + preview.contact
+    }
+
+    #[test]
+    fn test_pitch_preview_odds_add_up_everywhere() {
+        let engine = GameEngine::with_seed(1604);
+        let pitcher = preview_pitcher();
+        let batter = hitter_with_power(12.0, 400);
+        for idx in 0..pitcher.arsenal.len() {
+            for numpad in 1..=9 {
+                let preview = engine.preview_pitch(PitchLocation::from_numpad(numpad), idx, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH, (0, 0), 0);
+                assert!((preview_total(&preview) - 1.0).abs() < 0.001, "{:?}", preview);
+            }
+        }
+        let corner = engine.preview_pitch(PitchLocation::UpInside, 0, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH, (0, 0), 0);
+        let middle = engine.preview_pitch(PitchLocation::Middle, 0, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH, (0, 0), 0);
+        assert!(corner.ball > middle.ball && middle.contact > corner.contact, "corner {:?}, middle {:?}", corner, middle);
+    }
+
+    #[test]
+    fn test_pitch_preview_gives_up_more_contact_when_tired() {
+        let engine = GameEngine::with_seed(1604);
+        let pitcher = preview_pitcher();
+        let batter = hitter_with_power(12.0, 400);
+        let fresh = engine.preview_pitch(PitchLocation::Middle, 0, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_FRESH, (0, 0), 0);
+        let exhausted = engine.preview_pitch(PitchLocation::Middle, 0, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_EXHAUSTED, (0, 0), 0);
+        assert!(exhausted.contact > fresh.contact, "fresh {:?}, exhausted {:?}", fresh, exhausted);
+        assert!((preview_total(&exhausted) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_pitch_preview_matches_the_pitches_it_predicts() {
+        const PITCHES: u32 = 20_000;
+        let engine = GameEngine::with_seed(1604);
+        let pitcher = preview_pitcher();
+        let batter = hitter_with_power(12.0, 400);
+        let ai = BatterAi::default();
+        for aim in [PitchLocation::Middle, PitchLocation::DownOutside] {
+            let preview = engine.preview_pitch(aim, 1, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_TIRED, (0, 0), 0);
+            let (mut strikes, mut balls, mut contact) = (0, 0, 0);
+            for _ in 0..PITCHES {
+                let landed = engine.apply_control(aim, &pitcher.arsenal[1]);
+                let plan = ai.decide(landed, &mut *engine.rng());
+                let result = match plan {
+                    None if landed.is_strike() => PlayResult::Strike,
+                    None => PlayResult::Ball,
+                    Some(plan) => {
+                        let timing = classify_swing_timing(plan.frames_left, true);
+                        engine.calculate_pitch_result_with_timing(landed, Some(plan.location), 1, Some(&batter), Some(&pitcher), FATIGUE_PENALTY_TIRED, &timing, (0, 0), 0).0
+                    }
+                };
+                match result {
+                    PlayResult::Ball => balls += 1,
+                    PlayResult::Hit(_) | PlayResult::Out(_) => contact += 1,
+                    _ => strikes += 1,
+                }
+            }
+            let share = |count: u32| count as f32 / PITCHES as f32;
+            let report = format!("{:?}: preview {:?}, thrown {:.3}/{:.3}/{:.3}", aim, preview, share(strikes), share(balls), share(contact));
+            assert!((preview.strike - share(strikes)).abs() < 0.02, "{}", report);
+            assert!((preview.ball - share(balls)).abs() < 0.02, "{}", report);
+            assert!((preview.contact - share(contact)).abs() < 0.02, "{}", report);
+        }
     }
 }
@@ -0,0 +1,46 @@
+use crate::game::constants::*;
+use crate::game::state::PitchLocation;
+use crate::team::PlayerStats;
+
+/// How much damage a batter does on pitches to each part of the zone, 0 (cold) to 100 (hot).
+/// Rows run top to bottom and columns inside to outside, matching the strike zone display.
+pub type DangerGrid = [[u8; 3]; 3];
+
+/// Build a batter's heat map from their statcast profile. Hard, frequent contact heats up the
+/// whole zone - most of all middle-middle - and the launch angle they live at tilts the damage
+/// toward the top of the zone (fly ball hitters) or the bottom (ground ball hitters).
+pub fn danger_grid(stats: &PlayerStats) -> DangerGrid {
+    let power = (stats.barrel_percent - LEAGUE_AVG_BARREL_PERCENT) / LINEUP_BARREL_PERCENT_SPREAD;
+    let contact = (stats.avg_hit_speed - LEAGUE_AVG_HIT_SPEED) / LINEUP_HIT_SPEED_SPREAD;
+    let quality = (HEAT_BASELINE + HEAT_POINTS_PER_SPREAD * (power + contact) / 2.0).max(0.0);
+    let tilt = ((stats.avg_hit_angle - HEAT_NEUTRAL_LAUNCH_ANGLE) / HEAT_LAUNCH_ANGLE_SPREAD).clamp(-1.0, 1.0);
+
+    let mut grid = [[0; 3]; 3];
+    for (row, cells) in grid.iter_mut().enumerate() {
+        // Top row gains from a high launch angle, bottom row from a low one
+        let row_tilt = match row {
+            0 => tilt,
+            2 => -tilt,
+            _ => 0.0,
+        };
+        for (col, cell) in cells.iter_mut().enumerate() {
+            let weight = zone_weight(location_at(row, col));
+            *cell = (quality * weight + HEAT_TILT_POINTS * row_tilt).round().clamp(0.0, 100.0) as u8;
+        }
+    }
+    grid
+}
+
+/// Grid cell for a row and column of the display
+pub fn location_at(row: usize, col: usize) -> PitchLocation {
+    PitchLocation::from_numpad((7 - row * 3 + col) as u8)
+}
+
+/// Share of a batter's damage available in each spot - middle-middle is the best pitch to hit
+fn zone_weight(location: PitchLocation) -> f32 {
+    match location {
+        PitchLocation::Middle => 1.0,
+        loc if loc.is_strike() => HEAT_EDGE_WEIGHT,
+        _ => HEAT_CORNER_WEIGHT,
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::heat_map::{danger_grid, location_at};
+    use crate::game::PitchLocation;
+    use crate::roster::CustomRoster;
+    use crate::team::PlayerStats;
+
+    fn template_batter() -> PlayerStats {
+        CustomRoster::template("TST", "Test").into_team(DEFAULT_SEASON).batters[0].stats.clone()
+    }
+
+    #[test]
+    fn test_power_hitter_is_hotter_middle_middle_than_a_weak_hitter() {
+        let mut slugger = template_batter();
+        slugger.barrel_percent = 16.0;
+        slugger.avg_hit_speed = 93.0;
+        let mut slap_hitter = template_batter();
+        slap_hitter.barrel_percent = 2.0;
+        slap_hitter.avg_hit_speed = 85.0;
+
+        let hot = danger_grid(&slugger);
+        let cold = danger_grid(&slap_hitter);
+        assert!(hot[1][1] > cold[1][1], "slugger {:?} vs slap hitter {:?}", hot, cold);
+        assert!(hot[1][1] >= HEAT_HOT_MIN);
+        assert!(cold[1][1] < HEAT_HOT_MIN);
+    }
+
+    #[test]
+    fn test_middle_is_the_hottest_spot_and_corners_the_coldest() {
+        let grid = danger_grid(&template_batter());
+        let hottest = grid.iter().flatten().max().unwrap();
+        assert_eq!(grid[1][1], *hottest);
+        for (row, col) in [(0, 0), (0, 2), (2, 0), (2, 2)] {
+            assert!(grid[row][col] < grid[1][0], "corner {:?} in {:?}", (row, col), grid);
+        }
+    }
+
+    #[test]
+    fn test_launch_angle_tilts_damage_up_or_down() {
+        let mut fly_ball = template_batter();
+        fly_ball.avg_hit_angle = 20.0;
+        let grid = danger_grid(&fly_ball);
+        assert!(grid[0][1] > grid[2][1]);
+
+        let mut ground_ball = template_batter();
+        ground_ball.avg_hit_angle = 3.0;
+        let grid = danger_grid(&ground_ball);
+        assert!(grid[2][1] > grid[0][1]);
+    }
+
+    #[test]
+    fn test_grid_cells_line_up_with_the_strike_zone() {
+        assert_eq!(location_at(0, 0), PitchLocation::UpInside);
+        assert_eq!(location_at(1, 1), PitchLocation::Middle);
+        assert_eq!(location_at(2, 2), PitchLocation::DownOutside);
+    }
+}
@@ -1,31 +1,210 @@
 use crate::audio::AudioPlayer;
-use crate::game::{constants::*, GameEngine, GameState, OutType, PitchLocation, PitchState, PlayResult, TeamInputMode, SwingTiming};
+use crate::game::{constants::*, BullpenMenu, DefensiveSubMenu, GameEngine, GameState, InningHalf, OutType, PinchRunMenu, PitchLocation, PitchState, PlayResult, TeamInputMode, TeamSort, SwingTiming};
 use crate::input::{GameInput, InputState};
+use crate::game::matchup::{self, MatchupRoll};
+use crate::config::Setting;
+use crate::game::career::CareerCategory;
+use crate::game::engine::pitch_read_frames;
+use crate::game::practice::PracticeMode;
+use crate::team::TeamManager;
 use crate::logger::GameLogger;
 
 pub fn handle_input(
     state: &mut GameState,
+    team_manager: &mut TeamManager,
     engine: &GameEngine,
     input_state: &mut InputState,
     input: GameInput,
     audio_player: Option<&AudioPlayer>,
     logger: &GameLogger,
 ) {
+    state.idle_frames = 0;
+
+    // The help screen sits over everything: LEFT/RIGHT turn its pages and any other key closes
+    // it, T replaying the tutorial on the way out
+    if let Some(page) = state.help_page {
+        match input {
+            GameInput::Left => state.help_page = Some(page.saturating_sub(1)),
+            GameInput::Right => state.help_page = Some(page + 1),
+            _ => {
+                state.help_page = None;
+                if input == GameInput::ToggleSort && state.tutorial_available() {
+                    state.start_tutorial();
+                }
+            }
+        }
+        return;
+    }
+    // So does the career leaders screen, LEFT/RIGHT stepping through the categories
+    if let Some(tab) = state.leaders_tab {
+        let tabs = CareerCategory::ALL.len();
+        match input {
+            GameInput::Left => state.leaders_tab = Some((tab + tabs - 1) % tabs),
+            GameInput::Right => state.leaders_tab = Some((tab + 1) % tabs),
+            _ => state.leaders_tab = None,
+        }
+        return;
+    }
+    if input == GameInput::Leaders {
+        state.leaders_tab = Some(0);
+        return;
+    }
+    // And the settings screen, UP/DOWN picking a setting and LEFT/RIGHT changing it
+    if let Some(row) = state.settings_row {
+        let rows = Setting::ALL.len();
+        match input {
+            GameInput::Up => state.settings_row = Some((row + rows - 1) % rows),
+            GameInput::Down => state.settings_row = Some((row + 1) % rows),
+            GameInput::Left | GameInput::Right => state.config.step_setting(Setting::ALL[row], input == GameInput::Right),
+            _ => state.settings_row = None,
+        }
+        return;
+    }
+    if input == GameInput::Settings {
+        state.settings_row = Some(0);
+        return;
+    }
+    // ? is the surprise matchup until there's a game on
+    if input == GameInput::Help || (state.mode == crate::game::GameMode::Playing && input == GameInput::RandomMatchup) {
+        state.help_page = Some(0);
+        return;
+    }
+
     // Handle team selection first
     if let crate::game::GameMode::TeamSelection { .. } = &state.mode {
-        handle_team_selection_input(state, input);
+        handle_team_selection_input(state, team_manager, engine, input);
+        engine.set_ballpark(state.home_ballpark());
+        engine.set_weather(state.weather);
+        return;
+    }
+
+    if let crate::game::GameMode::LineupEdit(_) = &state.mode {
+        handle_lineup_input(state, input);
+        return;
+    }
+
+    // Holding F keeps the dead time between plays running fast - demos included
+    if input == GameInput::FastForward {
+        state.fast_forward_frames = FAST_FORWARD_HOLD_FRAMES;
+        return;
+    }
+
+    // In demo mode the CPU plays both sides - all that's left is pausing and the speed
+    if state.config.demo_mode {
+        handle_watch_input(state, input);
+        return;
+    }
+
+    // The pitch preview can be switched on and off at any point in the game
+    if input == GameInput::TogglePreview {
+        state.config.pitch_preview = !state.config.pitch_preview;
+        state.message = format!("Pitch preview {}", if state.config.pitch_preview { "on" } else { "off" });
+        return;
+    }
+
+    // The pitch keys call the pitch once it's on its way
+    let input = match (input, &state.pitch_state) {
+        (GameInput::SelectPitch(idx), PitchState::BallApproaching { .. }) => GameInput::GuessPitch(idx as u8),
+        (input, _) => input,
+    };
+
+    // The tutorial only lets its highlighted keys through - ESC skips the rest of it
+    if let Some(tutorial) = &mut state.tutorial {
+        if input == GameInput::Pause {
+            state.end_tutorial("Tutorial skipped - press ? between pitches to see it again");
+            return;
+        }
+        if !tutorial.accepts(&state.pitch_state, &input) {
+            return;
+        }
+        if tutorial.advance(&input) {
+            state.end_tutorial("Tutorial done - play ball!");
+        }
+    }
+
+    // Practice has no managing to do - ESC ends the session
+    if state.practice.is_some() {
+        match input {
+            GameInput::Pause => {
+                state.end_practice();
+                input_state.reset();
+                return;
+            }
+            GameInput::PinchRun | GameInput::DefensiveSub | GameInput::Bullpen | GameInput::SimInning | GameInput::StepOut => return,
+            _ => {}
+        }
+    }
+
+    // Pinch running happens between pitches
+    if state.pinch_run_menu.is_some() {
+        handle_pinch_run_input(state, input, logger);
+        return;
+    }
+    if input == GameInput::PinchRun {
+        if matches!(state.pitch_state, PitchState::ChoosePitch | PitchState::ShowResult { .. }) {
+            if let Err(e) = state.open_pinch_run_menu() {
+                state.message = e;
+            }
+        }
+        return;
+    }
+
+    // So do defensive replacements
+    if state.defensive_sub_menu.is_some() {
+        handle_defensive_sub_input(state, input, logger);
+        return;
+    }
+    if input == GameInput::DefensiveSub {
+        if matches!(state.pitch_state, PitchState::ChoosePitch | PitchState::ShowResult { .. }) {
+            if let Err(e) = state.open_defensive_sub_menu() {
+                state.message = e;
+            }
+        }
+        return;
+    }
+
+    // And pitching changes
+    if state.bullpen_menu.is_some() {
+        handle_bullpen_input(state, input, logger);
+        return;
+    }
+    if input == GameInput::Bullpen {
+        if matches!(state.pitch_state, PitchState::ChoosePitch | PitchState::ShowResult { .. }) {
+            if let Err(e) = state.open_bullpen_menu() {
+                state.message = e;
+            }
+        }
+        return;
+    }
+
+    // Quick sim also waits for a break between pitches
+    if input == GameInput::SimInning {
+        if matches!(state.pitch_state, PitchState::ChoosePitch | PitchState::ShowResult { .. }) {
+            super::quick_sim::sim_half_inning(state, engine);
+            input_state.reset();
+        }
+        return;
+    }
+
+    // Stepping out once the pitch clock is winding down costs the batter a strike
+    if input == GameInput::StepOut {
+        if matches!(state.pitch_state, PitchState::PitchClock { .. }) {
+            super::update::call_clock_violation(state, engine, PlayResult::Strike, audio_player);
+            input_state.reset();
+        }
         return;
     }
 
     match &state.pitch_state {
-        PitchState::ChoosePitch => {
+        PitchState::ChoosePitch if !state.cpu_pitching() => {
             if let GameInput::SelectPitch(idx) = input {
-                if idx < engine.pitch_types.len() {
-                    state.pitch_state = PitchState::Aiming { pitch_type: idx };
+                let pitcher = state.get_current_pitcher();
+                if idx < engine.arsenal(pitcher).len() {
                     state.message = format!(
                         "Aiming {}. Use arrows or SHIFT+(1-9) to aim, SPACE to pitch.",
-                        engine.get_pitch_name(idx)
+                        engine.get_pitch_name(pitcher, idx)
                     );
+                    state.pitch_state = PitchState::Aiming { pitch_type: idx };
                     input_state.reset();
                 }
             }
@@ -35,12 +214,21 @@ pub fn handle_input(
                 GameInput::Up | GameInput::Down | GameInput::Left | GameInput::Right => {
                     input_state.update(&input);
                 }
+                GameInput::CycleAim => {
+                    let location = input_state.aimed_location().next();
+                    input_state.aim_at(location);
+                    state.message = format!(
+                        "Aiming {}: {}. TAB for the next spot, SPACE to pitch.",
+                        engine.get_pitch_name(state.get_current_pitcher(), *pitch_type),
+                        location.name()
+                    );
+                }
                 GameInput::DirectPosition(num) => {
                     // Direct numpad selection - immediately lock in position and start pitch clock
                     let location = PitchLocation::from_numpad(num);
                     state.pitch_location = Some(location);
                     state.pitch_state = PitchState::PitchClock { 
-                        frames_left: PITCH_CLOCK_FRAMES, 
+                        frames_left: state.config.pitch_clock_frames, 
                         pitch_type: *pitch_type 
                     };
                     state.message = "Get ready! Pitch clock started...".to_string();
@@ -56,7 +244,7 @@ pub fn handle_input(
                     );
                     state.pitch_location = Some(location);
                     state.pitch_state = PitchState::PitchClock { 
-                        frames_left: PITCH_CLOCK_FRAMES, 
+                        frames_left: state.config.pitch_clock_frames, 
                         pitch_type: *pitch_type 
                     };
                     state.message = "Get ready! Pitch clock started...".to_string();
@@ -65,22 +253,24 @@ pub fn handle_input(
                 _ => {}
             }
         }
-        PitchState::BallApproaching { .. } => {
+        // Nobody bats in the bullpen
+        PitchState::BallApproaching { .. } if state.practice_mode() != Some(PracticeMode::Bullpen) => {
             match input {
                 GameInput::Up | GameInput::Down | GameInput::Left | GameInput::Right => {
                     input_state.update(&input);
                 }
+                // One call per pitch, and only when the batter's eye is on
+                GameInput::GuessPitch(idx) if state.config.batters_eye && state.pitch_guess.is_none() => {
+                    if let Some(pitch) = engine.pitch_type(state.get_current_pitcher(), idx as usize) {
+                        state.message = format!("Sitting on the {}...", pitch.name);
+                        state.pitch_guess = Some(idx as usize);
+                    }
+                }
                 GameInput::DirectPosition(num) => {
                     // Direct numpad selection - attempt swing with timing
                     let swing_loc = PitchLocation::from_numpad(num);
                     let timing = calculate_swing_timing(state);
-                    state.swing_location = Some(swing_loc);
-                    state.swing_timing = timing;
-                    state.pitch_state = PitchState::Swinging { 
-                        frames_left: SWINGING_ANIMATION_FRAMES, 
-                        swing_timing: timing
-                    };
-                    state.message = format!("Swing! ({})", format_timing(&timing));
+                    begin_swing(state, swing_loc, timing);
                     input_state.reset();
                 }
                 GameInput::Action => {
@@ -92,157 +282,247 @@ pub fn handle_input(
                         input_state.right,
                     );
                     let timing = calculate_swing_timing(state);
-                    state.swing_location = Some(swing_loc);
-                    state.swing_timing = timing;
-                    state.pitch_state = PitchState::Swinging { 
-                        frames_left: SWINGING_ANIMATION_FRAMES, 
-                        swing_timing: timing
-                    };
-                    state.message = format!("Swing! ({})", format_timing(&timing));
+                    begin_swing(state, swing_loc, timing);
                     input_state.reset();
                 }
                 _ => {}
             }
         }
-        PitchState::WaitingForBatter => {
-            // Legacy state - shouldn't happen with new timing system
-            match input {
-                GameInput::Action => {
-                    // Continue to next pitch
-                    input_state.reset();
-                    state.pitch_state = PitchState::ChoosePitch;
-                    state.pitch_location = None;
-                    state.swing_location = None;
-                    state.swing_timing = SwingTiming::NoSwing;
-                    state.message = "Choose your pitch!".to_string();
+        PitchState::Fielding { .. } if input == GameInput::Action => {
+            // Attempt to catch/field the ball
+            if let PitchState::Fielding { ball_in_play, frames_elapsed } = &state.pitch_state {
+                let perfect_timing = ball_in_play.hang_time / 2;
+                let (result, success_chance) = engine.calculate_fielding_result(
+                    ball_in_play,
+                    *frames_elapsed,
+                    perfect_timing,
+                    state.infield_fly_in_effect(),
+                    &state.defense(),
+                );
+                let result = engine.fielders_choice(result, state.force_at_second());
+                
+                // Log fielding attempt
+                logger.log_fielding_attempt(
+                    ball_in_play,
+                    *frames_elapsed,
+                    perfect_timing,
+                    success_chance,
+                    &result,
+                );
+                
+                // Play appropriate sound
+                if let Some(player) = audio_player.as_ref() {
+                    match &result {
+                        PlayResult::Out(OutType::Flyout)
+                        | PlayResult::Out(OutType::LineOut)
+                        | PlayResult::Out(OutType::InfieldFly) => {
+                            player.play_catch();
+                        }
+                        PlayResult::Out(OutType::Groundout | OutType::FieldersChoice) => {
+                            player.play_ground_ball();
+                        }
+                        PlayResult::Hit(_) => {
+                            match ball_in_play.initial_contact_quality {
+                                85..=100 => player.play_cheer_triple_and_homer(),
+                                60..=84 => player.play_cheer_double(),
+                                _ => player.play_cheer_single(),
+                            }
+                        }
+                        _ => {}
+                    }
                 }
-                _ => {}
+                
+                let (direction, ball_type) = (ball_in_play.direction, ball_in_play.ball_type.clone());
+                state.record_ball_in_play(direction, ball_type, &result);
+                super::update::process_play_result(state, engine, &result, audio_player);
+                state.fielding_cursor = None;
+                state.pitch_state = PitchState::ShowResult {
+                    result,
+                    frames_left: RESULT_DISPLAY_FRAMES,
+                };
             }
         }
-        PitchState::Fielding { .. } => {
-            // Handle fielding input - move fielder and attempt catch
+        PitchState::UmpireCall { result, .. } if input == GameInput::Action => {
+            // Don't keep me waiting
+            let result = result.clone();
+            super::update::reveal_call(state, engine, result, audio_player);
+        }
+        PitchState::ShowResult { .. } if input == GameInput::Action => {
+            // Continue to next pitch
+            input_state.reset();
+            state.pitch_state = PitchState::ChoosePitch;
+            state.pitch_location = None;
+            state.swing_location = None;
+            state.message = state.pitch_prompt();
+        }
+        _ => {}
+    }
+}
+
+fn handle_team_selection_input(state: &mut GameState, team_manager: &mut TeamManager, engine: &GameEngine, input: GameInput) {
+    if let crate::game::GameMode::TeamSelection { selected_home, selected_away, input_buffer, input_mode, search, roll, sort, practice } = &mut state.mode {
+        // Let a rolling matchup finish before taking more input
+        if roll.is_some() {
+            return;
+        }
+
+        // Search mode owns every key until Enter or Esc
+        if let Some(active) = search {
+            let matches = team_manager.search_teams(&active.query);
             match input {
+                GameInput::TextInput(c) => {
+                    active.query.push(c);
+                    active.cursor = 0;
+                }
+                GameInput::Backspace => {
+                    active.query.pop();
+                    active.cursor = 0;
+                }
+                GameInput::Up => active.cursor = active.cursor.saturating_sub(1),
+                GameInput::Down => active.cursor = (active.cursor + 1).min(matches.len().saturating_sub(1)),
+                GameInput::Left | GameInput::Right => {
+                    active.side = match active.side {
+                        TeamInputMode::SelectingAway => TeamInputMode::SelectingHome,
+                        _ => TeamInputMode::SelectingAway,
+                    };
+                }
+                GameInput::Pause => {
+                    *search = None;
+                    state.message = "Search closed".to_string();
+                }
                 GameInput::Action => {
-                    // Attempt to catch/field the ball
-                    if let PitchState::Fielding { ball_in_play, frames_elapsed } = &state.pitch_state {
-                        let perfect_timing = ball_in_play.hang_time / 2;
-                        let (result, success_chance) = engine.calculate_fielding_result(
-                            ball_in_play,
-                            *frames_elapsed,
-                            perfect_timing,
-                        );
-                        
-                        // Log fielding attempt
-                        logger.log_fielding_attempt(
-                            ball_in_play,
-                            *frames_elapsed,
-                            perfect_timing,
-                            success_chance,
-                            &result,
-                        );
-                        
-                        // Play appropriate sound
-                        if let Some(player) = audio_player.as_ref() {
-                            match &result {
-                                PlayResult::Out(OutType::Flyout) | PlayResult::Out(OutType::LineOut) => {
-                                    player.play_catch();
-                                }
-                                PlayResult::Out(OutType::Groundout) => {
-                                    player.play_ground_ball();
-                                }
-                                PlayResult::Hit(_) => {
-                                    match ball_in_play.initial_contact_quality {
-                                        85..=100 => player.play_cheer_triple_and_homer(),
-                                        60..=84 => player.play_cheer_double(),
-                                        _ => player.play_cheer_single(),
-                                    }
-                                }
-                                _ => {}
-                            }
+                    match matches.get(active.cursor) {
+                        Some(&idx) => {
+                            let key = team_manager.get_team_list()[idx].clone();
+                            let side = active.side.clone();
+                            state.message = choose_team(team_manager, &side, key, selected_away, selected_home, state.config.allow_same_team);
                         }
-                        
-                        super::update::process_play_result(state, &result, audio_player);
-                        state.fielding_cursor = None;
-                        state.pitch_state = PitchState::ShowResult {
-                            result,
-                            frames_left: RESULT_DISPLAY_FRAMES,
-                        };
+                        None => state.message = format!("No teams match '{}'", active.query),
                     }
+                    *search = None;
                 }
                 _ => {}
             }
+            return;
         }
-        PitchState::ShowResult { .. } => {
-            if input == GameInput::Action {
-                // Continue to next pitch
-                input_state.reset();
-                state.pitch_state = PitchState::ChoosePitch;
-                state.pitch_location = None;
-                state.swing_location = None;
-                state.message = "Choose your pitch!".to_string();
-            }
-        }
-        _ => {}
-    }
-}
 
-fn handle_team_selection_input(state: &mut GameState, input: GameInput) {
-    if let crate::game::GameMode::TeamSelection { selected_home, selected_away, input_buffer, input_mode } = &mut state.mode {
-        // Debug: log what input we received
-        
         match input {
+            GameInput::RandomMatchup | GameInput::BalancedMatchup => {
+                let balanced = input == GameInput::BalancedMatchup;
+                match matchup::roll_matchup(team_manager, balanced, &mut *engine.rng()) {
+                    Ok((away, home)) => {
+                        input_buffer.clear();
+                        *input_mode = TeamInputMode::None;
+                        let reveal = format!(
+                            "{} @ {}",
+                            team_manager.get_team_full_name(&away),
+                            team_manager.get_team_full_name(&home)
+                        );
+                        *roll = Some(MatchupRoll { frames_left: MATCHUP_ROLL_FRAMES, away, home, reveal });
+                        state.message = if balanced { "Finding a balanced matchup..." } else { "Surprise matchup..." }.to_string();
+                    }
+                    Err(e) => state.message = e,
+                }
+            }
+            GameInput::ToggleSort => {
+                *sort = match sort {
+                    TeamSort::Alphabetical => {
+                        // Ratings need roster data, so load everything up front
+                        for key in team_manager.get_team_list() {
+                            let _ = team_manager.load_team(&key);
+                        }
+                        state.message = "Teams sorted by rating (OFF/PIT on the 20-80 scale)".to_string();
+                        TeamSort::Rating
+                    }
+                    TeamSort::Rating => {
+                        state.message = "Teams sorted alphabetically".to_string();
+                        TeamSort::Alphabetical
+                    }
+                };
+            }
+            GameInput::Watch => {
+                // The picked teams, or a surprise matchup when they aren't both in yet
+                let teams = match (selected_away.clone(), selected_home.clone()) {
+                    (Some(away), Some(home)) => Ok((away, home)),
+                    _ => matchup::roll_matchup(team_manager, false, &mut *engine.rng()),
+                };
+                match teams {
+                    Ok((away, home)) => {
+                        state.config.demo_mode = true;
+                        state.start_game(team_manager, home, away);
+                        state.roll_weather(&mut *engine.rng());
+                        state.message = "Watching - SPACE pauses, LEFT/RIGHT change the speed, Q quits".to_string();
+                    }
+                    Err(e) => state.message = e,
+                }
+            }
+            GameInput::Practice => {
+                *practice = PracticeMode::cycle(*practice);
+                state.message = match practice {
+                    Some(mode) => format!("{} - pick both teams and press SPACE or ENTER (P again for the other mode)", mode.name()),
+                    None => "Practice off - SPACE or ENTER plays a game".to_string(),
+                };
+            }
+            GameInput::StartSearch => {
+                // Search for whichever side is being picked, else the first one still open
+                let side = match input_mode {
+                    TeamInputMode::SelectingHome => TeamInputMode::SelectingHome,
+                    TeamInputMode::SelectingAway => TeamInputMode::SelectingAway,
+                    TeamInputMode::None if selected_away.is_some() && selected_home.is_none() => TeamInputMode::SelectingHome,
+                    TeamInputMode::None => TeamInputMode::SelectingAway,
+                };
+                input_buffer.clear();
+                *input_mode = TeamInputMode::None;
+                *search = Some(crate::game::TeamSearch { query: String::new(), cursor: 0, side });
+                state.message = "Type to search, arrows to move, ENTER to pick, ESC to cancel".to_string();
+            }
             GameInput::SelectAwayTeam => {
                 *input_buffer = String::new();
                 *input_mode = TeamInputMode::SelectingAway;
-                state.message = "Enter away team number (1-30), then press ENTER:".to_string();
+                state.message = team_number_prompt(team_manager, input_mode);
             }
             GameInput::SelectHomeTeam => {
                 *input_buffer = String::new();
                 *input_mode = TeamInputMode::SelectingHome;
-                state.message = "Enter home team number (1-30), then press ENTER:".to_string();
+                state.message = team_number_prompt(team_manager, input_mode);
             }
-            GameInput::NumberInput(digit) => {
-                if *input_mode != TeamInputMode::None && input_buffer.len() < 2 {
-                    input_buffer.push(digit);
-                    state.message = format!("Entered: {}", input_buffer);
+            GameInput::NumberInput(digit)
+                if *input_mode != TeamInputMode::None
+                    && input_buffer.len() < team_manager.get_team_list().len().to_string().len() =>
+            {
+                input_buffer.push(digit);
+                state.message = format!("Entered: {}", input_buffer);
+            }
+            GameInput::NumberInput(_) if *input_mode != TeamInputMode::None => {
+                // One digit too many - say so instead of dropping it without a word
+                state.message = format!(
+                    "Team numbers only go to {} - BACKSPACE to fix {}",
+                    team_manager.get_team_list().len(),
+                    input_buffer
+                );
+            }
+            GameInput::Backspace if *input_mode != TeamInputMode::None => {
+                input_buffer.pop();
+                state.message = if input_buffer.is_empty() {
+                    team_number_prompt(team_manager, input_mode)
                 } else {
-                }
+                    format!("Entered: {}", input_buffer)
+                };
+            }
+            GameInput::Pause if *input_mode != TeamInputMode::None => {
+                input_buffer.clear();
+                *input_mode = TeamInputMode::None;
+                state.message = "Team entry cancelled - A or H to pick again".to_string();
             }
             GameInput::Action => {
                 if !input_buffer.is_empty() {
                     if let Ok(num) = input_buffer.parse::<usize>() {
-                        let teams = state.team_manager.get_team_list();
+                        let teams = team_manager.get_team_list();
                         let idx = num.saturating_sub(1);
-                        
+
                         if idx < teams.len() {
-                            match input_mode {
-                                TeamInputMode::SelectingAway => {
-                                    let new_away = teams[idx].clone();
-                                    // Load the team data
-                                    if let Err(e) = state.team_manager.load_team(&new_away) {
-                                        state.message = format!("Error loading team {}: {}", new_away, e);
-                                        *input_buffer = String::new();
-                                        *input_mode = TeamInputMode::None;
-                                        return;
-                                    }
-                                    *selected_away = Some(new_away.clone());
-                                    state.message = format!("Away team: {} selected", new_away);
-                                }
-                                TeamInputMode::SelectingHome => {
-                                    let new_home = teams[idx].clone();
-                                    // Load the team data
-                                    if let Err(e) = state.team_manager.load_team(&new_home) {
-                                        state.message = format!("Error loading team {}: {}", new_home, e);
-                                        *input_buffer = String::new();
-                                        *input_mode = TeamInputMode::None;
-                                        return;
-                                    }
-                                    *selected_home = Some(new_home.clone());
-                                    state.message = format!("Home team: {} selected", new_home);
-                                }
-                                _ => {
-                                }
-                            }
+                            let key = teams[idx].clone();
+                            state.message = choose_team(team_manager, input_mode, key, selected_away, selected_home, state.config.allow_same_team);
                         } else {
                             state.message = format!("Invalid team number: {}. Please choose 1-{}", num, teams.len());
                         }
@@ -255,7 +535,14 @@ fn handle_team_selection_input(state: &mut GameState, input: GameInput) {
                     // Start game if both teams selected and buffer is empty
                     let home = selected_home.clone().unwrap();
                     let away = selected_away.clone().unwrap();
-                    state.start_game(home, away);
+                    if let Some(mode) = *practice {
+                        state.start_practice(team_manager, home, away, mode);
+                    } else if state.config.demo_mode {
+                        state.start_game(team_manager, home, away);
+                    } else {
+                        state.start_lineup_edit(team_manager, home, away);
+                    }
+                    state.roll_weather(&mut *engine.rng());
                 }
             }
             _ => {}
@@ -263,34 +550,401 @@ fn handle_team_selection_input(state: &mut GameState, input: GameInput) {
     }
 }
 
-fn calculate_swing_timing(state: &GameState) -> SwingTiming {
-    if let PitchState::BallApproaching { frames_left, can_swing, .. } = &state.pitch_state {
-        if !can_swing {
-            return SwingTiming::TooEarly;
-        }
-        
-        // Calculate timing based on remaining frames
-        // Perfect timing is when ball is very close to plate
-        let perfect_start = PERFECT_TIMING_WINDOW_FRAMES / 2;
-        let perfect_end = perfect_start + PERFECT_TIMING_WINDOW_FRAMES;
-        
-        let early_start = perfect_start + PERFECT_TIMING_WINDOW_FRAMES;
-        let early_end = early_start + EARLY_LATE_WINDOW_FRAMES;
-        
-        let _late_start = 0;
-        let late_end = perfect_start;
-        
-        match *frames_left {
-            f if f <= late_end => SwingTiming::Late,
-            f if f <= perfect_end => SwingTiming::Perfect,
-            f if f <= early_end => SwingTiming::Early,
-            _ => SwingTiming::TooEarly,
+/// Keys while watching the CPU play: SPACE or ESC pauses and resumes, LEFT/RIGHT change the
+/// game speed. Q is handled with the quit confirmation like any other game.
+fn handle_watch_input(state: &mut GameState, input: GameInput) {
+    match input {
+        GameInput::Action | GameInput::Pause => {
+            state.paused = !state.paused;
+            state.message = if state.paused { "Paused - SPACE to resume" } else { "Play resumed" }.to_string();
+        }
+        GameInput::Left | GameInput::Right => {
+            let speed = &mut state.config.game_speed;
+            *speed = if input == GameInput::Left { speed.slower() } else { speed.faster() };
+            state.message = format!("Game speed: {}", speed.name());
+        }
+        _ => {}
+    }
+}
+
+/// Pinch run menu keys: UP/DOWN pick from the unused bench, LEFT/RIGHT move between the
+/// occupied bases and the plate, SPACE sends the runner or hitter in and ESC (or R again)
+/// closes the menu
+fn handle_pinch_run_input(state: &mut GameState, input: GameInput, logger: &GameLogger) {
+    let Some(menu) = state.pinch_run_menu.clone() else {
+        return;
+    };
+    let bench: Vec<String> = state
+        .get_current_batting_team()
+        .map(|team| team.bench().map(|p| p.stats.id.clone()).collect())
+        .unwrap_or_default();
+    let occupied: Vec<usize> = (0..BASES_COUNT).filter(|&base| state.bases[base].is_some()).chain([AT_THE_PLATE]).collect();
+
+    match input {
+        GameInput::Up | GameInput::Down => {
+            let cursor = if input == GameInput::Up {
+                menu.cursor.saturating_sub(1)
+            } else {
+                (menu.cursor + 1).min(bench.len().saturating_sub(1))
+            };
+            state.pinch_run_menu = Some(PinchRunMenu { cursor, ..menu });
+        }
+        GameInput::Left | GameInput::Right => {
+            let at = occupied.iter().position(|&base| base == menu.base).unwrap_or(0);
+            let at = if input == GameInput::Left {
+                (at + occupied.len() - 1) % occupied.len().max(1)
+            } else {
+                (at + 1) % occupied.len().max(1)
+            };
+            let base = occupied.get(at).copied().unwrap_or(menu.base);
+            state.pinch_run_menu = Some(PinchRunMenu { base, ..menu });
+        }
+        GameInput::Action => {
+            let Some(runner_in) = bench.get(menu.cursor) else {
+                return;
+            };
+            let sent_in = if menu.base == AT_THE_PLATE {
+                state.pinch_hit(runner_in)
+            } else {
+                state.pinch_run(menu.base, runner_in)
+            };
+            match sent_in {
+                Ok(message) => {
+                    let half_str = match state.half {
+                        InningHalf::Top => "Top",
+                        InningHalf::Bottom => "Bottom",
+                    };
+                    logger.log_substitution(state.inning, half_str, &message);
+                    state.message = message;
+                }
+                Err(e) => state.message = e,
+            }
+            state.pinch_run_menu = None;
+        }
+        GameInput::Pause | GameInput::PinchRun => {
+            state.pinch_run_menu = None;
+            state.message = "Pinch run cancelled".to_string();
+        }
+        _ => {}
+    }
+}
+
+fn handle_defensive_sub_input(state: &mut GameState, input: GameInput, logger: &GameLogger) {
+    let Some(menu) = state.defensive_sub_menu.clone() else {
+        return;
+    };
+    let bench: Vec<String> = state
+        .get_current_pitching_team()
+        .map(|team| team.bench().map(|p| p.stats.id.clone()).collect())
+        .unwrap_or_default();
+    let positions = crate::team::Position::FIELDERS;
+
+    match input {
+        GameInput::Up | GameInput::Down => {
+            let cursor = if input == GameInput::Up {
+                menu.cursor.saturating_sub(1)
+            } else {
+                (menu.cursor + 1).min(bench.len().saturating_sub(1))
+            };
+            state.defensive_sub_menu = Some(DefensiveSubMenu { cursor, ..menu });
+        }
+        GameInput::Left | GameInput::Right => {
+            let at = positions.iter().position(|&p| p == menu.position).unwrap_or(0);
+            let at = if input == GameInput::Left {
+                (at + positions.len() - 1) % positions.len()
+            } else {
+                (at + 1) % positions.len()
+            };
+            state.defensive_sub_menu = Some(DefensiveSubMenu { position: positions[at], ..menu });
+        }
+        GameInput::Action => {
+            let Some(player_in) = bench.get(menu.cursor) else {
+                return;
+            };
+            match state.defensive_sub(menu.position, player_in) {
+                Ok(message) => {
+                    let half_str = match state.half {
+                        InningHalf::Top => "Top",
+                        InningHalf::Bottom => "Bottom",
+                    };
+                    logger.log_substitution(state.inning, half_str, &message);
+                    state.message = message;
+                }
+                Err(e) => state.message = e,
+            }
+            state.defensive_sub_menu = None;
+        }
+        GameInput::Pause | GameInput::DefensiveSub => {
+            state.defensive_sub_menu = None;
+            state.message = "Defensive sub cancelled".to_string();
+        }
+        _ => {}
+    }
+}
+
+/// Bullpen menu keys: UP/DOWN pick a reliever and SPACE brings them in. When pitchers bat,
+/// a double switch is offered next: LEFT/RIGHT pick the lineup slot the reliever takes (or
+/// none), UP/DOWN the bench player who takes the pitcher's spot and SPACE makes the change.
+/// ESC (or C again) closes the menu.
+fn handle_bullpen_input(state: &mut GameState, input: GameInput, logger: &GameLogger) {
+    let Some(menu) = state.bullpen_menu.clone() else {
+        return;
+    };
+    let Some(team) = state.get_current_pitching_team() else {
+        return;
+    };
+    let relievers: Vec<String> = team.bullpen().map(|p| p.stats.id.clone()).collect();
+    let bench: Vec<String> = team.bench().map(|p| p.stats.id.clone()).collect();
+    let slots = team.double_switch_slots();
+    let rows = if menu.reliever.is_some() { bench.len() } else { relievers.len() };
+
+    let change = match input {
+        GameInput::Up | GameInput::Down => {
+            let cursor = if input == GameInput::Up {
+                menu.cursor.saturating_sub(1)
+            } else {
+                (menu.cursor + 1).min(rows.saturating_sub(1))
+            };
+            state.bullpen_menu = Some(BullpenMenu { cursor, ..menu });
+            return;
+        }
+        // The choices run "no double switch", then each eligible slot
+        GameInput::Left | GameInput::Right if menu.reliever.is_some() => {
+            let choices: Vec<Option<usize>> = std::iter::once(None).chain(slots.iter().copied().map(Some)).collect();
+            let at = choices.iter().position(|&slot| slot == menu.slot).unwrap_or(0);
+            let at = if input == GameInput::Left {
+                (at + choices.len() - 1) % choices.len()
+            } else {
+                (at + 1) % choices.len()
+            };
+            state.bullpen_menu = Some(BullpenMenu { slot: choices[at], ..menu });
+            return;
+        }
+        GameInput::Action => match &menu.reliever {
+            None => {
+                let Some(reliever) = relievers.get(menu.cursor) else {
+                    return;
+                };
+                if !slots.is_empty() {
+                    state.bullpen_menu = Some(BullpenMenu { reliever: Some(reliever.clone()), slot: None, cursor: 0 });
+                    state.message = "Double switch? LEFT/RIGHT picks the new pitcher's spot in the order, UP/DOWN who comes off the bench, SPACE makes the change".to_string();
+                    return;
+                }
+                state.pitching_change(reliever, None)
+            }
+            Some(reliever) => {
+                let switch = menu.slot.zip(bench.get(menu.cursor).map(String::as_str));
+                state.pitching_change(reliever, switch)
+            }
+        },
+        GameInput::Pause | GameInput::Bullpen => {
+            state.bullpen_menu = None;
+            state.message = "Pitching change cancelled".to_string();
+            return;
         }
+        _ => return,
+    };
+
+    match change {
+        Ok(message) => {
+            let half_str = match state.half {
+                InningHalf::Top => "Top",
+                InningHalf::Bottom => "Bottom",
+            };
+            logger.log_substitution(state.inning, half_str, &message);
+            state.message = message;
+        }
+        Err(e) => state.message = e,
+    }
+    state.bullpen_menu = None;
+}
+
+/// Lineup card keys: UP/DOWN move the cursor (carrying a grabbed hitter with it), SPACE grabs
+/// or drops a hitter or makes the highlighted pitcher the starter, LEFT/RIGHT switch teams and
+/// S starts the game once both lineups are valid
+fn handle_lineup_input(state: &mut GameState, input: GameInput) {
+    let crate::game::GameMode::LineupEdit(editor) = &state.mode else {
+        return;
+    };
+    let mut editor = editor.clone();
+    let Some(team) = state.lineup_team(editor.home) else {
+        return;
+    };
+    let order_size = team.batting_order_size().min(team.batters.len());
+    let rows = order_size + team.pitchers.len();
+
+    match input {
+        GameInput::Up | GameInput::Down => {
+            let target = if input == GameInput::Up {
+                editor.cursor.checked_sub(1)
+            } else {
+                Some(editor.cursor + 1).filter(|&row| row < rows)
+            };
+            match target {
+                Some(target) if editor.holding && target < order_size => {
+                    if let Some(team) = state.lineup_team_mut(editor.home) {
+                        let _ = team.move_batting_order(editor.cursor, target);
+                    }
+                    editor.cursor = target;
+                }
+                Some(target) if !editor.holding => editor.cursor = target,
+                _ => {}
+            }
+        }
+        GameInput::Left | GameInput::Right => {
+            editor = crate::game::LineupEditor { home: !editor.home, cursor: 0, holding: false };
+        }
+        GameInput::Action if editor.cursor < order_size => {
+            let name = team.batters[editor.cursor].stats.name.clone();
+            editor.holding = !editor.holding;
+            state.message = if editor.holding {
+                format!("Moving {} - UP/DOWN to slide, SPACE to drop", name)
+            } else {
+                format!("{} bats {}", name, editor.cursor + 1)
+            };
+        }
+        GameInput::Action => {
+            let idx = editor.cursor - order_size;
+            if let Some(team) = state.lineup_team_mut(editor.home) {
+                state.message = match team.set_starting_pitcher(idx) {
+                    Ok(()) => format!("{} starts for {}", team.pitchers[idx].stats.name, team.name),
+                    Err(e) => e,
+                };
+            }
+        }
+        GameInput::SimInning => {
+            let valid = [false, true]
+                .iter()
+                .filter_map(|&home| state.lineup_team(home))
+                .try_for_each(|team| team.validate_lineup());
+            match valid {
+                Ok(()) => {
+                    state.mode = crate::game::GameMode::Playing;
+                    state.message = state.pitch_prompt();
+                    return;
+                }
+                Err(e) => state.message = e,
+            }
+        }
+        _ => {}
+    }
+    state.mode = crate::game::GameMode::LineupEdit(editor);
+}
+
+/// Load the chosen team and fill in its side, returning the status message. The side is only
+/// filled once the team has loaded, so a failure leaves the previous pick in place.
+fn choose_team(
+    team_manager: &mut TeamManager,
+    side: &TeamInputMode,
+    key: String,
+    selected_away: &mut Option<String>,
+    selected_home: &mut Option<String>,
+    allow_same_team: bool,
+) -> String {
+    let (label, slot, other_label, other) = match side {
+        TeamInputMode::SelectingAway => ("Away", selected_away, "home", &*selected_home),
+        TeamInputMode::SelectingHome => ("Home", selected_home, "away", &*selected_away),
+        TeamInputMode::None => return String::new(),
+    };
+    if !allow_same_team && other.as_deref() == Some(key.as_str()) {
+        return format!("{} is already the {} team - pick a different one", key, other_label);
+    }
+    // Load the team data
+    if let Err(e) = team_manager.load_team(&key) {
+        let kept = match slot {
+            Some(previous) => format!(" - keeping {}", previous),
+            None => String::new(),
+        };
+        return format!("Error loading team {}: {}{}", key, e, kept);
+    }
+    let note = load_note(team_manager, &key);
+    *slot = Some(key.clone());
+    format!("{} team: {} selected{}", label, key, note)
+}
+
+/// "Enter away team number (1-30), then press ENTER:" for the side being picked
+fn team_number_prompt(team_manager: &TeamManager, side: &TeamInputMode) -> String {
+    let label = if *side == TeamInputMode::SelectingHome { "home" } else { "away" };
+    format!("Enter {} team number ({}), then press ENTER:", label, team_number_range(team_manager))
+}
+
+/// "1-N" for the teams found on disk
+pub fn team_number_range(team_manager: &TeamManager) -> String {
+    match team_manager.get_team_list().len() {
+        0 => "no teams found".to_string(),
+        1 => "1".to_string(),
+        count => format!("1-{}", count),
+    }
+}
+
+/// Warning suffix for a team whose CSVs had rows we couldn't read, or too few hitters for a
+/// lineup or pitchers for a staff
+fn load_note(team_manager: &TeamManager, abbr: &str) -> String {
+    let Some(team) = team_manager.get_team(abbr) else {
+        return String::new();
+    };
+    let mut notes = Vec::new();
+    if team.skipped_rows > 0 {
+        notes.push(format!("skipped {} malformed rows", team.skipped_rows));
+    }
+    if team.replacement_batters > 0 {
+        notes.push(format!("{} replacement hitters fill out the lineup", team.replacement_batters));
+    }
+    if team.replacement_pitchers > 0 {
+        notes.push("no pitcher qualified - a replacement pitcher takes the mound".to_string());
+    }
+    if notes.is_empty() {
+        return String::new();
+    }
+    format!(" ({})", notes.join(", "))
+}
+
+fn calculate_swing_timing(state: &GameState) -> SwingTiming {
+    if let PitchState::BallApproaching { frames_left, can_swing, pitch_type, .. } = &state.pitch_state {
+        classify_read_swing_timing(*frames_left, *can_swing, pitch_read_frames(state.pitch_guess, *pitch_type))
     } else {
         SwingTiming::NoSwing
     }
 }
 
+/// Timing of a swing started with the ball `frames_left` frames from the plate
+pub fn classify_swing_timing(frames_left: u8, can_swing: bool) -> SwingTiming {
+    classify_read_swing_timing(frames_left, can_swing, 0)
+}
+
+/// Like `classify_swing_timing`, with the perfect window `read_frames` wider on each side -
+/// narrower when negative - for a batter who called the pitch
+pub fn classify_read_swing_timing(frames_left: u8, can_swing: bool, read_frames: i8) -> SwingTiming {
+    if !can_swing {
+        return SwingTiming::TooEarly;
+    }
+
+    let late_end = LATE_END_FRAMES as i16 - read_frames as i16;
+    let perfect_end = PERFECT_END_FRAMES as i16 + read_frames as i16;
+    match frames_left as i16 {
+        f if f <= TOO_LATE_END_FRAMES as i16 => SwingTiming::TooLate,
+        f if f <= late_end => SwingTiming::Late,
+        f if f <= perfect_end => SwingTiming::Perfect,
+        f if f <= EARLY_END_FRAMES as i16 => SwingTiming::Early,
+        _ => SwingTiming::TooEarly,
+    }
+}
+
+/// Start the swing animation - shared by the human batter and the CPU batter
+pub fn begin_swing(state: &mut GameState, swing_loc: PitchLocation, timing: SwingTiming) {
+    if let (Some(session), PitchState::BallApproaching { frames_left, .. }) = (&mut state.practice, &state.pitch_state) {
+        session.record_swing(*frames_left);
+    }
+    state.swing_location = Some(swing_loc);
+    state.swing_timing = timing;
+    state.cpu_swing = None;
+    state.pitch_state = PitchState::Swinging {
+        frames_left: SWINGING_ANIMATION_FRAMES,
+        swing_timing: timing,
+    };
+    state.message = format!("Swing! ({})", format_timing(&timing));
+}
+
 fn format_timing(timing: &SwingTiming) -> &'static str {
     match timing {
         SwingTiming::TooEarly => "Too Early!",
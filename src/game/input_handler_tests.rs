@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::input_handler::{classify_read_swing_timing, classify_swing_timing, handle_input};
+    use crate::game::test_support::{league_manager, started_game};
+    use crate::game::{GameEngine, GameState, PitchState, SwingTiming};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::team::TeamManager;
+
+    #[test]
+    fn test_swing_timing_bands_cover_the_approach() {
+        let bands = [
+            (TOO_LATE_END_FRAMES, SwingTiming::TooLate),
+            (LATE_END_FRAMES, SwingTiming::Late),
+            (PERFECT_END_FRAMES, SwingTiming::Perfect),
+            (EARLY_END_FRAMES, SwingTiming::Early),
+            (BALL_APPROACH_FRAMES, SwingTiming::TooEarly),
+        ];
+        for frames_left in 0..=BALL_APPROACH_FRAMES {
+            let expected = bands.iter().find(|(end, _)| frames_left <= *end).unwrap().1;
+            assert_eq!(classify_swing_timing(frames_left, true), expected, "frames_left {}", frames_left);
+        }
+    }
+
+    #[test]
+    fn test_swing_timing_band_edges() {
+        let table = [
+            (0, SwingTiming::TooLate),
+            (1, SwingTiming::TooLate),
+            (2, SwingTiming::Late),
+            (3, SwingTiming::Late),
+            (4, SwingTiming::Perfect),
+            (9, SwingTiming::Perfect),
+            (10, SwingTiming::Early),
+            (21, SwingTiming::Early),
+            (22, SwingTiming::TooEarly),
+            (SWING_TIMING_WINDOW_FRAMES, SwingTiming::TooEarly),
+        ];
+        for (frames_left, expected) in table {
+            assert_eq!(classify_swing_timing(frames_left, true), expected, "frames_left {}", frames_left);
+        }
+    }
+
+    #[test]
+    fn test_swing_before_the_window_opens_is_too_early() {
+        for frames_left in 0..=BALL_APPROACH_FRAMES {
+            assert_eq!(classify_swing_timing(frames_left, false), SwingTiming::TooEarly);
+        }
+    }
+
+    #[test]
+    fn test_calling_the_pitch_moves_the_perfect_window_edges() {
+        let read = PITCH_READ_BONUS_FRAMES as u8;
+        let misread = PITCH_MISREAD_PENALTY_FRAMES as u8;
+        assert_eq!(classify_read_swing_timing(LATE_END_FRAMES, true, PITCH_READ_BONUS_FRAMES), SwingTiming::Perfect);
+        assert_eq!(classify_read_swing_timing(PERFECT_END_FRAMES + read, true, PITCH_READ_BONUS_FRAMES), SwingTiming::Perfect);
+        assert_eq!(classify_read_swing_timing(PERFECT_END_FRAMES + read + 1, true, PITCH_READ_BONUS_FRAMES), SwingTiming::Early);
+        assert_eq!(classify_read_swing_timing(LATE_END_FRAMES + misread, true, -PITCH_MISREAD_PENALTY_FRAMES), SwingTiming::Late);
+        assert_eq!(classify_read_swing_timing(PERFECT_END_FRAMES, true, -PITCH_MISREAD_PENALTY_FRAMES), SwingTiming::Early);
+        assert_eq!(classify_read_swing_timing(TOO_LATE_END_FRAMES, true, PITCH_READ_BONUS_FRAMES), SwingTiming::TooLate);
+    }
+
+    /// A game with the second pitch of the arsenal `frames_left` frames from the plate
+    fn pitch_on_the_way(manager: &mut TeamManager, frames_left: u8) -> GameState {
+        let mut state = started_game(manager);
+        state.pitch_location = Some(crate::game::PitchLocation::Middle);
+        state.pitch_state = PitchState::BallApproaching {
+            frames_left,
+            approach_frames: BALL_APPROACH_FRAMES,
+            ball_position: 0.9,
+            pitch_type: 1,
+            can_swing: true,
+        };
+        state
+    }
+
+    fn swing_after_calling(batters_eye: bool, calls: &[usize], frames_left: u8) -> (Option<usize>, SwingTiming) {
+        let engine = GameEngine::with_seed(1605);
+        let mut manager = league_manager();
+        let mut state = pitch_on_the_way(&mut manager, frames_left);
+        state.config.batters_eye = batters_eye;
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+        for &call in calls {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::SelectPitch(call), None, &logger);
+        }
+        let guess = state.pitch_guess;
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+        (guess, state.swing_timing)
+    }
+
+    #[test]
+    fn test_the_right_call_saves_a_swing_and_a_wrong_one_costs_one() {
+        let just_early = PERFECT_END_FRAMES + 1;
+        assert_eq!(swing_after_calling(true, &[], just_early), (None, SwingTiming::Early));
+        // Only the first call counts
+        assert_eq!(swing_after_calling(true, &[1, 0], just_early), (Some(1), SwingTiming::Perfect));
+        assert_eq!(swing_after_calling(true, &[0], PERFECT_END_FRAMES), (Some(0), SwingTiming::Early));
+        // Purists get neither the cue nor the call
+        assert_eq!(swing_after_calling(false, &[1], just_early), (None, SwingTiming::Early));
+    }
+}
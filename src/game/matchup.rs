@@ -0,0 +1,183 @@
+use crate::game::constants::*;
+use crate::team::{split_team_key, team_key, Hand, Team, TeamManager};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A random matchup that's been picked and is cycling names on screen before it locks in
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupRoll {
+    pub frames_left: u8,
+    pub away: String,
+    pub home: String,
+    pub reveal: String, // "Away @ Home" in full, for the status line once it locks in
+}
+
+/// Average barrel% of the starting lineup - a rough measure of how hard a team hits
+pub fn team_barrel_percent(team: &Team) -> f32 {
+    let lineup: Vec<f32> = team
+        .batters
+        .iter()
+        .take(team.batting_order_size())
+        .map(|p| p.stats.barrel_percent)
+        .collect();
+    if lineup.is_empty() {
+        return 0.0;
+    }
+    lineup.iter().sum::<f32>() / lineup.len() as f32
+}
+
+/// Scouting-scale grades for a team, 20 to 80 in steps of 5 with 50 as league average
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeamRating {
+    pub offense: u8,
+    pub pitching: u8,
+}
+
+impl TeamRating {
+    pub fn overall(&self) -> u8 {
+        ((self.offense as u16 + self.pitching as u16) / 2) as u8
+    }
+}
+
+/// Grade a team: offense from its lineup's barrel% and average exit velocity, pitching
+/// from the barrel% its staff allows
+pub fn rate_team(team: &Team) -> TeamRating {
+    let lineup: Vec<_> = team.batters.iter().take(team.batting_order_size()).collect();
+    let avg_hit_speed = if lineup.is_empty() {
+        LEAGUE_AVG_HIT_SPEED
+    } else {
+        lineup.iter().map(|p| p.stats.avg_hit_speed).sum::<f32>() / lineup.len() as f32
+    };
+    let barrel_percent = if lineup.is_empty() { LEAGUE_AVG_BARREL_PERCENT } else { team_barrel_percent(team) };
+    let staff_barrel_percent = if team.pitchers.is_empty() {
+        LEAGUE_AVG_BARREL_PERCENT
+    } else {
+        team.pitchers.iter().map(|p| p.stats.barrel_percent).sum::<f32>() / team.pitchers.len() as f32
+    };
+
+    let power = (barrel_percent - LEAGUE_AVG_BARREL_PERCENT) / LINEUP_BARREL_PERCENT_SPREAD;
+    let contact = (avg_hit_speed - LEAGUE_AVG_HIT_SPEED) / LINEUP_HIT_SPEED_SPREAD;
+    let stuff = (LEAGUE_AVG_BARREL_PERCENT - staff_barrel_percent) / STAFF_BARREL_PERCENT_SPREAD;
+    TeamRating {
+        offense: scouting_grade((power + contact) / 2.0),
+        pitching: scouting_grade(stuff),
+    }
+}
+
+/// Standard deviations from average onto the 20-80 scale, where each 10 points is one
+fn scouting_grade(deviations: f32) -> u8 {
+    let grade = 50.0 + 10.0 * deviations;
+    ((grade / 5.0).round() * 5.0).clamp(20.0, 80.0) as u8
+}
+
+/// The batter has the platoon edge facing a pitcher who throws from the other side, which a
+/// switch hitter always does. A switch-pitcher picks the same side as the hitter.
+pub fn platoon_advantage(throws: Hand, bats: Hand) -> bool {
+    match (throws, bats) {
+        (_, Hand::Switch) => true,
+        (Hand::Switch, _) => false,
+        (throws, bats) => throws != bats,
+    }
+}
+
+/// "RHP vs LHB" for the pitcher and batter in the box, with whether the batter has the
+/// platoon edge. Nothing until both hands are known.
+pub fn handedness_matchup(throws: Option<Hand>, bats: Option<Hand>) -> Option<(String, bool)> {
+    let (throws, bats) = (throws?, bats?);
+    Some((format!("{}HP vs {}HB", throws.letter(), bats.letter()), platoon_advantage(throws, bats)))
+}
+
+/// Two different teams at random, as (away, home)
+pub fn random_matchup<R: Rng>(keys: &[String], rng: &mut R) -> Option<(String, String)> {
+    let mut picked = keys.choose_multiple(rng, 2);
+    Some((picked.next()?.clone(), picked.next()?.clone()))
+}
+
+/// Two teams whose barrel% are within `max_gap` of each other, chosen at random among all
+/// such pairs. When no pair is that close the closest one is used instead.
+pub fn balanced_matchup<R: Rng>(ratings: &[(String, f32)], max_gap: f32, rng: &mut R) -> Option<(String, String)> {
+    let mut pairs = Vec::new();
+    for (i, (_, a)) in ratings.iter().enumerate() {
+        for (j, (_, b)) in ratings.iter().enumerate().skip(i + 1) {
+            pairs.push(((a - b).abs(), i, j));
+        }
+    }
+
+    let close: Vec<&(f32, usize, usize)> = pairs.iter().filter(|(gap, _, _)| *gap <= max_gap).collect();
+    let &(_, i, j) = match close.choose(rng) {
+        Some(pair) => *pair,
+        None => pairs.iter().min_by(|a, b| a.0.total_cmp(&b.0))?,
+    };
+
+    // Either side can be the home team
+    if rng.gen_bool(0.5) {
+        Some((ratings[i].0.clone(), ratings[j].0.clone()))
+    } else {
+        Some((ratings[j].0.clone(), ratings[i].0.clone()))
+    }
+}
+
+/// Pick and load a matchup from the teams on disk. A team that fails to load is dropped
+/// and the matchup rolled again.
+pub fn roll_matchup<R: Rng>(team_manager: &mut TeamManager, balanced: bool, rng: &mut R) -> Result<(String, String), String> {
+    let mut candidates = team_manager.get_team_list();
+    for _ in 0..MATCHUP_MAX_REROLLS {
+        let pick = if balanced {
+            // Ratings need roster data, so load everything up front
+            candidates.retain(|key| team_manager.load_team(key).is_ok());
+            let ratings: Vec<(String, f32)> = candidates
+                .iter()
+                .filter_map(|key| Some((key.clone(), team_barrel_percent(team_manager.get_team(key)?))))
+                .collect();
+            balanced_matchup(&ratings, BALANCED_MATCHUP_MAX_BARREL_GAP, rng)
+        } else {
+            random_matchup(&candidates, rng)
+        };
+        let (away, home) = pick.ok_or("Need at least two teams with data for a random matchup")?;
+
+        match (team_manager.load_team(&away), team_manager.load_team(&home)) {
+            (Ok(()), Ok(())) => return Ok((away, home)),
+            (away_result, _) => {
+                let failed = if away_result.is_err() { away } else { home };
+                candidates.retain(|key| *key != failed);
+            }
+        }
+    }
+    Err("Couldn't load a random matchup - check the team data files".to_string())
+}
+
+/// Teams for a quick-play game, as (away, home): the pair asked for, or a random one when
+/// there isn't one. Either way both teams are different and loaded before this returns.
+pub fn quick_play_matchup<R: Rng>(
+    team_manager: &mut TeamManager,
+    pair: Option<(String, String)>,
+    rng: &mut R,
+) -> Result<(String, String), String> {
+    let Some((away, home)) = pair else {
+        return roll_matchup(team_manager, false, rng);
+    };
+    // A bare abbreviation means its latest season on the team list, else the configured one
+    let teams = team_manager.get_team_list();
+    let resolve = |team: String| {
+        let team = team.to_uppercase();
+        if split_team_key(&team).is_some() {
+            return team;
+        }
+        teams
+            .iter()
+            .rev()
+            .find(|key| split_team_key(key).is_some_and(|(abbr, _)| abbr == team))
+            .cloned()
+            .unwrap_or_else(|| team_key(&team, team_manager.season))
+    };
+    let (away, home) = (resolve(away), resolve(home));
+    if away == home {
+        return Err(format!("Quick play needs two different teams, not {} twice", away));
+    }
+    for key in [&away, &home] {
+        team_manager
+            .load_team(key)
+            .map_err(|e| format!("Couldn't load {} for quick play: {}", key, e))?;
+    }
+    Ok((away, home))
+}
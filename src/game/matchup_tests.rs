@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::matchup::{
+        balanced_matchup, handedness_matchup, quick_play_matchup, random_matchup, rate_team, roll_matchup, team_barrel_percent, TeamRating,
+    };
+    use crate::game::test_support::league_manager;
+    use crate::roster::CustomRoster;
+    use crate::team::{Hand, TeamManager};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn ratings(values: &[(&str, f32)]) -> Vec<(String, f32)> {
+        values.iter().map(|(key, barrel)| (key.to_string(), *barrel)).collect()
+    }
+
+    #[test]
+    fn test_random_matchup_never_pairs_a_team_with_itself() {
+        let keys: Vec<String> = ["AAA_2025", "BBB_2025"].iter().map(|k| k.to_string()).collect();
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let (away, home) = random_matchup(&keys, &mut rng).unwrap();
+            assert_ne!(away, home);
+        }
+        assert_eq!(random_matchup(&keys[..1], &mut rng), None);
+    }
+
+    #[test]
+    fn test_balanced_matchup_stays_within_the_gap() {
+        let teams = ratings(&[("SLUG", 12.0), ("MID", 8.0), ("MID2", 8.5), ("WEAK", 3.0)]);
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..50 {
+            let (away, home) = balanced_matchup(&teams, BALANCED_MATCHUP_MAX_BARREL_GAP, &mut rng).unwrap();
+            let mut pair = [away, home];
+            pair.sort();
+            assert_eq!(pair, ["MID".to_string(), "MID2".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_balanced_matchup_falls_back_to_the_closest_pair() {
+        let teams = ratings(&[("SLUG", 12.0), ("MID", 8.0), ("WEAK", 2.0)]);
+        let mut rng = StdRng::seed_from_u64(5);
+        let (away, home) = balanced_matchup(&teams, 1.0, &mut rng).unwrap();
+        let mut pair = [away, home];
+        pair.sort();
+        assert_eq!(pair, ["MID".to_string(), "SLUG".to_string()]);
+    }
+
+    #[test]
+    fn test_team_barrel_percent_averages_the_lineup() {
+        let mut team = CustomRoster::template("TST", "Test").into_team(DEFAULT_SEASON);
+        team.batters[0].stats.barrel_percent = 17.0; // Template batters are all 8.0
+        assert!((team_barrel_percent(&team) - 9.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_roll_matchup_loads_both_teams() {
+        let mut manager = league_manager();
+        let mut rng = StdRng::seed_from_u64(6);
+
+        let (away, home) = roll_matchup(&mut manager, true, &mut rng).unwrap();
+        assert_ne!(away, home);
+        assert!(manager.get_team(&away).is_some() && manager.get_team(&home).is_some());
+    }
+
+    #[test]
+    fn test_quick_play_picks_two_different_loaded_teams() {
+        for seed in 0..10 {
+            let mut manager = league_manager();
+            let (away, home) = quick_play_matchup(&mut manager, None, &mut StdRng::seed_from_u64(seed)).unwrap();
+            assert_ne!(away, home);
+            assert!(manager.get_team_list().contains(&away) && manager.get_team_list().contains(&home));
+            assert!(manager.get_team(&away).is_some() && manager.get_team(&home).is_some());
+        }
+    }
+
+    #[test]
+    fn test_quick_play_uses_the_pair_asked_for() {
+        let mut manager = league_manager();
+        let mut rng = StdRng::seed_from_u64(1593);
+        let pair = |away: &str, home: &str| Some((away.to_string(), home.to_string()));
+
+        let (away, home) = quick_play_matchup(&mut manager, pair("thw_2026", "SDG"), &mut rng).unwrap();
+        assert_eq!((away.as_str(), home.as_str()), ("THW_2026", "SDG_2026"));
+        assert!(manager.get_team("SDG_2026").is_some());
+
+        assert!(quick_play_matchup(&mut manager, pair("THW", "THW_2026"), &mut rng).is_err());
+        let err = quick_play_matchup(&mut manager, pair("THW", "NOPE"), &mut rng).unwrap_err();
+        assert!(err.contains("NOPE_2025"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_league_average_roster_rates_fifty_across_the_board() {
+        let team = CustomRoster::template("AVG", "Average").into_team(DEFAULT_SEASON);
+        assert_eq!(rate_team(&team), TeamRating { offense: 50, pitching: 50 });
+    }
+
+    #[test]
+    fn test_rating_rewards_power_and_stingy_pitching() {
+        let mut team = CustomRoster::template("GUD", "Good").into_team(DEFAULT_SEASON);
+        for batter in &mut team.batters {
+            batter.stats.barrel_percent = 12.0; // Two spreads above average
+            batter.stats.avg_hit_speed = 92.0;  // Two spreads above average
+        }
+        for pitcher in &mut team.pitchers {
+            pitcher.stats.barrel_percent = 6.5; // One spread better than average
+        }
+        let rating = rate_team(&team);
+        assert_eq!(rating, TeamRating { offense: 70, pitching: 60 });
+        assert_eq!(rating.overall(), 65);
+    }
+
+    #[test]
+    fn test_rating_stays_on_the_scouting_scale() {
+        let mut team = CustomRoster::template("BAD", "Bad").into_team(DEFAULT_SEASON);
+        for batter in &mut team.batters {
+            batter.stats.barrel_percent = 0.0;
+            batter.stats.avg_hit_speed = 70.0;
+        }
+        for pitcher in &mut team.pitchers {
+            pitcher.stats.barrel_percent = 30.0;
+        }
+        assert_eq!(rate_team(&team), TeamRating { offense: 20, pitching: 20 });
+
+        // Bench players don't count toward the lineup grade
+        let mut team = CustomRoster::template("BEN", "Bench").into_team(DEFAULT_SEASON);
+        let mut slugger = team.batters[0].clone();
+        slugger.stats.barrel_percent = 40.0;
+        team.batters.push(slugger);
+        assert_eq!(rate_team(&team).offense, 50);
+    }
+
+    #[test]
+    fn test_ratings_are_cached_when_teams_load() {
+        let mut manager = TeamManager::new(format!("{}/fixtures/teams", env!("CARGO_MANIFEST_DIR")), DEFAULT_SEASON);
+        manager.load_league(format!("{}/fixtures/league.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+        let teams = manager.get_team_list();
+        assert!(manager.team_rating(&teams[0]).is_none());
+        assert_eq!(manager.teams_by_rating(), (0..teams.len()).collect::<Vec<_>>());
+
+        for key in &teams {
+            manager.load_team(key).unwrap();
+        }
+        let order = manager.teams_by_rating();
+        let overall: Vec<u8> = order.iter().map(|&idx| manager.team_rating(&teams[idx]).unwrap().overall()).collect();
+        assert!(overall.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn test_handedness_matchup_labels_every_combination() {
+        let cases = [
+            (Hand::Right, Hand::Right, "RHP vs RHB", false),
+            (Hand::Right, Hand::Left, "RHP vs LHB", true),
+            (Hand::Right, Hand::Switch, "RHP vs SHB", true),
+            (Hand::Left, Hand::Right, "LHP vs RHB", true),
+            (Hand::Left, Hand::Left, "LHP vs LHB", false),
+            (Hand::Left, Hand::Switch, "LHP vs SHB", true),
+            (Hand::Switch, Hand::Right, "SHP vs RHB", false),
+        ];
+        for (throws, bats, label, batter_edge) in cases {
+            assert_eq!(handedness_matchup(Some(throws), Some(bats)), Some((label.to_string(), batter_edge)));
+        }
+        assert_eq!(handedness_matchup(None, Some(Hand::Left)), None);
+        assert_eq!(handedness_matchup(Some(Hand::Left), None), None);
+    }
+}
@@ -3,11 +3,71 @@ pub mod engine;
 pub mod constants;
 pub mod input_handler;
 pub mod update;
+pub mod ai;
+pub mod box_score;
+pub mod career;
+pub mod clock;
+pub mod demo;
+pub mod ballpark;
+pub mod baserunning;
+pub mod matchup;
+pub mod momentum;
+pub mod post_game;
+pub mod practice;
+pub mod quick_sim;
+pub mod weather;
+pub mod heat_map;
+pub mod scouting;
+pub mod scorecard;
+pub mod season;
+pub mod spray_chart;
+pub mod tutorial;
 
+#[cfg(test)]
+mod ai_tests;
+#[cfg(test)]
+mod balance_tests;
+#[cfg(test)]
+mod baserunning_tests;
+#[cfg(test)]
+mod career_tests;
+#[cfg(test)]
+mod clock_tests;
+#[cfg(test)]
+mod demo_tests;
 #[cfg(test)]
 mod engine_tests;
 #[cfg(test)]
+mod heat_map_tests;
+#[cfg(test)]
+mod input_handler_tests;
+#[cfg(test)]
+mod matchup_tests;
+#[cfg(test)]
+mod momentum_tests;
+#[cfg(test)]
+mod post_game_tests;
+#[cfg(test)]
+mod practice_tests;
+#[cfg(test)]
+mod scorecard_tests;
+#[cfg(test)]
+mod scouting_tests;
+#[cfg(test)]
+mod season_tests;
+#[cfg(test)]
+mod spray_chart_tests;
+#[cfg(test)]
 mod state_tests;
+#[cfg(test)]
+mod test_support;
+#[cfg(test)]
+mod tutorial_tests;
+#[cfg(test)]
+mod update_tests;
 
-pub use state::{GameMode, GameState, InningHalf, PitchState, PlayResult, PitchLocation, HitType, OutType, TeamInputMode, SwingTiming};
+pub use state::{GameMode, GameState, InningHalf, LineupEditor, BullpenMenu, DefensiveSubMenu, PinchRunMenu, PitchState, PlayResult, PitchLocation, HitType, OutType, TeamInputMode, TeamSearch, TeamSort, SwingTiming};
 pub use engine::GameEngine;
+pub use clock::TickClock;
+pub use input_handler::handle_input;
+pub use update::update_game_state;
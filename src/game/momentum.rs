@@ -0,0 +1,56 @@
+use crate::game::constants::*;
+
+/// One team's run of form. Hits and runs build it, strikeouts knock it down, and it drifts
+/// back toward even as each new batter steps in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Momentum {
+    pub value: i8, // -MOMENTUM_MAX to MOMENTUM_MAX
+}
+
+impl Momentum {
+    pub fn hit(&mut self) {
+        self.shift(MOMENTUM_HIT);
+    }
+
+    pub fn run(&mut self) {
+        self.shift(MOMENTUM_RUN);
+    }
+
+    pub fn strikeout(&mut self) {
+        self.shift(-MOMENTUM_STRIKEOUT);
+    }
+
+    /// A new batter - ease back toward even without crossing it
+    pub fn decay(&mut self) {
+        self.value -= self.value.signum() * MOMENTUM_DECAY.min(self.value.abs());
+    }
+
+    fn shift(&mut self, amount: i8) {
+        self.value = self.value.saturating_add(amount).clamp(-MOMENTUM_MAX, MOMENTUM_MAX);
+    }
+
+    pub fn is_extreme(&self) -> bool {
+        self.value.abs() >= MOMENTUM_EXTREME
+    }
+
+    /// Contact quality nudge for a batter on this team - only at the extremes
+    pub fn contact_bonus(&self) -> i32 {
+        if self.is_extreme() {
+            self.value.signum() as i32 * MOMENTUM_CONTACT_BONUS
+        } else {
+            0
+        }
+    }
+
+    /// Cheer volume, 1.0 when the game is even
+    pub fn crowd_intensity(&self) -> f32 {
+        1.0 + MOMENTUM_CROWD_SWING * self.value as f32 / MOMENTUM_MAX as f32
+    }
+
+    /// Scoreboard meter, e.g. "▲▲▲··" for a team on a roll or "▼····" for one going cold
+    pub fn meter(&self) -> String {
+        let filled = (self.value.abs() * MOMENTUM_METER_CELLS + MOMENTUM_MAX / 2) / MOMENTUM_MAX;
+        let arrow = if self.value < 0 { "▼" } else { "▲" };
+        format!("{}{}", arrow.repeat(filled as usize), "·".repeat((MOMENTUM_METER_CELLS - filled) as usize))
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GameConfig;
+    use crate::game::constants::*;
+    use crate::game::momentum::Momentum;
+    use crate::game::{GameEngine, GameState, InningHalf};
+
+    #[test]
+    fn test_hits_and_runs_build_momentum_up_to_the_cap() {
+        let mut momentum = Momentum::default();
+        momentum.hit();
+        assert_eq!(momentum.value, MOMENTUM_HIT);
+        momentum.run();
+        assert_eq!(momentum.value, MOMENTUM_HIT + MOMENTUM_RUN);
+        for _ in 0..10 {
+            momentum.hit();
+        }
+        assert_eq!(momentum.value, MOMENTUM_MAX);
+    }
+
+    #[test]
+    fn test_strikeouts_drag_momentum_down_to_the_floor() {
+        let mut momentum = Momentum { value: 1 };
+        momentum.strikeout();
+        assert_eq!(momentum.value, 1 - MOMENTUM_STRIKEOUT);
+        for _ in 0..10 {
+            momentum.strikeout();
+        }
+        assert_eq!(momentum.value, -MOMENTUM_MAX);
+    }
+
+    #[test]
+    fn test_decay_drifts_toward_even_without_crossing() {
+        let mut hot = Momentum { value: 5 };
+        hot.decay();
+        assert_eq!(hot.value, 5 - MOMENTUM_DECAY);
+        let mut cold = Momentum { value: -5 };
+        cold.decay();
+        assert_eq!(cold.value, -5 + MOMENTUM_DECAY);
+        let mut even = Momentum::default();
+        for _ in 0..3 {
+            even.decay();
+        }
+        assert_eq!(even.value, 0);
+    }
+
+    #[test]
+    fn test_only_extreme_momentum_moves_contact_and_the_crowd_follows_it() {
+        assert_eq!(Momentum { value: MOMENTUM_EXTREME - 1 }.contact_bonus(), 0);
+        assert_eq!(Momentum { value: MOMENTUM_EXTREME }.contact_bonus(), MOMENTUM_CONTACT_BONUS);
+        assert_eq!(Momentum { value: -MOMENTUM_MAX }.contact_bonus(), -MOMENTUM_CONTACT_BONUS);
+
+        assert_eq!(Momentum::default().crowd_intensity(), 1.0);
+        assert!(Momentum { value: MOMENTUM_MAX }.crowd_intensity() > Momentum { value: 2 }.crowd_intensity());
+        assert!(Momentum { value: -MOMENTUM_MAX }.crowd_intensity() < 1.0);
+    }
+
+    #[test]
+    fn test_meter_fills_with_momentum() {
+        assert_eq!(Momentum::default().meter(), "·····");
+        assert_eq!(Momentum { value: MOMENTUM_MAX }.meter(), "▲▲▲▲▲");
+        assert_eq!(Momentum { value: -4 }.meter(), "▼▼···");
+    }
+
+    #[test]
+    fn test_a_rally_builds_the_batting_sides_momentum_and_decays_per_batter() {
+        let mut state = GameState::new();
+        let engine = GameEngine::with_seed(1597);
+        state.half = InningHalf::Bottom;
+        for _ in 0..4 {
+            state.add_hit(1, &mut *engine.rng());
+        }
+        assert!(state.home_momentum.is_extreme(), "{:?}", state.home_momentum);
+        assert_eq!(state.away_momentum, Momentum::default());
+        assert_eq!(state.momentum_contact_bonus(), MOMENTUM_CONTACT_BONUS);
+
+        let before = state.home_momentum.value;
+        state.bases = Default::default(); // No run forced in
+        state.add_walk();
+        assert_eq!(state.home_momentum.value, before - MOMENTUM_DECAY);
+    }
+
+    #[test]
+    fn test_momentum_effects_can_be_turned_off() {
+        let config = GameConfig { momentum_effects: false, ..GameConfig::default() };
+        let mut state = GameState::with_config(config);
+        state.away_momentum = Momentum { value: MOMENTUM_MAX };
+        assert_eq!(state.momentum_contact_bonus(), 0);
+        // The meter still shows it
+        assert_eq!(state.batting_momentum().meter(), "▲▲▲▲▲");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::game::box_score::{BoxScore, GameBatterLine, GamePitcherLine};
+use crate::game::constants::*;
+use crate::game::{GameState, InningHalf, PitchState};
+
+/// The game is over and the final play has had its time on screen
+pub fn showing_post_game(state: &GameState) -> bool {
+    state.game_over && !matches!(state.pitch_state, PitchState::ShowResult { .. })
+}
+
+/// A hit that drove in runs, kept for the post-game highlights
+#[derive(Debug, Clone, PartialEq)]
+pub struct Highlight {
+    pub inning: u8,
+    pub half: InningHalf,
+    pub batter: String, // Box score key
+    pub bases: u8, // 4 for a home run
+    pub runs: u8,
+}
+
+impl Highlight {
+    fn rank(&self) -> (bool, u8, u8) {
+        // Home runs first, then the biggest hits, then the latest in the game
+        (self.bases == 4, self.runs, self.inning)
+    }
+
+    /// "Bottom 7: Jane Doe, 3-run home run"
+    pub fn describe(&self, batter_name: &str) -> String {
+        let half = match self.half {
+            InningHalf::Top => "Top",
+            InningHalf::Bottom => "Bottom",
+        };
+        let hit = match self.bases {
+            1 => "single",
+            2 => "double",
+            3 => "triple",
+            _ => "home run",
+        };
+        let what = match (self.bases, self.runs) {
+            (4, 4) => "grand slam".to_string(),
+            (4, 1) => "solo home run".to_string(),
+            (_, 1) => format!("RBI {}", hit),
+            (_, runs) => format!("{}-run {}", runs, hit),
+        };
+        format!("{} {}: {}, {}", half, self.inning, batter_name, what)
+    }
+}
+
+/// Player of the game points for a batting line
+pub fn batter_points(line: &GameBatterLine) -> i32 {
+    line.hits as i32 * MVP_POINTS_PER_HIT
+        + line.home_runs as i32 * MVP_POINTS_PER_HOME_RUN
+        + line.rbi as i32 * MVP_POINTS_PER_RBI
+        + line.runs as i32 * MVP_POINTS_PER_RUN
+        + line.walks as i32 * MVP_POINTS_PER_WALK
+}
+
+/// Player of the game points for a pitching line - outs and strikeouts, less earned runs
+pub fn pitcher_points(line: &GamePitcherLine) -> i32 {
+    line.outs_recorded as i32 * MVP_POINTS_PER_OUT + line.strikeouts as i32 * MVP_POINTS_PER_STRIKEOUT
+        - line.earned_runs as i32 * MVP_POINTS_PER_EARNED_RUN
+}
+
+/// Everyone in the box score by player of the game points, best first. A two-way player's
+/// batting and pitching count together; ties go to the lower key so the order is stable.
+pub fn rank_players(box_score: &BoxScore) -> Vec<(String, i32)> {
+    let mut points: Vec<(String, i32)> = Vec::new();
+    let mut add = |key: &String, value: i32| match points.iter_mut().find(|(k, _)| k == key) {
+        Some((_, total)) => *total += value,
+        None => points.push((key.clone(), value)),
+    };
+    for (key, line) in &box_score.batting {
+        add(key, batter_points(line));
+    }
+    for (key, line) in &box_score.pitching {
+        add(key, pitcher_points(line));
+    }
+    points.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    points
+}
+
+/// The `n` plays worth showing again
+pub fn top_highlights(highlights: &[Highlight], n: usize) -> Vec<&Highlight> {
+    let mut ranked: Vec<&Highlight> = highlights.iter().collect();
+    ranked.sort_by_key(|h| std::cmp::Reverse(h.rank()));
+    ranked.truncate(n);
+    ranked
+}
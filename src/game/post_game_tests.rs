@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::box_score::{BoxScore, GameBatterLine, GamePitcherLine};
+    use crate::game::constants::*;
+    use crate::game::post_game::{batter_points, rank_players, showing_post_game, top_highlights, Highlight};
+    use crate::game::{GameEngine, GameState, InningHalf, PitchState, PlayResult};
+
+    fn highlight(inning: u8, bases: u8, runs: u8) -> Highlight {
+        Highlight { inning, half: InningHalf::Top, batter: format!("B{}", inning), bases, runs }
+    }
+
+    #[test]
+    fn test_home_run_counts_on_top_of_the_hit() {
+        let single = GameBatterLine { at_bats: 1, hits: 1, ..Default::default() };
+        let homer = GameBatterLine { home_runs: 1, ..single.clone() };
+        assert_eq!(batter_points(&homer) - batter_points(&single), MVP_POINTS_PER_HOME_RUN);
+    }
+
+    #[test]
+    fn test_big_bat_outranks_a_shaky_start_and_two_way_lines_add_up() {
+        let mut box_score = BoxScore::default();
+        *box_score.batter_mut("SLUGGER") = GameBatterLine { at_bats: 4, runs: 2, hits: 2, home_runs: 2, rbi: 4, ..Default::default() };
+        *box_score.batter_mut("SLAPPER") = GameBatterLine { at_bats: 4, hits: 1, ..Default::default() };
+        *box_score.pitcher_mut("SHAKY") = GamePitcherLine { outs_recorded: 9, strikeouts: 2, earned_runs: 5, ..Default::default() };
+        // Six shutout innings plus a hit of their own
+        *box_score.pitcher_mut("ACE") = GamePitcherLine { outs_recorded: 18, strikeouts: 7, ..Default::default() };
+        *box_score.batter_mut("ACE") = GameBatterLine { at_bats: 2, hits: 1, ..Default::default() };
+
+        let ranked = rank_players(&box_score);
+        let order: Vec<&str> = ranked.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(order, ["ACE", "SLUGGER", "SLAPPER", "SHAKY"]);
+        assert_eq!(ranked[0].1, 18 * MVP_POINTS_PER_OUT + 7 * MVP_POINTS_PER_STRIKEOUT + MVP_POINTS_PER_HIT);
+    }
+
+    #[test]
+    fn test_home_runs_lead_the_highlights_then_the_biggest_hits() {
+        let plays = [highlight(1, 2, 2), highlight(3, 4, 1), highlight(5, 1, 1), highlight(7, 3, 3), highlight(8, 2, 2)];
+        let top = top_highlights(&plays, HIGHLIGHTS_SHOWN);
+        let innings: Vec<u8> = top.iter().map(|h| h.inning).collect();
+        assert_eq!(innings, [3, 7, 8]);
+    }
+
+    #[test]
+    fn test_highlights_read_like_a_recap() {
+        assert_eq!(highlight(3, 4, 1).describe("Jane Doe"), "Top 3: Jane Doe, solo home run");
+        assert_eq!(highlight(9, 4, 4).describe("Jane Doe"), "Top 9: Jane Doe, grand slam");
+        assert_eq!(highlight(2, 2, 1).describe("Jane Doe"), "Top 2: Jane Doe, RBI double");
+        assert_eq!(highlight(6, 1, 2).describe("Jane Doe"), "Top 6: Jane Doe, 2-run single");
+    }
+
+    #[test]
+    fn test_run_scoring_hits_are_recorded_as_highlights() {
+        let mut state = GameState::new();
+        let engine = GameEngine::with_seed(1598);
+        state.add_hit(1, &mut *engine.rng()); // Nobody on - not a highlight
+        assert!(state.highlights.is_empty());
+
+        state.bases = Default::default();
+        state.add_hit(4, &mut *engine.rng());
+        assert_eq!(state.highlights.len(), 1);
+        assert_eq!(state.highlights[0].bases, 4);
+        assert_eq!(state.highlights[0].runs, 1);
+        let key = state.highlights[0].batter.clone();
+        assert_eq!(state.box_score.batter(&key).unwrap().home_runs, 1);
+    }
+
+    #[test]
+    fn test_post_game_waits_for_the_final_play() {
+        let mut state = GameState::new();
+        assert!(!showing_post_game(&state));
+        state.game_over = true;
+        state.pitch_state = PitchState::ShowResult { result: PlayResult::Ball, frames_left: 1 };
+        assert!(!showing_post_game(&state));
+        state.pitch_state = PitchState::ChoosePitch;
+        assert!(showing_post_game(&state));
+    }
+}
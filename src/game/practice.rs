@@ -0,0 +1,192 @@
+use crate::game::constants::*;
+use crate::game::{HitType, PitchLocation, PlayResult};
+
+/// Where the bullpen catcher sets up, in order - working the corners, then changing eye levels
+const BULLPEN_TARGETS: [PitchLocation; 6] = [
+    PitchLocation::DownOutside,
+    PitchLocation::DownInside,
+    PitchLocation::UpInside,
+    PitchLocation::Down,
+    PitchLocation::UpOutside,
+    PitchLocation::Outside,
+];
+
+/// Practice without innings, outs or a score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PracticeMode {
+    BattingCage, // The away lineup hits against a CPU pitcher from the home staff
+    Bullpen,     // The home starter throws to a sequence of targets with nobody at the plate
+}
+
+impl PracticeMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PracticeMode::BattingCage => "Batting Cage",
+            PracticeMode::Bullpen => "Bullpen",
+        }
+    }
+
+    /// Team selection's P key cycles through the modes and back to a real game
+    pub fn cycle(current: Option<PracticeMode>) -> Option<PracticeMode> {
+        match current {
+            None => Some(PracticeMode::BattingCage),
+            Some(PracticeMode::BattingCage) => Some(PracticeMode::Bullpen),
+            Some(PracticeMode::Bullpen) => None,
+        }
+    }
+}
+
+/// Running tally for a practice session. Every pitch stands on its own - the count is
+/// cleared after each one and nothing reaches the box score's outs or runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PracticeSession {
+    pub mode: PracticeMode,
+    pub pitches: u32,
+    pub swings: u32,
+    pub barrels: u32,       // Contact of CONTACT_EXCELLENT_MIN or better
+    pub hits: u32,
+    pub outs: u32,          // Balls in play the fielders turned into outs
+    pub fouls: u32,
+    pub misses: u32,
+    pub on_target: u32,     // Bullpen pitches that ended up where they were aimed
+    pub strikes: u32,       // Bullpen pitches in the zone
+    timing_total: i32,      // Sum of the swings' frames away from the middle of the perfect window
+    last_contact: Option<i32>,
+    swung: bool,            // The pitch being settled was swung at
+    target_idx: usize,
+}
+
+impl PracticeSession {
+    pub fn new(mode: PracticeMode) -> Self {
+        Self {
+            mode,
+            pitches: 0,
+            swings: 0,
+            barrels: 0,
+            hits: 0,
+            outs: 0,
+            fouls: 0,
+            misses: 0,
+            on_target: 0,
+            strikes: 0,
+            timing_total: 0,
+            last_contact: None,
+            swung: false,
+            target_idx: 0,
+        }
+    }
+
+    /// Where the bullpen catcher is setting up for the next pitch
+    pub fn target(&self) -> PitchLocation {
+        BULLPEN_TARGETS[self.target_idx % BULLPEN_TARGETS.len()]
+    }
+
+    /// A swing started with the ball `frames_left` frames from the plate
+    pub fn record_swing(&mut self, frames_left: u8) {
+        self.swings += 1;
+        self.swung = true;
+        self.timing_total += PERFECT_TIMING_CENTER_FRAMES as i32 - frames_left as i32;
+    }
+
+    /// Contact quality of the swing just taken, `None` for a miss
+    pub fn record_contact(&mut self, contact_quality: Option<i32>) {
+        if contact_quality.is_some_and(|quality| quality >= CONTACT_EXCELLENT_MIN) {
+            self.barrels += 1;
+        }
+        self.last_contact = contact_quality;
+    }
+
+    /// Frames the average swing came after the middle of the perfect window - negative is early
+    pub fn average_timing(&self) -> f32 {
+        if self.swings == 0 {
+            return 0.0;
+        }
+        self.timing_total as f32 / self.swings as f32
+    }
+
+    /// Tally a finished pitch, `location` being where it actually crossed the plate.
+    /// Returns the feedback for the status line.
+    pub fn record_result(&mut self, result: &PlayResult, location: Option<PitchLocation>) -> String {
+        self.pitches += 1;
+        let contact = self.last_contact.take();
+        let swung = std::mem::take(&mut self.swung);
+        match self.mode {
+            PracticeMode::BattingCage => {
+                let outcome = match result {
+                    PlayResult::Hit(hit_type) => {
+                        self.hits += 1;
+                        match hit_type {
+                            HitType::Single => "Single",
+                            HitType::Double => "Double",
+                            HitType::Triple => "Triple",
+                            HitType::HomeRun => "Home run",
+                        }
+                    }
+                    PlayResult::Strike if swung => {
+                        self.misses += 1;
+                        "Swing and a miss"
+                    }
+                    PlayResult::Strike => "Called strike",
+                    PlayResult::Out(_) => {
+                        self.outs += 1;
+                        "Out"
+                    }
+                    PlayResult::ReachedOnError => "Error",
+                    PlayResult::Foul => {
+                        self.fouls += 1;
+                        "Foul"
+                    }
+                    PlayResult::Ball => "Ball",
+                };
+                match contact {
+                    Some(quality) if quality >= CONTACT_EXCELLENT_MIN => format!("BARREL! {} (contact {})", outcome, quality),
+                    Some(quality) => format!("{} (contact {})", outcome, quality),
+                    None => outcome.to_string(),
+                }
+            }
+            PracticeMode::Bullpen => {
+                let target = self.target();
+                let location = location.unwrap_or(PitchLocation::Middle);
+                if location.is_strike() {
+                    self.strikes += 1;
+                }
+                self.target_idx += 1;
+                let next = self.target();
+                if location == target {
+                    self.on_target += 1;
+                    format!("On target! Next: {}", next.name())
+                } else {
+                    format!("Aimed {}, went {}. Next: {}", target.name(), location.name(), next.name())
+                }
+            }
+        }
+    }
+
+    /// One-line recap, e.g. "32 swings, 8 barrels, 11 hits, avg timing +2 frames"
+    pub fn summary(&self) -> String {
+        match self.mode {
+            PracticeMode::BattingCage => format!(
+                "{} swings, {} barrels, {} hits, avg timing {:+.0} frames",
+                self.swings,
+                self.barrels,
+                self.hits,
+                self.average_timing()
+            ),
+            PracticeMode::Bullpen => format!(
+                "{} pitches, {} on target ({:.0}%), {} strikes",
+                self.pitches,
+                self.on_target,
+                self.accuracy() * 100.0,
+                self.strikes
+            ),
+        }
+    }
+
+    /// Share of bullpen pitches that hit their target
+    pub fn accuracy(&self) -> f32 {
+        if self.pitches == 0 {
+            return 0.0;
+        }
+        self.on_target as f32 / self.pitches as f32
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::input_handler::handle_input;
+    use crate::game::practice::{PracticeMode, PracticeSession};
+    use crate::game::test_support::{league_manager, load_matchup};
+    use crate::game::update::update_game_state;
+    use crate::game::{GameEngine, GameMode, GameState, HitType, PitchLocation, PitchState, PlayResult};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::team::TeamManager;
+
+    const MAX_PITCH_FRAMES: u32 = 10_000;
+
+    fn practice_state(manager: &mut TeamManager, mode: PracticeMode) -> GameState {
+        let (home, away) = load_matchup(manager);
+        let mut state = GameState::new();
+        state.start_practice(manager, home, away, mode);
+        state
+    }
+
+    #[test]
+    fn test_cage_session_tallies_swings_barrels_and_timing() {
+        let mut session = PracticeSession::new(PracticeMode::BattingCage);
+        session.record_swing(PERFECT_TIMING_CENTER_FRAMES);
+        session.record_contact(Some(CONTACT_EXCELLENT_MIN + 5));
+        session.record_result(&PlayResult::Hit(HitType::Double), Some(PitchLocation::Middle));
+
+        session.record_swing(PERFECT_TIMING_CENTER_FRAMES - 4); // Late
+        session.record_contact(None);
+        let feedback = session.record_result(&PlayResult::Strike, Some(PitchLocation::Down));
+        assert_eq!(feedback, "Swing and a miss");
+
+        session.record_contact(None);
+        assert_eq!(session.record_result(&PlayResult::Strike, Some(PitchLocation::Up)), "Called strike");
+
+        assert_eq!((session.swings, session.barrels, session.hits, session.misses), (2, 1, 1, 1));
+        assert_eq!(session.average_timing(), 2.0);
+        assert_eq!(session.summary(), "2 swings, 1 barrels, 1 hits, avg timing +2 frames");
+    }
+
+    #[test]
+    fn test_bullpen_scores_each_pitch_against_its_target_and_moves_on() {
+        let mut session = PracticeSession::new(PracticeMode::Bullpen);
+        let first = session.target();
+        let feedback = session.record_result(&PlayResult::Strike, Some(first));
+        assert!(feedback.starts_with("On target!"), "{}", feedback);
+        assert_ne!(session.target(), first);
+
+        session.record_result(&PlayResult::Ball, Some(PitchLocation::Middle));
+        assert_eq!((session.pitches, session.on_target), (2, 1));
+        assert_eq!(session.accuracy(), 0.5);
+    }
+
+    #[test]
+    fn test_practice_is_picked_on_team_selection() {
+        let engine = GameEngine::with_seed(1599);
+        let mut manager = league_manager();
+        let mut state = GameState::new();
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+        for input in [GameInput::Practice, GameInput::Practice] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        for input in [GameInput::SelectAwayTeam, GameInput::NumberInput('1'), GameInput::Action] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        for input in [GameInput::SelectHomeTeam, GameInput::NumberInput('2'), GameInput::Action, GameInput::Action] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert_eq!(state.mode, GameMode::Playing);
+        assert_eq!(state.practice_mode(), Some(PracticeMode::Bullpen));
+    }
+
+    #[test]
+    fn test_batting_cage_pitches_forever_without_touching_the_game() {
+        let engine = GameEngine::with_seed(1599);
+        let mut manager = league_manager();
+        let mut state = practice_state(&mut manager, PracticeMode::BattingCage);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        const SWINGS: u32 = 12;
+        let mut frames = 0;
+        while state.practice.as_ref().unwrap().pitches < SWINGS {
+            assert!(frames < MAX_PITCH_FRAMES * SWINGS, "cage stuck at {:?}", state.pitch_state);
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+            if let PitchState::BallApproaching { frames_left: PERFECT_TIMING_CENTER_FRAMES, can_swing: true, .. } = state.pitch_state {
+                handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+            }
+            frames += 1;
+        }
+
+        let session = state.practice.clone().unwrap();
+        assert_eq!(session.swings, SWINGS);
+        assert_eq!(session.average_timing(), 0.0);
+        assert_eq!(session.hits + session.outs + session.fouls + session.misses, SWINGS);
+        assert_eq!((state.inning, state.outs, state.balls, state.strikes), (1, 0, 0, 0));
+        assert_eq!((state.away_score, state.home_score), (0, 0));
+        assert!(state.bases.iter().all(Option::is_none));
+
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Pause, None, &logger);
+        assert!(matches!(state.mode, GameMode::TeamSelection { .. }));
+        assert!(state.practice.is_none());
+        assert_eq!(state.message, format!("Batting Cage session: {}", session.summary()));
+    }
+
+    #[test]
+    fn test_bullpen_has_nobody_at_the_plate() {
+        let engine = GameEngine::with_seed(1599);
+        let mut manager = league_manager();
+        let mut state = practice_state(&mut manager, PracticeMode::Bullpen);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        for pitch in 1..=3 {
+            let target = state.practice.as_ref().unwrap().target();
+            let numpad = (1..=9).find(|&n| PitchLocation::from_numpad(n) == target).unwrap();
+            for input in [GameInput::SelectPitch(0), GameInput::DirectPosition(numpad)] {
+                handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+            }
+            let mut frames = 0;
+            while !matches!(state.pitch_state, PitchState::ShowResult { .. }) {
+                assert!(frames < MAX_PITCH_FRAMES, "bullpen stuck at {:?}", state.pitch_state);
+                // Swing keys do nothing without a batter
+                handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+                update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+                frames += 1;
+            }
+            assert_eq!(state.practice.as_ref().unwrap().pitches, pitch);
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+        }
+
+        let session = state.practice.as_ref().unwrap();
+        assert_eq!(session.swings, 0);
+        assert_eq!((state.balls, state.strikes, state.outs), (0, 0, 0));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::game::constants::*;
+use crate::game::engine::PlateAppearanceResult;
+use crate::game::update::process_play_result;
+use crate::game::{GameEngine, GameState, HitType, OutType, PitchState, PlayResult};
+
+/// What happened while the rest of a half inning was simulated
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InningSim {
+    pub plays: Vec<&'static str>, // Scorebook shorthand, one entry per plate appearance
+    pub runs: u8,
+    pub hits: u8,
+    pub outs: u8, // Outs recorded during the sim, not the inning total
+    pub gave_up: bool, // A guard tripped and the half inning was ended early
+}
+
+impl InningSim {
+    /// One-line recap for the status bar, e.g. "1B K BB 2B FO GO - 2 R, 2 H"
+    pub fn summary(&self) -> String {
+        let mut text = format!("{} - {} R, {} H", self.plays.join(" "), self.runs, self.hits);
+        if self.gave_up {
+            text.push_str(" (sim cut short)");
+        }
+        text
+    }
+}
+
+/// Fast-forward the half inning in progress with the headless simulation, applying every
+/// plate appearance to `state` exactly as if it had been played out.
+pub fn sim_half_inning(state: &mut GameState, engine: &GameEngine) -> InningSim {
+    let inning = state.inning;
+    let half = state.half;
+    let mut sim = InningSim::default();
+
+    // Start from a clean count so a half-finished at-bat doesn't skew the first result
+    state.balls = 0;
+    state.strikes = 0;
+
+    let mut plate_appearances = 0;
+    while state.inning == inning && state.half == half && !state.game_over {
+        if plate_appearances >= SIM_MAX_PLATE_APPEARANCES_PER_INNING {
+            sim.gave_up = true;
+            break;
+        }
+        plate_appearances += 1;
+
+        let batter = state.get_current_batter().cloned();
+        let pitcher = state.get_current_pitcher().cloned();
+        let fatigue_penalty = state
+            .get_current_pitching_team()
+            .map(|t| t.get_fatigue_penalty())
+            .unwrap_or(FATIGUE_PENALTY_FRESH);
+        let Some(result) = engine.try_simulate_plate_appearance(
+            batter.as_ref(),
+            pitcher.as_ref(),
+            fatigue_penalty,
+            state.momentum_contact_bonus(),
+            &state.defense(),
+            SIM_MAX_PITCHES_PER_PLATE_APPEARANCE,
+        ) else {
+            sim.gave_up = true;
+            break;
+        };
+
+        let runs_before = state.away_score + state.home_score;
+        sim.plays.push(scorebook_code(&result));
+        match result {
+            PlateAppearanceResult::Walk => state.add_walk(),
+            PlateAppearanceResult::Hit(hit_type) => {
+                sim.hits += 1;
+                process_play_result(state, engine, &PlayResult::Hit(hit_type), None);
+            }
+            PlateAppearanceResult::Out(out_type) => {
+                sim.outs += 1;
+                let result = engine.fielders_choice(PlayResult::Out(out_type), state.force_at_second());
+                if result == PlayResult::Out(OutType::FieldersChoice) {
+                    *sim.plays.last_mut().unwrap() = "FC";
+                }
+                process_play_result(state, engine, &result, None);
+            }
+            PlateAppearanceResult::ReachedOnError => state.add_error(),
+        }
+        sim.runs += (state.away_score + state.home_score) - runs_before;
+    }
+
+    if sim.gave_up && state.inning == inning && state.half == half && !state.game_over {
+        state.end_half_inning();
+    }
+
+    state.pitch_state = PitchState::ChoosePitch;
+    state.pitch_location = None;
+    state.swing_location = None;
+    if !state.game_over {
+        state.message = format!("Quick sim: {}", sim.summary());
+    }
+    sim
+}
+
+fn scorebook_code(result: &PlateAppearanceResult) -> &'static str {
+    match result {
+        PlateAppearanceResult::Walk => "BB",
+        PlateAppearanceResult::Hit(HitType::Single) => "1B",
+        PlateAppearanceResult::Hit(HitType::Double) => "2B",
+        PlateAppearanceResult::Hit(HitType::Triple) => "3B",
+        PlateAppearanceResult::Hit(HitType::HomeRun) => "HR",
+        PlateAppearanceResult::Out(OutType::Strikeout) => "K",
+        PlateAppearanceResult::Out(OutType::Groundout) => "GO",
+        PlateAppearanceResult::Out(OutType::Flyout) => "FO",
+        PlateAppearanceResult::Out(OutType::LineOut) => "LO",
+        PlateAppearanceResult::Out(OutType::InfieldFly) => "IF",
+        PlateAppearanceResult::Out(OutType::FieldersChoice) => "FC",
+        PlateAppearanceResult::ReachedOnError => "E",
+    }
+}
@@ -0,0 +1,206 @@
+use crate::game::box_score::{GameBatterLine, GamePitcherLine};
+use crate::game::constants::BOX_SCORE_NAME_WIDTH;
+use crate::game::GameState;
+use std::fs;
+use std::path::Path;
+
+/// One team's part of the box score - everyone who appeared, in batting order then roster order
+struct SideLines {
+    name: String,
+    runs_by_inning: Vec<u8>,
+    batters: Vec<(String, GameBatterLine)>, // Player names with their lines
+    pitchers: Vec<(String, GamePitcherLine)>,
+}
+
+impl SideLines {
+    fn runs(&self) -> u32 {
+        self.runs_by_inning.iter().map(|&r| r as u32).sum()
+    }
+
+    fn hits(&self) -> u32 {
+        self.batters.iter().map(|(_, line)| line.hits as u32).sum()
+    }
+}
+
+/// Box score keys that belong to one side, in the order they're listed: today's lineup, then
+/// anyone else on the roster who got into the game, then "TEAM#..." placeholders
+fn side_keys<'a>(state: &GameState, home: bool, keys: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let keys: Vec<&String> = keys.collect();
+    let mut ordered: Vec<String> = Vec::new();
+    let roster = if home { &state.home_roster } else { &state.away_roster };
+    if let Some(team) = roster {
+        let lineup = team.batters.iter().take(team.batting_order_size());
+        ordered.extend(lineup.map(|p| p.stats.id.clone()));
+        for player in team.batters.iter().chain(team.pitchers.iter()) {
+            let id = &player.stats.id;
+            let appeared = keys.contains(&id) || team.removed_players.contains(id);
+            if appeared && !ordered.contains(id) {
+                ordered.push(id.clone());
+            }
+        }
+    }
+
+    let prefix = format!("{}#", state.side_key(home));
+    let mut placeholders: Vec<String> = keys
+        .into_iter()
+        .filter(|key| key.starts_with(&prefix) && !ordered.contains(key))
+        .cloned()
+        .collect();
+    placeholders.sort();
+    ordered.extend(placeholders);
+    ordered
+}
+
+fn side_lines(state: &GameState, home: bool) -> SideLines {
+    let box_score = &state.box_score;
+    let roster = if home { &state.home_roster } else { &state.away_roster };
+    let name = roster
+        .as_ref()
+        .map(|team| team.name.clone())
+        .unwrap_or_else(|| state.side_key(home));
+
+    let batters = side_keys(state, home, box_score.batting.keys())
+        .into_iter()
+        .filter(|key| {
+            // Pitchers only bat with a line of their own, i.e. when the DH is off
+            box_score.batter(key).is_some() || !roster.as_ref().is_some_and(|t| t.pitchers.iter().any(|p| &p.stats.id == key))
+        })
+        .map(|key| (state.player_name(&key), box_score.batter(&key).cloned().unwrap_or_default()))
+        .collect();
+    let pitchers = side_keys(state, home, box_score.pitching.keys())
+        .into_iter()
+        .filter_map(|key| box_score.pitcher(&key).map(|line| (state.player_name(&key), line.clone())))
+        .collect();
+    let runs_by_inning = if home { &state.home_runs_by_inning } else { &state.away_runs_by_inning };
+
+    SideLines { name, runs_by_inning: runs_by_inning.clone(), batters, pitchers }
+}
+
+/// A name cut or padded to the name column, with a suffix like " (S)" kept whole
+fn name_column(name: &str, suffix: &str) -> String {
+    let room = BOX_SCORE_NAME_WIDTH.saturating_sub(suffix.chars().count());
+    let short: String = name.chars().take(room).collect();
+    format!("{:<w$}", format!("{}{}", short, suffix), w = BOX_SCORE_NAME_WIDTH)
+}
+
+/// AB, R, H, RBI, BB and SO
+fn batting_columns(line: &GameBatterLine) -> [u32; 6] {
+    [line.at_bats, line.runs, line.hits, line.rbi, line.walks, line.strikeouts].map(u32::from)
+}
+
+/// H, R, ER, BB and SO - innings pitched go in front of them
+fn pitching_columns(line: &GamePitcherLine) -> [u32; 5] {
+    [line.hits, line.runs, line.earned_runs, line.walks, line.strikeouts].map(u32::from)
+}
+
+fn number_columns(numbers: &[u32]) -> String {
+    numbers.iter().map(|n| format!("{:>4}", n)).collect()
+}
+
+fn label_columns(labels: &[&str]) -> String {
+    labels.iter().map(|label| format!("{:>4}", label)).collect()
+}
+
+/// Classic text box score - the line score on top, then each team's batting and pitching lines
+pub fn render_box_score_text(state: &GameState) -> String {
+    let sides = [side_lines(state, false), side_lines(state, true)];
+    let status = if state.game_over { "Final" } else { "In progress" };
+    let mut out = format!(
+        "BOX SCORE - {} {}, {} {} ({})\n\n",
+        sides[0].name,
+        sides[0].runs(),
+        sides[1].name,
+        sides[1].runs(),
+        status
+    );
+
+    let innings = sides.iter().map(|s| s.runs_by_inning.len()).max().unwrap_or(0);
+    let header: String = (1..=innings).map(|n| format!("{:>3}", n)).collect();
+    out.push_str(&format!("{}{} {}\n", name_column("", ""), header, label_columns(&["R", "H"])));
+    for side in &sides {
+        let runs: String = (0..innings)
+            .map(|i| format!("{:>3}", side.runs_by_inning.get(i).copied().unwrap_or(0)))
+            .collect();
+        out.push_str(&format!("{}{} {}\n", name_column(&side.name, ""), runs, number_columns(&[side.runs(), side.hits()])));
+    }
+
+    for side in &sides {
+        out.push_str(&format!("\n{}{}\n", name_column(&side.name, ""), label_columns(&["AB", "R", "H", "RBI", "BB", "SO"])));
+        let mut totals = [0; 6];
+        for (name, line) in &side.batters {
+            let columns = batting_columns(line);
+            out.push_str(&format!("{}{}\n", name_column(name, ""), number_columns(&columns)));
+            for (total, n) in totals.iter_mut().zip(columns) {
+                *total += n;
+            }
+        }
+        out.push_str(&format!("{}{}\n", name_column("Totals", ""), number_columns(&totals)));
+    }
+
+    for side in &sides {
+        out.push_str(&format!("\n{}{:>5}{}\n", name_column(&side.name, ""), "IP", label_columns(&["H", "R", "ER", "BB", "SO"])));
+        for (name, line) in &side.pitchers {
+            out.push_str(&format!(
+                "{}{:>5}{}\n",
+                name_column(name, if line.save { " (S)" } else { "" }),
+                line.innings_pitched(),
+                number_columns(&pitching_columns(line))
+            ));
+        }
+    }
+    out
+}
+
+/// The same box score as CSV - line score, batting and pitching blocks, each with its own
+/// header row and a blank line between them
+pub fn render_box_score_csv(state: &GameState) -> Result<String, Box<dyn std::error::Error>> {
+    let sides = [side_lines(state, false), side_lines(state, true)];
+    let innings = sides.iter().map(|s| s.runs_by_inning.len()).max().unwrap_or(0);
+
+    let mut line_score = csv::Writer::from_writer(Vec::new());
+    let mut header = vec!["team".to_string()];
+    header.extend((1..=innings).map(|n| n.to_string()));
+    header.extend(["R".to_string(), "H".to_string()]);
+    line_score.write_record(&header)?;
+    for side in &sides {
+        let mut row = vec![side.name.clone()];
+        row.extend((0..innings).map(|i| side.runs_by_inning.get(i).copied().unwrap_or(0).to_string()));
+        row.extend([side.runs().to_string(), side.hits().to_string()]);
+        line_score.write_record(&row)?;
+    }
+
+    let mut batting = csv::Writer::from_writer(Vec::new());
+    batting.write_record(["team", "player", "AB", "R", "H", "RBI", "BB", "SO"])?;
+    for side in &sides {
+        for (name, line) in &side.batters {
+            let mut row = vec![side.name.clone(), name.clone()];
+            row.extend(batting_columns(line).iter().map(|n| n.to_string()));
+            batting.write_record(&row)?;
+        }
+    }
+
+    let mut pitching = csv::Writer::from_writer(Vec::new());
+    pitching.write_record(["team", "player", "IP", "H", "R", "ER", "BB", "SO", "SV"])?;
+    for side in &sides {
+        for (name, line) in &side.pitchers {
+            let mut row = vec![side.name.clone(), name.clone(), line.innings_pitched()];
+            row.extend(pitching_columns(line).iter().map(|n| n.to_string()));
+            row.push(u8::from(line.save).to_string());
+            pitching.write_record(&row)?;
+        }
+    }
+
+    let blocks = [line_score.into_inner()?, batting.into_inner()?, pitching.into_inner()?];
+    let blocks: Vec<String> = blocks.into_iter().map(String::from_utf8).collect::<Result<_, _>>()?;
+    Ok(blocks.join("\n"))
+}
+
+pub fn export_box_score_text<P: AsRef<Path>>(state: &GameState, path: P) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(path, render_box_score_text(state))?;
+    Ok(())
+}
+
+pub fn export_box_score_csv<P: AsRef<Path>>(state: &GameState, path: P) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(path, render_box_score_csv(state)?)?;
+    Ok(())
+}
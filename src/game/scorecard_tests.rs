@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::box_score::{GameBatterLine, GamePitcherLine};
+    use crate::game::constants::*;
+    use crate::game::scorecard::{export_box_score_csv, export_box_score_text, render_box_score_csv, render_box_score_text};
+    use crate::game::test_support::{league_manager, started_game};
+    use crate::game::GameState;
+
+    /// A finished game: the away leadoff hitter went 2 for 4 with a home run, the away
+    /// pitcher went five and two thirds and the home pitcher picked up a save
+    fn finished_game() -> GameState {
+        let mut state = started_game(&mut league_manager());
+        let away = state.away_roster.as_ref().unwrap();
+        let leadoff = away.batters[0].stats.id.clone();
+        let starter = away.pitchers[0].stats.id.clone();
+        let closer = state.home_roster.as_ref().unwrap().pitchers[0].stats.id.clone();
+
+        *state.box_score.batter_mut(&leadoff) = GameBatterLine { at_bats: 4, runs: 1, hits: 2, home_runs: 1, rbi: 1, walks: 0, strikeouts: 1 };
+        *state.box_score.pitcher_mut(&starter) = GamePitcherLine { outs_recorded: 17, hits: 2, runs: 1, earned_runs: 1, strikeouts: 6, ..Default::default() };
+        *state.box_score.pitcher_mut(&closer) = GamePitcherLine { outs_recorded: 10, save: true, ..Default::default() };
+        state.away_runs_by_inning[0] = 1;
+        state.away_score = 1;
+        state.game_over = true;
+        state
+    }
+
+    /// The rows of one table in the text box score, header first
+    fn table<'a>(text: &'a str, header_end: &str, team: &str) -> Vec<&'a str> {
+        let lines: Vec<&str> = text.lines().collect();
+        let start = lines.iter().position(|l| l.starts_with(team) && l.ends_with(header_end)).unwrap();
+        lines[start..].iter().take_while(|l| !l.is_empty()).copied().collect()
+    }
+
+    #[test]
+    fn test_batting_columns_line_up_for_long_names_and_zero_plate_appearances() {
+        let mut state = finished_game();
+        let away = state.away_roster.as_mut().unwrap();
+        away.batters[0].stats.name = "Featherstonehaugh-Smythe, Bartholomew".to_string();
+        let lineup = away.batting_order_size();
+        let away_name = away.name.clone();
+
+        let text = render_box_score_text(&state);
+        let rows = table(&text, "SO", &away_name);
+        assert_eq!(rows.len(), lineup + 2, "{}", text); // Header, the lineup and the totals
+        assert!(rows.iter().all(|row| row.len() == rows[0].len()), "{}", text);
+        assert_eq!(rows[1], format!("{:<20}{}", "Featherstonehaugh-Sm", "   4   1   2   1   0   1"));
+        assert!(rows[2].ends_with("   0   0   0   0   0   0"), "{}", rows[2]);
+        assert!(rows.last().unwrap().starts_with("Totals"));
+        assert!(text.starts_with(&format!("BOX SCORE - {} 1, ", away_name)));
+    }
+
+    #[test]
+    fn test_pitching_lines_show_innings_earned_runs_and_the_save() {
+        let state = finished_game();
+        let text = render_box_score_text(&state);
+        let pitching = |roster: &Option<crate::team::Team>| {
+            let header = format!("{:<20}   IP", roster.as_ref().unwrap().name);
+            table(&text, "SO", &header).iter().map(|row| row.to_string()).collect::<Vec<_>>()
+        };
+
+        let away = pitching(&state.away_roster);
+        assert_eq!(away.len(), 2, "{}", text);
+        assert!(away[1].ends_with("  5.2   2   1   1   0   6"), "{}", away[1]);
+        let home = pitching(&state.home_roster);
+        assert_eq!(home.len(), 2, "{}", text);
+        assert!(home[1][..BOX_SCORE_NAME_WIDTH].trim_end().ends_with(" (S)"), "{}", home[1]);
+        assert!(away.iter().chain(&home).all(|row| row.len() == away[0].len()), "{}", text);
+    }
+
+    #[test]
+    fn test_csv_keeps_names_whole_and_quoted() {
+        let state = finished_game();
+        let leadoff = &state.away_roster.as_ref().unwrap().batters[0].stats.name;
+        let csv_text = render_box_score_csv(&state).unwrap();
+
+        let mut reader = csv::ReaderBuilder::new().has_headers(false).flexible(true).from_reader(csv_text.as_bytes());
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(&rows[0][0], "team");
+        let batter = rows.iter().find(|r| &r[1] == leadoff.as_str()).unwrap();
+        assert_eq!(batter.iter().skip(2).collect::<Vec<_>>(), ["4", "1", "2", "1", "0", "1"]);
+        let save = rows.iter().find(|r| r.len() == 9 && &r[8] == "1").unwrap();
+        assert_eq!(&save[2], "3.1");
+        assert!(csv_text.contains(&format!("\"{}\"", leadoff)), "names with commas are quoted");
+    }
+
+    #[test]
+    fn test_export_writes_the_rendered_box_score() {
+        let state = finished_game();
+        let dir = std::env::temp_dir();
+        let text_path = dir.join(format!("bitbatter_box_score_{}.txt", std::process::id()));
+        let csv_path = dir.join(format!("bitbatter_box_score_{}.csv", std::process::id()));
+
+        export_box_score_text(&state, &text_path).unwrap();
+        export_box_score_csv(&state, &csv_path).unwrap();
+        assert_eq!(std::fs::read_to_string(&text_path).unwrap(), render_box_score_text(&state));
+        assert_eq!(std::fs::read_to_string(&csv_path).unwrap(), render_box_score_csv(&state).unwrap());
+        let _ = std::fs::remove_file(text_path);
+        let _ = std::fs::remove_file(csv_path);
+    }
+}
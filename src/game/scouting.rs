@@ -0,0 +1,58 @@
+use crate::game::constants::*;
+use crate::team::PlayerStats;
+
+/// What the advance scouts say about a batter, checked in this order - the first that fits wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoutingCategory {
+    Dangerous,    // Barrels the ball often - nothing over the heart of the plate
+    GroundBall,   // Beats the ball into the ground - pitch up in the zone
+    FlyBall,      // Lifts everything - keep it down
+    HardContact,  // Few barrels but squares it up - change speeds
+    LightBat,     // Weak contact - attack the zone
+    Average,
+}
+
+impl ScoutingCategory {
+    /// Sort a batter from their statcast profile
+    pub fn from_stats(stats: &PlayerStats) -> Self {
+        if stats.barrel_percent >= SCOUT_DANGEROUS_BARREL_PERCENT {
+            ScoutingCategory::Dangerous
+        } else if stats.avg_hit_angle <= SCOUT_GROUND_BALL_LAUNCH_ANGLE {
+            ScoutingCategory::GroundBall
+        } else if stats.avg_hit_angle >= SCOUT_FLY_BALL_LAUNCH_ANGLE {
+            ScoutingCategory::FlyBall
+        } else if stats.avg_hit_speed >= SCOUT_HARD_CONTACT_HIT_SPEED {
+            ScoutingCategory::HardContact
+        } else if stats.barrel_percent < SCOUT_LIGHT_BAT_BARREL_PERCENT {
+            ScoutingCategory::LightBat
+        } else {
+            ScoutingCategory::Average
+        }
+    }
+
+    /// How to pitch to this kind of hitter
+    pub fn advice(&self) -> &'static str {
+        match self {
+            ScoutingCategory::Dangerous => "stay off the heart of the plate",
+            ScoutingCategory::GroundBall => "pitch up in the zone",
+            ScoutingCategory::FlyBall => "keep the ball down",
+            ScoutingCategory::HardContact => "change speeds",
+            ScoutingCategory::LightBat => "attack the zone",
+            ScoutingCategory::Average => "mix it up",
+        }
+    }
+}
+
+/// One-line scouting report, quoting the number the category was picked on
+pub fn scouting_report(stats: &PlayerStats) -> String {
+    let category = ScoutingCategory::from_stats(stats);
+    let label = match category {
+        ScoutingCategory::Dangerous => format!("Dangerous - {:.1}% barrels", stats.barrel_percent),
+        ScoutingCategory::GroundBall => format!("Ground ball hitter - {:.1} deg launch angle", stats.avg_hit_angle),
+        ScoutingCategory::FlyBall => format!("Fly ball hitter - {:.1} deg launch angle", stats.avg_hit_angle),
+        ScoutingCategory::HardContact => format!("Hard contact - {:.1} mph exit velo", stats.avg_hit_speed),
+        ScoutingCategory::LightBat => format!("Light bat - {:.1}% barrels", stats.barrel_percent),
+        ScoutingCategory::Average => "Average hitter".to_string(),
+    };
+    format!("{}, {}", label, category.advice())
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::scouting::{scouting_report, ScoutingCategory};
+    use crate::roster::CustomRoster;
+    use crate::team::PlayerStats;
+
+    fn template_batter() -> PlayerStats {
+        CustomRoster::template("TST", "Test").into_team(DEFAULT_SEASON).batters[0].stats.clone()
+    }
+
+    fn stat_line(barrel_percent: f32, avg_hit_angle: f32, avg_hit_speed: f32) -> PlayerStats {
+        let mut stats = template_batter();
+        stats.barrel_percent = barrel_percent;
+        stats.avg_hit_angle = avg_hit_angle;
+        stats.avg_hit_speed = avg_hit_speed;
+        stats
+    }
+
+    #[test]
+    fn test_representative_stat_lines_map_to_categories() {
+        let cases = [
+            (stat_line(16.5, 17.0, 93.5), ScoutingCategory::Dangerous),    // Slugger
+            (stat_line(6.0, 4.5, 88.0), ScoutingCategory::GroundBall),     // Beats it into the ground
+            (stat_line(9.0, 21.0, 89.0), ScoutingCategory::FlyBall),       // Uppercut swing
+            (stat_line(7.5, 11.0, 92.0), ScoutingCategory::HardContact),   // Line drives, few barrels
+            (stat_line(2.0, 12.0, 85.5), ScoutingCategory::LightBat),      // Slap hitter
+            (stat_line(8.0, 12.5, 89.0), ScoutingCategory::Average),
+        ];
+        for (stats, expected) in cases {
+            assert_eq!(ScoutingCategory::from_stats(&stats), expected, "{:?}", stats);
+        }
+    }
+
+    #[test]
+    fn test_barrels_outrank_launch_angle() {
+        // A barrel machine is dangerous whatever angle they hit at
+        let stats = stat_line(SCOUT_DANGEROUS_BARREL_PERCENT, 5.0, 90.0);
+        assert_eq!(ScoutingCategory::from_stats(&stats), ScoutingCategory::Dangerous);
+    }
+
+    #[test]
+    fn test_template_batter_scouts_as_average() {
+        assert_eq!(ScoutingCategory::from_stats(&template_batter()), ScoutingCategory::Average);
+    }
+
+    #[test]
+    fn test_report_quotes_the_deciding_number() {
+        let report = scouting_report(&stat_line(16.5, 17.0, 93.5));
+        assert_eq!(report, "Dangerous - 16.5% barrels, stay off the heart of the plate");
+        let report = scouting_report(&stat_line(6.0, 4.5, 88.0));
+        assert!(report.contains("4.5 deg"), "{}", report);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use crate::game::constants::*;
+use crate::game::quick_sim::sim_half_inning;
+use crate::game::{GameEngine, GameState};
+use crate::team::TeamManager;
+
+/// One game on the schedule, by team key
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledGame {
+    pub home: String,
+    pub away: String,
+}
+
+/// Final score of a simulated game
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameResult {
+    pub home: String,
+    pub away: String,
+    pub home_score: u8,
+    pub away_score: u8,
+    pub innings: u8,
+}
+
+/// A team's line in the standings
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TeamRecord {
+    pub wins: u16,
+    pub losses: u16,
+    pub ties: u16, // Still level after SEASON_MAX_INNINGS
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+}
+
+impl TeamRecord {
+    fn record(&mut self, scored: u8, allowed: u8) {
+        match scored.cmp(&allowed) {
+            std::cmp::Ordering::Greater => self.wins += 1,
+            std::cmp::Ordering::Less => self.losses += 1,
+            std::cmp::Ordering::Equal => self.ties += 1,
+        }
+        self.runs_scored += scored as u32;
+        self.runs_allowed += allowed as u32;
+    }
+
+    /// Winning percentage, ties counting as half a win
+    pub fn win_pct(&self) -> f64 {
+        let games = self.wins + self.losses + self.ties;
+        if games == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + self.ties as f64 / 2.0) / games as f64
+    }
+}
+
+/// Every team's record, keyed by team key so the order never depends on who finished first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Standings {
+    pub records: BTreeMap<String, TeamRecord>,
+}
+
+impl Standings {
+    pub fn from_results(results: &[GameResult]) -> Self {
+        let mut standings = Standings::default();
+        for result in results {
+            standings.records.entry(result.home.clone()).or_default().record(result.home_score, result.away_score);
+            standings.records.entry(result.away.clone()).or_default().record(result.away_score, result.home_score);
+        }
+        standings
+    }
+
+    /// Best record first, ties broken by run differential and then team key
+    pub fn table(&self) -> Vec<(&str, TeamRecord)> {
+        let mut table: Vec<_> = self.records.iter().map(|(team, record)| (team.as_str(), *record)).collect();
+        table.sort_by(|(a_team, a), (b_team, b)| {
+            let differential = |r: &TeamRecord| r.runs_scored as i64 - r.runs_allowed as i64;
+            b.win_pct()
+                .total_cmp(&a.win_pct())
+                .then(differential(b).cmp(&differential(a)))
+                .then(a_team.cmp(b_team))
+        });
+        table
+    }
+}
+
+/// A schedule of games that share nothing, so they can be simulated in any order or all at once
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Season {
+    pub schedule: Vec<ScheduledGame>,
+}
+
+impl Season {
+    /// Every team hosts every other team `games_per_park` times
+    pub fn round_robin(teams: &[String], games_per_park: usize) -> Self {
+        let mut schedule = Vec::new();
+        for _ in 0..games_per_park {
+            for home in teams {
+                for away in teams.iter().filter(|away| *away != home) {
+                    schedule.push(ScheduledGame { home: home.clone(), away: away.clone() });
+                }
+            }
+        }
+        Self { schedule }
+    }
+
+    /// Simulate the schedule one game after another
+    pub fn simulate_all(&self, teams: &TeamManager, base_seed: u64) -> Standings {
+        let results: Vec<GameResult> = self
+            .schedule
+            .iter()
+            .enumerate()
+            .map(|(index, game)| simulate_game(teams, game, game_seed(base_seed, index)))
+            .collect();
+        Standings::from_results(&results)
+    }
+
+    /// Simulate the schedule across every available core. Each game gets its own engine seeded
+    /// from its place on the schedule, so the standings match `simulate_all` exactly.
+    pub fn simulate_all_parallel(&self, teams: &TeamManager, base_seed: u64) -> Standings {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_len = self.schedule.len().div_ceil(threads).max(1);
+        let results: Vec<GameResult> = std::thread::scope(|scope| {
+            let workers: Vec<_> = self
+                .schedule
+                .chunks(chunk_len)
+                .enumerate()
+                .map(|(chunk, games)| {
+                    scope.spawn(move || {
+                        games
+                            .iter()
+                            .enumerate()
+                            .map(|(offset, game)| simulate_game(teams, game, game_seed(base_seed, chunk * chunk_len + offset)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("season sim thread panicked"))
+                .collect()
+        });
+        Standings::from_results(&results)
+    }
+}
+
+/// Seed for the game at `index` on the schedule
+pub fn game_seed(base_seed: u64, index: usize) -> u64 {
+    base_seed.wrapping_add(index as u64)
+}
+
+/// Play one game start to finish with the quick sim
+pub fn simulate_game(teams: &TeamManager, game: &ScheduledGame, seed: u64) -> GameResult {
+    let engine = GameEngine::with_seed(seed);
+    let mut state = GameState::new();
+    state.config.max_innings = SEASON_MAX_INNINGS;
+    state.start_game(teams, game.home.clone(), game.away.clone());
+    while !state.game_over {
+        sim_half_inning(&mut state, &engine);
+    }
+    GameResult {
+        home: game.home.clone(),
+        away: game.away.clone(),
+        home_score: state.home_score,
+        away_score: state.away_score,
+        innings: state.inning,
+    }
+}
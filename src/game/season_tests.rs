@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::season::{GameResult, Season, Standings};
+    use crate::roster::CustomRoster;
+    use crate::team::TeamManager;
+
+    const TEAMS: [&str; 4] = ["AAA", "BBB", "CCC", "DDD"];
+
+    fn league() -> TeamManager {
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        for abbr in TEAMS {
+            teams.teams.insert(abbr.to_string(), CustomRoster::template(abbr, abbr).into_team(DEFAULT_SEASON));
+        }
+        teams
+    }
+
+    fn season() -> Season {
+        Season::round_robin(&TEAMS.map(String::from), 2)
+    }
+
+    #[test]
+    fn test_round_robin_has_every_team_host_every_other() {
+        let season = season();
+        assert_eq!(season.schedule.len(), TEAMS.len() * (TEAMS.len() - 1) * 2);
+        for home in TEAMS {
+            let hosted = season.schedule.iter().filter(|game| game.home == home).count();
+            assert_eq!(hosted, (TEAMS.len() - 1) * 2);
+            assert!(season.schedule.iter().all(|game| game.home != game.away));
+        }
+    }
+
+    #[test]
+    fn test_serial_and_parallel_seasons_have_identical_standings() {
+        let teams = league();
+        let season = season();
+        let serial = season.simulate_all(&teams, 1610);
+        assert_eq!(serial, season.simulate_all_parallel(&teams, 1610));
+
+        let games: u16 = serial.records.values().map(|r| r.wins + r.losses + r.ties).sum();
+        assert_eq!(games as usize, season.schedule.len() * 2);
+        let scored: u32 = serial.records.values().map(|r| r.runs_scored).sum();
+        let allowed: u32 = serial.records.values().map(|r| r.runs_allowed).sum();
+        assert_eq!(scored, allowed);
+        assert!(scored > 0);
+    }
+
+    #[test]
+    fn test_standings_table_ranks_by_record_then_run_differential() {
+        let result = |home: &str, away: &str, home_score, away_score| GameResult {
+            home: home.to_string(),
+            away: away.to_string(),
+            home_score,
+            away_score,
+            innings: INNINGS_PER_GAME,
+        };
+        let standings = Standings::from_results(&[result("AAA", "BBB", 1, 5), result("CCC", "AAA", 2, 2), result("BBB", "CCC", 3, 0)]);
+        let order: Vec<&str> = standings.table().iter().map(|(team, _)| *team).collect();
+        // AAA and CCC are both 0-1-1, CCC by a better run differential
+        assert_eq!(order, ["BBB", "CCC", "AAA"]);
+        assert_eq!(standings.records["AAA"].ties, 1);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::game::state::{BallType, FieldDirection, PlayResult};
+use std::fs;
+use std::path::Path;
+
+const OUTFIELD: [FieldDirection; 5] = [
+    FieldDirection::LeftField,
+    FieldDirection::LeftCenter,
+    FieldDirection::CenterField,
+    FieldDirection::RightCenter,
+    FieldDirection::RightField,
+];
+const INFIELD: [FieldDirection; 4] = [
+    FieldDirection::ThirdBase,
+    FieldDirection::Shortstop,
+    FieldDirection::SecondBase,
+    FieldDirection::FirstBase,
+];
+const FOUL: [FieldDirection; 3] = [
+    FieldDirection::FoulLeft,
+    FieldDirection::BehindPlate,
+    FieldDirection::FoulRight,
+];
+const SECTOR_WIDTH: usize = 10;
+
+/// One ball put in play and what came of it
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattedBall {
+    pub inning: u8,
+    pub batting_team: &'static str, // "Away" or "Home"
+    pub direction: FieldDirection,
+    pub ball_type: BallType,
+    pub result: PlayResult,
+}
+
+impl BattedBall {
+    pub fn is_hit(&self) -> bool {
+        matches!(self.result, PlayResult::Hit(_))
+    }
+}
+
+/// Every ball in play this game, for the post-game spray chart
+#[derive(Debug, Clone, Default)]
+pub struct SprayChart {
+    pub balls: Vec<BattedBall>,
+}
+
+impl SprayChart {
+    pub fn record(&mut self, ball: BattedBall) {
+        self.balls.push(ball);
+    }
+
+    /// Hits and outs to one part of the field
+    pub fn tally(&self, direction: FieldDirection) -> (usize, usize) {
+        let to_sector = self.balls.iter().filter(|b| b.direction == direction);
+        let hits = to_sector.clone().filter(|b| b.is_hit()).count();
+        (hits, to_sector.count() - hits)
+    }
+
+    /// Text chart laid out like the field - outfield sectors across the top, then the infield,
+    /// then foul territory - followed by a log of every ball in play
+    pub fn render(&self) -> String {
+        let mut out = format!("SPRAY CHART - {} balls in play (H = hits, O = outs)\n\n", self.balls.len());
+        out.push_str(&self.sector_rows(&OUTFIELD, 0));
+        out.push('\n');
+        out.push_str(&self.sector_rows(&INFIELD, SECTOR_WIDTH / 2));
+        out.push('\n');
+        out.push_str(&self.sector_rows(&FOUL, SECTOR_WIDTH));
+        out.push_str("\nBalls in play:\n");
+        for (n, ball) in self.balls.iter().enumerate() {
+            out.push_str(&format!(
+                "{:>3}. {} {:<2} {:<3} {:<10} {}\n",
+                n + 1,
+                ball.batting_team,
+                ball.inning,
+                sector_label(ball.direction),
+                format!("{:?}", ball.ball_type),
+                describe_result(&ball.result),
+            ));
+        }
+        out
+    }
+
+    fn sector_rows(&self, sectors: &[FieldDirection], indent: usize) -> String {
+        let labels: String = sectors.iter().map(|d| format!("{:^w$}", sector_label(*d), w = SECTOR_WIDTH)).collect();
+        let counts: String = sectors
+            .iter()
+            .map(|d| {
+                let (hits, outs) = self.tally(*d);
+                format!("{:^w$}", format!("H{} O{}", hits, outs), w = SECTOR_WIDTH)
+            })
+            .collect();
+        format!("{pad}{}\n{pad}{}\n", labels.trim_end(), counts.trim_end(), pad = " ".repeat(indent))
+    }
+
+    pub fn export_spray_chart<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, self.render())?;
+        Ok(())
+    }
+}
+
+pub fn sector_label(direction: FieldDirection) -> &'static str {
+    match direction {
+        FieldDirection::LeftField => "LF",
+        FieldDirection::LeftCenter => "LC",
+        FieldDirection::CenterField => "CF",
+        FieldDirection::RightCenter => "RC",
+        FieldDirection::RightField => "RF",
+        FieldDirection::ThirdBase => "3B",
+        FieldDirection::Shortstop => "SS",
+        FieldDirection::SecondBase => "2B",
+        FieldDirection::FirstBase => "1B",
+        FieldDirection::FoulLeft => "FL",
+        FieldDirection::FoulRight => "FR",
+        FieldDirection::BehindPlate => "BP",
+    }
+}
+
+fn describe_result(result: &PlayResult) -> String {
+    match result {
+        PlayResult::Hit(hit_type) => format!("Hit - {:?}", hit_type),
+        PlayResult::Out(out_type) => format!("Out - {:?}", out_type),
+        other => format!("{:?}", other),
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::spray_chart::{BattedBall, SprayChart};
+    use crate::game::state::{BallType, FieldDirection};
+    use crate::game::update::process_play_result;
+    use crate::game::{GameEngine, GameState, HitType, OutType, PlayResult};
+
+    fn ball(direction: FieldDirection, ball_type: BallType, result: PlayResult) -> BattedBall {
+        BattedBall { inning: 1, batting_team: "Away", direction, ball_type, result }
+    }
+
+    /// The count line under a sector's label, found by the label's column
+    fn sector_counts(chart: &str, label: &str) -> String {
+        let lines: Vec<&str> = chart.lines().collect();
+        let row = lines.iter().position(|line| line.split_whitespace().any(|word| word == label)).unwrap();
+        let col = lines[row].find(label).unwrap();
+        lines[row + 1][col.saturating_sub(3)..].split_whitespace().take(2).collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_balls_land_in_their_own_sectors() {
+        let mut chart = SprayChart::default();
+        chart.record(ball(FieldDirection::LeftField, BallType::FlyBall, PlayResult::Hit(HitType::Double)));
+        chart.record(ball(FieldDirection::Shortstop, BallType::Grounder, PlayResult::Out(OutType::Groundout)));
+
+        let text = chart.render();
+        assert_eq!(sector_counts(&text, "LF"), "H1 O0");
+        assert_eq!(sector_counts(&text, "SS"), "H0 O1");
+        assert_eq!(sector_counts(&text, "CF"), "H0 O0");
+        assert_eq!(sector_counts(&text, "1B"), "H0 O0");
+        assert!(text.contains("Hit - Double"));
+        assert!(text.contains("Out - Groundout"));
+    }
+
+    #[test]
+    fn test_export_writes_the_chart() {
+        let mut chart = SprayChart::default();
+        chart.record(ball(FieldDirection::RightField, BallType::LineDrive, PlayResult::Hit(HitType::Single)));
+        let path = std::env::temp_dir().join("bitbatter_spray_chart_test.txt");
+        chart.export_spray_chart(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), chart.render());
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_ball_is_recorded_in_the_inning_it_happened() {
+        let mut state = GameState::new();
+        state.outs = 2;
+        let result = PlayResult::Out(OutType::Flyout);
+        state.record_ball_in_play(FieldDirection::CenterField, BallType::FlyBall, &result);
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &result, None); // Third out flips the half
+
+        let recorded = &state.spray_chart.balls[0];
+        assert_eq!(recorded.batting_team, "Away");
+        assert_eq!(state.spray_chart.tally(FieldDirection::CenterField), (0, 1));
+    }
+
+    #[test]
+    fn test_caught_foul_pops_are_charted_but_dropped_ones_are_not() {
+        let mut state = GameState::new();
+        state.record_ball_in_play(FieldDirection::BehindPlate, BallType::PopFly, &PlayResult::Out(OutType::Flyout));
+        state.record_ball_in_play(FieldDirection::FoulLeft, BallType::PopFly, &PlayResult::Foul);
+
+        assert_eq!(state.spray_chart.balls.len(), 1);
+        let text = state.spray_chart.render();
+        assert_eq!(sector_counts(&text, "BP"), "H0 O1");
+        assert_eq!(sector_counts(&text, "FL"), "H0 O0");
+    }
+}
@@ -1,4 +1,18 @@
-use crate::team::{Team, TeamManager};
+use crate::config::GameConfig;
+use crate::team::{Defense, Position, Team, TeamManager};
+use super::ai::SwingDecision;
+use super::ballpark::Ballpark;
+use super::baserunning::{self, BatterAdvance};
+use super::engine::{runner_speed_factor, GameEngine, SpraySide};
+use rand::Rng;
+use super::box_score::BoxScore;
+use super::weather::Weather;
+use super::momentum::Momentum;
+use super::post_game::Highlight;
+use super::practice::{PracticeMode, PracticeSession};
+use super::spray_chart::{BattedBall, SprayChart};
+use super::tutorial::Tutorial;
+use super::matchup::MatchupRoll;
 use super::constants::*;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -11,18 +25,17 @@ pub enum InningHalf {
 pub enum PitchState {
     ChoosePitch,
     Aiming { pitch_type: usize },
-    PitchClock { frames_left: u16, pitch_type: usize }, // New: 3-second countdown
-    Pitching { frames_left: u8 },
+    PitchClock { frames_left: u16, pitch_type: usize }, // Batter's countdown to get set, `GameConfig::pitch_clock_frames` long
     BallApproaching { 
         frames_left: u8, 
+        approach_frames: u8, // Whole trip to the plate - set by the pitch's speed
         ball_position: f32,  // 0.0 (mound) to 1.0 (plate)
         pitch_type: usize,
         can_swing: bool,     // Timing window for swinging
     },
-    WaitingForBatter,
     Swinging { frames_left: u8, swing_timing: SwingTiming },
-    BallInPlay { frames_left: u8 },
     Fielding { ball_in_play: BallInPlay, frames_elapsed: u8 },
+    UmpireCall { result: PlayResult, frames_left: u8 }, // Taken pitch, the call not yet revealed
     ShowResult { result: PlayResult, frames_left: u8 },
 }
 
@@ -51,6 +64,9 @@ pub struct BallInPlay {
     pub speed: f32,                 // Ball speed (affects catch difficulty)
     pub hang_time: u8,              // Frames until ball lands (for fly balls)
     pub initial_contact_quality: i32, // Original contact quality
+    pub hr_chance: f32, // Batter's park-neutral home run chance if excellent contact gets through
+    pub speed_factor: f32, // Batter's `batter_speed_factor`, for stretching a hit into a triple
+    pub spray: SpraySide, // Pulled, up the middle or the other way - the pull side has the power
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -64,6 +80,47 @@ pub enum FieldDirection {
     Shortstop,
     SecondBase,
     FirstBase,
+    FoulLeft,    // Foul territory down the third base line
+    FoulRight,   // Foul territory down the first base line
+    BehindPlate,
+}
+
+impl FieldDirection {
+    pub fn is_foul(&self) -> bool {
+        matches!(self, FieldDirection::FoulLeft | FieldDirection::FoulRight | FieldDirection::BehindPlate)
+    }
+
+    /// Where the ball went, for the fielding prompt
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldDirection::LeftField => "left field",
+            FieldDirection::LeftCenter => "left center",
+            FieldDirection::CenterField => "center field",
+            FieldDirection::RightCenter => "right center",
+            FieldDirection::RightField => "right field",
+            FieldDirection::ThirdBase => "third base",
+            FieldDirection::Shortstop => "shortstop",
+            FieldDirection::SecondBase => "second base",
+            FieldDirection::FirstBase => "first base",
+            FieldDirection::FoulLeft => "foul territory down the left field line",
+            FieldDirection::FoulRight => "foul territory down the right field line",
+            FieldDirection::BehindPlate => "behind the plate",
+        }
+    }
+
+    /// Who makes the play - the gaps belong to the center fielder, foul pops to the corners
+    pub fn fielder(&self) -> Position {
+        match self {
+            FieldDirection::LeftField => Position::LeftField,
+            FieldDirection::LeftCenter | FieldDirection::CenterField | FieldDirection::RightCenter => Position::CenterField,
+            FieldDirection::RightField => Position::RightField,
+            FieldDirection::ThirdBase | FieldDirection::FoulLeft => Position::ThirdBase,
+            FieldDirection::Shortstop => Position::Shortstop,
+            FieldDirection::SecondBase => Position::SecondBase,
+            FieldDirection::FirstBase | FieldDirection::FoulRight => Position::FirstBase,
+            FieldDirection::BehindPlate => Position::Catcher,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -73,10 +130,62 @@ pub enum GameMode {
         selected_away: Option<String>,
         input_buffer: String,
         input_mode: TeamInputMode,
+        search: Option<TeamSearch>,
+        roll: Option<MatchupRoll>,
+        sort: TeamSort,
+        practice: Option<PracticeMode>, // Starting with both teams picked goes to practice instead of a game
     },
+    LineupEdit(LineupEditor),
     Playing,
 }
 
+/// Pre-game lineup card, shown once both teams are picked. Edits go straight to the game's
+/// own copies of the rosters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineupEditor {
+    pub home: bool,     // Which team's card is up
+    pub cursor: usize,  // Row - the batting order first, then the pitching staff
+    pub holding: bool,  // The hitter under the cursor moves with it
+}
+
+/// Bench list for picking a pinch runner, open between pitches
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinchRunMenu {
+    pub base: usize,   // Base of the runner being replaced, or AT_THE_PLATE to pinch hit
+    pub cursor: usize, // Row in the batting team's unused bench
+}
+
+/// Bench list for a defensive replacement, open between pitches
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefensiveSubMenu {
+    pub position: Position, // Spot in the field being upgraded
+    pub cursor: usize,      // Row in the fielding team's unused bench
+}
+
+/// Bullpen list for a pitching change, open between pitches. Once a reliever is picked and
+/// pitchers bat, it offers a double switch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BullpenMenu {
+    pub reliever: Option<String>, // Picked reliever while the double switch is on offer
+    pub slot: Option<usize>,      // Lineup slot the reliever takes in a double switch, None for a straight change
+    pub cursor: usize,            // Row in the bullpen, then in the bench
+}
+
+/// Order of the team lists on the selection screen, toggled with 'T'
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TeamSort {
+    Alphabetical,
+    Rating, // Best overall scouting grade first
+}
+
+/// Type-to-filter search over the team list, opened with '/'
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamSearch {
+    pub query: String,
+    pub cursor: usize,       // Highlighted row among the current matches
+    pub side: TeamInputMode, // SelectingAway or SelectingHome
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TeamInputMode {
     None,
@@ -91,6 +200,14 @@ pub enum PlayResult {
     Foul,
     Hit(HitType),
     Out(OutType),
+    ReachedOnError, // A fielder got to the ball and botched it
+}
+
+impl PlayResult {
+    /// The ball was put in play - the fielders, not the swing, decide what it becomes
+    pub fn is_batted_ball(&self) -> bool {
+        matches!(self, PlayResult::Hit(_) | PlayResult::Out(OutType::Groundout | OutType::Flyout | OutType::LineOut | OutType::InfieldFly))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -107,9 +224,11 @@ pub enum OutType {
     Groundout,
     Flyout,
     LineOut,
+    InfieldFly,
+    FieldersChoice, // Grounder where the lead runner is forced at second and the batter is safe
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PitchLocation {
     UpInside,
     Up,
@@ -153,6 +272,53 @@ impl PitchLocation {
         }
     }
 
+    /// Numpad key for this location - 7 is up and in, 3 is down and away
+    pub fn numpad(&self) -> u8 {
+        match self {
+            PitchLocation::UpInside => 7,
+            PitchLocation::Up => 8,
+            PitchLocation::UpOutside => 9,
+            PitchLocation::Inside => 4,
+            PitchLocation::Middle => 5,
+            PitchLocation::Outside => 6,
+            PitchLocation::DownInside => 1,
+            PitchLocation::Down => 2,
+            PitchLocation::DownOutside => 3,
+        }
+    }
+
+    /// The next location in reading order - left to right, top row first - wrapping from
+    /// down and away back to up and in
+    pub fn next(&self) -> Self {
+        let num = self.numpad();
+        let next = match num {
+            3 => 7,
+            n if n.is_multiple_of(3) => n - 5, // End of a row drops to the start of the one below
+            n => n + 1,
+        };
+        PitchLocation::from_numpad(next)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PitchLocation::UpInside => "Up and In",
+            PitchLocation::Up => "Up",
+            PitchLocation::UpOutside => "Up and Away",
+            PitchLocation::Inside => "Inside",
+            PitchLocation::Middle => "Middle",
+            PitchLocation::Outside => "Away",
+            PitchLocation::DownInside => "Down and In",
+            PitchLocation::Down => "Down",
+            PitchLocation::DownOutside => "Down and Away",
+        }
+    }
+
+    /// Spots apart on the 3x3 grid, counting rows and columns - 0 for the same spot, 4 corner to corner
+    pub fn distance(&self, other: PitchLocation) -> u8 {
+        let (a, b) = (self.numpad() - 1, other.numpad() - 1);
+        (a / 3).abs_diff(b / 3) + (a % 3).abs_diff(b % 3)
+    }
+
     pub fn is_strike(&self) -> bool {
         !matches!(self, PitchLocation::UpInside | PitchLocation::UpOutside | 
                        PitchLocation::DownInside | PitchLocation::DownOutside)
@@ -162,9 +328,10 @@ impl PitchLocation {
 #[derive(Debug, Clone)]
 pub struct GameState {
     pub mode: GameMode,
-    pub team_manager: TeamManager,
-    pub home_team: Option<String>,
+    pub home_team: Option<String>, // Team keys, e.g. "LAD_2024"
     pub away_team: Option<String>,
+    pub home_roster: Option<Team>, // The game's own copies - stamina and substitutions live here
+    pub away_roster: Option<Team>,
     pub inning: u8,
     pub half: InningHalf,
     pub outs: u8,
@@ -172,33 +339,76 @@ pub struct GameState {
     pub strikes: u8,
     pub home_score: u8,
     pub away_score: u8,
-    pub bases: [bool; 3], // 1st, 2nd, 3rd
+    pub home_runs_by_inning: Vec<u8>, // One entry per inning played so far, extra innings included
+    pub away_runs_by_inning: Vec<u8>,
+    pub bases: [Option<String>; BASES_COUNT], // Player ids on 1st, 2nd, 3rd
     pub current_batter_idx: usize,
     pub pitch_state: PitchState,
     pub pitch_location: Option<PitchLocation>,
+    pub pitch_type: usize, // Index into the current pitcher's arsenal for the pitch in flight
     pub swing_location: Option<PitchLocation>,
     pub swing_timing: SwingTiming,
     pub message: String,
     pub game_over: bool,
     pub fielding_cursor: Option<FieldDirection>, // Active fielder position
+    pub pinch_run_menu: Option<PinchRunMenu>, // Bench list while a pinch runner is being picked
+    pub defensive_sub_menu: Option<DefensiveSubMenu>, // Bench list while a defensive replacement is being picked
+    pub bullpen_menu: Option<BullpenMenu>, // Relievers while a pitching change is being made
     pub quit_requested: bool, // Quit confirmation state
+    pub config: GameConfig,
+    pub cpu_swing: Option<SwingDecision>, // CPU batter's plan for the pitch in flight
+    pub attract_mode: bool, // A demo game started from an idle menu - any key goes back
+    pub idle_frames: u32, // Frames since the last key press on team selection
+    pub box_score: BoxScore,
+    pub spray_chart: SprayChart,
+    pub pitcher_clock_used: u16, // Frames the pitcher has spent picking and aiming the next pitch
+    pub pitch_count: u32, // Swings logged this game, numbering the entries in the game log
+    pub inning_hits: u8,  // Hits in the current half inning
+    pub inning_errors: u8, // Errors in the current half inning - each would have been an out
+    pub error_runners: Vec<String>, // Runners this half inning who are on base because of an error
+    pub save_chance: Option<(&'static str, String)>, // Side and closer in a save situation they haven't blown
+    pub weather: Weather, // Rolled once both teams are picked
+    pub home_momentum: Momentum,
+    pub away_momentum: Momentum,
+    pub highlights: Vec<Highlight>, // Every hit that drove in a run, for the post-game screen
+    pub practice: Option<PracticeSession>, // Set for a batting cage or bullpen session instead of a game
+    pub tutorial: Option<Tutorial>, // Walking a new player through a pitch
+    pub help_page: Option<usize>, // The key binding help screen is up, on this page
+    pub leaders_tab: Option<usize>, // The career leaders screen is up, on this category
+    pub settings_row: Option<usize>, // The settings screen is up, with this setting picked
+    pub fast_forward_frames: u8, // Frames of fast-forward left from the last F press
+    pub dead_time_progress: u16, // Percent of a frame of dead time carried over at slow and fast speeds
+    pub paused: bool, // A game being watched is frozen until SPACE or ESC
+    pub pitch_guess: Option<usize>, // The pitch the batter called on its way in, cleared with each new pitch
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GameState {
     pub fn new() -> Self {
-        let mut team_manager = TeamManager::new();
-        let _ = team_manager.load_teams(); // Load teams at startup (now a no-op)
-        
+        Self::with_config(GameConfig::default())
+    }
+
+    pub fn with_config(config: GameConfig) -> Self {
         Self {
             mode: GameMode::TeamSelection { 
                 selected_home: None, 
                 selected_away: None,
                 input_buffer: String::new(),
                 input_mode: TeamInputMode::None,
+                search: None,
+                roll: None,
+                sort: TeamSort::Alphabetical,
+                practice: None,
             },
-            team_manager,
             home_team: None,
             away_team: None,
+            home_roster: None,
+            away_roster: None,
             inning: 1,
             half: InningHalf::Top,
             outs: 0,
@@ -206,46 +416,208 @@ impl GameState {
             strikes: 0,
             home_score: 0,
             away_score: 0,
-            bases: [false; BASES_COUNT],
+            home_runs_by_inning: vec![0; INNINGS_PER_GAME as usize],
+            away_runs_by_inning: vec![0; INNINGS_PER_GAME as usize],
+            bases: Default::default(),
             current_batter_idx: 0,
             pitch_state: PitchState::ChoosePitch,
             pitch_location: None,
+            pitch_type: 0,
             swing_location: None,
             swing_timing: SwingTiming::NoSwing,
             message: "Select teams to start playing!".to_string(),
             game_over: false,
             fielding_cursor: None,
+            pinch_run_menu: None,
+            defensive_sub_menu: None,
+            bullpen_menu: None,
             quit_requested: false,
+            config,
+            cpu_swing: None,
+            attract_mode: false,
+            idle_frames: 0,
+            box_score: BoxScore::default(),
+            spray_chart: SprayChart::default(),
+            pitcher_clock_used: 0,
+            pitch_count: 0,
+            inning_hits: 0,
+            inning_errors: 0,
+            error_runners: Vec::new(),
+            save_chance: None,
+            weather: Weather::Clear,
+            home_momentum: Momentum::default(),
+            away_momentum: Momentum::default(),
+            highlights: Vec::new(),
+            practice: None,
+            tutorial: None,
+            help_page: None,
+            leaders_tab: None,
+            settings_row: None,
+            fast_forward_frames: 0,
+            dead_time_progress: 0,
+            paused: false,
+            pitch_guess: None,
         }
     }
 
-    pub fn start_game(&mut self, home_team: String, away_team: String) {
+    /// Take copies of both teams out of the manager and play ball. The manager isn't needed
+    /// again until the next game.
+    pub fn start_game(&mut self, team_manager: &TeamManager, home_team: String, away_team: String) {
+        self.home_roster = team_manager.get_team(&home_team).cloned();
+        self.away_roster = team_manager.get_team(&away_team).cloned();
+        if self.config.pitchers_bat {
+            for team in [&mut self.home_roster, &mut self.away_roster].into_iter().flatten() {
+                team.bat_pitcher();
+            }
+        }
         self.home_team = Some(home_team);
         self.away_team = Some(away_team);
         self.mode = GameMode::Playing;
-        self.message = "Choose your pitch!".to_string();
+        self.message = self.pitch_prompt();
+    }
+
+    /// Practice with the picked teams - the away lineup hits in the cage, the home starter
+    /// throws in the bullpen
+    pub fn start_practice(&mut self, team_manager: &TeamManager, home_team: String, away_team: String, mode: PracticeMode) {
+        self.start_game(team_manager, home_team, away_team);
+        self.practice = Some(PracticeSession::new(mode));
+        self.message = match mode {
+            PracticeMode::BattingCage => "Batting cage - the CPU keeps pitching, ESC ends the session".to_string(),
+            PracticeMode::Bullpen => self.pitch_prompt(),
+        };
+    }
+
+    pub fn practice_mode(&self) -> Option<PracticeMode> {
+        self.practice.as_ref().map(|session| session.mode)
+    }
+
+    /// The CPU picks and throws every pitch - in a demo, and in the batting cage
+    pub fn cpu_pitching(&self) -> bool {
+        self.config.demo_mode || self.practice_mode() == Some(PracticeMode::BattingCage)
+    }
+
+    /// Finish a practice session and go back to team selection with its recap
+    pub fn end_practice(&mut self) {
+        let Some(session) = self.practice.take() else {
+            return;
+        };
+        *self = GameState::with_config(self.config.clone());
+        self.message = format!("{} session: {}", session.mode.name(), session.summary());
+    }
+
+    /// Saves happen between pitches. A result on screen has already been applied, and a call the
+    /// umpire is still holding is made now. Anything else mid-pitch - a clock running, a ball on
+    /// its way, a swing or a ball in the air - goes back to picking the next pitch with the count,
+    /// outs, bases and score as they stand, so a loaded game never resumes into an animation
+    /// partway through. A ball still in play hasn't been settled, so that pitch is thrown again
+    /// and the pitch and stamina it was charged are given back first.
+    pub fn pre_save_normalize(&mut self, engine: &GameEngine) {
+        match self.pitch_state.clone() {
+            PitchState::ChoosePitch => return,
+            PitchState::UmpireCall { result, .. } => super::update::reveal_call(self, engine, result, None),
+            PitchState::Fielding { .. } => self.unrecord_swing(),
+            _ => {}
+        }
+        self.pitch_state = PitchState::ChoosePitch;
+        self.pitch_location = None;
+        self.swing_location = None;
+        self.swing_timing = SwingTiming::NoSwing;
+        self.cpu_swing = None;
+        self.pitch_guess = None;
+        self.fielding_cursor = None;
+        self.pitcher_clock_used = 0;
+        self.fast_forward_frames = 0;
+        self.dead_time_progress = 0;
+        self.message = self.pitch_prompt();
+    }
+
+    /// Walk through the next pitch step by step
+    pub fn start_tutorial(&mut self) {
+        self.tutorial = Some(Tutorial::default());
+        self.message = "Tutorial - ESC skips it".to_string();
+    }
+
+    /// The tutorial can be replayed between pitches of a real game
+    pub fn tutorial_available(&self) -> bool {
+        self.mode == GameMode::Playing
+            && self.tutorial.is_none()
+            && self.practice.is_none()
+            && !self.config.demo_mode
+            && matches!(self.pitch_state, PitchState::ChoosePitch | PitchState::ShowResult { .. })
+    }
+
+    /// Done with the tutorial, whether it was finished or skipped - it isn't offered again
+    pub fn end_tutorial(&mut self, message: &str) {
+        self.tutorial = None;
+        self.config.tutorial_done = true;
+        self.message = message.to_string();
+    }
+
+    /// Decide the game's weather - a dome whenever the home park has a roof
+    pub fn roll_weather(&mut self, rng: &mut impl Rng) {
+        self.weather = Weather::roll(rng, self.home_ballpark().has_roof());
+    }
+
+    /// Start the game on the lineup card, so both managers can set their batting order and
+    /// starting pitcher before the first pitch
+    pub fn start_lineup_edit(&mut self, team_manager: &TeamManager, home_team: String, away_team: String) {
+        self.start_game(team_manager, home_team, away_team);
+        self.mode = GameMode::LineupEdit(LineupEditor { home: false, cursor: 0, holding: false });
+        self.message = "Set the lineups - SPACE grabs a hitter or picks the starter, S plays ball".to_string();
+    }
+
+    /// Roster shown on the lineup card
+    pub fn lineup_team(&self, home: bool) -> Option<&Team> {
+        if home { self.home_roster.as_ref() } else { self.away_roster.as_ref() }
+    }
+
+    pub fn lineup_team_mut(&mut self, home: bool) -> Option<&mut Team> {
+        if home { self.home_roster.as_mut() } else { self.away_roster.as_mut() }
+    }
+
+    /// The home team's park governs the whole game
+    pub fn home_ballpark(&self) -> Ballpark {
+        self.home_roster
+            .as_ref()
+            .map(|team| Ballpark::lookup(&team.ballpark_name))
+            .unwrap_or(Ballpark::NEUTRAL)
     }
 
     pub fn get_current_batting_team(&self) -> Option<&Team> {
         match self.half {
-            InningHalf::Top => self.away_team.as_ref().and_then(|t| self.team_manager.get_team(t)),
-            InningHalf::Bottom => self.home_team.as_ref().and_then(|t| self.team_manager.get_team(t)),
+            InningHalf::Top => self.away_roster.as_ref(),
+            InningHalf::Bottom => self.home_roster.as_ref(),
         }
     }
 
     pub fn get_current_pitching_team(&self) -> Option<&Team> {
         match self.half {
-            InningHalf::Top => self.home_team.as_ref().and_then(|t| self.team_manager.get_team(t)),
-            InningHalf::Bottom => self.away_team.as_ref().and_then(|t| self.team_manager.get_team(t)),
+            InningHalf::Top => self.home_roster.as_ref(),
+            InningHalf::Bottom => self.away_roster.as_ref(),
+        }
+    }
+
+    pub fn get_current_batting_team_mut(&mut self) -> Option<&mut Team> {
+        match self.half {
+            InningHalf::Top => self.away_roster.as_mut(),
+            InningHalf::Bottom => self.home_roster.as_mut(),
         }
     }
 
     pub fn get_current_pitching_team_mut(&mut self) -> Option<&mut Team> {
-        let team_abbr = match self.half {
-            InningHalf::Top => self.home_team.as_ref()?,
-            InningHalf::Bottom => self.away_team.as_ref()?,
-        };
-        self.team_manager.get_team_mut(team_abbr)
+        match self.half {
+            InningHalf::Top => self.home_roster.as_mut(),
+            InningHalf::Bottom => self.away_roster.as_mut(),
+        }
+    }
+
+    /// Full names for the scoreboard, falling back to the team key
+    pub fn away_name(&self) -> &str {
+        display_name(&self.away_roster, &self.away_team, "Away")
+    }
+
+    pub fn home_name(&self) -> &str {
+        display_name(&self.home_roster, &self.home_team, "Home")
     }
 
     pub fn get_current_batter(&self) -> Option<&crate::team::Player> {
@@ -256,23 +628,117 @@ impl GameState {
         self.get_current_pitching_team()?.get_current_pitcher()
     }
 
-    pub fn batting_team(&self) -> &str {
+    /// Box score key for the batter at the plate - player id when known, else team key and
+    /// slot, like "NYY_2024#3"
+    pub fn current_batter_key(&self) -> String {
+        match self.get_current_batter() {
+            Some(batter) => batter.stats.id.clone(),
+            None => format!("{}#{}", self.side_key(self.half == InningHalf::Bottom), self.current_batter_idx + 1),
+        }
+    }
+
+    /// Team key for one side, or just "Home" or "Away" without a team picked
+    pub fn side_key(&self, home: bool) -> String {
+        let (team, side) = if home { (&self.home_team, "Home") } else { (&self.away_team, "Away") };
+        team.clone().unwrap_or_else(|| side.to_string())
+    }
+
+    /// Message waiting for the pitcher - the scouting report on the first pitch of an at-bat
+    pub fn pitch_prompt(&self) -> String {
+        if let Some(session) = self.practice.as_ref().filter(|s| s.mode == PracticeMode::Bullpen) {
+            return format!("Bullpen - target {}. Pick a pitch, ESC ends the session", session.target().name());
+        }
+        match self.get_current_batter() {
+            Some(batter) if self.balls == 0 && self.strikes == 0 => format!(
+                "{}: {}",
+                batter.stats.name,
+                crate::game::scouting::scouting_report(&batter.stats)
+            ),
+            _ => "Choose your pitch!".to_string(),
+        }
+    }
+
+    pub fn current_pitcher_key(&self) -> String {
+        match self.get_current_pitcher() {
+            Some(pitcher) => pitcher.stats.id.clone(),
+            None => format!("{}#P", self.side_key(self.half == InningHalf::Top)),
+        }
+    }
+
+    /// Count a pitch that was actually thrown against the pitcher on the mound
+    pub fn record_pitch(&mut self) {
+        let key = self.current_pitcher_key();
+        self.box_score.pitcher_mut(&key).pitches += 1;
+    }
+
+    /// Take back a swing that was counted but never settled - the box score pitch, the logged
+    /// pitch number and the stamina it cost
+    fn unrecord_swing(&mut self) {
+        let key = self.current_pitcher_key();
+        let line = self.box_score.pitcher_mut(&key);
+        line.pitches = line.pitches.saturating_sub(1);
+        self.pitch_count = self.pitch_count.saturating_sub(1);
+        let practicing = self.practice.is_some();
+        if let Some(team) = self.get_current_pitching_team_mut().filter(|_| !practicing) {
+            team.pitcher_stamina = (team.pitcher_stamina + STAMINA_COST_SWING).min(STARTING_STAMINA);
+        }
+    }
+
+    pub fn batting_team(&self) -> &'static str {
         match self.half {
             InningHalf::Top => "Away",
             InningHalf::Bottom => "Home",
         }
     }
 
-    pub fn advance_batter(&mut self) {
+    /// Run of form for the side batting now
+    pub fn batting_momentum(&self) -> Momentum {
+        match self.half {
+            InningHalf::Top => self.away_momentum,
+            InningHalf::Bottom => self.home_momentum,
+        }
+    }
+
+    fn momentum_mut(&mut self, home: bool) -> &mut Momentum {
+        if home {
+            &mut self.home_momentum
+        } else {
+            &mut self.away_momentum
+        }
+    }
+
+    /// Fielding ratings of the team in the field
+    pub fn defense(&self) -> Defense {
+        self.get_current_pitching_team().map(|t| t.defense()).unwrap_or_default()
+    }
+
+    /// Contact quality nudge for the batter at the plate, when momentum effects are on
+    pub fn momentum_contact_bonus(&self) -> i32 {
+        if self.config.momentum_effects {
+            self.batting_momentum().contact_bonus()
+        } else {
+            0
+        }
+    }
+
+    /// Slot in the batting order `ahead` hitters after the one at the plate - 1 is on deck,
+    /// 2 in the hole - coming back around to the top after the last slot
+    pub fn upcoming_batter_idx(&self, ahead: usize) -> usize {
         let batting_order_size = self.get_current_batting_team()
             .map(|t| t.batting_order_size())
-            .unwrap_or(9);
-        
-        // Ensure we don't divide by zero
-        if batting_order_size > 0 {
-            self.current_batter_idx = (self.current_batter_idx + 1) % batting_order_size;
-        }
+            .unwrap_or(BATTING_ORDER_SIZE);
+        (self.current_batter_idx + ahead) % batting_order_size.max(1)
+    }
+
+    pub fn upcoming_batter(&self, ahead: usize) -> Option<&crate::team::Player> {
+        self.get_current_batting_team()?.get_batter(self.upcoming_batter_idx(ahead))
+    }
+
+    pub fn advance_batter(&mut self) {
+        self.current_batter_idx = self.upcoming_batter_idx(1);
         
+        self.momentum_mut(self.half == InningHalf::Bottom).decay();
+
         self.balls = 0;
         self.strikes = 0;
         self.pitch_state = PitchState::ChoosePitch;
@@ -281,6 +747,8 @@ impl GameState {
     }
 
     pub fn add_out(&mut self) {
+        let pitcher = self.current_pitcher_key();
+        self.box_score.pitcher_mut(&pitcher).outs_recorded += 1;
         self.outs += 1;
         if self.outs >= MAX_OUTS {
             self.end_half_inning();
@@ -301,6 +769,17 @@ impl GameState {
                         "Game Over! Final Score - Home: {} Away: {}",
                         self.home_score, self.away_score
                     );
+                    if let Some(closer) = self.credit_save() {
+                        self.message.push_str(&format!(" - Save: {}", closer));
+                    }
+                } else if self.config.max_innings > 0
+                    && self.inning >= self.config.max_innings.max(INNINGS_PER_GAME)
+                {
+                    self.game_over = true;
+                    self.message = format!(
+                        "Game Over! Final (tie) after {} innings - Home: {} Away: {}",
+                        self.inning, self.home_score, self.away_score
+                    );
                 } else {
                     self.inning += 1;
                     self.half = InningHalf::Top;
@@ -308,93 +787,453 @@ impl GameState {
             }
         }
         self.outs = 0;
-        self.bases = [false; BASES_COUNT];
+        self.inning_hits = 0;
+        self.inning_errors = 0;
+        self.error_runners.clear();
+        self.bases = Default::default();
+        self.box_score.clear_runners();
         
         // Don't reset pitcher stamina - it carries across innings
         // Coach may need to change pitcher if fatigue is too high
+        if !self.game_over {
+            if let Some(team) = self.get_current_pitching_team_mut() {
+                team.relieve_removed_pitcher();
+            }
+            self.check_save_situation();
+        }
         
         self.advance_batter();
     }
 
+    /// Late in the game with a lead of SAVE_MAX_LEAD or fewer, the bullpen hands the ball to
+    /// the designated closer
+    fn check_save_situation(&mut self) {
+        let (side, lead, pitching) = match self.half {
+            InningHalf::Top => ("Home", self.home_score as i16 - self.away_score as i16, &mut self.home_roster),
+            InningHalf::Bottom => ("Away", self.away_score as i16 - self.home_score as i16, &mut self.away_roster),
+        };
+        if self.inning < INNINGS_PER_GAME || !(1..=SAVE_MAX_LEAD).contains(&lead) {
+            return;
+        }
+        let Some(team) = pitching.as_mut() else {
+            return;
+        };
+        team.bring_in_closer();
+        let on_mound = team.get_current_pitcher().map(|p| &p.stats.id);
+        if let Some(closer) = team.closer.as_ref().filter(|&closer| Some(closer) == on_mound) {
+            self.save_chance = Some((side, closer.clone()));
+        }
+    }
+
+    /// At the final out, give the winning closer a save if they held the lead the whole way.
+    /// Returns the closer's name for the final message.
+    fn credit_save(&mut self) -> Option<String> {
+        let (side, closer) = self.save_chance.take()?;
+        let winner = match side {
+            "Home" if self.home_score > self.away_score => self.home_roster.as_ref(),
+            "Away" if self.away_score > self.home_score => self.away_roster.as_ref(),
+            _ => None,
+        }?;
+        let pitcher = winner.get_current_pitcher().filter(|p| p.stats.id == closer)?;
+        let name = pitcher.stats.name.clone();
+        self.box_score.pitcher_mut(&closer).save = true;
+        Some(name)
+    }
+
     pub fn add_walk(&mut self) {
         self.message = "Ball 4! Walk!".to_string();
-        self.advance_runners(0); // 0 = walk
+        let runs = self.advance_runners(0, |_| false); // 0 = walk
+        let line = self.box_score.batter_mut(&self.current_batter_key());
+        line.walks += 1;
+        line.rbi += runs; // Bases-loaded walk forces in a run
+        let pitcher = self.current_pitcher_key();
+        self.box_score.pitcher_mut(&pitcher).walks += 1;
         self.advance_batter();
     }
 
     pub fn add_strikeout(&mut self) {
         self.message = "Strike 3! You're out!".to_string();
+        let line = self.box_score.batter_mut(&self.current_batter_key());
+        line.at_bats += 1;
+        line.strikeouts += 1;
+        let pitcher = self.current_pitcher_key();
+        self.box_score.pitcher_mut(&pitcher).strikeouts += 1;
+        self.momentum_mut(self.half == InningHalf::Bottom).strikeout();
         self.add_out();
     }
 
-    pub fn advance_runners(&mut self, bases_to_advance: u8) {
-        let mut runners_scored = 0;
+    /// Batter reaches on a hit; runners who score are credited as RBIs. A runner on second
+    /// tries to score on a single, and the faster they are the more often they make it.
+    pub fn add_hit(&mut self, bases: u8, rng: &mut impl Rng) {
+        let send_chance = self.bases[1]
+            .as_deref()
+            .map(|runner| (SCORE_FROM_SECOND_CHANCE * runner_speed_factor(self.runner_sprint_speed(runner))).min(1.0))
+            .unwrap_or(0.0);
+        let runs = self.advance_runners(bases, |_| rng.gen_bool(send_chance as f64));
+        let batter = self.current_batter_key();
+        let line = self.box_score.batter_mut(&batter);
+        line.at_bats += 1;
+        line.hits += 1;
+        line.rbi += runs;
+        if bases == 4 {
+            line.home_runs += 1;
+        }
+        if runs > 0 {
+            self.highlights.push(Highlight { inning: self.inning, half: self.half, batter, bases, runs });
+        }
+        let pitcher = self.current_pitcher_key();
+        self.box_score.pitcher_mut(&pitcher).hits += 1;
+        self.momentum_mut(self.half == InningHalf::Bottom).hit();
+        self.advance_batter();
+    }
 
-        // Move runners backwards to avoid overwriting
-        if self.bases[2] {
-            // Runner on 3rd
-            if bases_to_advance > 0 {
-                runners_scored += 1;
-                self.bases[2] = false;
-            }
+    /// Note a ball in play for the spray chart - call before the result is applied, while
+    /// the inning and batting side are still the ones it happened in
+    pub fn record_ball_in_play(&mut self, direction: FieldDirection, ball_type: BallType, result: &PlayResult) {
+        // A foul pop nobody caught is just a foul - it was never in play
+        if *result == PlayResult::Foul {
+            return;
         }
-        if self.bases[1] {
-            // Runner on 2nd
-            if bases_to_advance >= 2 {
-                runners_scored += 1;
-                self.bases[1] = false;
-            } else if bases_to_advance == 1 {
-                self.bases[2] = true;
-                self.bases[1] = false;
-            }
+        self.spray_chart.record(BattedBall {
+            inning: self.inning,
+            batting_team: self.batting_team(),
+            direction,
+            ball_type,
+            result: result.clone(),
+        });
+    }
+
+    /// Infield fly rule: runners on first and second with fewer than two outs
+    pub fn infield_fly_in_effect(&self) -> bool {
+        self.bases[0].is_some() && self.bases[1].is_some() && self.outs + 1 < MAX_OUTS
+    }
+
+    /// A force is on at second - the defense can get the lead runner instead of the batter
+    pub fn force_at_second(&self) -> bool {
+        self.bases[0].is_some() && self.outs + 1 < MAX_OUTS
+    }
+
+    /// Fielder's choice: the runner from first is out at second and the batter is safe at
+    /// first. Runners forced along by the play move up. An at-bat, but not a hit.
+    pub fn add_fielders_choice(&mut self) {
+        let batter = self.current_batter_key();
+        self.bases[0] = None; // Retired at second
+        let mut scored = Vec::new();
+        if self.bases[1].is_some() {
+            scored.extend(self.bases[2].take());
+            self.bases[2] = self.bases[1].take();
         }
-        if self.bases[0] {
-            // Runner on 1st
-            match bases_to_advance {
-                0 => {
-                    // Walk - force advance
-                    if self.bases[1] {
-                        if self.bases[2] {
-                            runners_scored += 1;
-                        } else {
-                            self.bases[2] = true;
-                        }
-                    }
-                    self.bases[1] = true;
-                }
-                1 => {
-                    if !self.bases[1] {
-                        self.bases[1] = true;
-                        self.bases[0] = false;
-                    }
-                }
-                2 => {
-                    self.bases[2] = true;
-                    self.bases[0] = false;
-                }
-                3 | 4 => {
-                    // Triple or HR
-                    runners_scored += 1;
-                    self.bases[0] = false;
-                }
-                _ => {}
-            }
+        self.bases[0] = Some(batter.clone());
+        self.box_score.runner_reached(&batter, &self.current_pitcher_key());
+        let runs = self.score_runners(scored);
+
+        let line = self.box_score.batter_mut(&batter);
+        line.at_bats += 1;
+        line.rbi += runs;
+        self.add_out();
+    }
+
+    /// Batted-ball out. A fly ball with fewer than two outs lets the runner on third
+    /// tag up - a sacrifice fly, which is an RBI but not an at-bat.
+    pub fn add_batted_out(&mut self, fly_ball: bool) {
+        let key = self.current_batter_key();
+        if fly_ball && self.bases[2].is_some() && self.outs + 1 < MAX_OUTS {
+            let runner = self.bases[2].take().unwrap_or_default();
+            self.score_runners(vec![runner]);
+            self.box_score.batter_mut(&key).rbi += 1;
+            self.message = "Sacrifice fly! Runner tags and scores!".to_string();
+        } else {
+            self.box_score.batter_mut(&key).at_bats += 1;
         }
+        self.add_out();
+    }
 
-        // Add batter to base
-        match bases_to_advance {
-            0 => self.bases[0] = true, // Walk
-            1 => self.bases[0] = true, // Single
-            2 => self.bases[1] = true, // Double
-            3 => self.bases[2] = true, // Triple
-            4 => runners_scored += 1,  // Home run
-            _ => {}
+    /// Batter reaches first on a fielding error. It's an at-bat but not a hit, and runners move
+    /// up a base without an RBI. Neither the batter nor anyone who scores on the error scores
+    /// an earned run.
+    pub fn add_error(&mut self) {
+        self.message = "Error! Batter reaches first!".to_string();
+        self.inning_errors += 1;
+        let batter = self.current_batter_key();
+        let (bases, scored) = baserunning::advance_runners(&self.bases, &batter, BatterAdvance::Hit(1), |_| false);
+        self.bases = bases;
+        self.box_score.runner_reached(&batter, &self.current_pitcher_key());
+        self.error_runners.push(batter.clone());
+        self.error_runners.extend(scored.iter().cloned());
+        self.score_runners(scored);
+        self.box_score.batter_mut(&batter).at_bats += 1;
+        self.advance_batter();
+    }
+
+    /// Credit each runner who crossed the plate and put the runs on the board. A run is earned
+    /// unless the runner is only on because of an error, or the inning would already be over
+    /// had each error been the out it should have been.
+    fn score_runners(&mut self, runners: Vec<String>) -> u8 {
+        let pitcher = self.current_pitcher_key();
+        let inning_over = self.outs + self.inning_errors >= MAX_OUTS;
+        for runner in &runners {
+            let earned = !inning_over && !self.error_runners.contains(runner);
+            self.error_runners.retain(|r| r != runner);
+            self.box_score.batter_mut(runner).runs += 1;
+            self.box_score.charge_run(runner, &pitcher, earned);
         }
+        let runs = runners.len() as u8;
+        self.add_runs(runs);
+        runs
+    }
 
-        // Update score
-        match self.half {
-            InningHalf::Top => self.away_score += runners_scored,
-            InningHalf::Bottom => self.home_score += runners_scored,
+    /// Put one run on the board for a side in an inning, growing both line scores into
+    /// extra innings so the two always have the same number of columns
+    pub fn add_run(&mut self, home: bool, inning: u8) {
+        let columns = (inning as usize).max(self.home_runs_by_inning.len());
+        self.home_runs_by_inning.resize(columns, 0);
+        self.away_runs_by_inning.resize(columns, 0);
+        let idx = (inning as usize).saturating_sub(1);
+        self.momentum_mut(home).run();
+        if home {
+            self.home_runs_by_inning[idx] += 1;
+            self.home_score += 1;
+        } else {
+            self.away_runs_by_inning[idx] += 1;
+            self.away_score += 1;
+        }
+    }
+
+    fn add_runs(&mut self, runs: u8) {
+        let home = self.half == InningHalf::Bottom;
+        for _ in 0..runs {
+            self.add_run(home, self.inning);
+        }
+        let (batting, pitching) = if home {
+            (self.home_score, self.away_score)
+        } else {
+            (self.away_score, self.home_score)
+        };
+        let pitching_side = match self.half {
+            InningHalf::Top => "Home",
+            InningHalf::Bottom => "Away",
+        };
+        if batting >= pitching && self.save_chance.as_ref().is_some_and(|(side, _)| *side == pitching_side) {
+            self.save_chance = None; // Blown - the closer no longer has a lead to protect
+        }
+    }
+
+    /// Name for a box score key from either roster, or the key itself for a player the
+    /// rosters don't know
+    pub fn player_name(&self, key: &str) -> String {
+        [&self.away_roster, &self.home_roster]
+            .into_iter()
+            .flatten()
+            .flat_map(|team| team.batters.iter().chain(team.pitchers.iter()))
+            .find(|p| p.stats.id == key)
+            .map(|p| p.stats.name.clone())
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Sprint speed of a player on the batting team, league average if they can't be found
+    pub fn runner_sprint_speed(&self, id: &str) -> f32 {
+        self.get_current_batting_team()
+            .and_then(|team| team.batters.iter().find(|p| p.stats.id == id))
+            .map(|p| p.sprint_speed)
+            .unwrap_or(LEAGUE_AVG_SPRINT_SPEED)
+    }
+
+    /// Move the batter and runners up, returning how many runners scored.
+    /// See `baserunning::advance_runners` for `takes_extra_base`.
+    pub fn advance_runners(&mut self, bases_to_advance: u8, takes_extra_base: impl FnMut(&str) -> bool) -> u8 {
+        let batter = self.current_batter_key();
+        let advance = match bases_to_advance {
+            0 => BatterAdvance::Walk,
+            bases => BatterAdvance::Hit(bases),
+        };
+        let (bases, scored) = baserunning::advance_runners(&self.bases, &batter, advance, takes_extra_base);
+        self.bases = bases;
+
+        if bases_to_advance as usize <= BASES_COUNT {
+            self.box_score.runner_reached(&batter, &self.current_pitcher_key());
+        }
+        self.score_runners(scored)
+    }
+
+    /// Which bases have a runner, for display
+    pub fn occupied_bases(&self) -> [bool; BASES_COUNT] {
+        [self.bases[0].is_some(), self.bases[1].is_some(), self.bases[2].is_some()]
+    }
+
+    /// Balk - every runner moves up a base and the batter stays in with the same count.
+    /// Returns how many runners scored.
+    pub fn balk(&mut self) -> u8 {
+        let scored: Vec<String> = self.bases[BASES_COUNT - 1].take().into_iter().collect();
+        for base in (1..BASES_COUNT).rev() {
+            self.bases[base] = self.bases[base - 1].take();
+        }
+        self.score_runners(scored)
+    }
+
+    /// Open the bench list to pinch run for the lead runner, or to pinch hit with the bases empty
+    pub fn open_pinch_run_menu(&mut self) -> Result<(), String> {
+        let base = self.bases.iter().rposition(Option::is_some).unwrap_or(AT_THE_PLATE);
+        let team = self.get_current_batting_team().ok_or("No batting team")?;
+        if team.bench().next().is_none() {
+            return Err("No one left on the bench".to_string());
+        }
+        self.pinch_run_menu = Some(PinchRunMenu { base, cursor: 0 });
+        self.message = "Pinch run or hit - UP/DOWN picks a player, LEFT/RIGHT the base or the plate, SPACE sends them in, ESC cancels".to_string();
+        Ok(())
+    }
+
+    /// Send bench player `hitter_in` up for the batter at the plate, keeping the count. The
+    /// batter is out of the game - hitting for the pitcher means a new one next half inning.
+    pub fn pinch_hit(&mut self, hitter_in: &str) -> Result<String, String> {
+        let batter_id = self.current_batter_key();
+        let team = self.get_current_batting_team_mut().ok_or("No batting team")?;
+        let (replaced, for_pitcher) = team
+            .batters
+            .iter()
+            .find(|p| p.stats.id == batter_id)
+            .map(|p| (p.stats.name.clone(), p.is_pitcher))
+            .ok_or("That batter can't be replaced")?;
+        let hitter = team
+            .bench()
+            .find(|p| p.stats.id == hitter_in)
+            .ok_or_else(|| format!("{} isn't available to pinch hit", hitter_in))?
+            .stats
+            .name
+            .clone();
+        let for_pitcher = for_pitcher || team.pitcher_pinch_hitter.as_deref() == Some(batter_id.as_str());
+        team.substitute(&batter_id, hitter_in);
+        let mut message = format!("Pinch hitter {} bats for {}", hitter, replaced);
+        if for_pitcher {
+            team.pitcher_pinch_hitter = Some(hitter_in.to_string());
+            message.push_str(" - a new pitcher comes in next inning");
+        }
+        Ok(message)
+    }
+
+    /// Send bench player `runner_in` in for the runner on `base`. The replaced player is out
+    /// of the game and the pinch runner takes over their spot in the order.
+    pub fn pinch_run(&mut self, base: usize, runner_in: &str) -> Result<String, String> {
+        let runner_id = self
+            .bases
+            .get(base)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| format!("No runner on {}", base_name(base)))?;
+        if runner_id == self.current_batter_key() {
+            return Err("Can't pinch run for the batter at the plate".to_string());
+        }
+        let team = self.get_current_batting_team_mut().ok_or("No batting team")?;
+        let replaced = team
+            .batters
+            .iter()
+            .find(|p| p.stats.id == runner_id)
+            .map(|p| p.stats.name.clone())
+            .ok_or("That runner can't be replaced")?;
+        let runner = team
+            .bench()
+            .find(|p| p.stats.id == runner_in)
+            .ok_or_else(|| format!("{} isn't available to pinch run", runner_in))?
+            .stats
+            .clone();
+        team.substitute(&runner_id, &runner.id);
+        self.box_score.runner_replaced(&runner_id, &runner.id);
+
+        self.bases[base] = Some(runner.id);
+        Ok(format!("Pinch runner {} replaces {} at {}", runner.name, replaced, base_name(base)))
+    }
+
+    /// Open the fielding team's bench to bring in a defensive replacement
+    pub fn open_defensive_sub_menu(&mut self) -> Result<(), String> {
+        let team = self.get_current_pitching_team().ok_or("No team in the field")?;
+        if team.bench().next().is_none() {
+            return Err("No one left on the bench".to_string());
+        }
+        self.defensive_sub_menu = Some(DefensiveSubMenu { position: Position::FIELDERS[0], cursor: 0 });
+        self.message = "Defensive sub - UP/DOWN picks a player, LEFT/RIGHT the position, SPACE sends them in, ESC cancels".to_string();
+        Ok(())
+    }
+
+    /// Send bench player `player_in` out to `position` for the team in the field. The
+    /// replaced fielder is out of the game and the sub takes their spot in the order.
+    pub fn defensive_sub(&mut self, position: Position, player_in: &str) -> Result<String, String> {
+        if position == Position::Pitcher {
+            return Err("Pitching changes come from the bullpen".to_string());
+        }
+        let team = self.get_current_pitching_team_mut().ok_or("No team in the field")?;
+        let replaced = team
+            .fielder(position)
+            .map(|p| p.stats.clone())
+            .ok_or_else(|| format!("Nobody is playing {}", position.name()))?;
+        let sub = team
+            .bench()
+            .find(|p| p.stats.id == player_in)
+            .map(|p| p.stats.name.clone())
+            .ok_or_else(|| format!("{} isn't available to come in", player_in))?;
+        team.substitute(&replaced.id, player_in);
+        if let Some(player) = team.batters.iter_mut().find(|p| p.stats.id == player_in) {
+            player.position = position;
         }
+        Ok(format!("Defensive sub: {} replaces {} at {}", sub, replaced.name, position.name()))
+    }
+
+    /// Open the fielding team's bullpen for a pitching change
+    pub fn open_bullpen_menu(&mut self) -> Result<(), String> {
+        let team = self.get_current_pitching_team().ok_or("No team in the field")?;
+        if team.bullpen().next().is_none() {
+            return Err("No one left in the bullpen".to_string());
+        }
+        self.bullpen_menu = Some(BullpenMenu { reliever: None, slot: None, cursor: 0 });
+        self.message = "Bullpen - UP/DOWN picks a reliever, SPACE brings them in, ESC cancels".to_string();
+        Ok(())
+    }
+
+    /// Bring in `reliever` for the team in the field, optionally as a double switch that sends
+    /// bench player `sub` in for the fielder batting in lineup slot `slot` (0-based)
+    pub fn pitching_change(&mut self, reliever: &str, double_switch: Option<(usize, &str)>) -> Result<String, String> {
+        let team = self.get_current_pitching_team_mut().ok_or("No team in the field")?;
+        let outgoing = team.get_current_pitcher().map(|p| p.stats.name.clone()).unwrap_or_default();
+        let replaced = double_switch
+            .and_then(|(slot, _)| team.batters.get(slot))
+            .map(|p| (p.stats.name.clone(), p.position));
+        team.bring_in_reliever(reliever, double_switch)?;
+
+        let name = |id: &str| {
+            team.pitchers
+                .iter()
+                .chain(&team.batters)
+                .find(|p| p.stats.id == id)
+                .map(|p| p.stats.name.clone())
+                .unwrap_or_default()
+        };
+        let mut message = format!("Pitching change: {} replaces {}", name(reliever), outgoing);
+        if let (Some((slot, sub)), Some((fielder, position))) = (double_switch, replaced) {
+            let sub_slot = team.batters.iter().position(|p| p.stats.id == sub).unwrap_or_default();
+            message.push_str(&format!(
+                " and bats {} - double switch: {} replaces {} at {} and bats {}",
+                slot + 1,
+                name(sub),
+                fielder,
+                position.name(),
+                sub_slot + 1
+            ));
+        }
+        Ok(message)
+    }
+}
+
+fn display_name<'a>(roster: &'a Option<Team>, key: &'a Option<String>, side: &'a str) -> &'a str {
+    match (roster, key) {
+        (Some(team), _) => &team.name,
+        (None, Some(key)) => key,
+        (None, None) => side,
+    }
+}
+
+/// "first", "second" or "third"
+pub fn base_name(base: usize) -> &'static str {
+    match base {
+        0 => "first",
+        1 => "second",
+        _ => "third",
     }
 }
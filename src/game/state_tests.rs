@@ -1,6 +1,25 @@
 #[cfg(test)]
 mod tests {
-    use crate::game::{constants::*, GameState, InningHalf};
+    use crate::game::update::process_play_result;
+    use crate::game::input_handler::handle_input;
+    use crate::game::{GameMode, TeamInputMode};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::team::{Position, TeamManager};
+    use crate::game::engine::SpraySide;
+    use crate::game::state::{BallInPlay, BallType, FieldDirection};
+    use crate::game::{constants::*, GameEngine, GameState, HitType, InningHalf, OutType, PitchLocation, PitchState, PlayResult, SwingTiming};
+
+    /// Placeholder runner ids "R1".."R3" on the occupied bases
+    fn runners(occupied: [bool; 3]) -> [Option<String>; 3] {
+        let mut bases: [Option<String>; 3] = Default::default();
+        for (base, on) in occupied.iter().enumerate() {
+            if *on {
+                bases[base] = Some(format!("R{}", base + 1));
+            }
+        }
+        bases
+    }
 
     #[test]
     fn test_new_game_state() {
@@ -12,10 +31,10 @@ mod tests {
         assert_eq!(state.strikes, 0);
         assert_eq!(state.home_score, 0);
         assert_eq!(state.away_score, 0);
-        assert_eq!(state.bases, [false, false, false]);
+        assert_eq!(state.occupied_bases(), [false, false, false]);
         assert_eq!(state.current_batter_idx, 0);
-        assert_eq!(state.game_over, false);
-        assert_eq!(state.quit_requested, false);
+        assert!(!state.game_over);
+        assert!(!state.quit_requested);
     }
 
     #[test]
@@ -56,13 +75,13 @@ mod tests {
         state.half = InningHalf::Top; // Away team batting
         
         // Single with nobody on
-        state.advance_runners(1);
-        assert_eq!(state.bases, [true, false, false]);
+        state.advance_runners(1, |_| false);
+        assert_eq!(state.occupied_bases(), [true, false, false]);
         assert_eq!(state.away_score, 0);
         
         // Another single - runner advances to 2nd
-        state.advance_runners(1);
-        assert_eq!(state.bases, [true, true, false]);
+        state.advance_runners(1, |_| false);
+        assert_eq!(state.occupied_bases(), [true, true, false]);
         assert_eq!(state.away_score, 0);
     }
 
@@ -74,11 +93,11 @@ mod tests {
         state.half = InningHalf::Top; // Away team batting
         
         // Load the bases
-        state.bases = [true, true, true];
+        state.bases = runners([true, true, true]);
         
         // Home run clears bases and scores 4
-        state.advance_runners(4);
-        assert_eq!(state.bases, [false, false, false]);
+        state.advance_runners(4, |_| false);
+        assert_eq!(state.occupied_bases(), [false, false, false]);
         assert_eq!(state.away_score, 4); // 3 runners + batter
     }
 
@@ -90,8 +109,8 @@ mod tests {
         state.half = InningHalf::Bottom; // Home team batting
         
         // Walk with nobody on
-        state.advance_runners(0);
-        assert_eq!(state.bases, [true, false, false]);
+        state.advance_runners(0, |_| false);
+        assert_eq!(state.occupied_bases(), [true, false, false]);
         assert_eq!(state.home_score, 0);
     }
 
@@ -100,13 +119,13 @@ mod tests {
         let mut state = GameState::new();
         state.home_team = Some("NYY".to_string());
         state.away_team = Some("BOS".to_string());
-        state.bases = [true, true, true];
+        state.bases = runners([true, true, true]);
         state.outs = 2;
         
         state.add_out(); // Third out
         
         // Bases should be cleared
-        assert_eq!(state.bases, [false, false, false]);
+        assert_eq!(state.occupied_bases(), [false, false, false]);
         assert_eq!(state.outs, 0);
     }
 
@@ -121,10 +140,10 @@ mod tests {
         state.home_score = 3;
         state.outs = 2;
         
-        assert_eq!(state.game_over, false);
+        assert!(!state.game_over);
         state.add_out(); // End bottom of 9th
         
-        assert_eq!(state.game_over, true);
+        assert!(state.game_over);
     }
 
     #[test]
@@ -141,11 +160,26 @@ mod tests {
         state.add_out(); // End bottom of 9th
         
         // Game should continue to extra innings
-        assert_eq!(state.game_over, false);
+        assert!(!state.game_over);
         assert_eq!(state.inning, INNINGS_PER_GAME + 1);
         assert_eq!(state.half, InningHalf::Top);
     }
 
+    #[test]
+    fn test_scoring_in_the_eleventh_extends_both_line_scores() {
+        let mut state = GameState::new();
+        state.add_run(false, 3);
+        assert_eq!(state.away_runs_by_inning.len(), INNINGS_PER_GAME as usize);
+
+        state.add_run(true, 11);
+        assert_eq!(state.home_runs_by_inning.len(), 11);
+        assert_eq!(state.away_runs_by_inning.len(), 11);
+        assert_eq!(state.home_runs_by_inning[10], 1);
+        assert_eq!(state.away_runs_by_inning[2], 1);
+        assert_eq!(state.away_runs_by_inning[10], 0);
+        assert_eq!((state.away_score, state.home_score), (1, 1));
+    }
+
     #[test]
     fn test_balls_and_strikes() {
         let mut state = GameState::new();
@@ -166,4 +200,1044 @@ mod tests {
         state.balls = 3;
         assert_eq!(state.balls, 3);
     }
+
+    #[test]
+    fn test_grand_slam_credits_four_rbis() {
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.bases = runners([true, true, true]);
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Hit(HitType::HomeRun), None);
+
+        let line = state.box_score.batter(&batter).unwrap();
+        assert_eq!(line.rbi, 4);
+        assert_eq!(line.hits, 1);
+        assert_eq!(line.at_bats, 1);
+        assert_eq!(state.away_score, 4);
+    }
+
+    #[test]
+    fn test_bases_clearing_double_credits_every_run() {
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.bases = runners([false, true, true]);
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Hit(HitType::Double), None);
+
+        assert_eq!(state.box_score.batter(&batter).unwrap().rbi, 2);
+        assert_eq!(state.occupied_bases(), [false, true, false]);
+    }
+
+    #[test]
+    fn test_sac_fly_credits_one_rbi_and_no_at_bat() {
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.bases = runners([false, false, true]);
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Out(OutType::Flyout), None);
+
+        let line = state.box_score.batter(&batter).unwrap();
+        assert_eq!(line.rbi, 1);
+        assert_eq!(line.at_bats, 0);
+        assert_eq!(state.away_score, 1);
+        assert_eq!(state.outs, 1);
+    }
+
+    #[test]
+    fn test_no_sac_fly_with_two_outs() {
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.outs = 2;
+        state.bases = runners([false, false, true]);
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Out(OutType::Flyout), None);
+
+        let line = state.box_score.batter(&batter).unwrap();
+        assert_eq!(line.rbi, 0);
+        assert_eq!(line.at_bats, 1);
+        assert_eq!(state.away_score, 0);
+    }
+
+    #[test]
+    fn test_bases_loaded_walk_credits_rbi() {
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.bases = runners([true, true, true]);
+        state.add_walk();
+
+        let line = state.box_score.batter(&batter).unwrap();
+        assert_eq!(line.rbi, 1);
+        assert_eq!(line.walks, 1);
+        assert_eq!(line.at_bats, 0);
+    }
+
+    fn pop_fly() -> BallInPlay {
+        BallInPlay {
+            ball_type: BallType::PopFly,
+            direction: FieldDirection::CenterField,
+            speed: 40.0,
+            hang_time: 40,
+            initial_contact_quality: 50,
+            hr_chance: HR_CHANCE_LEAGUE_AVG,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        }
+    }
+
+    #[test]
+    fn test_infield_fly_is_an_out_even_when_dropped() {
+        let engine = GameEngine::with_seed(7);
+        let mut state = GameState::new();
+        let batter = state.current_batter_key();
+        state.outs = 1;
+        state.bases = runners([true, true, false]);
+        assert!(state.infield_fly_in_effect());
+
+        // Hopeless timing and the fielder never reaching it both still yield the out
+        for _ in 0..50 {
+            let (result, _) = engine.calculate_fielding_result(&pop_fly(), 200, 20, true, &[0; 9]);
+            assert_eq!(result, PlayResult::Out(OutType::InfieldFly));
+        }
+        let result = engine.uncaught_result(&pop_fly(), state.infield_fly_in_effect());
+        assert_eq!(result, PlayResult::Out(OutType::InfieldFly));
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &result, None);
+        assert_eq!(state.outs, 2);
+        assert_eq!(state.occupied_bases(), [true, true, false]);
+        assert_eq!(state.away_score, 0);
+        assert_eq!(state.box_score.batter(&batter).unwrap().at_bats, 1);
+    }
+
+    #[test]
+    fn test_infield_fly_needs_two_lead_runners_and_less_than_two_outs() {
+        let mut state = GameState::new();
+        state.bases = runners([true, false, true]);
+        assert!(!state.infield_fly_in_effect());
+        state.bases = runners([true, true, true]);
+        assert!(state.infield_fly_in_effect());
+        state.outs = 2;
+        assert!(!state.infield_fly_in_effect());
+
+        let engine = GameEngine::with_seed(7);
+        for _ in 0..50 {
+            let (result, _) = engine.calculate_fielding_result(&pop_fly(), 200, 20, false, &[0; 9]);
+            assert_ne!(result, PlayResult::Out(OutType::InfieldFly));
+        }
+    }
+
+    fn search_state(state: &GameState) -> Option<crate::game::TeamSearch> {
+        match &state.mode {
+            GameMode::TeamSelection { search, .. } => search.clone(),
+            GameMode::LineupEdit(_) | GameMode::Playing => None,
+        }
+    }
+
+    #[test]
+    fn test_team_search_picks_a_team_for_the_open_side() {
+        let mut state = GameState::new();
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.load_league(format!("{}/fixtures/league.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert_eq!(teams.search_teams("s"), vec![0, 1]);
+        let engine = GameEngine::with_seed(1);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        let mut press = |state: &mut GameState, input: GameInput| {
+            handle_input(state, &mut teams, &engine, &mut input_state, input, None, &logger);
+        };
+
+        // Esc closes without choosing anything
+        press(&mut state, GameInput::StartSearch);
+        press(&mut state, GameInput::TextInput('x'));
+        press(&mut state, GameInput::Pause);
+        assert_eq!(search_state(&state), None);
+
+        // Backspace edits the query; Down moves through the matches
+        press(&mut state, GameInput::StartSearch);
+        assert_eq!(search_state(&state).unwrap().side, TeamInputMode::SelectingAway);
+        press(&mut state, GameInput::TextInput('q'));
+        press(&mut state, GameInput::Backspace);
+        press(&mut state, GameInput::TextInput('s'));
+        press(&mut state, GameInput::Down);
+        press(&mut state, GameInput::Down); // Stops at the last match
+        assert_eq!(search_state(&state).unwrap().cursor, 1);
+        press(&mut state, GameInput::Action);
+
+        // Away is taken, so the next search is for home
+        press(&mut state, GameInput::StartSearch);
+        assert_eq!(search_state(&state).unwrap().side, TeamInputMode::SelectingHome);
+        for c in "drag".chars() {
+            press(&mut state, GameInput::TextInput(c));
+        }
+        press(&mut state, GameInput::Action);
+
+        match &state.mode {
+            GameMode::TeamSelection { selected_away, selected_home, search, .. } => {
+                assert_eq!(selected_away.as_deref(), Some("THW_2026"));
+                assert_eq!(selected_home.as_deref(), Some("SDG_2026"));
+                assert!(search.is_none());
+            }
+            GameMode::LineupEdit(_) | GameMode::Playing => panic!("game should not start until confirmed"),
+        }
+    }
+
+    /// Team selection over a data directory with a good roster (GOOD) and a listed team whose
+    /// CSVs have no players (BAD), so loading it fails
+    fn selection_with_bad_team(name: &str, allow_same_team: bool) -> (GameState, TeamManager, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(format!("bitbatter_{}_{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        crate::roster::CustomRoster::template("GOOD", "Good Team")
+            .save(dir.join(format!("team_GOOD_{}.json", DEFAULT_SEASON)))
+            .unwrap();
+        std::fs::write(dir.join(format!("batter_BAD_{}.csv", DEFAULT_SEASON)), "").unwrap();
+        std::fs::write(dir.join(format!("pitcher_BAD_{}.csv", DEFAULT_SEASON)), "").unwrap();
+        let config = crate::config::GameConfig {
+            data_dir: dir.to_string_lossy().into_owned(),
+            allow_same_team,
+            ..Default::default()
+        };
+        let (teams, warning) = TeamManager::from_config(&config);
+        assert!(warning.is_none());
+        (GameState::with_config(config), teams, dir)
+    }
+
+    /// Pick team number `num` for one side the way a player would: A/H, digits, Enter
+    fn pick_team(state: &mut GameState, teams: &mut TeamManager, side: GameInput, num: char) {
+        let engine = GameEngine::with_seed(1);
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        for input in [side, GameInput::NumberInput(num), GameInput::Action] {
+            handle_input(state, teams, &engine, &mut InputState::new(), input, None, &logger);
+        }
+    }
+
+    fn selections(state: &GameState) -> (Option<String>, Option<String>, TeamInputMode) {
+        match &state.mode {
+            GameMode::TeamSelection { selected_away, selected_home, input_mode, .. } => {
+                (selected_away.clone(), selected_home.clone(), input_mode.clone())
+            }
+            GameMode::LineupEdit(_) | GameMode::Playing => panic!("still picking teams"),
+        }
+    }
+
+    #[test]
+    fn test_failed_team_load_keeps_the_previous_selection() {
+        let (mut state, mut teams, dir) = selection_with_bad_team("failed_load", false);
+        let good = format!("GOOD_{}", DEFAULT_SEASON);
+        assert_eq!(teams.get_team_list(), vec![format!("BAD_{}", DEFAULT_SEASON), good.clone()]);
+
+        pick_team(&mut state, &mut teams, GameInput::SelectAwayTeam, '2');
+        pick_team(&mut state, &mut teams, GameInput::SelectAwayTeam, '1');
+
+        assert_eq!(selections(&state), (Some(good.clone()), None, TeamInputMode::None));
+        assert!(state.message.starts_with("Error loading team BAD_"));
+        assert!(state.message.ends_with(&format!("keeping {}", good)));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_same_team_cannot_be_home_and_away_unless_allowed() {
+        let (mut state, mut teams, dir) = selection_with_bad_team("same_team", false);
+        let good = format!("GOOD_{}", DEFAULT_SEASON);
+        pick_team(&mut state, &mut teams, GameInput::SelectAwayTeam, '2');
+        pick_team(&mut state, &mut teams, GameInput::SelectHomeTeam, '2');
+        assert_eq!(selections(&state), (Some(good.clone()), None, TeamInputMode::None));
+        assert!(state.message.contains("already the away team"));
+
+        state.config.allow_same_team = true;
+        pick_team(&mut state, &mut teams, GameInput::SelectHomeTeam, '2');
+        assert_eq!(selections(&state), (Some(good.clone()), Some(good), TeamInputMode::None));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_team_number_entry_can_be_corrected_and_cancelled() {
+        let (mut state, mut teams, dir) = selection_with_bad_team("number_entry", false);
+        let engine = GameEngine::with_seed(1);
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        let mut press = |state: &mut GameState, input| handle_input(state, &mut teams, &engine, &mut InputState::new(), input, None, &logger);
+        let buffer = |state: &GameState| match &state.mode {
+            GameMode::TeamSelection { input_buffer, .. } => input_buffer.clone(),
+            GameMode::LineupEdit(_) | GameMode::Playing => panic!("still picking teams"),
+        };
+
+        // Two teams means one digit; a second is refused out loud
+        press(&mut state, GameInput::SelectAwayTeam);
+        press(&mut state, GameInput::NumberInput('1'));
+        press(&mut state, GameInput::NumberInput('2'));
+        assert_eq!(buffer(&state), "1");
+        assert!(state.message.starts_with("Team numbers only go to 2"), "{}", state.message);
+
+        // Backspace fixes the typo
+        press(&mut state, GameInput::Backspace);
+        assert_eq!(buffer(&state), "");
+        assert!(state.message.starts_with("Enter away team number"), "{}", state.message);
+        press(&mut state, GameInput::NumberInput('2'));
+        press(&mut state, GameInput::Action);
+        assert_eq!(selections(&state), (Some(format!("GOOD_{}", DEFAULT_SEASON)), None, TeamInputMode::None));
+
+        // Esc abandons a pick halfway through
+        press(&mut state, GameInput::SelectHomeTeam);
+        press(&mut state, GameInput::NumberInput('1'));
+        press(&mut state, GameInput::Pause);
+        assert_eq!(buffer(&state), "");
+        assert_eq!(selections(&state), (Some(format!("GOOD_{}", DEFAULT_SEASON)), None, TeamInputMode::None));
+        assert!(state.message.contains("cancelled"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn state_with_bench() -> GameState {
+        let mut team = crate::roster::CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON);
+        for n in 1..=2 {
+            let mut bench = team.batters[0].clone();
+            bench.stats.id = format!("BENCH{}", n);
+            bench.stats.name = format!("Bench, Player {}", n);
+            team.batters.push(bench);
+        }
+        let mut state = GameState::new();
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TST".to_string(), team);
+        state.start_game(&teams, "TST".to_string(), "TST".to_string());
+        state
+    }
+
+    #[test]
+    fn test_scouting_report_shows_on_the_first_pitch_only() {
+        let mut state = state_with_bench();
+        assert!(state.message.starts_with("Batter, Number 1: Average hitter"), "{}", state.message);
+        state.balls = 1;
+        assert_eq!(state.pitch_prompt(), "Choose your pitch!");
+        state.advance_batter();
+        assert!(state.pitch_prompt().starts_with("Batter, Number 2: "), "{}", state.pitch_prompt());
+    }
+
+    #[test]
+    fn test_pinch_runner_takes_over_the_base() {
+        let mut state = state_with_bench();
+        state.add_hit(2, &mut *GameEngine::with_seed(1590).rng()); // Leadoff double
+        let starter = state.bases[1].clone().unwrap();
+        assert_eq!(starter, "TST-B1");
+
+        let message = state.pinch_run(1, "BENCH1").unwrap();
+        assert!(message.contains("Bench, Player 1"), "unexpected message: {}", message);
+        assert_eq!(state.bases[1].as_deref(), Some("BENCH1"));
+
+        // The pinch runner has the starter's spot in the order; the starter is done
+        let team = state.get_current_batting_team().unwrap();
+        assert_eq!(team.batters[0].stats.id, "BENCH1");
+        assert!(team.removed_players.contains(&starter));
+        assert_eq!(team.bench().map(|p| p.stats.id.as_str()).collect::<Vec<_>>(), vec!["BENCH2"]);
+    }
+
+    #[test]
+    fn test_defensive_sub_takes_the_fielders_spot_and_position() {
+        let mut state = state_with_bench();
+        let message = state.defensive_sub(Position::Shortstop, "BENCH2").unwrap();
+        assert!(message.contains("Bench, Player 2") && message.contains("SS"), "unexpected message: {}", message);
+
+        // The home team is in the field in the top half; the batting side is untouched
+        let team = state.get_current_pitching_team().unwrap();
+        assert_eq!(team.batters[4].stats.id, "BENCH2");
+        assert_eq!(team.batters[4].position, Position::Shortstop);
+        assert_eq!(team.fielder(Position::Shortstop).unwrap().stats.id, "BENCH2");
+        assert!(team.removed_players.contains("TST-B5"));
+        assert_eq!(state.get_current_batting_team().unwrap().bench().count(), 2);
+
+        assert_eq!(state.defensive_sub(Position::Shortstop, "BENCH2").unwrap_err(), "BENCH2 isn't available to come in");
+        assert!(state.defensive_sub(Position::Pitcher, "BENCH1").is_err());
+        state.defensive_sub(Position::Catcher, "BENCH1").unwrap();
+        assert_eq!(state.open_defensive_sub_menu().unwrap_err(), "No one left on the bench");
+    }
+
+    #[test]
+    fn test_pinch_running_needs_a_runner_and_a_bench() {
+        let mut state = state_with_bench();
+        assert!(state.pinch_run(0, "BENCH1").is_err());
+        // With the bases empty the menu opens at the plate, for a pinch hitter
+        state.open_pinch_run_menu().unwrap();
+        assert_eq!(state.pinch_run_menu.take().map(|m| m.base), Some(AT_THE_PLATE));
+
+        state.add_hit(1, &mut *GameEngine::with_seed(1590).rng());
+        state.pinch_run(0, "BENCH2").unwrap();
+        assert_eq!(state.pinch_run(0, "BENCH2").unwrap_err(), "BENCH2 isn't available to pinch run");
+        assert!(state.pinch_run(0, "TST-B1").is_err()); // Already out of the game
+        state.pinch_run(0, "BENCH1").unwrap(); // Pinch runner for the pinch runner
+        assert_eq!(state.bases[0].as_deref(), Some("BENCH1"));
+        assert_eq!(state.open_pinch_run_menu().unwrap_err(), "No one left on the bench");
+    }
+
+    #[test]
+    fn test_pinch_run_menu_picks_the_runner_and_the_base() {
+        let mut state = state_with_bench();
+        state.add_hit(2, &mut *GameEngine::with_seed(1593).rng()); // B1 on second
+        state.add_walk(); // B2 on first
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        let engine = GameEngine::with_seed(1593);
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        let mut press = |state: &mut GameState, input: GameInput| {
+            handle_input(state, &mut teams, &engine, &mut InputState::new(), input, None, &logger);
+        };
+
+        press(&mut state, GameInput::PinchRun);
+        assert_eq!(state.pinch_run_menu.as_ref().map(|m| m.base), Some(1)); // Lead runner first
+
+        // Pitches wait while the menu is open
+        press(&mut state, GameInput::SelectPitch(0));
+        assert_eq!(state.pitch_state, crate::game::PitchState::ChoosePitch);
+
+        press(&mut state, GameInput::Left);
+        press(&mut state, GameInput::Down);
+        press(&mut state, GameInput::Action);
+        assert!(state.pinch_run_menu.is_none());
+        assert_eq!(state.bases[0].as_deref(), Some("BENCH2"));
+        assert_eq!(state.bases[1].as_deref(), Some("TST-B1"));
+
+        press(&mut state, GameInput::PinchRun);
+        press(&mut state, GameInput::Pause);
+        assert!(state.pinch_run_menu.is_none());
+        assert_eq!(state.bases[1].as_deref(), Some("TST-B1"));
+    }
+
+    fn state_without_dh() -> GameState {
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        let mut team = crate::roster::CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON);
+        let mut bench = team.batters[0].clone();
+        bench.stats.id = "BENCH1".to_string();
+        bench.stats.name = "Bench, Player 1".to_string();
+        team.batters.push(bench);
+        teams.teams.insert("TST".to_string(), team);
+        let mut state = GameState::new();
+        state.config.pitchers_bat = true;
+        state.start_game(&teams, "TST".to_string(), "TST".to_string());
+        state
+    }
+
+    #[test]
+    fn test_without_dh_the_pitcher_bats_ninth() {
+        let state = state_without_dh();
+        let team = state.get_current_batting_team().unwrap();
+        assert_eq!(team.batting_order_size(), BATTING_ORDER_SIZE);
+        let ninth = &team.batters[BATTING_ORDER_SIZE - 1];
+        assert!(ninth.is_pitcher);
+        assert_eq!(ninth.stats.id, team.get_current_pitcher().unwrap().stats.id);
+        assert!(ninth.stats.barrel_percent < team.batters[0].stats.barrel_percent);
+        // The ninth hitter from the DH lineup heads to the bench
+        assert_eq!(team.batters[BATTING_ORDER_SIZE].stats.id, "TST-B9");
+    }
+
+    #[test]
+    fn test_pinch_hitting_for_the_pitcher_brings_in_a_reliever() {
+        let mut state = state_without_dh();
+        state.current_batter_idx = BATTING_ORDER_SIZE - 1;
+        let starter = state.current_batter_key();
+        state.open_pinch_run_menu().unwrap();
+        assert_eq!(state.pinch_run_menu.take().map(|m| m.base), Some(AT_THE_PLATE));
+
+        let message = state.pinch_hit("TST-B9").unwrap();
+        assert!(message.ends_with("a new pitcher comes in next inning"), "{}", message);
+        assert_eq!(state.current_batter_key(), "TST-B9");
+        // Hitting for the pinch hitter keeps the pitcher's slot tracked
+        let message = state.pinch_hit("BENCH1").unwrap();
+        assert!(message.starts_with("Pinch hitter Bench, Player 1 bats for Batter, Number 9"), "{}", message);
+
+        state.end_half_inning();
+        let team = state.get_current_pitching_team().unwrap();
+        let reliever = team.get_current_pitcher().unwrap().stats.id.clone();
+        assert_ne!(reliever, starter);
+        assert!(team.removed_players.contains(&starter));
+        assert!(team.removed_players.contains("BENCH1"));
+        let ninth = &team.batters[BATTING_ORDER_SIZE - 1];
+        assert!(ninth.is_pitcher);
+        assert_eq!(ninth.stats.id, reliever);
+    }
+
+    #[test]
+    fn test_double_switch_puts_the_reliever_in_the_fielders_slot() {
+        let mut state = state_without_dh();
+        // The home team is in the field in the top half
+        let message = state.pitching_change("TST-P2", Some((3, "BENCH1"))).unwrap();
+        assert_eq!(
+            message,
+            "Pitching change: Pitcher, Number 2 replaces Pitcher, Number 1 and bats 4 - double switch: Bench, Player 1 replaces Batter, Number 4 at 3B and bats 9"
+        );
+
+        let team = state.get_current_pitching_team().unwrap();
+        assert_eq!(team.get_current_pitcher().unwrap().stats.id, "TST-P2");
+        assert_eq!(team.batters[3].stats.id, "TST-P2");
+        assert!(team.batters[3].is_pitcher);
+        assert_eq!(team.batters[BATTING_ORDER_SIZE - 1].stats.id, "BENCH1");
+        assert_eq!(team.batters[BATTING_ORDER_SIZE - 1].position, Position::ThirdBase);
+        assert_eq!(team.fielder(Position::ThirdBase).unwrap().stats.id, "BENCH1");
+        assert_eq!(team.batting_order_size(), BATTING_ORDER_SIZE);
+        assert!(team.removed_players.contains("TST-P1") && team.removed_players.contains("TST-B4"));
+        assert_eq!(team.batters.iter().take(BATTING_ORDER_SIZE).filter(|p| p.is_pitcher).count(), 1);
+    }
+
+    #[test]
+    fn test_pitching_changes_reject_reentry_and_bad_double_switches() {
+        let mut state = state_without_dh();
+        assert_eq!(state.pitching_change("TST-P1", None).unwrap_err(), "TST-P1 isn't available to pitch");
+        assert_eq!(state.pitching_change("TST-P2", Some((BATTING_ORDER_SIZE - 1, "BENCH1"))).unwrap_err(), "The new pitcher can't bat 9");
+        assert_eq!(state.pitching_change("TST-P2", Some((3, "TST-B1"))).unwrap_err(), "TST-B1 isn't available to come in");
+        // A failed double switch leaves everything as it was
+        assert_eq!(state.get_current_pitcher().unwrap().stats.id, "TST-P1");
+
+        state.pitching_change("TST-P2", None).unwrap();
+        let team = state.get_current_pitching_team().unwrap();
+        assert_eq!(team.batters[BATTING_ORDER_SIZE - 1].stats.id, "TST-P2");
+        // The starter is out of the game for good
+        assert_eq!(state.pitching_change("TST-P1", None).unwrap_err(), "TST-P1 isn't available to pitch");
+
+        // With a DH there's no double switch to make
+        let mut state = state_with_bench();
+        assert!(state.get_current_pitching_team().unwrap().double_switch_slots().is_empty());
+        assert!(state.pitching_change("TST-P2", Some((3, "BENCH1"))).is_err());
+        state.pitching_change("TST-P2", None).unwrap();
+    }
+
+    #[test]
+    fn test_bullpen_menu_offers_a_double_switch_when_pitchers_bat() {
+        let mut state = state_without_dh();
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        let engine = GameEngine::with_seed(1615);
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        let mut press = |state: &mut GameState, input: GameInput| {
+            handle_input(state, &mut teams, &engine, &mut InputState::new(), input, None, &logger);
+        };
+
+        press(&mut state, GameInput::Bullpen);
+        press(&mut state, GameInput::Action);
+        let menu = state.bullpen_menu.clone().unwrap();
+        assert_eq!(menu.reliever.as_deref(), Some("TST-P2"));
+        assert!(state.message.starts_with("Double switch?"), "{}", state.message);
+
+        // Round to the last slot before the pitcher's, then back up one
+        press(&mut state, GameInput::Left);
+        press(&mut state, GameInput::Left);
+        assert_eq!(state.bullpen_menu.as_ref().unwrap().slot, Some(6));
+        press(&mut state, GameInput::Down);
+        press(&mut state, GameInput::Action);
+        assert!(state.bullpen_menu.is_none());
+        assert!(state.message.contains("double switch"), "{}", state.message);
+        let team = state.get_current_pitching_team().unwrap();
+        assert_eq!(team.batters[6].stats.id, "TST-P2");
+        assert_eq!(team.batters[BATTING_ORDER_SIZE - 1].stats.id, "BENCH1");
+    }
+
+    #[test]
+    fn test_cant_pinch_run_for_the_batter_at_the_plate() {
+        let mut state = state_with_bench();
+        state.bases[0] = Some(state.current_batter_key());
+        assert_eq!(state.pinch_run(0, "BENCH1").unwrap_err(), "Can't pinch run for the batter at the plate");
+    }
+
+    #[test]
+    fn test_single_puts_batter_on_first_and_next_single_moves_them_up() {
+        let mut state = state_with_bench();
+        state.add_hit(1, &mut *GameEngine::with_seed(1590).rng());
+        assert_eq!(state.bases, [Some("TST-B1".to_string()), None, None]);
+
+        state.add_hit(1, &mut *GameEngine::with_seed(1590).rng());
+        assert_eq!(state.bases, [Some("TST-B2".to_string()), Some("TST-B1".to_string()), None]);
+    }
+
+    #[test]
+    fn test_runs_are_credited_to_the_runner_who_scores() {
+        let mut state = state_with_bench();
+        state.add_hit(3, &mut *GameEngine::with_seed(1590).rng()); // B1 triples
+        state.add_hit(1, &mut *GameEngine::with_seed(1590).rng()); // B2 singles him in
+
+        assert_eq!(state.bases, [Some("TST-B2".to_string()), None, None]);
+        assert_eq!(state.box_score.batter("TST-B1").unwrap().runs, 1);
+        assert_eq!(state.box_score.batter("TST-B2").unwrap().runs, 0);
+        assert_eq!(state.box_score.batter("TST-B2").unwrap().rbi, 1);
+
+        state.add_hit(4, &mut *GameEngine::with_seed(1590).rng()); // B3 homers: B2 and B3 score
+        assert_eq!(state.box_score.batter("TST-B2").unwrap().runs, 1);
+        assert_eq!(state.box_score.batter("TST-B3").unwrap().runs, 1);
+        assert_eq!(state.away_score, 3);
+    }
+
+    #[test]
+    fn test_runs_the_errors_let_in_are_unearned_and_charged_to_whoever_put_the_runner_on() {
+        let mut state = state_with_bench();
+        let rng = GameEngine::with_seed(1592);
+        state.add_hit(1, &mut *rng.rng()); // B1 singles off P1
+        state.add_hit(2, &mut *rng.rng()); // B2 doubles him in - earned
+        state.add_error(); // B3 reaches, B2 to third - still one out to go rebuilt without it
+        assert_eq!(state.bases, [Some("TST-B3".to_string()), None, Some("TST-B2".to_string())]);
+
+        state.get_current_pitching_team_mut().unwrap().change_pitcher();
+        state.add_hit(4, &mut *rng.rng()); // B4 homers off P2: B2 earned, B3 not, B4 earned
+        state.add_hit(3, &mut *rng.rng()); // B5 triples
+        state.add_error(); // B6 reaches and B5 scores on the error - unearned
+
+        let p1 = state.box_score.pitcher("TST-P1").unwrap();
+        assert_eq!((p1.runs, p1.earned_runs, p1.hits), (3, 2, 2));
+        let p2 = state.box_score.pitcher("TST-P2").unwrap();
+        assert_eq!((p2.runs, p2.earned_runs, p2.hits), (2, 1, 2));
+
+        let rbi = |id: &str| state.box_score.batter(id).unwrap().rbi;
+        assert_eq!((rbi("TST-B2"), rbi("TST-B4"), rbi("TST-B6")), (1, 3, 0));
+        let b3 = state.box_score.batter("TST-B3").unwrap();
+        assert_eq!((b3.at_bats, b3.hits, b3.runs), (1, 0, 1));
+        assert_eq!(state.away_score, 5);
+
+        // A new half inning starts clean (both sides share the TST roster, so P1 again)
+        for _ in 0..MAX_OUTS {
+            state.add_out();
+        }
+        state.add_hit(4, &mut *rng.rng());
+        assert_eq!(state.box_score.pitcher("TST-P1").unwrap().earned_runs, 3);
+    }
+
+    #[test]
+    fn test_runs_after_an_error_that_should_have_ended_the_inning_are_unearned() {
+        let mut state = state_with_bench();
+        let rng = GameEngine::with_seed(1592);
+        state.add_out();
+        state.add_out();
+        state.add_hit(1, &mut *rng.rng()); // B3 singles
+        state.add_error(); // Should have been the third out
+        state.add_hit(4, &mut *rng.rng()); // B5 homers - three runs, none earned
+
+        let p1 = state.box_score.pitcher("TST-P1").unwrap();
+        assert_eq!((p1.runs, p1.earned_runs), (3, 0));
+        assert_eq!(state.box_score.batter("TST-B5").unwrap().rbi, 3);
+    }
+
+    #[test]
+    fn test_a_booted_grounder_puts_the_batter_on_and_his_run_is_unearned() {
+        let engine = GameEngine::with_seed(1592);
+        let mut state = state_with_bench();
+        let grounder = BallInPlay {
+            ball_type: BallType::Grounder,
+            direction: FieldDirection::Shortstop,
+            speed: 70.0,
+            hang_time: 0,
+            initial_contact_quality: 50,
+            hr_chance: 0.0,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        // Fielders get to nearly every routine grounder, and boot a few of them
+        let result = (0..2_000)
+            .map(|_| engine.calculate_fielding_result(&grounder, 0, 0, false, &[0; 9]).0)
+            .find(|result| *result == PlayResult::ReachedOnError)
+            .expect("no errors in 2,000 grounders");
+
+        process_play_result(&mut state, &engine, &result, None);
+        assert_eq!(state.bases[0], Some("TST-B1".to_string()));
+        assert_eq!((state.outs, state.inning_errors), (0, 1));
+
+        process_play_result(&mut state, &engine, &PlayResult::Hit(HitType::HomeRun), None);
+        let p1 = state.box_score.pitcher("TST-P1").unwrap();
+        assert_eq!((p1.runs, p1.earned_runs), (2, 1));
+        let b1 = state.box_score.batter("TST-B1").unwrap();
+        assert_eq!((b1.at_bats, b1.hits, b1.runs), (1, 0, 1));
+    }
+
+    #[test]
+    fn test_sim_inning_records_three_outs_and_flips_the_half() {
+        let engine = GameEngine::with_seed(1575);
+        let mut state = state_with_bench();
+        state.strikes = 2; // Mid at-bat
+
+        let sim = crate::game::quick_sim::sim_half_inning(&mut state, &engine);
+
+        assert_eq!(sim.outs, MAX_OUTS);
+        assert!(!sim.gave_up);
+        assert_eq!(state.half, InningHalf::Bottom);
+        assert_eq!(state.inning, 1);
+        assert_eq!(state.outs, 0);
+        assert_eq!(state.away_score, sim.runs);
+        assert_eq!(sim.plays.len() as u8, sim.outs + sim.hits + sim.plays.iter().filter(|p| **p == "BB").count() as u8);
+        assert!(state.message.starts_with("Quick sim:"));
+    }
+
+    #[test]
+    fn test_swinging_foul_with_two_strikes_keeps_the_at_bat_alive() {
+        let mut state = GameState::new();
+        state.strikes = 2;
+
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Foul, None);
+
+        assert_eq!(state.outs, 0);
+        assert_eq!(state.strikes, 2);
+        assert!(state.box_score.batter(&state.current_batter_key()).is_none());
+    }
+
+    #[test]
+    fn test_inning_hits_reset_when_the_half_ends() {
+        let mut state = GameState::new();
+        state.inning_hits = 3;
+        state.pitch_count = 12;
+
+        state.end_half_inning();
+
+        assert_eq!(state.inning_hits, 0);
+        assert_eq!(state.pitch_count, 12); // The log numbers pitches across the whole game
+    }
+
+    #[test]
+    fn test_cycle_aim_visits_every_location_and_wraps() {
+        let mut state = GameState::new();
+        state.mode = GameMode::Playing;
+        state.pitch_state = crate::game::PitchState::Aiming { pitch_type: 0 };
+        let engine = GameEngine::with_seed(1);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        let start = input_state.aimed_location();
+        let mut visited = Vec::new();
+        for _ in 0..9 {
+            handle_input(&mut state, &mut teams, &engine, &mut input_state, GameInput::CycleAim, None, &logger);
+            visited.push(input_state.aimed_location().numpad());
+        }
+
+        let mut keys = visited.clone();
+        keys.sort();
+        assert_eq!(keys, (1..=9).collect::<Vec<u8>>());
+        assert_eq!(visited.last(), Some(&start.numpad())); // Ninth press is back where it began
+        assert!(state.message.contains(start.name()));
+    }
+
+    #[test]
+    fn test_three_strikeouts_credit_the_pitcher_a_full_inning() {
+        let mut state = state_with_bench();
+        let pitcher = state.current_pitcher_key();
+        for _ in 0..3 * MAX_STRIKES {
+            process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Strike, None);
+        }
+
+        assert_eq!(state.half, InningHalf::Bottom);
+        let line = state.box_score.pitcher(&pitcher).unwrap();
+        assert_eq!(line.strikeouts, 3);
+        assert_eq!(line.innings_pitched(), "1.0");
+        assert_eq!(line.walks, 0);
+        assert_eq!(line.runs, 0);
+    }
+
+    #[test]
+    fn test_innings_pitched_count_in_thirds() {
+        let mut state = state_with_bench();
+        let pitcher = state.current_pitcher_key();
+        state.add_out();
+        assert_eq!(state.box_score.pitcher(&pitcher).unwrap().innings_pitched(), "0.1");
+        state.add_out();
+        assert_eq!(state.box_score.pitcher(&pitcher).unwrap().innings_pitched(), "0.2");
+    }
+
+    #[test]
+    fn test_pitching_change_leaves_inherited_runner_on_the_starter() {
+        let mut state = state_with_bench();
+        let starter = state.current_pitcher_key();
+        state.add_walk();
+
+        state.get_current_pitching_team_mut().unwrap().change_pitcher();
+        let reliever = state.current_pitcher_key();
+        assert_ne!(reliever, starter);
+        process_play_result(&mut state, &GameEngine::with_seed(1590), &PlayResult::Hit(HitType::HomeRun), None);
+
+        let starter_line = state.box_score.pitcher(&starter).unwrap();
+        assert_eq!((starter_line.walks, starter_line.hits, starter_line.runs, starter_line.earned_runs), (1, 0, 1, 1));
+        let reliever_line = state.box_score.pitcher(&reliever).unwrap();
+        assert_eq!((reliever_line.walks, reliever_line.hits, reliever_line.runs, reliever_line.earned_runs), (0, 1, 1, 1));
+    }
+
+    /// Home up `home`-`away` with two out in the bottom of the 8th and TST-P5 as the closer
+    fn late_lead(home: u8, away: u8) -> GameState {
+        let mut state = state_with_bench();
+        state.home_roster.as_mut().unwrap().closer = Some("TST-P5".to_string());
+        state.inning = 8;
+        state.half = InningHalf::Bottom;
+        state.outs = 2;
+        state.home_score = home;
+        state.away_score = away;
+        state
+    }
+
+    fn play_out_the_game(state: &mut GameState) {
+        while !state.game_over {
+            state.add_out();
+        }
+    }
+
+    #[test]
+    fn test_closer_finishing_a_one_run_ninth_gets_the_save() {
+        let mut state = late_lead(4, 3);
+        state.add_out();
+        assert_eq!((state.inning, state.half), (9, InningHalf::Top));
+        assert_eq!(state.current_pitcher_key(), "TST-P5");
+
+        play_out_the_game(&mut state);
+        assert!(state.box_score.pitcher("TST-P5").unwrap().save);
+        assert!(state.message.ends_with("Save: Pitcher, Number 5"));
+    }
+
+    #[test]
+    fn test_blowout_keeps_the_closer_in_the_bullpen() {
+        let mut state = late_lead(10, 3);
+        state.add_out();
+        assert_eq!(state.current_pitcher_key(), "TST-P1");
+
+        play_out_the_game(&mut state);
+        assert!(state.box_score.pitching.values().all(|line| !line.save));
+    }
+
+    #[test]
+    fn test_blown_lead_costs_the_closer_the_save() {
+        let mut state = late_lead(4, 3);
+        state.add_out();
+        state.add_hit(4, &mut *GameEngine::with_seed(1590).rng()); // Tying homer off the closer
+        state.home_score += 1; // Home walks it back ahead later
+        play_out_the_game(&mut state);
+        assert!(!state.box_score.pitcher("TST-P5").unwrap().save);
+    }
+
+    #[test]
+    fn test_lineup_card_reorders_hitters_and_waits_for_a_valid_lineup() {
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TST".to_string(), crate::roster::CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON));
+        let mut state = GameState::new();
+        state.start_lineup_edit(&teams, "TST".to_string(), "TST".to_string());
+        let engine = GameEngine::with_seed(1586);
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        let mut press = |state: &mut GameState, input: GameInput| {
+            handle_input(state, &mut teams, &engine, &mut InputState::new(), input, None, &logger);
+        };
+
+        // Grab the nine hitter and carry them up to leadoff
+        for _ in 0..8 {
+            press(&mut state, GameInput::Down);
+        }
+        press(&mut state, GameInput::Action);
+        for _ in 0..8 {
+            press(&mut state, GameInput::Up);
+        }
+        press(&mut state, GameInput::Action);
+        let away = state.away_roster.as_ref().unwrap();
+        assert_eq!(away.batters[0].stats.id, "TST-B9");
+        assert_eq!(away.batters[1].stats.id, "TST-B1");
+
+        // The pitching staff follows the lineup on the card
+        press(&mut state, GameInput::Right);
+        for _ in 0..10 {
+            press(&mut state, GameInput::Down);
+        }
+        press(&mut state, GameInput::Action);
+        assert_eq!(state.home_roster.as_ref().unwrap().get_current_pitcher().unwrap().stats.id, "TST-P2");
+        assert_eq!(state.away_roster.as_ref().unwrap().current_pitcher_idx, 0);
+
+        // Short a hitter, the game won't start
+        state.home_roster.as_mut().unwrap().batters.pop();
+        press(&mut state, GameInput::SimInning);
+        assert!(matches!(state.mode, GameMode::LineupEdit(_)));
+        assert_eq!(state.message, "Test Team needs 9 hitters but only has 8");
+
+        let spare = state.away_roster.as_ref().unwrap().batters[0].clone();
+        state.home_roster.as_mut().unwrap().batters.push(spare);
+        press(&mut state, GameInput::SimInning);
+        assert_eq!(state.mode, GameMode::Playing);
+        assert_eq!(state.get_current_batter().unwrap().stats.id, "TST-B9");
+    }
+
+    fn tied_after(inning: u8, max_innings: u8) -> GameState {
+        let mut state = state_with_bench();
+        state.config.max_innings = max_innings;
+        state.inning = inning;
+        state.half = InningHalf::Bottom;
+        state.outs = 2;
+        state.home_score = 3;
+        state.away_score = 3;
+        state
+    }
+
+    #[test]
+    fn test_tie_ends_after_the_bottom_of_the_innings_cap() {
+        let mut state = tied_after(11, 12);
+        state.add_out();
+        assert!(!state.game_over);
+        assert_eq!((state.inning, state.half), (12, InningHalf::Top));
+
+        let mut state = tied_after(12, 12);
+        state.add_out();
+        assert!(state.game_over);
+        assert_eq!(state.message, "Game Over! Final (tie) after 12 innings - Home: 3 Away: 3");
+    }
+
+    #[test]
+    fn test_a_decided_game_at_the_innings_cap_ends_normally() {
+        let mut state = tied_after(12, 12);
+        state.home_score = 4;
+        state.add_out();
+        assert!(state.game_over);
+        assert!(state.message.starts_with("Game Over! Final Score - Home: 4 Away: 3"), "unexpected: {}", state.message);
+
+        // Walking it off in the bottom of the cap inning is a win, not a tie
+        let mut state = tied_after(12, 12);
+        state.add_hit(4, &mut *GameEngine::with_seed(1594).rng());
+        state.add_out();
+        assert!(state.game_over);
+        assert!(!state.message.contains("tie"), "unexpected: {}", state.message);
+    }
+
+    #[test]
+    fn test_ties_play_on_without_a_cap() {
+        let mut state = tied_after(20, 0);
+        state.add_out();
+        assert!(!state.game_over);
+        assert_eq!(state.inning, 21);
+    }
+
+    #[test]
+    fn test_fielders_choice_records_one_out_and_leaves_the_batter_on_first() {
+        let mut state = state_with_bench();
+        state.bases = runners([true, false, false]);
+        let batter = state.current_batter_key();
+        let engine = GameEngine::with_seed(1596);
+
+        process_play_result(&mut state, &engine, &PlayResult::Out(OutType::FieldersChoice), None);
+        assert_eq!(state.outs, 1);
+        assert_eq!(state.bases, [Some(batter.clone()), None, None]);
+        let line = state.box_score.batter(&batter).unwrap();
+        assert_eq!((line.at_bats, line.hits, line.rbi), (1, 0, 0));
+        assert_eq!(state.inning_hits, 0);
+    }
+
+    #[test]
+    fn test_fielders_choice_with_the_bases_loaded_forces_in_a_run() {
+        let mut state = state_with_bench();
+        state.bases = runners([true, true, true]);
+        state.outs = 1;
+        let batter = state.current_batter_key();
+
+        state.add_fielders_choice();
+        assert_eq!(state.outs, 2);
+        assert_eq!(state.bases, [Some(batter.clone()), None, Some("R2".to_string())]);
+        assert_eq!(state.away_score, 1);
+        assert_eq!(state.box_score.batter(&batter).unwrap().rbi, 1);
+    }
+
+    #[test]
+    fn test_fielders_choice_needs_a_force_at_second() {
+        let engine = GameEngine::with_seed(1596);
+        let groundout = PlayResult::Out(OutType::Groundout);
+        let choices = (0..200)
+            .filter(|_| engine.fielders_choice(groundout.clone(), true) == PlayResult::Out(OutType::FieldersChoice))
+            .count();
+        assert!(choices > 0 && choices < 200, "{} fielder's choices", choices);
+        assert!((0..200).all(|_| engine.fielders_choice(groundout.clone(), false) == groundout));
+        // Only grounders - a fly ball can't be a force play
+        assert!((0..200).all(|_| engine.fielders_choice(PlayResult::Out(OutType::Flyout), true) == PlayResult::Out(OutType::Flyout)));
+
+        let mut state = state_with_bench();
+        state.bases = runners([false, true, false]);
+        assert!(!state.force_at_second());
+        state.bases = runners([true, false, false]);
+        assert!(state.force_at_second());
+        state.outs = 2;
+        assert!(!state.force_at_second());
+    }
+
+    #[test]
+    fn test_balk_moves_every_runner_up_and_scores_from_third() {
+        let mut state = state_with_bench();
+        state.bases = runners([true, false, true]);
+        state.outs = 1;
+        state.balls = 2;
+        state.strikes = 1;
+        let batter = state.current_batter_idx;
+
+        assert_eq!(state.balk(), 1);
+        assert_eq!(state.bases, [None, Some("R1".to_string()), None]);
+        assert_eq!(state.away_score, 1);
+        assert_eq!((state.outs, state.balls, state.strikes), (1, 2, 1));
+        assert_eq!(state.current_batter_idx, batter);
+    }
+
+    #[test]
+    fn test_balk_from_the_update_loop_leaves_the_count_alone() {
+        let mut state = state_with_bench();
+        state.bases = runners([true, true, true]);
+        state.strikes = 2;
+        state.pitch_location = Some(crate::game::PitchLocation::Middle);
+        state.pitch_state = crate::game::PitchState::PitchClock { frames_left: 1, pitch_type: 0 };
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_state_tests.log").to_string_lossy());
+        crate::game::update::call_balk(&mut state, &logger);
+
+        assert_eq!(state.message, "Balk! The runners move up and a run scores");
+        assert_eq!(state.bases, [None, Some("R1".to_string()), Some("R2".to_string())]);
+        assert_eq!((state.away_score, state.outs, state.strikes), (1, 0, 2));
+        assert_eq!(state.pitch_state, crate::game::PitchState::ChoosePitch);
+    }
+
+    #[test]
+    fn test_fast_runner_scores_from_second_on_a_single_more_often() {
+        let engine = GameEngine::with_seed(1590);
+        let runs_scored = |sprint_speed: f32| {
+            (0..200)
+                .filter(|_| {
+                    let mut state = state_with_bench();
+                    if let Some(team) = state.away_roster.as_mut() {
+                        team.batters.iter_mut().find(|p| p.stats.id == "TST-B9").unwrap().sprint_speed = sprint_speed;
+                    }
+                    state.bases = [None, Some("TST-B9".to_string()), None];
+                    state.add_hit(1, &mut *engine.rng());
+                    state.away_score == 1
+                })
+                .count()
+        };
+
+        let fast = runs_scored(30.0);
+        let slow = runs_scored(24.0);
+        assert!(fast > slow * 2, "fast runner scored {} times, slow runner {}", fast, slow);
+    }
+
+    #[test]
+    fn test_saving_mid_pitch_resumes_at_choose_pitch_with_the_count() {
+        let mut state = GameState::new();
+        state.mode = GameMode::Playing;
+        (state.balls, state.strikes, state.outs) = (3, 2, 1);
+        state.bases = runners([true, false, true]);
+        state.pitch_location = Some(PitchLocation::DownOutside);
+        state.swing_timing = SwingTiming::Late;
+        state.pitch_state = PitchState::BallApproaching {
+            frames_left: 12,
+            approach_frames: BALL_APPROACH_FRAMES,
+            ball_position: 0.85,
+            pitch_type: 2,
+            can_swing: true,
+        };
+
+        state.pre_save_normalize(&GameEngine::with_seed(1605));
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+        assert_eq!((state.balls, state.strikes, state.outs), (3, 2, 1));
+        assert_eq!(state.bases, runners([true, false, true]));
+        assert_eq!((state.pitch_location, state.swing_timing), (None, SwingTiming::NoSwing));
+
+        // Already between pitches - nothing to do
+        state.message = "Full count".to_string();
+        state.pre_save_normalize(&GameEngine::with_seed(1605));
+        assert_eq!(state.message, "Full count");
+    }
+
+    #[test]
+    fn test_on_deck_wraps_to_the_top_of_the_order() {
+        let mut state = state_with_bench();
+        let order_size = state.get_current_batting_team().unwrap().batting_order_size();
+        assert!(state.get_current_batting_team().unwrap().batters.len() > order_size);
+
+        state.current_batter_idx = order_size - 2;
+        assert_eq!((state.upcoming_batter_idx(1), state.upcoming_batter_idx(2)), (order_size - 1, 0));
+
+        state.current_batter_idx = order_size - 1;
+        assert_eq!((state.upcoming_batter_idx(1), state.upcoming_batter_idx(2)), (0, 1));
+        // The leadoff hitter, not the first man on the bench
+        let leadoff = state.get_current_batting_team().unwrap().batters[0].stats.id.clone();
+        assert_eq!(state.upcoming_batter(1).unwrap().stats.id, leadoff);
+
+        state.advance_batter();
+        assert_eq!(state.current_batter_idx, 0);
+        assert_eq!(state.get_current_batter().unwrap().stats.id, leadoff);
+    }
 }
@@ -0,0 +1,27 @@
+use crate::game::constants::DEFAULT_SEASON;
+use crate::game::GameState;
+use crate::team::TeamManager;
+
+/// The two-team fixture league
+pub fn league_manager() -> TeamManager {
+    let mut manager = TeamManager::new(format!("{}/fixtures/teams", env!("CARGO_MANIFEST_DIR")), DEFAULT_SEASON);
+    manager.load_league(format!("{}/fixtures/league.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    manager
+}
+
+/// Load the league's first two teams, returned as (home, away) - the second team is at home
+pub fn load_matchup(manager: &mut TeamManager) -> (String, String) {
+    let teams = manager.get_team_list();
+    let (away, home) = (teams[0].clone(), teams[1].clone());
+    manager.load_team(&away).unwrap();
+    manager.load_team(&home).unwrap();
+    (home, away)
+}
+
+/// A game between the league's first two teams, ready for the first pitch
+pub fn started_game(manager: &mut TeamManager) -> GameState {
+    let (home, away) = load_matchup(manager);
+    let mut state = GameState::new();
+    state.start_game(manager, home, away);
+    state
+}
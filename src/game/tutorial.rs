@@ -0,0 +1,192 @@
+use crate::game::constants::*;
+use crate::game::PitchState;
+use crate::input::GameInput;
+
+/// One stop on the tutorial. Play runs until `waits_in` matches the pitch state, then holds
+/// there with the step's text up until an input it `accepts` comes in. Accepted inputs are
+/// played as normal; the ones it `advances` on also move to the next step.
+#[derive(Debug, Clone)]
+pub struct TutorialStep {
+    pub title: &'static str,
+    pub text: &'static str,
+    pub waits_in: fn(&PitchState) -> bool,
+    pub accepts: fn(&GameInput) -> bool,
+    pub advances: fn(&GameInput) -> bool,
+    pub starts_pitch: bool, // Where to go back to when the pitch ends before reaching a later step
+}
+
+fn choose_pitch(state: &PitchState) -> bool {
+    matches!(state, PitchState::ChoosePitch)
+}
+
+fn aiming(state: &PitchState) -> bool {
+    matches!(state, PitchState::Aiming { .. })
+}
+
+fn pitch_clock(state: &PitchState) -> bool {
+    matches!(state, PitchState::PitchClock { .. })
+}
+
+/// The ball is dead center in the perfect timing window
+fn swing_window(state: &PitchState) -> bool {
+    matches!(state, PitchState::BallApproaching { can_swing: true, frames_left, .. } if *frames_left <= PERFECT_TIMING_CENTER_FRAMES)
+}
+
+/// Halfway through the ball's hang time - the best moment to field it
+fn fielding_window(state: &PitchState) -> bool {
+    matches!(state, PitchState::Fielding { ball_in_play, frames_elapsed } if *frames_elapsed >= ball_in_play.hang_time / 2)
+}
+
+fn show_result(state: &PitchState) -> bool {
+    matches!(state, PitchState::ShowResult { .. })
+}
+
+fn action(input: &GameInput) -> bool {
+    *input == GameInput::Action
+}
+
+fn pitch_key(input: &GameInput) -> bool {
+    matches!(input, GameInput::SelectPitch(_))
+}
+
+fn aim_keys(input: &GameInput) -> bool {
+    matches!(input, GameInput::Up | GameInput::Down | GameInput::Left | GameInput::Right | GameInput::CycleAim)
+}
+
+fn throw_keys(input: &GameInput) -> bool {
+    matches!(input, GameInput::Action | GameInput::DirectPosition(_))
+}
+
+fn swing_keys(input: &GameInput) -> bool {
+    aim_keys(input) || throw_keys(input)
+}
+
+/// The walkthrough, in order - one pitch from both sides of the ball
+pub fn tutorial_steps() -> Vec<TutorialStep> {
+    vec![
+        TutorialStep {
+            title: "Welcome",
+            text: "This walks through one pitch from both sides: pitching, the pitch clock, swinging and fielding. Press SPACE to start, or ESC to skip.",
+            waits_in: choose_pitch,
+            accepts: action,
+            advances: action,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "Pick a pitch",
+            text: "You're pitching first. Each number key is a pitch in this pitcher's arsenal - press 1.",
+            waits_in: choose_pitch,
+            accepts: pitch_key,
+            advances: pitch_key,
+            starts_pitch: true,
+        },
+        TutorialStep {
+            title: "Aim it",
+            text: "The arrow keys move your aim around the 3x3 zone (TAB steps through it). The four corners are out of the zone. Press an arrow.",
+            waits_in: aiming,
+            accepts: aim_keys,
+            advances: aim_keys,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "Throw it",
+            text: "SPACE throws the pitch where you're aiming. SHIFT+1-9 aims and throws in one go, laid out like a numpad.",
+            waits_in: aiming,
+            accepts: |input| aim_keys(input) || throw_keys(input),
+            advances: throw_keys,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "Pitch clock",
+            text: "Now you're the batter. The pitch clock gives you 3 seconds to get set before the ball is on its way. Press SPACE to let it run.",
+            waits_in: pitch_clock,
+            accepts: action,
+            advances: action,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "Swing",
+            text: "The ball is in the perfect timing window. Arrows aim the swing at where the pitch is headed, then SPACE swings - or SHIFT+1-9 swings at a spot.",
+            waits_in: swing_window,
+            accepts: swing_keys,
+            advances: throw_keys,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "Field it",
+            text: "It's in play and now you're the fielder. Halfway through its hang time is the best moment - press SPACE to make the play.",
+            waits_in: fielding_window,
+            accepts: action,
+            advances: action,
+            starts_pitch: false,
+        },
+        TutorialStep {
+            title: "That's the game",
+            text: "Every pitch goes like that. F1 or ? opens the key list any time, and T from there replays this. SPACE to play on.",
+            waits_in: show_result,
+            accepts: action,
+            advances: action,
+            starts_pitch: false,
+        },
+    ]
+}
+
+/// Where a player is in the tutorial
+#[derive(Debug, Clone)]
+pub struct Tutorial {
+    pub step: usize,
+    steps: Vec<TutorialStep>,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Self { step: 0, steps: tutorial_steps() }
+    }
+}
+
+impl Tutorial {
+    pub fn current(&self) -> &TutorialStep {
+        &self.steps[self.step.min(self.steps.len() - 1)]
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Play holds here until the step's input comes in
+    pub fn holds(&self, pitch_state: &PitchState) -> bool {
+        (self.current().waits_in)(pitch_state)
+    }
+
+    /// Whether `input` gets through to the game right now - everything else is ignored
+    pub fn accepts(&self, pitch_state: &PitchState, input: &GameInput) -> bool {
+        self.holds(pitch_state) && (self.current().accepts)(input)
+    }
+
+    /// Move on if `input` finishes the current step. Returns true once the last step is done.
+    pub fn advance(&mut self, input: &GameInput) -> bool {
+        if (self.current().advances)(input) {
+            self.step += 1;
+        }
+        self.step >= self.steps.len()
+    }
+
+    /// The pitch ended before getting to the current step - a swing and a miss never reaches
+    /// fielding, say. Go back to the start of the pitch and returns true if so.
+    pub fn retry_if_missed(&mut self, pitch_state: &PitchState) -> bool {
+        if !show_result(pitch_state) || self.holds(pitch_state) {
+            return false;
+        }
+        match self.steps[..self.step].iter().rposition(|step| step.starts_pitch) {
+            Some(start) => {
+                self.step = start;
+                true
+            }
+            None => false,
+        }
+    }
+}
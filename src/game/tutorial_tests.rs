@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::*;
+    use crate::game::input_handler::handle_input;
+    use crate::game::test_support::{league_manager, started_game};
+    use crate::game::tutorial::{tutorial_steps, Tutorial};
+    use crate::game::update::update_game_state;
+    use crate::game::{GameEngine, GameState, PitchLocation, PitchState, PlayResult};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::team::TeamManager;
+
+    const MAX_TUTORIAL_FRAMES: u32 = 100_000;
+
+    fn tutorial_state(manager: &mut TeamManager) -> GameState {
+        let mut state = started_game(manager);
+        state.start_tutorial();
+        state
+    }
+
+    /// What a player following the overlay would press
+    fn follow_step(state: &GameState) -> GameInput {
+        match state.tutorial.as_ref().unwrap().current().title {
+            "Pick a pitch" => GameInput::SelectPitch(0),
+            "Aim it" => GameInput::Up,
+            "Swing" => {
+                let target = state.pitch_location.unwrap_or(PitchLocation::Middle);
+                GameInput::DirectPosition((1..=9).find(|&n| PitchLocation::from_numpad(n) == target).unwrap())
+            }
+            _ => GameInput::Action,
+        }
+    }
+
+    #[test]
+    fn test_play_waits_on_the_step_and_ignores_other_keys() {
+        let engine = GameEngine::with_seed(1600);
+        let mut manager = league_manager();
+        let mut state = tutorial_state(&mut manager);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        for _ in 0..PITCHER_CLOCK_FRAMES * 2 {
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+        }
+        assert_eq!(state.pitcher_clock_used, 0);
+
+        // Welcome wants SPACE, so a pitch can't be picked yet
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::SelectPitch(0), None, &logger);
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Action, None, &logger);
+        assert_eq!(state.tutorial.as_ref().unwrap().current().title, "Pick a pitch");
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::SelectPitch(0), None, &logger);
+        assert!(matches!(state.pitch_state, PitchState::Aiming { .. }));
+    }
+
+    #[test]
+    fn test_following_the_steps_finishes_the_tutorial() {
+        let engine = GameEngine::with_seed(1600);
+        let mut manager = league_manager();
+        let mut state = tutorial_state(&mut manager);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        let mut frames = 0;
+        while let Some(tutorial) = &state.tutorial {
+            assert!(frames < MAX_TUTORIAL_FRAMES, "tutorial stuck at {:?} in {:?}", tutorial, state.pitch_state);
+            if tutorial.holds(&state.pitch_state) {
+                let input = follow_step(&state);
+                handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+            }
+            update_game_state(&mut state, &engine, &mut input_state, None, &logger);
+            frames += 1;
+        }
+
+        assert!(state.config.tutorial_done);
+        assert!(!state.game_over);
+    }
+
+    #[test]
+    fn test_a_pitch_that_never_gets_to_the_step_is_thrown_again() {
+        let mut tutorial = Tutorial::default();
+        let fielding = tutorial_steps().iter().position(|step| step.title == "Field it").unwrap();
+        tutorial.step = fielding;
+        let struck_out = PitchState::ShowResult { result: PlayResult::Strike, frames_left: 1 };
+
+        assert!(tutorial.retry_if_missed(&struck_out));
+        assert_eq!(tutorial.current().title, "Pick a pitch");
+        // Already waiting for the next pitch - nothing to go back to
+        assert!(!tutorial.retry_if_missed(&struck_out));
+        assert!(!tutorial.retry_if_missed(&PitchState::ChoosePitch));
+    }
+
+    #[test]
+    fn test_escape_skips_and_the_help_screen_brings_it_back_between_pitches() {
+        let engine = GameEngine::with_seed(1600);
+        let mut manager = league_manager();
+        let mut state = tutorial_state(&mut manager);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+
+        handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Pause, None, &logger);
+        assert!(state.tutorial.is_none());
+        assert!(state.config.tutorial_done);
+
+        // T on the help screen replays it, but not in the middle of a pitch
+        for input in [GameInput::SelectPitch(0), GameInput::Help, GameInput::ToggleSort] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert!(state.tutorial.is_none());
+
+        state.pitch_state = PitchState::ChoosePitch;
+        for input in [GameInput::Help, GameInput::ToggleSort] {
+            handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert!(state.help_page.is_none());
+        assert_eq!(state.tutorial.as_ref().unwrap().step, 0);
+    }
+}
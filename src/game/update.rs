@@ -1,5 +1,7 @@
 use crate::audio::AudioPlayer;
-use crate::game::{constants::*, GameEngine, GameState, HitType, InningHalf, OutType, PitchState, PlayResult, SwingTiming};
+use crate::game::{ai::{self, BatterAi}, constants::*, GameEngine, GameMode, GameState, HitType, InningHalf, OutType, PitchLocation, PitchState, PlayResult, SwingTiming};
+use crate::game::input_handler::{begin_swing, classify_swing_timing};
+use crate::game::practice::PracticeMode;
 use crate::input::InputState;
 use crate::logger::GameLogger;
 
@@ -9,13 +11,67 @@ pub fn update_game_state(
     input_state: &mut InputState,
     audio_player: Option<&AudioPlayer>,
     logger: &GameLogger,
-    pitch_count: &mut u32,
-    inning_hits: &mut u8,
 ) {
+    // Everything waits while the help, leaders or settings screen is up
+    if state.help_page.is_some() || state.leaders_tab.is_some() || state.settings_row.is_some() || state.paused {
+        return;
+    }
+    if let GameMode::TeamSelection { .. } = state.mode {
+        state.idle_frames = state.idle_frames.saturating_add(1);
+        update_matchup_roll(state);
+        return;
+    }
+    if let GameMode::LineupEdit(_) = state.mode {
+        return;
+    }
+    // Play - and the pitcher's clock - stops while a pinch runner, defensive sub or reliever is picked
+    if state.pinch_run_menu.is_some() || state.defensive_sub_menu.is_some() || state.bullpen_menu.is_some() {
+        return;
+    }
+    // The tutorial holds play wherever its current step has something to show
+    if let Some(tutorial) = &mut state.tutorial {
+        if tutorial.retry_if_missed(&state.pitch_state) {
+            state.message = "Not in play that time - let's throw another".to_string();
+        }
+        if tutorial.holds(&state.pitch_state) {
+            return;
+        }
+    }
+
+    let dead_time = dead_time_frames(state);
+
+    // The pitcher's clock only runs while a pitch is being picked and aimed
+    if !matches!(state.pitch_state, PitchState::ChoosePitch | PitchState::Aiming { .. }) {
+        state.pitcher_clock_used = 0;
+    }
+
+    let cpu_pitching = state.cpu_pitching();
     match &mut state.pitch_state {
+        PitchState::ChoosePitch if cpu_pitching && !state.game_over => {
+            // CPU pitcher picks a pitch and a spot, then works the clock like a human would
+            let pitcher = state.get_current_pitcher();
+            let mut rng = engine.rng();
+            let pitch_type = ai::choose_pitch_type(engine.arsenal(pitcher).len(), &mut *rng);
+            let location = ai::choose_pitch_location(&mut *rng);
+            drop(rng);
+            state.message = format!("CPU throws a {}...", engine.get_pitch_name(pitcher, pitch_type));
+            state.pitch_location = Some(location);
+            state.pitch_state = PitchState::PitchClock {
+                frames_left: state.config.pitch_clock_frames,
+                pitch_type,
+            };
+        }
+        PitchState::ChoosePitch | PitchState::Aiming { .. }
+            if state.config.pitcher_clock_frames > 0 && !state.game_over && !state.quit_requested && state.practice.is_none() =>
+        {
+            state.pitcher_clock_used = state.pitcher_clock_used.saturating_add(1);
+            if state.pitcher_clock_used >= state.config.pitcher_clock_frames {
+                call_clock_violation(state, engine, PlayResult::Ball, audio_player);
+            }
+        }
         PitchState::PitchClock { frames_left, pitch_type } => {
-            *frames_left -= 1;
-            let seconds_left = (*frames_left as f32 / TARGET_FPS as f32).ceil() as u16;
+            let expired = run_down(frames_left, dead_time);
+            let seconds_left = frames_to_seconds(*frames_left as u64);
             
             if seconds_left <= 3 {
                 state.message = format!("GET READY! {}...", seconds_left);
@@ -23,78 +79,83 @@ pub fn update_game_state(
                 state.message = format!("Pitch clock: {}s - Get in position!", seconds_left);
             }
             
-            if *frames_left == 0 {
-                // Clock expires - start ball approach
+            if expired {
+                let pitch_type = *pitch_type;
+                let runners_on = state.bases.iter().any(Option::is_some);
+                let fatigue_penalty = state.get_current_pitching_team()
+                    .map(|t| t.get_fatigue_penalty())
+                    .unwrap_or(FATIGUE_PENALTY_FRESH);
+                if engine.roll_balk(runners_on, fatigue_penalty) {
+                    call_balk(state, logger);
+                    return;
+                }
+
+                // Clock expires - the pitch may miss its spot depending on its control
+                if let (Some(intended), Some(pitch)) = (state.pitch_location, engine.pitch_type(state.get_current_pitcher(), pitch_type)) {
+                    state.pitch_location = Some(engine.apply_control(intended, pitch));
+                }
+                state.pitch_type = pitch_type;
+
+                // Start ball approach - faster pitches cover the track in fewer frames
+                let approach_frames = engine.approach_frames(state.get_current_pitcher(), pitch_type);
+                state.pitch_guess = None;
                 state.pitch_state = PitchState::BallApproaching {
-                    frames_left: BALL_APPROACH_FRAMES,
+                    frames_left: approach_frames,
+                    approach_frames,
                     ball_position: 0.0,
-                    pitch_type: *pitch_type,
+                    pitch_type,
                     can_swing: false,
                 };
                 state.message = "Here comes the pitch! Watch the ball!".to_string();
             }
         }
-        PitchState::Pitching { frames_left } => {
-            *frames_left -= 1;
-            if *frames_left == 0 {
-                // Legacy - transition to ball approaching
-                state.pitch_state = PitchState::BallApproaching {
-                    frames_left: BALL_APPROACH_FRAMES,
-                    ball_position: 0.0,
-                    pitch_type: 0, // Default pitch type
-                    can_swing: false,
-                };
-                state.message = "Here comes the pitch!".to_string();
-                input_state.reset();
-            }
-        }
-        PitchState::BallApproaching { frames_left, ball_position, can_swing, .. } => {
-            *frames_left -= 1;
+        PitchState::BallApproaching { frames_left, approach_frames, ball_position, can_swing, .. } => {
+            let reached_plate = run_down(frames_left, 1);
             
             // Update ball position (0.0 = mound, 1.0 = plate)
-            *ball_position = 1.0 - (*frames_left as f32 / BALL_APPROACH_FRAMES as f32);
+            *ball_position = 1.0 - (*frames_left as f32 / (*approach_frames).max(1) as f32);
             
             // Enable swinging when ball enters timing window
             let timing_window_start = SWING_TIMING_WINDOW_FRAMES;
             if *frames_left <= timing_window_start && !*can_swing {
                 *can_swing = true;
                 state.message = "SWING NOW! Time your swing!".to_string();
+
+                if state.config.demo_mode {
+                    let batter_ai = BatterAi::new(state.config.batter_aggressiveness);
+                    state.cpu_swing = state.pitch_location
+                        .and_then(|loc| batter_ai.decide(loc, &mut *engine.rng()));
+                }
             }
             
             // Update message with timing cues
             if *can_swing {
-                if *frames_left <= PERFECT_TIMING_WINDOW_FRAMES {
+                if *frames_left <= TOO_LATE_END_FRAMES {
+                    state.message = "Too late to catch up to it!".to_string();
+                } else if *frames_left <= PERFECT_TIMING_WINDOW_FRAMES {
                     state.message = "PERFECT TIMING!".to_string();
                 } else if *frames_left <= (PERFECT_TIMING_WINDOW_FRAMES + EARLY_LATE_WINDOW_FRAMES) {
                     state.message = "Good timing zone...".to_string();
                 }
             }
             
-            if *frames_left == 0 {
+            if let Some(plan) = state.cpu_swing {
+                if *frames_left <= plan.frames_left {
+                    let timing = classify_swing_timing(*frames_left, *can_swing);
+                    begin_swing(state, plan.location, timing);
+                    return;
+                }
+            }
+
+            if reached_plate {
                 // Ball reaches plate - no swing means take
-                state.swing_timing = SwingTiming::NoSwing;
-                let pitch_loc = state.pitch_location.unwrap();
-                
-                let result = if pitch_loc.is_strike() {
-                    PlayResult::Strike
-                } else {
-                    PlayResult::Ball
-                };
-                
-                state.pitch_state = PitchState::ShowResult {
-                    result,
-                    frames_left: RESULT_DISPLAY_FRAMES,
-                };
-                state.message = "Taken!".to_string();
+                state.cpu_swing = None;
+                take_pitch(state);
             }
         }
-        PitchState::WaitingForBatter => {
-            // Auto-take after configured frames (~2 seconds)
-            // This allows batter to choose not to swing
-        }
         PitchState::Swinging { frames_left, swing_timing } => {
-            *frames_left -= 1;
-            if *frames_left == 0 {
+            let expired = run_down(frames_left, dead_time);
+            if expired {
                 // Collect all data needed for calculation
                 let pitch_loc = state.pitch_location.unwrap();
                 let swing_loc = state.swing_location;
@@ -105,31 +166,43 @@ pub fn update_game_state(
                 let batter = state.get_current_batter().cloned();
                 let pitcher = state.get_current_pitcher().cloned();
                 
-                // Now modify state - decrease pitcher stamina
-                if let Some(team) = state.get_current_pitching_team_mut() {
+                // Now modify state - decrease pitcher stamina, which practice doesn't use up
+                let practicing = state.practice.is_some();
+                if let Some(team) = state.get_current_pitching_team_mut().filter(|_| !practicing) {
                     let stamina_cost = if swing_loc.is_some() { STAMINA_COST_SWING } else { STAMINA_COST_TAKE };
                     team.decrease_stamina(stamina_cost);
                 }
+                state.record_pitch();
+
+                // The crowd feels the batting side's momentum, and with momentum effects on so does the batter
+                if let Some(player) = audio_player {
+                    player.set_crowd_intensity(state.batting_momentum().crowd_intensity());
+                }
                 
                 // Calculate result with timing consideration
                 let (result, contact_quality) = engine.calculate_pitch_result_with_timing(
                     pitch_loc,
                     swing_loc,
-                    0,
+                    state.pitch_type,
                     batter.as_ref(),
                     pitcher.as_ref(),
                     fatigue_penalty,
                     &swing_timing_copy,
+                    (state.balls, state.strikes),
+                    state.momentum_contact_bonus(),
                 );
+                if let Some(session) = &mut state.practice {
+                    session.record_contact(contact_quality);
+                }
                 
                 // Log pitch result
-                *pitch_count += 1;
+                state.pitch_count += 1;
                 let half_str = match state.half {
                     InningHalf::Top => "Top",
                     InningHalf::Bottom => "Bottom",
                 };
                 logger.log_pitch_result(
-                    *pitch_count,
+                    state.pitch_count,
                     state.inning,
                     half_str,
                     batter.as_ref(),
@@ -141,11 +214,6 @@ pub fn update_game_state(
                     fatigue_penalty,
                 );
                 
-                // Track hits for inning summary
-                if matches!(&result, PlayResult::Hit(_)) {
-                    *inning_hits += 1;
-                }
-                
                 // Play sound based on result
                 if let Some(player) = audio_player {
                     match &result {
@@ -159,40 +227,38 @@ pub fn update_game_state(
                     }
                 }
                 
-                // Check if result should trigger fielding gameplay
-                // ONLY trigger fielding for hits - outs are automatic
-                match &result {
-                    PlayResult::Hit(_) => {
-                        // Generate ball-in-play with contact quality
-                        if let Some(contact_quality) = contact_quality {
-                            if let Some(ball_in_play) = engine.generate_ball_in_play(contact_quality, batter.as_ref(), pitcher.as_ref()) {
-                                // Switch to fielding mode
-                                state.fielding_cursor = Some(ball_in_play.direction);
-                                state.message = format!("{:?} to {:?}! Press SPACE to field!", ball_in_play.ball_type, ball_in_play.direction);
-                                state.pitch_state = PitchState::Fielding {
-                                    ball_in_play,
-                                    frames_elapsed: 0,
-                                };
-                            } else {
-                                // Fallback to immediate result
-                                process_play_result(state, &result, audio_player);
-                                state.pitch_state = PitchState::ShowResult {
-                                    result,
-                                    frames_left: RESULT_DISPLAY_FRAMES,
-                                };
-                            }
-                        } else {
-                            // No contact quality - immediate result
-                            process_play_result(state, &result, audio_player);
-                            state.pitch_state = PitchState::ShowResult {
-                                result,
-                                frames_left: RESULT_DISPLAY_FRAMES,
-                            };
-                        }
+                // Every batted ball goes to the fielders - the fielding play alone decides
+                // whether it's a hit or an out, so the swing never settles it up front.
+                // A weak foul can pop up where a fielder can catch it, too.
+                let ball_in_play = match contact_quality {
+                    Some(contact_quality) if result.is_batted_ball() => {
+                        Some(engine.generate_ball_in_play(contact_quality, batter.as_ref(), pitcher.as_ref(), &swing_timing_copy))
                     }
-                    _ => {
-                        // Immediate result (strike, ball, foul)
-                        process_play_result(state, &result, audio_player);
+                    Some(contact_quality) if result == PlayResult::Foul => engine.generate_foul_pop(contact_quality),
+                    _ => None,
+                };
+                match ball_in_play {
+                    Some(ball_in_play) if state.practice_mode() == Some(PracticeMode::BattingCage) => {
+                        // Nobody fields in the cage - the fielders play it straight away
+                        let (result, _) = engine.fielder_plays_ball(&ball_in_play, false, &state.defense());
+                        state.record_ball_in_play(ball_in_play.direction, ball_in_play.ball_type, &result);
+                        process_play_result(state, engine, &result, audio_player);
+                        state.pitch_state = PitchState::ShowResult {
+                            result,
+                            frames_left: RESULT_DISPLAY_FRAMES,
+                        };
+                    }
+                    Some(ball_in_play) => {
+                        state.fielding_cursor = Some(ball_in_play.direction);
+                        state.message = format!("{:?} to {}! Press SPACE to field!", ball_in_play.ball_type, ball_in_play.direction.name());
+                        state.pitch_state = PitchState::Fielding {
+                            ball_in_play,
+                            frames_elapsed: 0,
+                        };
+                    }
+                    None => {
+                        // Immediate result (strike, ball or foul)
+                        process_play_result(state, engine, &result, audio_player);
                         state.pitch_state = PitchState::ShowResult {
                             result,
                             frames_left: RESULT_DISPLAY_FRAMES,
@@ -202,22 +268,26 @@ pub fn update_game_state(
             }
         }
         PitchState::Fielding { ball_in_play, frames_elapsed } => {
-            *frames_elapsed += 1;
+            *frames_elapsed = frames_elapsed.saturating_add(1);
             
             // Auto-resolve if player doesn't act in time
-            let max_time = ball_in_play.hang_time.max(45);
+            let max_time = ball_in_play.hang_time.max(FIELDING_MIN_WAIT_FRAMES);
             if *frames_elapsed >= max_time {
-                // Too slow - ball gets through
-                let result = engine.ball_gets_through(ball_in_play);
+                // Too slow - the fielder is left to play it alone
+                let ball = ball_in_play.clone();
+                let catch_timing = *frames_elapsed;
+                let (result, success_chance) = engine.fielder_plays_ball(&ball, state.infield_fly_in_effect(), &state.defense());
+                let result = engine.fielders_choice(result, state.force_at_second());
+                logger.log_fielding_attempt(&ball, catch_timing, ball.hang_time / 2, success_chance, &result);
+                state.record_ball_in_play(ball.direction, ball.ball_type.clone(), &result);
                 
                 if let Some(player) = audio_player {
-                    match &result {
-                        PlayResult::Hit(_) => player.play_cheer_single(),
-                        _ => {}
+                    if let PlayResult::Hit(_) = &result {
+                        player.play_cheer_single();
                     }
                 }
                 
-                process_play_result(state, &result, audio_player);
+                process_play_result(state, engine, &result, audio_player);
                 state.fielding_cursor = None;
                 state.pitch_state = PitchState::ShowResult {
                     result,
@@ -225,30 +295,142 @@ pub fn update_game_state(
                 };
             }
         }
-        PitchState::BallInPlay { frames_left } => {
-            *frames_left -= 1;
-            if *frames_left == 0 {
-                // Ball play resolved - continue
-                state.pitch_state = PitchState::ChoosePitch;
+        PitchState::UmpireCall { result, frames_left } => {
+            let expired = run_down(frames_left, dead_time);
+            if expired {
+                let result = result.clone();
+                reveal_call(state, engine, result, audio_player);
             }
         }
         PitchState::ShowResult { frames_left, .. } => {
-            *frames_left -= 1;
-            if *frames_left == 0 {
+            let expired = run_down(frames_left, dead_time);
+            if expired {
                 // Auto-continue after timeout
                 input_state.reset();
                 state.pitch_state = PitchState::ChoosePitch;
                 state.pitch_location = None;
                 state.swing_location = None;
                 state.swing_timing = SwingTiming::NoSwing;
-                state.message = "Choose your pitch!".to_string();
+                state.message = state.pitch_prompt();
             }
         }
         _ => {}
     }
 }
 
-pub fn process_play_result(state: &mut GameState, result: &PlayResult, audio_player: Option<&AudioPlayer>) {
+/// Take `frames` off a countdown, stopping at zero, and say whether it has run out. A timer
+/// entered at zero, or overshot by a fast-forwarded frame, expires rather than wrapping around.
+fn run_down<T: Copy + Into<u16> + TryFrom<u16>>(frames_left: &mut T, frames: u16) -> bool {
+    let left = (*frames_left).into().saturating_sub(frames);
+    if let Ok(left) = T::try_from(left) {
+        *frames_left = left;
+    }
+    left == 0
+}
+
+/// Frames the dead time between plays - the pitch clock, the swing animation and the result on
+/// screen - runs down this frame, for the game speed and fast-forward. The swing and fielding
+/// windows never go through here, so they're the same length at every speed.
+fn dead_time_frames(state: &mut GameState) -> u16 {
+    let fast_forward = state.fast_forward_frames > 0;
+    state.fast_forward_frames = state.fast_forward_frames.saturating_sub(1);
+    let Some(percent) = state.config.game_speed.percent() else {
+        return u16::MAX;
+    };
+    let percent = if fast_forward { percent.saturating_mul(state.config.fast_forward.max(1)) } else { percent };
+    let progress = u32::from(state.dead_time_progress) + u32::from(percent);
+    state.dead_time_progress = (progress % 100) as u16;
+    u16::try_from(progress / 100).unwrap_or(u16::MAX)
+}
+
+/// Count down a random matchup and lock both teams in when it stops cycling
+fn update_matchup_roll(state: &mut GameState) {
+    if let GameMode::TeamSelection { selected_home, selected_away, roll, .. } = &mut state.mode {
+        let Some(active) = roll else {
+            return;
+        };
+        if run_down(&mut active.frames_left, 1) {
+            state.message = format!("{} - press SPACE or ENTER to play ball!", active.reveal);
+            *selected_away = Some(active.away.clone());
+            *selected_home = Some(active.home.clone());
+            *roll = None;
+        }
+    }
+}
+
+/// Pitch clock violation - an automatic ball on the pitcher or an automatic strike on the batter,
+/// charged without a pitch being thrown
+pub fn call_clock_violation(state: &mut GameState, engine: &GameEngine, result: PlayResult, audio_player: Option<&AudioPlayer>) {
+    let offender = if result == PlayResult::Ball { "pitcher" } else { "batter" };
+    state.pitcher_clock_used = 0;
+    state.pitch_location = None;
+    state.swing_location = None;
+    state.swing_timing = SwingTiming::NoSwing;
+    process_play_result(state, engine, &result, audio_player);
+    state.message = format!("Pitch clock violation on the {}! {}", offender, state.message);
+    state.pitch_state = PitchState::ShowResult {
+        result,
+        frames_left: RESULT_DISPLAY_FRAMES,
+    };
+}
+
+/// The pitcher balked coming set - runners move up and the pitch never happens
+pub fn call_balk(state: &mut GameState, logger: &GameLogger) {
+    let runs = state.balk();
+    let half_str = match state.half {
+        InningHalf::Top => "Top",
+        InningHalf::Bottom => "Bottom",
+    };
+    logger.log_balk(state.inning, half_str, state.get_current_pitcher(), runs);
+    state.pitch_location = None;
+    state.pitch_state = PitchState::ChoosePitch;
+    state.message = match runs {
+        0 => "Balk! The runners move up a base".to_string(),
+        _ => "Balk! The runners move up and a run scores".to_string(),
+    };
+}
+
+/// Batter lets the pitch go - it's called on location alone
+fn take_pitch(state: &mut GameState) {
+    state.swing_timing = SwingTiming::NoSwing;
+    state.record_pitch();
+    let pitch_loc = state.pitch_location.unwrap_or(PitchLocation::Middle);
+
+    let result = if pitch_loc.is_strike() {
+        PlayResult::Strike
+    } else {
+        PlayResult::Ball
+    };
+
+    // The umpire takes a beat before the count changes
+    state.message = "Taken... and the call is".to_string();
+    state.pitch_state = PitchState::UmpireCall {
+        result,
+        frames_left: UMPIRE_CALL_FRAMES,
+    };
+}
+
+/// The umpire's call on a taken pitch - when the pause runs out, or straight away on a keypress
+pub fn reveal_call(state: &mut GameState, engine: &GameEngine, result: PlayResult, audio_player: Option<&AudioPlayer>) {
+    process_play_result(state, engine, &result, audio_player);
+    if state.practice.is_none() {
+        state.message = format!("Taken! {}", state.message);
+    }
+    state.pitch_state = PitchState::ShowResult {
+        result,
+        frames_left: RESULT_DISPLAY_FRAMES,
+    };
+}
+
+pub fn process_play_result(state: &mut GameState, engine: &GameEngine, result: &PlayResult, audio_player: Option<&AudioPlayer>) {
+    // Practice keeps no count, outs or score - every pitch is tallied on its own
+    if let Some(session) = &mut state.practice {
+        state.message = session.record_result(result, state.pitch_location);
+        state.balls = 0;
+        state.strikes = 0;
+        return;
+    }
+
     match result {
         PlayResult::Strike => {
             state.strikes += 1;
@@ -292,8 +474,8 @@ pub fn process_play_result(state: &mut GameState, result: &PlayResult, audio_pla
                 HitType::Triple => "Triple!".to_string(),
                 HitType::HomeRun => "HOME RUN!".to_string(),
             };
-            state.advance_runners(bases);
-            state.advance_batter();
+            state.inning_hits += 1;
+            state.add_hit(bases, &mut *engine.rng());
         }
         PlayResult::Out(out_type) => {
             state.message = match out_type {
@@ -301,8 +483,17 @@ pub fn process_play_result(state: &mut GameState, result: &PlayResult, audio_pla
                 OutType::Groundout => "Groundout!".to_string(),
                 OutType::Flyout => "Fly out!".to_string(),
                 OutType::LineOut => "Line out!".to_string(),
+                OutType::InfieldFly => "Infield fly rule - batter is out!".to_string(),
+                OutType::FieldersChoice => "Fielder's choice! Out at second, batter safe at first".to_string(),
             };
-            state.add_out();
+            match out_type {
+                OutType::Strikeout => state.add_strikeout(),
+                OutType::Flyout => state.add_batted_out(true),
+                // Runners hold on an infield fly
+                OutType::Groundout | OutType::LineOut | OutType::InfieldFly => state.add_batted_out(false),
+                OutType::FieldersChoice => state.add_fielders_choice(),
+            }
         }
+        PlayResult::ReachedOnError => state.add_error(),
     }
 }
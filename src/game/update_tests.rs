@@ -0,0 +1,433 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::engine::SpraySide;
+    use crate::game::state::{BallInPlay, BallType, FieldDirection};
+    use crate::game::update::update_game_state;
+    use crate::config::GameSpeed;
+    use crate::game::{constants::*, test_support, GameEngine, GameState, PitchLocation, PitchState, PlayResult, SwingTiming};
+    use crate::input::InputState;
+    use crate::logger::GameLogger;
+    use crate::roster::CustomRoster;
+    use crate::team::TeamManager;
+
+    fn playing_state(pitch_state: PitchState) -> GameState {
+        let team = CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON);
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TST".to_string(), team);
+        let mut state = GameState::new();
+        state.start_game(&teams, "TST".to_string(), "TST".to_string());
+        state.pitch_location = Some(PitchLocation::Middle);
+        state.pitch_state = pitch_state;
+        state
+    }
+
+    fn tick(state: &mut GameState) {
+        tick_with(state, &GameEngine::with_seed(1585));
+    }
+
+    fn tick_with(state: &mut GameState, engine: &GameEngine) {
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_update_tests.log").to_string_lossy());
+        update_game_state(state, engine, &mut InputState::new(), None, &logger);
+    }
+
+    #[test]
+    fn test_countdowns_entered_at_zero_expire_instead_of_wrapping() {
+        let exhausted = [
+            PitchState::PitchClock { frames_left: 0, pitch_type: 0 },
+            PitchState::BallApproaching { frames_left: 0, approach_frames: BALL_APPROACH_FRAMES, ball_position: 1.0, pitch_type: 0, can_swing: true },
+            PitchState::Swinging { frames_left: 0, swing_timing: SwingTiming::Perfect },
+            PitchState::UmpireCall { result: PlayResult::Ball, frames_left: 0 },
+            PitchState::ShowResult { result: PlayResult::Ball, frames_left: 0 },
+        ];
+        for pitch_state in exhausted {
+            let before = format!("{:?}", pitch_state);
+            let mut state = playing_state(pitch_state);
+            tick(&mut state);
+            let after = format!("{:?}", state.pitch_state);
+            assert_ne!(after, before, "{} never expired", before);
+            assert!(!after.contains("frames_left: 255"), "{} wrapped to {}", before, after);
+        }
+    }
+
+    #[test]
+    fn test_countdowns_on_their_last_frame_expire_on_the_next_tick() {
+        let last_frame = [
+            PitchState::PitchClock { frames_left: 1, pitch_type: 0 },
+            PitchState::BallApproaching { frames_left: 1, approach_frames: BALL_APPROACH_FRAMES, ball_position: 0.9, pitch_type: 0, can_swing: false },
+            PitchState::Swinging { frames_left: 1, swing_timing: SwingTiming::Perfect },
+            PitchState::UmpireCall { result: PlayResult::Ball, frames_left: 1 },
+            PitchState::ShowResult { result: PlayResult::Ball, frames_left: 1 },
+        ];
+        for pitch_state in last_frame {
+            let before = format!("{:?}", pitch_state);
+            let mut state = playing_state(pitch_state);
+            state.config.game_speed = GameSpeed::Normal;
+            tick(&mut state);
+            let after = format!("{:?}", state.pitch_state);
+            assert_ne!(after, before, "{} didn't expire", before);
+            assert!(!after.contains("frames_left: 0,"), "{} stuck at zero as {}", before, after);
+        }
+    }
+
+    #[test]
+    fn test_fast_forward_overshooting_a_countdown_expires_it() {
+        for frames_left in [0, 1, 2] {
+            for pitch_state in [
+                PitchState::Swinging { frames_left, swing_timing: SwingTiming::Perfect },
+                PitchState::ShowResult { result: PlayResult::Ball, frames_left },
+            ] {
+                let before = std::mem::discriminant(&pitch_state);
+                let mut state = playing_state(pitch_state);
+                state.config.game_speed = GameSpeed::Fast;
+                state.fast_forward_frames = u8::MAX;
+                tick(&mut state);
+                assert_ne!(std::mem::discriminant(&state.pitch_state), before, "{:?} with {} left survived", state.pitch_state, frames_left);
+            }
+        }
+    }
+
+    #[test]
+    fn test_taken_pitch_is_called_after_the_umpires_pause() {
+        let mut state = playing_state(PitchState::BallApproaching {
+            frames_left: 1,
+            approach_frames: BALL_APPROACH_FRAMES,
+            ball_position: 0.9,
+            pitch_type: 0,
+            can_swing: true,
+        });
+        state.config.game_speed = GameSpeed::Normal;
+        tick(&mut state);
+        assert_eq!(state.pitch_state, PitchState::UmpireCall { result: PlayResult::Strike, frames_left: UMPIRE_CALL_FRAMES });
+
+        // The count waits for the call
+        for _ in 1..UMPIRE_CALL_FRAMES {
+            tick(&mut state);
+            assert!(matches!(state.pitch_state, PitchState::UmpireCall { .. }), "{:?}", state.pitch_state);
+            assert_eq!(state.strikes, 0);
+        }
+        tick(&mut state);
+        assert_eq!(state.pitch_state, PitchState::ShowResult { result: PlayResult::Strike, frames_left: RESULT_DISPLAY_FRAMES });
+        assert_eq!(state.strikes, 1);
+        assert!(state.message.starts_with("Taken! Strike 1"), "{}", state.message);
+    }
+
+    #[test]
+    fn test_a_keypress_skips_the_umpires_pause() {
+        let mut state = playing_state(PitchState::UmpireCall { result: PlayResult::Ball, frames_left: UMPIRE_CALL_FRAMES });
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_update_tests.log").to_string_lossy());
+        let mut teams = TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        let engine = GameEngine::with_seed(1613);
+        crate::game::handle_input(&mut state, &mut teams, &engine, &mut InputState::new(), crate::input::GameInput::Action, None, &logger);
+        assert_eq!(state.pitch_state, PitchState::ShowResult { result: PlayResult::Ball, frames_left: RESULT_DISPLAY_FRAMES });
+        assert_eq!(state.balls, 1);
+    }
+
+    #[test]
+    fn test_fielding_timer_saturates_and_resolves() {
+        let mut state = playing_state(PitchState::ChoosePitch);
+        let ball = BallInPlay {
+            ball_type: BallType::FlyBall,
+            direction: FieldDirection::CenterField,
+            speed: 80.0,
+            hang_time: u8::MAX,
+            initial_contact_quality: 50,
+            hr_chance: HR_CHANCE_LEAGUE_AVG,
+            speed_factor: 1.0,
+            spray: SpraySide::Center,
+        };
+        state.pitch_state = PitchState::Fielding { ball_in_play: ball, frames_elapsed: u8::MAX };
+        tick(&mut state);
+        assert!(matches!(state.pitch_state, PitchState::ShowResult { .. }));
+    }
+
+    #[test]
+    fn test_batted_balls_are_decided_by_the_fielding_play_alone() {
+        let mut fielded = 0;
+        for seed in 0..300 {
+            let engine = GameEngine::with_seed(seed);
+            let mut state = playing_state(PitchState::Swinging { frames_left: 1, swing_timing: SwingTiming::Perfect });
+            state.swing_location = Some(PitchLocation::Middle);
+            tick_with(&mut state, &engine);
+
+            let ball = match &state.pitch_state {
+                PitchState::Fielding { ball_in_play, .. } => ball_in_play.clone(),
+                PitchState::ShowResult { result, .. } => {
+                    assert!(!result.is_batted_ball(), "{:?} skipped the fielders", result);
+                    continue;
+                }
+                other => panic!("unexpected state after the swing: {:?}", other),
+            };
+            assert_eq!(state.inning_hits, 0, "a hit was counted before the ball was fielded");
+
+            // Nobody presses SPACE, so the fielder plays it alone
+            while matches!(state.pitch_state, PitchState::Fielding { .. }) {
+                tick_with(&mut state, &engine);
+            }
+            let PitchState::ShowResult { result, .. } = &state.pitch_state else {
+                panic!("fielding didn't end in a result: {:?}", state.pitch_state);
+            };
+            if *result == PlayResult::Foul {
+                assert!(ball.direction.is_foul(), "a fair ball came back foul");
+                assert!(state.spray_chart.balls.is_empty(), "a dropped foul pop was charted");
+                continue;
+            }
+            let recorded = state.spray_chart.balls.last().expect("ball in play wasn't charted");
+            assert_eq!(&recorded.result, result);
+            assert_eq!(recorded.ball_type, ball.ball_type);
+            if let PlayResult::Out(out_type) = result {
+                assert_eq!(*out_type, GameEngine::caught_out_type(&ball.ball_type));
+            }
+            fielded += 1;
+        }
+        assert!(fielded > 0, "no ball was ever put in play");
+    }
+
+    /// Frames until `pitch_state` gives way to something else
+    fn frames_in(mut state: GameState, leaving: fn(&PitchState) -> bool) -> u32 {
+        let engine = GameEngine::with_seed(1602);
+        let mut frames = 0;
+        while !leaving(&state.pitch_state) {
+            tick_with(&mut state, &engine);
+            frames += 1;
+            assert!(frames < 10_000, "stuck at {:?}", state.pitch_state);
+        }
+        frames
+    }
+
+    fn result_state(speed: GameSpeed, fast_forward: bool) -> GameState {
+        let mut state = playing_state(PitchState::ShowResult { result: PlayResult::Ball, frames_left: RESULT_DISPLAY_FRAMES });
+        state.config.game_speed = speed;
+        if fast_forward {
+            state.fast_forward_frames = u8::MAX;
+        }
+        state
+    }
+
+    #[test]
+    fn test_game_speed_scales_the_result_screen() {
+        let gone = |p: &PitchState| matches!(p, PitchState::ChoosePitch);
+        let normal = frames_in(result_state(GameSpeed::Normal, false), gone);
+        assert_eq!(normal, RESULT_DISPLAY_FRAMES as u32);
+        assert_eq!(frames_in(result_state(GameSpeed::Slow, false), gone), normal * 2);
+        assert_eq!(frames_in(result_state(GameSpeed::Fast, false), gone), normal / 2);
+        assert_eq!(frames_in(result_state(GameSpeed::Normal, true), gone), normal / FAST_FORWARD_MULTIPLIER as u32 + 1);
+        assert_eq!(frames_in(result_state(GameSpeed::Instant, false), gone), 1);
+    }
+
+    #[test]
+    fn test_fast_forward_multiplier_is_configurable() {
+        let gone = |p: &PitchState| matches!(p, PitchState::ChoosePitch);
+        let frames: Vec<u32> = FAST_FORWARD_CHOICES
+            .iter()
+            .map(|&multiplier| {
+                let mut state = result_state(GameSpeed::Normal, true);
+                state.config.fast_forward = multiplier;
+                frames_in(state, gone)
+            })
+            .collect();
+        assert!(frames.windows(2).all(|pair| pair[0] > pair[1]), "{:?}", frames);
+        assert!(frames[0] < RESULT_DISPLAY_FRAMES as u32);
+
+        // 1x is the same as not holding F at all
+        let mut state = result_state(GameSpeed::Normal, true);
+        state.config.fast_forward = 1;
+        assert_eq!(frames_in(state, gone), RESULT_DISPLAY_FRAMES as u32);
+    }
+
+    #[test]
+    fn test_out_of_range_fast_forward_expires_the_countdown() {
+        let gone = |p: &PitchState| matches!(p, PitchState::ChoosePitch);
+        let mut state = result_state(GameSpeed::Fast, true);
+        state.config.fast_forward = u16::MAX;
+        state.dead_time_progress = 99; // Left over from a slower frame
+        assert_eq!(frames_in(state, gone), 1);
+    }
+
+    #[test]
+    fn test_swing_window_is_the_same_length_at_every_speed() {
+        let approaching = || PitchState::BallApproaching {
+            frames_left: BALL_APPROACH_FRAMES,
+            approach_frames: BALL_APPROACH_FRAMES,
+            ball_position: 0.0,
+            pitch_type: 0,
+            can_swing: false,
+        };
+        let past_the_plate = |p: &PitchState| !matches!(p, PitchState::BallApproaching { .. });
+        let mut lengths = Vec::new();
+        for (speed, fast_forward) in [(GameSpeed::Normal, false), (GameSpeed::Instant, true), (GameSpeed::Slow, false), (GameSpeed::Fast, true)] {
+            let mut state = playing_state(approaching());
+            state.config.game_speed = speed;
+            state.fast_forward_frames = if fast_forward { u8::MAX } else { 0 };
+            lengths.push(frames_in(state, past_the_plate));
+        }
+        assert!(lengths.iter().all(|&frames| frames == BALL_APPROACH_FRAMES as u32), "{:?}", lengths);
+    }
+
+    /// What a save has to carry over: the pitcher's pitches, the logged pitch number, stamina
+    /// and the count
+    fn tally(state: &GameState) -> (u16, u32, f32, u8, u8) {
+        let pitches = state.box_score.pitcher(&state.current_pitcher_key()).map_or(0, |line| line.pitches);
+        let stamina = state.get_current_pitching_team().unwrap().pitcher_stamina;
+        (pitches, state.pitch_count, stamina, state.balls, state.strikes)
+    }
+
+    /// A fixture game with a pitch on the way over the middle, swung at or not
+    fn pitch_in_flight(pitch_state: PitchState) -> GameState {
+        let mut state = test_support::started_game(&mut test_support::league_manager());
+        state.config.game_speed = GameSpeed::Normal;
+        state.pitch_location = Some(PitchLocation::Middle);
+        if matches!(pitch_state, PitchState::Swinging { .. }) {
+            state.swing_location = Some(PitchLocation::Middle);
+        }
+        state.pitch_state = pitch_state;
+        state
+    }
+
+    /// Play on until `stop` matches, giving up if the pitch is over first
+    fn play_until(state: &mut GameState, engine: &GameEngine, stop: fn(&PitchState) -> bool) -> bool {
+        for _ in 0..1_000 {
+            if stop(&state.pitch_state) {
+                return true;
+            }
+            if state.pitch_state == PitchState::ChoosePitch {
+                return false;
+            }
+            tick_with(state, engine);
+        }
+        false
+    }
+
+    #[test]
+    fn test_saving_before_the_pitch_is_charged_keeps_the_tally() {
+        let engine = GameEngine::with_seed(1605);
+        for pitch_state in [
+            PitchState::Aiming { pitch_type: 0 },
+            PitchState::PitchClock { frames_left: 30, pitch_type: 0 },
+            PitchState::BallApproaching { frames_left: 10, approach_frames: BALL_APPROACH_FRAMES, ball_position: 0.5, pitch_type: 0, can_swing: true },
+            PitchState::Swinging { frames_left: 10, swing_timing: SwingTiming::Perfect },
+        ] {
+            let mut state = pitch_in_flight(pitch_state.clone());
+            state.balls = 2;
+            let before = tally(&state);
+            tick_with(&mut state, &engine);
+            assert_eq!(std::mem::discriminant(&state.pitch_state), std::mem::discriminant(&pitch_state));
+
+            state.pre_save_normalize(&engine);
+            assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+            assert_eq!(tally(&state), before, "saved during {:?}", pitch_state);
+        }
+    }
+
+    #[test]
+    fn test_saving_with_the_ball_in_play_gives_the_pitch_back() {
+        let fielding = |p: &PitchState| matches!(p, PitchState::Fielding { .. });
+        let (mut state, engine) = (1..100)
+            .map(|seed| (pitch_in_flight(PitchState::Swinging { frames_left: 1, swing_timing: SwingTiming::Perfect }), GameEngine::with_seed(seed)))
+            .find_map(|(mut state, engine)| play_until(&mut state, &engine, fielding).then_some((state, engine)))
+            .expect("no seed put the ball in play");
+        let (pitches, pitch_count, stamina, balls, strikes) = tally(&state);
+        assert_eq!((pitches, pitch_count), (1, 1), "the swing was charged");
+        assert!(stamina < STARTING_STAMINA);
+
+        // The pitch will be thrown again, so none of it sticks
+        state.pre_save_normalize(&engine);
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+        assert_eq!(tally(&state), (0, 0, STARTING_STAMINA, balls, strikes));
+        assert_eq!(state.fielding_cursor, None);
+    }
+
+    #[test]
+    fn test_saving_during_the_call_or_the_result_settles_the_pitch() {
+        let approaching = || {
+            pitch_in_flight(PitchState::BallApproaching { frames_left: 1, approach_frames: BALL_APPROACH_FRAMES, ball_position: 0.9, pitch_type: 0, can_swing: true })
+        };
+        let engine = GameEngine::with_seed(1605);
+        let before = tally(&approaching());
+        let settled = (before.0 + 1, before.1, before.2, before.3, before.4 + 1);
+
+        let stops: [fn(&PitchState) -> bool; 2] = [
+            |p| matches!(p, PitchState::UmpireCall { .. }),
+            |p| matches!(p, PitchState::ShowResult { .. }),
+        ];
+        for stop in stops {
+            let mut state = approaching();
+            assert!(play_until(&mut state, &engine, stop));
+            state.pre_save_normalize(&engine);
+            assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+            assert_eq!(tally(&state), settled, "the taken strike counts once");
+        }
+    }
+
+    /// A fixture game with the pitch clock and the pitcher's clock set to the given lengths
+    fn clocked_game(pitch_clock_frames: u16, pitcher_clock_frames: u16) -> GameState {
+        let mut state = test_support::started_game(&mut test_support::league_manager());
+        state.config.pitch_clock_frames = pitch_clock_frames;
+        state.config.pitcher_clock_frames = pitcher_clock_frames;
+        state
+    }
+
+    #[test]
+    fn test_pitcher_clock_expiry_calls_a_ball() {
+        let engine = GameEngine::with_seed(3);
+        let mut state = clocked_game(PITCH_CLOCK_FRAMES, 10);
+        state.pitch_state = PitchState::Aiming { pitch_type: 0 };
+
+        for _ in 0..9 {
+            tick_with(&mut state, &engine);
+        }
+        assert!(matches!(state.pitch_state, PitchState::Aiming { .. }));
+
+        tick_with(&mut state, &engine);
+        assert!(matches!(
+            state.pitch_state,
+            PitchState::ShowResult { result: PlayResult::Ball, .. }
+        ));
+        assert_eq!(state.balls, 1);
+        assert_eq!(state.strikes, 0);
+    }
+
+    #[test]
+    fn test_pitcher_clock_of_zero_never_expires() {
+        let engine = GameEngine::with_seed(4);
+        let mut state = clocked_game(PITCH_CLOCK_FRAMES, 0);
+
+        for _ in 0..PITCHER_CLOCK_FRAMES * 2 {
+            tick_with(&mut state, &engine);
+        }
+
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+        assert_eq!(state.balls, 0);
+    }
+
+    #[test]
+    fn test_batter_stepping_out_on_the_clock_is_a_strike() {
+        let engine = GameEngine::with_seed(5);
+        let mut manager = test_support::league_manager();
+        let mut state = test_support::started_game(&mut manager);
+        state.pitch_location = Some(PitchLocation::Middle);
+        state.pitch_state = PitchState::PitchClock { frames_left: 30, pitch_type: 0 };
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_update_tests.log").to_string_lossy());
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut InputState::new(), crate::input::GameInput::StepOut, None, &logger);
+
+        assert!(matches!(
+            state.pitch_state,
+            PitchState::ShowResult { result: PlayResult::Strike, .. }
+        ));
+        assert_eq!(state.strikes, 1);
+    }
+
+    #[test]
+    fn test_configured_pitch_clock_length_is_used() {
+        let engine = GameEngine::with_seed(6);
+        let mut state = clocked_game(5, PITCHER_CLOCK_FRAMES);
+        state.pitch_location = Some(PitchLocation::Middle);
+        state.pitch_state = PitchState::PitchClock { frames_left: state.config.pitch_clock_frames, pitch_type: 0 };
+
+        for _ in 0..5 {
+            tick_with(&mut state, &engine);
+        }
+
+        assert!(matches!(state.pitch_state, PitchState::BallApproaching { .. }));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::game::constants::*;
+use rand::Rng;
+
+/// Conditions for a game, rolled when it starts. Parks with a roof always play as a dome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weather {
+    #[default]
+    Clear,
+    WindOut,
+    WindIn,
+    Rain,
+    Dome,
+}
+
+impl Weather {
+    pub fn roll<R: Rng>(rng: &mut R, has_roof: bool) -> Weather {
+        if has_roof {
+            return Weather::Dome;
+        }
+        let roll: f64 = rng.gen();
+        if roll < WEATHER_WIND_OUT_CHANCE {
+            Weather::WindOut
+        } else if roll < WEATHER_WIND_OUT_CHANCE + WEATHER_WIND_IN_CHANCE {
+            Weather::WindIn
+        } else if roll < WEATHER_WIND_OUT_CHANCE + WEATHER_WIND_IN_CHANCE + WEATHER_RAIN_CHANCE {
+            Weather::Rain
+        } else {
+            Weather::Clear
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "Clear",
+            Weather::WindOut => "Wind blowing out",
+            Weather::WindIn => "Wind blowing in",
+            Weather::Rain => "Rain",
+            Weather::Dome => "Dome",
+        }
+    }
+
+    /// Home run multiplier, on top of the park's
+    pub fn hr_factor(&self) -> f32 {
+        match self {
+            Weather::WindOut => WIND_OUT_HR_FACTOR,
+            Weather::WindIn => WIND_IN_HR_FACTOR,
+            Weather::Rain => RAIN_HR_FACTOR,
+            Weather::Clear | Weather::Dome => 1.0,
+        }
+    }
+
+    /// Multiplier on a fly ball's speed off the bat - the stand-in for how far it carries
+    pub fn fly_ball_speed_factor(&self) -> f32 {
+        match self {
+            Weather::WindOut => WIND_OUT_FLY_SPEED_FACTOR,
+            Weather::WindIn => WIND_IN_FLY_SPEED_FACTOR,
+            _ => 1.0,
+        }
+    }
+
+    pub fn grounder_speed_factor(&self) -> f32 {
+        match self {
+            Weather::Rain => RAIN_GROUNDER_SPEED_FACTOR,
+            _ => 1.0,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use crate::game::PitchLocation;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum GameInput {
@@ -8,13 +10,34 @@ pub enum GameInput {
     Left,
     Right,
     Action,  // Space or Enter - context dependent (pitch/swing)
+    CycleAim, // Tab - step the aim through the nine locations
     SelectPitch(usize),
+    GuessPitch(u8), // A pitch key pressed while the pitch is on its way - calling what it is
     SelectAwayTeam,
     SelectHomeTeam,
     NumberInput(char),
+    StartSearch,
+    TextInput(char),
+    Backspace,
+    PinchRun,
+    DefensiveSub,
+    Bullpen,
+    SimInning,
+    StepOut,
+    RandomMatchup,
+    Help,
+    BalancedMatchup,
+    ToggleSort,
+    Practice,
+    FastForward,
+    Watch,
+    TogglePreview,
+    Leaders,
+    Settings,
     Pause,
     Quit,
     DirectPosition(u8), // Numpad 1-9 for direct strike zone selection
+    Resize, // Terminal was resized - nothing to handle, but the screen must be redrawn
 }
 
 /// Input mode state for team selection
@@ -23,12 +46,19 @@ pub enum TeamSelectionInputMode {
     None,
     AwaitingAwayNumber,
     AwaitingHomeNumber,
+    Searching,
 }
 
 pub struct InputPoller {
     team_selection_mode: TeamSelectionInputMode,
 }
 
+impl Default for InputPoller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl InputPoller {
     pub fn new() -> Self {
         Self {
@@ -38,16 +68,18 @@ impl InputPoller {
 
     pub fn poll_input(&mut self, poll_timeout_ms: u64) -> Result<Option<GameInput>, std::io::Error> {
         if event::poll(Duration::from_millis(poll_timeout_ms))? {
-            if let Event::Key(key_event) = event::read()? {
-                if key_event.kind == crossterm::event::KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key_event) if key_event.kind == crossterm::event::KeyEventKind::Press => {
                     return Ok(self.parse_key_input(key_event));
                 }
+                Event::Resize(..) => return Ok(Some(GameInput::Resize)),
+                _ => {}
             }
         }
         Ok(None)
     }
 
-    fn parse_key_input(&mut self, key_event: KeyEvent) -> Option<GameInput> {
+    pub(crate) fn parse_key_input(&mut self, key_event: KeyEvent) -> Option<GameInput> {
         // Check if we're waiting for a number after A or H
         match &self.team_selection_mode {
             TeamSelectionInputMode::AwaitingAwayNumber | TeamSelectionInputMode::AwaitingHomeNumber => {
@@ -61,12 +93,34 @@ impl InputPoller {
                 } else if key_event.code == KeyCode::Enter {
                     self.team_selection_mode = TeamSelectionInputMode::None;
                     return Some(GameInput::Action);
+                } else if key_event.code == KeyCode::Backspace {
+                    return Some(GameInput::Backspace);
                 } else if key_event.code == KeyCode::Esc {
                     self.team_selection_mode = TeamSelectionInputMode::None;
-                    return None;
+                    return Some(GameInput::Pause);
                 }
                 return None;
             }
+            TeamSelectionInputMode::Searching => {
+                // Every printable key is part of the query until Enter or Esc
+                match key_event.code {
+                    KeyCode::Char(c) => return Some(GameInput::TextInput(c)),
+                    KeyCode::Backspace => return Some(GameInput::Backspace),
+                    KeyCode::Up => return Some(GameInput::Up),
+                    KeyCode::Down => return Some(GameInput::Down),
+                    KeyCode::Left => return Some(GameInput::Left),
+                    KeyCode::Right => return Some(GameInput::Right),
+                    KeyCode::Enter => {
+                        self.team_selection_mode = TeamSelectionInputMode::None;
+                        return Some(GameInput::Action);
+                    }
+                    KeyCode::Esc => {
+                        self.team_selection_mode = TeamSelectionInputMode::None;
+                        return Some(GameInput::Pause);
+                    }
+                    _ => return None,
+                }
+            }
             TeamSelectionInputMode::None => {
                 // Normal input processing
             }
@@ -78,17 +132,18 @@ impl InputPoller {
             KeyCode::Left => Some(GameInput::Left),
             KeyCode::Right => Some(GameInput::Right),
             KeyCode::Char(' ') | KeyCode::Enter => Some(GameInput::Action),
+            KeyCode::Tab => Some(GameInput::CycleAim),
             KeyCode::Char('q') | KeyCode::Char('Q') => Some(GameInput::Quit),
             KeyCode::Esc => Some(GameInput::Pause),
             
             // Regular number keys (1-4) for pitch selection
-            KeyCode::Char(c) if c >= '1' && c <= '4' && !key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(c) if ('1'..='4').contains(&c) && !key_event.modifiers.contains(KeyModifiers::SHIFT) => {
                 let num = c.to_digit(10).unwrap() as usize;
                 Some(GameInput::SelectPitch(num - 1))
             }
             
             // SHIFT + number keys (1-9) for direct aiming (simulates numpad)
-            KeyCode::Char(c) if c >= '1' && c <= '9' && key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(c) if ('1'..='9').contains(&c) && key_event.modifiers.contains(KeyModifiers::SHIFT) => {
                 let num = c.to_digit(10).unwrap() as u8;
                 Some(GameInput::DirectPosition(num))
             }
@@ -99,6 +154,55 @@ impl InputPoller {
                 Some(GameInput::SelectAwayTeam)
             }
             
+            // Handle R to pinch run for the lead runner
+            KeyCode::Char('r') | KeyCode::Char('R') => Some(GameInput::PinchRun),
+
+            // Handle D for a defensive replacement
+            KeyCode::Char('d') | KeyCode::Char('D') => Some(GameInput::DefensiveSub),
+
+            // Handle C to call the bullpen
+            KeyCode::Char('c') | KeyCode::Char('C') => Some(GameInput::Bullpen),
+
+            // Handle S to quick-sim the rest of the half inning
+            KeyCode::Char('s') | KeyCode::Char('S') => Some(GameInput::SimInning),
+
+            // Handle O for the batter stepping out of the box
+            KeyCode::Char('o') | KeyCode::Char('O') => Some(GameInput::StepOut),
+
+            // Handle F1 for the help screen - so does ? once a game is on
+            KeyCode::F(1) => Some(GameInput::Help),
+
+            // Handle F2 for the settings screen
+            KeyCode::F(2) => Some(GameInput::Settings),
+
+            // Handle ? for a random matchup and B for a balanced one
+            KeyCode::Char('?') => Some(GameInput::RandomMatchup),
+            KeyCode::Char('b') | KeyCode::Char('B') => Some(GameInput::BalancedMatchup),
+
+            // Handle T to switch the team lists between alphabetical and rating order
+            KeyCode::Char('t') | KeyCode::Char('T') => Some(GameInput::ToggleSort),
+
+            // Handle F to fast-forward to the next pitch
+            KeyCode::Char('f') | KeyCode::Char('F') => Some(GameInput::FastForward),
+
+            // Handle W to watch the CPU play both sides
+            KeyCode::Char('w') | KeyCode::Char('W') => Some(GameInput::Watch),
+
+            // Handle V to show or hide the pitch preview
+            KeyCode::Char('v') | KeyCode::Char('V') => Some(GameInput::TogglePreview),
+
+            // Handle L for the career leaders
+            KeyCode::Char('l') | KeyCode::Char('L') => Some(GameInput::Leaders),
+
+            // Handle P to pick a practice mode instead of a game
+            KeyCode::Char('p') | KeyCode::Char('P') => Some(GameInput::Practice),
+
+            // Handle / for team search
+            KeyCode::Char('/') => {
+                self.team_selection_mode = TeamSelectionInputMode::Searching;
+                Some(GameInput::StartSearch)
+            }
+
             // Handle H for home team selection
             KeyCode::Char('h') | KeyCode::Char('H') => {
                 self.team_selection_mode = TeamSelectionInputMode::AwaitingHomeNumber;
@@ -110,33 +214,193 @@ impl InputPoller {
     }
 }
 
+/// Which part of the game a key binding belongs to, for the help screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPhase {
+    TeamSelection,
+    Pitching,
+    Batting,
+    Fielding,
+    General,
+}
+
+impl KeyPhase {
+    pub const ALL: [KeyPhase; 5] = [
+        KeyPhase::TeamSelection,
+        KeyPhase::Pitching,
+        KeyPhase::Batting,
+        KeyPhase::Fielding,
+        KeyPhase::General,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyPhase::TeamSelection => "Team Selection",
+            KeyPhase::Pitching => "Pitching",
+            KeyPhase::Batting => "Batting",
+            KeyPhase::Fielding => "Fielding",
+            KeyPhase::General => "General",
+        }
+    }
+}
+
+/// One line of the help screen. `code` (with SHIFT if `shift`) is a press that triggers it,
+/// so the table can be checked against the key parser.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBinding {
+    pub phase: KeyPhase,
+    pub keys: &'static str,
+    pub action: &'static str,
+    pub code: KeyCode,
+    pub shift: bool,
+}
+
+const fn bind(phase: KeyPhase, keys: &'static str, action: &'static str, code: KeyCode) -> KeyBinding {
+    KeyBinding { phase, keys, action, code, shift: false }
+}
+
+/// Every key the game listens to, grouped by phase
+pub const KEY_BINDINGS: &[KeyBinding] = &[
+    bind(KeyPhase::TeamSelection, "A / H + number", "Pick the away / home team by number", KeyCode::Char('a')),
+    bind(KeyPhase::TeamSelection, "/", "Search teams by name", KeyCode::Char('/')),
+    bind(KeyPhase::TeamSelection, "?", "Surprise matchup", KeyCode::Char('?')),
+    bind(KeyPhase::TeamSelection, "B", "Balanced matchup", KeyCode::Char('b')),
+    bind(KeyPhase::TeamSelection, "T", "Sort teams by name or rating", KeyCode::Char('t')),
+    bind(KeyPhase::TeamSelection, "P", "Batting cage, bullpen or a game", KeyCode::Char('p')),
+    bind(KeyPhase::TeamSelection, "W", "Watch the CPU play both sides (a surprise matchup if none is picked)", KeyCode::Char('w')),
+    bind(KeyPhase::TeamSelection, "SPACE", "Lineup card, then S to play ball", KeyCode::Char(' ')),
+    bind(KeyPhase::Pitching, "1-4", "Pick a pitch", KeyCode::Char('1')),
+    bind(KeyPhase::Pitching, "Arrows", "Aim", KeyCode::Up),
+    bind(KeyPhase::Pitching, "TAB", "Step the aim through the zone", KeyCode::Tab),
+    KeyBinding { shift: true, ..bind(KeyPhase::Pitching, "SHIFT+1-9", "Aim and throw at a spot", KeyCode::Char('7')) },
+    bind(KeyPhase::Pitching, "SPACE", "Throw", KeyCode::Enter),
+    bind(KeyPhase::Pitching, "V", "Show or hide the odds of the aimed pitch", KeyCode::Char('v')),
+    bind(KeyPhase::Batting, "O", "Step out during the pitch clock", KeyCode::Char('o')),
+    bind(KeyPhase::Batting, "1-4", "Call the pitch on its way - right widens the perfect window, wrong narrows it", KeyCode::Char('2')),
+    bind(KeyPhase::Batting, "Arrows", "Aim the swing", KeyCode::Down),
+    KeyBinding { shift: true, ..bind(KeyPhase::Batting, "SHIFT+1-9", "Swing at a spot", KeyCode::Char('5')) },
+    bind(KeyPhase::Batting, "SPACE", "Swing - or do nothing to take", KeyCode::Char(' ')),
+    bind(KeyPhase::Fielding, "SPACE", "Make the play, best halfway through the hang time", KeyCode::Char(' ')),
+    bind(KeyPhase::General, "F1 / ?", "This screen - F1 on team selection, where ? is the surprise matchup. T from here replays the tutorial", KeyCode::F(1)),
+    bind(KeyPhase::General, "F2", "Settings - innings, CPU batters, pitch preview, batter's eye, colors, sound and speed", KeyCode::F(2)),
+    bind(KeyPhase::General, "L", "Career leaders from the games played here", KeyCode::Char('l')),
+    bind(KeyPhase::General, "F (hold)", "Fast-forward to the next pitch", KeyCode::Char('f')),
+    bind(KeyPhase::General, "S", "Quick-sim the half inning", KeyCode::Char('s')),
+    bind(KeyPhase::General, "SPACE / ESC", "Pause or resume a game you're watching", KeyCode::Esc),
+    bind(KeyPhase::General, "Left / Right", "Slower / faster while watching", KeyCode::Right),
+    bind(KeyPhase::General, "R", "Pinch run or pinch hit", KeyCode::Char('r')),
+    bind(KeyPhase::General, "D", "Defensive sub", KeyCode::Char('d')),
+    bind(KeyPhase::General, "C", "Call the bullpen - a pitching change or double switch", KeyCode::Char('c')),
+    bind(KeyPhase::General, "ESC", "Cancel a menu, skip the tutorial, end practice", KeyCode::Esc),
+    bind(KeyPhase::General, "Q", "Quit (press twice)", KeyCode::Char('q')),
+];
+
+/// Where the arrow keys are aiming. Each arrow steps the aim one spot that way - back off the
+/// opposite edge toward the middle, or out to the edge - and arrows on different axes combine,
+/// so up then left aims up and in from the middle. Two arrows on different axes pressed within
+/// `AIM_CHORD_WINDOW_MS` of each other are one diagonal press and aim straight at that corner,
+/// wherever the aim was. Terminals only send presses, and a held arrow sends a stream of them,
+/// so a press of the same arrow within the repeat window of its last one is the key repeating
+/// and is ignored.
 pub struct InputState {
     pub up: bool,
     pub down: bool,
     pub left: bool,
     pub right: bool,
+    repeat_window: Duration,
+    last_press: [Option<Instant>; 4], // Up, down, left, right - kept through reset so a key held across a pitch stays quiet
+    last_step: Option<(usize, Instant)>, // The last arrow that moved the aim, by `last_press` slot
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InputState {
     pub fn new() -> Self {
+        Self::with_repeat_window(Duration::from_millis(crate::game::constants::AIM_REPEAT_WINDOW_MS))
+    }
+
+    pub fn with_repeat_window(repeat_window: Duration) -> Self {
         Self {
             up: false,
             down: false,
             left: false,
             right: false,
+            repeat_window,
+            last_press: [None; 4],
+            last_step: None,
         }
     }
 
     pub fn update(&mut self, input: &GameInput) {
-        match input {
-            GameInput::Up => self.up = true,
-            GameInput::Down => self.down = true,
-            GameInput::Left => self.left = true,
-            GameInput::Right => self.right = true,
-            _ => {}
+        self.update_at(input, Instant::now());
+    }
+
+    /// Take an arrow press that arrived at `now`
+    pub fn update_at(&mut self, input: &GameInput, now: Instant) {
+        let slot = match input {
+            GameInput::Up => 0,
+            GameInput::Down => 1,
+            GameInput::Left => 2,
+            GameInput::Right => 3,
+            _ => return,
+        };
+        // Measured from the last press seen, so a held key stays quiet however long it's held
+        let repeating = self.last_press[slot].is_some_and(|last| now.saturating_duration_since(last) < self.repeat_window);
+        self.last_press[slot] = Some(now);
+        if repeating {
+            return;
+        }
+
+        // Up or down hard on the heels of left or right, or the other way round, is a diagonal
+        let chord_window = Duration::from_millis(crate::game::constants::AIM_CHORD_WINDOW_MS);
+        let chord = self
+            .last_step
+            .filter(|&(last, at)| last / 2 != slot / 2 && now.saturating_duration_since(at) < chord_window);
+        self.last_step = Some((slot, now));
+        if let Some((first, _)) = chord {
+            self.reset();
+            for pressed in [first, slot] {
+                match pressed {
+                    0 => self.up = true,
+                    1 => self.down = true,
+                    2 => self.left = true,
+                    _ => self.right = true,
+                }
+            }
+            return;
+        }
+
+        let (toward, away) = match input {
+            GameInput::Up => (&mut self.up, &mut self.down),
+            GameInput::Down => (&mut self.down, &mut self.up),
+            GameInput::Left => (&mut self.left, &mut self.right),
+            _ => (&mut self.right, &mut self.left),
+        };
+        if *away {
+            *away = false;
+        } else {
+            *toward = true;
         }
     }
 
+    /// Location the arrow keys currently point at
+    pub fn aimed_location(&self) -> PitchLocation {
+        PitchLocation::from_direction(self.up, self.down, self.left, self.right)
+    }
+
+    /// Point the arrow keys at `location`, replacing whatever was held
+    pub fn aim_at(&mut self, location: PitchLocation) {
+        let num = location.numpad();
+        self.up = num >= 7;
+        self.down = num <= 3;
+        self.left = num % 3 == 1;
+        self.right = num.is_multiple_of(3);
+    }
+
     pub fn reset(&mut self) {
         self.up = false;
         self.down = false;
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::AIM_CHORD_WINDOW_MS;
+    use crate::game::PitchLocation;
+    use crate::input::{GameInput, InputPoller, InputState, KeyPhase, KEY_BINDINGS};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::time::{Duration, Instant};
+
+    fn parse(code: KeyCode, modifiers: KeyModifiers) -> Option<GameInput> {
+        InputPoller::new().parse_key_input(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_every_listed_binding_is_a_key_the_game_reads() {
+        for binding in KEY_BINDINGS {
+            let modifiers = if binding.shift { KeyModifiers::SHIFT } else { KeyModifiers::NONE };
+            assert!(parse(binding.code, modifiers).is_some(), "{} ({:?}) does nothing", binding.keys, binding.phase);
+        }
+    }
+
+    #[test]
+    fn test_every_phase_has_bindings() {
+        for phase in KeyPhase::ALL {
+            assert!(KEY_BINDINGS.iter().any(|b| b.phase == phase), "{} has no keys", phase.name());
+        }
+    }
+
+    #[test]
+    fn test_f1_is_help_and_question_mark_stays_the_surprise_matchup() {
+        assert_eq!(parse(KeyCode::F(1), KeyModifiers::NONE), Some(GameInput::Help));
+        assert_eq!(parse(KeyCode::Char('?'), KeyModifiers::SHIFT), Some(GameInput::RandomMatchup));
+    }
+
+    #[test]
+    fn test_team_number_entry_reads_backspace_and_esc() {
+        let mut poller = InputPoller::new();
+        let mut parse_next = |code| poller.parse_key_input(KeyEvent::new(code, KeyModifiers::NONE));
+        assert_eq!(parse_next(KeyCode::Char('a')), Some(GameInput::SelectAwayTeam));
+        assert_eq!(parse_next(KeyCode::Char('1')), Some(GameInput::NumberInput('1')));
+        assert_eq!(parse_next(KeyCode::Backspace), Some(GameInput::Backspace));
+        assert_eq!(parse_next(KeyCode::Esc), Some(GameInput::Pause));
+        // Out of number entry, digits pick pitches again
+        assert_eq!(parse_next(KeyCode::Char('1')), Some(GameInput::SelectPitch(0)));
+    }
+
+    /// Aim after each press, `ms` after the first
+    fn aim_after(presses: &[(GameInput, u64)]) -> PitchLocation {
+        let start = Instant::now();
+        let mut input_state = InputState::with_repeat_window(Duration::from_millis(100));
+        for (input, ms) in presses {
+            input_state.update_at(input, start + Duration::from_millis(*ms));
+        }
+        input_state.aimed_location()
+    }
+
+    #[test]
+    fn test_quick_repeats_of_one_arrow_count_once() {
+        // A held down arrow steps out of the top row and stops in the middle
+        let held = [(GameInput::Up, 0), (GameInput::Down, 500), (GameInput::Down, 530), (GameInput::Down, 560), (GameInput::Down, 590)];
+        assert_eq!(aim_after(&held), PitchLocation::Middle);
+        // The same presses spaced out past the window step all the way down
+        let tapped = [(GameInput::Up, 0), (GameInput::Down, 500), (GameInput::Down, 700)];
+        assert_eq!(aim_after(&tapped), PitchLocation::Down);
+    }
+
+    #[test]
+    fn test_arrows_step_back_through_the_middle() {
+        assert_eq!(aim_after(&[(GameInput::Left, 0), (GameInput::Right, 200)]), PitchLocation::Middle);
+        assert_eq!(aim_after(&[(GameInput::Left, 0), (GameInput::Right, 200), (GameInput::Right, 400)]), PitchLocation::Outside);
+        assert_eq!(aim_after(&[(GameInput::Down, 0), (GameInput::Down, 200)]), PitchLocation::Down);
+    }
+
+    #[test]
+    fn test_near_simultaneous_up_and_left_aim_the_corner() {
+        assert_eq!(aim_after(&[(GameInput::Up, 0), (GameInput::Left, 5)]), PitchLocation::UpInside);
+        assert_eq!(aim_after(&[(GameInput::Left, 0), (GameInput::Up, 5), (GameInput::Left, 20), (GameInput::Up, 25)]), PitchLocation::UpInside);
+    }
+
+    #[test]
+    fn test_up_and_left_pressed_together_aim_the_corner_from_anywhere() {
+        // Aimed outside, up then left inside the chord window goes straight to up and in
+        let chord = [(GameInput::Right, 0), (GameInput::Up, 500), (GameInput::Left, 500 + AIM_CHORD_WINDOW_MS - 10)];
+        assert_eq!(aim_after(&chord), PitchLocation::UpInside);
+        // Past the window, left is a step of its own back to the middle column
+        let steps = [(GameInput::Right, 0), (GameInput::Up, 500), (GameInput::Left, 500 + AIM_CHORD_WINDOW_MS + 10)];
+        assert_eq!(aim_after(&steps), PitchLocation::Up);
+    }
+
+    #[test]
+    fn test_no_repeat_window_takes_every_press() {
+        let start = Instant::now();
+        let mut input_state = InputState::with_repeat_window(Duration::ZERO);
+        for input in [GameInput::Up, GameInput::Down, GameInput::Down] {
+            input_state.update_at(&input, start);
+        }
+        assert_eq!(input_state.aimed_location(), PitchLocation::Down);
+    }
+}
@@ -0,0 +1,31 @@
+//! The interactive game as a library: the engine, game state, rosters and the input and update
+//! functions that drive a game, so a game can be played out without a terminal. The binary in
+//! main.rs is the terminal front end on top.
+
+pub mod game;
+pub mod input;
+pub mod ui;
+pub mod team;
+pub mod audio;
+pub mod logger;
+pub mod config;
+pub mod roster;
+pub mod terminal;
+#[cfg(feature = "net")]
+pub mod download;
+
+pub use game::{handle_input, update_game_state, GameEngine, GameState};
+pub use team::TeamManager;
+
+#[cfg(test)]
+mod config_tests;
+#[cfg(all(test, feature = "net"))]
+mod download_tests;
+#[cfg(test)]
+mod input_tests;
+#[cfg(test)]
+mod team_tests;
+#[cfg(test)]
+mod terminal_tests;
+#[cfg(test)]
+mod ui_tests;
@@ -1,14 +1,15 @@
-use std::fs::OpenOptions;
+use std::fs::{File, OpenOptions};
 use std::io::Write;
 use chrono::Local;
 use crate::game::state::{PlayResult, PitchLocation, BallInPlay};
 use crate::team::Player;
 
 pub struct GameLogger {
-    log_path: String,
+    log_path: Option<String>, // None writes nothing
 }
 
 impl GameLogger {
+    #[allow(clippy::new_without_default)] // Creates a log file - not something a default should do
     pub fn new() -> Self {
         let timestamp = Local::now().format("%Y%m%d_%H%M%S");
         let log_path = format!("game_log_{}.txt", timestamp);
@@ -24,12 +25,28 @@ impl GameLogger {
             let _ = writeln!(file, "TERMINAL BASEBALL - GAME LOG");
             let _ = writeln!(file, "Started: {}", Local::now().format("%Y-%m-%d %H:%M:%S"));
             let _ = writeln!(file, "{}", "=".repeat(80));
-            let _ = writeln!(file, "");
+            let _ = writeln!(file);
         }
         
-        Self { log_path }
+        Self { log_path: Some(log_path) }
+    }
+
+    /// Logger that drops everything, for games nobody is playing (the attract loop)
+    pub fn disabled() -> Self {
+        Self { log_path: None }
+    }
+
+    /// Logger writing to an explicit path, so tests don't litter the working directory
+    #[cfg(test)]
+    pub fn with_path<P: Into<String>>(log_path: P) -> Self {
+        Self { log_path: Some(log_path.into()) }
     }
     
+    fn open(&self) -> Option<File> {
+        OpenOptions::new().create(true).append(true).open(self.log_path.as_ref()?).ok()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn log_pitch_result(
         &self,
         pitch_num: u32,
@@ -43,11 +60,7 @@ impl GameLogger {
         result: &PlayResult,
         fatigue_penalty: f32,
     ) {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.log_path)
-        {
+        if let Some(mut file) = self.open() {
             let _ = writeln!(file, "\n{}", "-".repeat(80));
             let _ = writeln!(file, "PITCH #{} - Inning {} {}", pitch_num, inning, half);
             let _ = writeln!(file, "{}", "-".repeat(80));
@@ -92,8 +105,11 @@ impl GameLogger {
                 PlayResult::Strike => "STRIKE".to_string(),
                 PlayResult::Ball => "BALL".to_string(),
                 PlayResult::Foul => "FOUL".to_string(),
+                // Batted balls are settled by the fielding attempt logged after this
+                result if result.is_batted_ball() => "IN PLAY".to_string(),
                 PlayResult::Hit(hit_type) => format!("HIT - {:?}", hit_type),
                 PlayResult::Out(out_type) => format!("OUT - {:?}", out_type),
+                PlayResult::ReachedOnError => "ERROR".to_string(),
             });
         }
     }
@@ -106,11 +122,7 @@ impl GameLogger {
         success_chance: f32,
         result: &PlayResult,
     ) {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.log_path)
-        {
+        if let Some(mut file) = self.open() {
             let _ = writeln!(file, "\n  FIELDING ATTEMPT:");
             let _ = writeln!(file, "    Ball Type: {:?}", ball.ball_type);
             let _ = writeln!(file, "    Direction: {:?}", ball.direction);
@@ -128,6 +140,23 @@ impl GameLogger {
         }
     }
     
+    pub fn log_balk(&self, inning: u8, half: &str, pitcher: Option<&Player>, runs: u8) {
+        if let Some(mut file) = self.open() {
+            let _ = writeln!(file, "\n{}", "-".repeat(80));
+            let _ = writeln!(file, "BALK - Inning {} {}", inning, half);
+            let _ = writeln!(file, "PITCHER: {}", pitcher.map(|p| p.stats.name.as_str()).unwrap_or("Unknown"));
+            let _ = writeln!(file, "Runners advance one base, {} run(s) score", runs);
+        }
+    }
+
+    pub fn log_substitution(&self, inning: u8, half: &str, description: &str) {
+        if let Some(mut file) = self.open() {
+            let _ = writeln!(file, "\n{}", "-".repeat(80));
+            let _ = writeln!(file, "SUBSTITUTION - Inning {} {}", inning, half);
+            let _ = writeln!(file, "{}", description);
+        }
+    }
+
     pub fn log_inning_summary(
         &self,
         inning: u8,
@@ -136,11 +165,7 @@ impl GameLogger {
         hits: u8,
         outs: u8,
     ) {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.log_path)
-        {
+        if let Some(mut file) = self.open() {
             let _ = writeln!(file, "\n{}", "=".repeat(80));
             let _ = writeln!(file, "INNING {} {} SUMMARY", inning, half);
             let _ = writeln!(file, "  Runs: {}", runs);
@@ -157,18 +182,14 @@ impl GameLogger {
         away_score: u8,
         home_score: u8,
     ) {
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.log_path)
-        {
+        if let Some(mut file) = self.open() {
             let _ = writeln!(file, "\n\n{}", "=".repeat(80));
             let _ = writeln!(file, "FINAL SCORE");
             let _ = writeln!(file, "{}", "=".repeat(80));
             let _ = writeln!(file, "{}: {}", away_team, away_score);
             let _ = writeln!(file, "{}: {}", home_team, home_score);
             let _ = writeln!(file, "{}", "=".repeat(80));
-            let _ = writeln!(file, "Log saved to: {}", self.log_path);
+            let _ = writeln!(file, "Log saved to: {}", self.log_path.as_deref().unwrap_or_default());
         }
     }
 }
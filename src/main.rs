@@ -1,18 +1,17 @@
-mod game;
-mod input;
-mod ui;
-mod team;
-mod audio;
-mod logger;
 
+use bitbatter::{audio, config, game, input, logger, team, terminal, ui};
+#[cfg(feature = "net")]
+use bitbatter::download;
 use audio::AudioPlayer;
+use config::GameConfig;
 use logger::GameLogger;
 use crossterm::{
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{enable_raw_mode, EnterAlternateScreen},
 };
-use game::{constants::*, GameEngine, GameState};
+use game::{career::CareerStats, constants::*, scorecard, GameEngine, GameState, TickClock};
 use input::InputPoller;
+use team::TeamManager;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{
     io,
@@ -21,7 +20,60 @@ use std::{
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Setup terminal
+    // Settings come first so a bad flag is reported before the terminal is taken over
+    let (mut config, config_warning) = GameConfig::load_or_default();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let download_requested = take_flag(&mut args, "--download-data");
+    let new_team = take_value(&mut args, "--new-team")?;
+    let leaders = take_value(&mut args, "--leaders")?;
+    let career = take_flag(&mut args, "--career");
+    let quick_play = take_flag(&mut args, "--quick-play");
+    let matchup = take_value(&mut args, "--matchup")?
+        .map(|pair| match pair.split_once('@') {
+            Some((away, home)) => Ok((away.to_string(), home.to_string())),
+            None => Err(format!("--matchup expects AWAY@HOME, got '{}'", pair)),
+        })
+        .transpose()?;
+    config.apply_args(args)?;
+    let source_warning = config.check_data_source();
+    let config_warning = match (config_warning, source_warning) {
+        (Some(a), Some(b)) => Some(format!("{} | {}", a, b)),
+        (a, b) => a.or(b),
+    };
+
+    if let Some(abbr) = new_team {
+        let manager = team::TeamManager::new(&config.data_dir, config.season);
+        let path = manager.create_team_template(&abbr.to_uppercase())?;
+        println!("Wrote a template roster to {}", path.display());
+        println!("Edit the names and stats, then start BitBatter to pick the team.");
+        return Ok(());
+    }
+
+    if let Some(flag) = leaders {
+        let category = team::StatCategory::from_flag(&flag).ok_or_else(|| {
+            let flags: Vec<&str> = team::StatCategory::ALL.iter().map(|c| c.flag()).collect();
+            format!("--leaders needs one of: {}", flags.join(", "))
+        })?;
+        print_league_leaders(&config, category);
+        return Ok(());
+    }
+
+    if career {
+        print_career_stats()?;
+        return Ok(());
+    }
+
+    // Downloading happens before the game starts, so the team list picks up the new files
+    if download_requested {
+        #[cfg(feature = "net")]
+        download::download_all(&config)?;
+        #[cfg(not(feature = "net"))]
+        return Err("--download-data needs a build with `--features net`".into());
+    }
+
+    // Setup terminal - from here on the guard puts it back however we leave
+    terminal::install_panic_hook();
+    let mut guard = terminal::TerminalGuard::new(terminal::CrosstermRestore);
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -33,37 +85,148 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     terminal.hide_cursor()?;
 
     // Run game with proper error handling
-    let res = run_game(&mut terminal);
+    let quick_play = (quick_play || matchup.is_some()).then_some(matchup);
+    let res = run_game(&mut terminal, config, config_warning, quick_play);
 
-    // ALWAYS restore terminal - even on panic
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+    // Restore explicitly so errors are reported; the guard covers panics and early returns
+    guard.restore()?;
 
     res
 }
 
+/// Load every team the config can see and print the top hitters in `category`
+fn print_league_leaders(config: &GameConfig, category: team::StatCategory) {
+    let (mut manager, warning) = TeamManager::from_config(config);
+    if let Some(warning) = warning {
+        eprintln!("{}", warning);
+    }
+    for key in manager.get_team_list() {
+        if let Err(e) = manager.load_team(&key) {
+            eprintln!("Skipping {}: {}", key, e);
+        }
+    }
+
+    println!("League Leaders - {}", category.name());
+    for (rank, leader) in manager.stat_leaders(category, LEAGUE_LEADERS_SHOWN).iter().enumerate() {
+        println!("{:>2}. {:<24} {:<10} {:>6.1}", rank + 1, leader.name, leader.team, leader.value);
+    }
+}
+
+/// Print everyone's accumulated lines from the games played so far, most at-bats and most
+/// innings first
+fn print_career_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let career = CareerStats::load(config::CAREER_STATS_FILE)?;
+    if career.batting.is_empty() && career.pitching.is_empty() {
+        println!("No finished games yet - career stats start with the first one");
+        return Ok(());
+    }
+
+    let mut batters: Vec<_> = career.batting.values().collect();
+    batters.sort_by_key(|line| std::cmp::Reverse(line.at_bats));
+    println!("{:<24} {:>4} {:>5} {:>4} {:>4} {:>4} {:>4} {:>4} {:>5}", "Batting", "G", "AB", "R", "H", "HR", "RBI", "BB", "AVG");
+    for line in batters {
+        println!(
+            "{:<24} {:>4} {:>5} {:>4} {:>4} {:>4} {:>4} {:>4} {:>5.3}",
+            line.name, line.games, line.at_bats, line.runs, line.hits, line.home_runs, line.rbi, line.walks, line.average()
+        );
+    }
+
+    let mut pitchers: Vec<_> = career.pitching.values().collect();
+    pitchers.sort_by_key(|line| std::cmp::Reverse(line.outs_recorded));
+    println!();
+    println!("{:<24} {:>4} {:>6} {:>4} {:>4} {:>4} {:>4} {:>3} {:>5}", "Pitching", "G", "IP", "H", "ER", "BB", "K", "SV", "ERA");
+    for line in pitchers {
+        println!(
+            "{:<24} {:>4} {:>6} {:>4} {:>4} {:>4} {:>4} {:>3} {:>5.2}",
+            line.name, line.games, line.innings_pitched(), line.hits, line.earned_runs, line.walks, line.strikeouts, line.saves, line.era()
+        );
+    }
+    Ok(())
+}
+
+/// Remove a standalone flag from the argument list, reporting whether it was there
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != before
+}
+
+/// Remove a flag and the value after it from the argument list
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(pos) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    if pos + 1 >= args.len() {
+        return Err(format!("{} needs a value", flag));
+    }
+    let value = args.remove(pos + 1);
+    args.remove(pos);
+    Ok(Some(value))
+}
+
 fn run_game(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    config: GameConfig,
+    config_warning: Option<String>,
+    quick_play: Option<Option<(String, String)>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut game_state = GameState::new();
+    let (mut team_manager, teams_warning) = TeamManager::from_config(&config);
+    let mut menu_config = config.clone(); // For getting back to team selection after a demo
+    let (mut career, career_warning) = CareerStats::load_or_default(config::CAREER_STATS_FILE);
+    let mut game_state = GameState::with_config(config);
+    if let Some(warning) = config_warning.or(teams_warning).or(career_warning) {
+        game_state.message = warning;
+    }
     let engine = GameEngine::new();
-    let mut input_state = input::InputState::new();
+
+    // Quick play skips team selection and the lineup card; if it can't, team selection says why
+    if let Some(pair) = quick_play {
+        match game::matchup::quick_play_matchup(&mut team_manager, pair, &mut *engine.rng()) {
+            Ok((away, home)) => {
+                game_state.start_game(&team_manager, home, away);
+                game_state.roll_weather(&mut *engine.rng());
+                engine.set_ballpark(game_state.home_ballpark());
+                engine.set_weather(game_state.weather);
+            }
+            Err(e) => game_state.message = e,
+        }
+    }
+    let mut input_state = input::InputState::with_repeat_window(Duration::from_millis(game_state.config.aim_repeat_ms));
     let mut input_poller = InputPoller::new();
     let audio_player = AudioPlayer::new();
     let logger = GameLogger::new();
-    
-    let mut pitch_count = 0u32;
-    let mut inning_hits = 0u8;
+    let demo_logger = GameLogger::disabled();
 
     let frame_time = Duration::from_millis(FRAME_TIME_MS);
+    let mut clock = TickClock::new();
+    let mut last_tick = Instant::now();
+    let mut last_drawn: Option<ui::RenderKey> = None;
+    let mut force_redraw = true;
+    let mut game_saved = false;
 
     loop {
         let frame_start = Instant::now();
 
         // Handle input
         if let Some(input) = input_poller.poll_input(INPUT_POLL_TIMEOUT_MS)? {
-            if input == input::GameInput::Quit {
+            force_redraw = true;
+            if input == input::GameInput::Resize {
+                // Nothing to handle - just draw at the new size
+            } else if game_state.attract_mode {
+                // Any key ends the demo
+                game_state = GameState::with_config(menu_config.clone());
+                input_state.reset();
+                continue;
+            } else if game::post_game::showing_post_game(&game_state) {
+                if game_state.config.demo_mode && !menu_config.demo_mode {
+                    // A game watched from team selection goes back there
+                    game_state = GameState::with_config(menu_config.clone());
+                    input_state.reset();
+                    game_saved = false;
+                    continue;
+                }
+                break; // Any key leaves the post-game screen
+            } else if input == input::GameInput::Quit {
                 // Handle quit confirmation
                 if game_state.quit_requested {
                     break; // Confirmed quit
@@ -80,32 +243,76 @@ fn run_game(
                     game_state.message = "Quit cancelled. Continue playing!".to_string();
                 }
                 
-                game::input_handler::handle_input(
+                let settings_open = game_state.settings_row.is_some();
+                let sound = audio_player.as_ref().filter(|_| game_state.config.sound);
+                game::handle_input(
                     &mut game_state,
+                    &mut team_manager,
                     &engine,
                     &mut input_state,
                     input,
-                    audio_player.as_ref(),
+                    sound,
                     &logger,
                 );
+                // Closing the settings screen keeps its changes for later games and saves them
+                if settings_open && game_state.settings_row.is_none() {
+                    menu_config.copy_settings_from(&game_state.config);
+                    if let Err(e) = game_state.config.save_settings(config::CONFIG_FILE) {
+                        game_state.message = format!("Couldn't save settings: {}", e);
+                    }
+                }
             }
         }
 
-        // Update game logic (animations, etc.)
-        game::update::update_game_state(
-            &mut game_state,
-            &engine,
-            &mut input_state,
-            audio_player.as_ref(),
-            &logger,
-            &mut pitch_count,
-            &mut inning_hits,
-        );
+        // Update game logic (animations, etc.) by however much real time has passed, so a
+        // slow terminal doesn't stretch the timing windows
+        let now = Instant::now();
+        let frame_logger = if game_state.attract_mode { &demo_logger } else { &logger };
+        let sound = audio_player.as_ref().filter(|_| game_state.config.sound);
+        for _ in 0..clock.advance(now - last_tick) {
+            game::update_game_state(
+                &mut game_state,
+                &engine,
+                &mut input_state,
+                sound,
+                frame_logger,
+            );
+        }
+        last_tick = now;
 
-        // Render ONCE per frame - critical for no flicker!
-        terminal.draw(|frame| {
-            ui::render_game(frame, &game_state, &engine, &input_state);
-        })?;
+        // First time playing: walk through the opening pitch. Once it's finished or skipped,
+        // remember that so it isn't offered again.
+        if !menu_config.tutorial_done && game_state.mode == game::GameMode::Playing {
+            if game_state.config.tutorial_done {
+                menu_config.tutorial_done = true;
+                // Being offered the tutorial again isn't worth failing over
+                let _ = GameConfig::save_tutorial_done(config::CONFIG_FILE);
+            } else if game_state.tutorial.is_none() && !game_state.config.demo_mode && game_state.practice.is_none() {
+                game_state.start_tutorial();
+                force_redraw = true;
+            }
+        }
+
+        // Left alone on team selection, the game plays itself until a key is pressed
+        if game::demo::idle_long_enough(&game_state) {
+            let _ = game::demo::start_demo(&mut game_state, &mut team_manager, &engine);
+            force_redraw = true;
+        } else if game::demo::demo_finished(&game_state) {
+            game_state = GameState::with_config(menu_config.clone());
+            input_state.reset();
+            force_redraw = true;
+        }
+
+        // Render at most once per frame - critical for no flicker! - and only when something
+        // on screen changed, so sitting on a menu doesn't keep the CPU busy
+        let render_key = ui::RenderKey::capture(&game_state, &input_state);
+        if force_redraw || last_drawn.as_ref() != Some(&render_key) {
+            terminal.draw(|frame| {
+                ui::render_game(frame, &game_state, &team_manager, &career, &engine, &input_state);
+            })?;
+            last_drawn = Some(render_key);
+            force_redraw = false;
+        }
 
         // Frame rate limiting to prevent CPU spam
         let elapsed = frame_start.elapsed();
@@ -113,10 +320,30 @@ fn run_game(
             thread::sleep(frame_time - elapsed);
         }
 
-        // Exit if game is over
-        if game_state.game_over && matches!(game_state.pitch_state, game::PitchState::ShowResult { .. }) {
-            thread::sleep(Duration::from_secs(GAME_OVER_DELAY_SECONDS));
-            break;
+        // Save the spray chart and box score and add the box score to the career stats once the game is over -
+        // the post-game screen stays up until a key is pressed, and a demo goes back to team
+        // selection instead
+        if game_state.game_over && !game_state.attract_mode && !game_saved {
+            // CPU against CPU isn't anyone's career
+            if !game_state.config.demo_mode {
+                let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+                // Losing the chart or the box score isn't worth failing the game over
+                let _ = game_state.spray_chart.export_spray_chart(format!("spray_chart_{}.txt", stamp));
+                let _ = scorecard::export_box_score_text(&game_state, format!("box_score_{}.txt", stamp));
+                let _ = scorecard::export_box_score_csv(&game_state, format!("box_score_{}.csv", stamp));
+                match CareerStats::record_game(config::CAREER_STATS_FILE, &game_state.box_score, |key| game_state.player_name(key)) {
+                    Ok(warning) => {
+                        if let Some(warning) = warning {
+                            game_state.message = warning;
+                        }
+                        // The next game's batter cards include this one
+                        career = CareerStats::load_or_default(config::CAREER_STATS_FILE).0;
+                    }
+                    Err(e) => game_state.message = format!("Couldn't save career stats: {}", e),
+                }
+                force_redraw = true;
+            }
+            game_saved = true;
         }
     }
 
@@ -0,0 +1,206 @@
+use crate::team::{batting_position, estimate_sprint_speed, Hand, PitcherStats, Player, PlayerStats, Position, Team};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE_BATTERS: usize = 9;
+const TEMPLATE_PITCHERS: usize = 5;
+
+/// Hand-authored roster saved as `team_<ABBR>_<YEAR>.json` in the data directory.
+/// Player entries use the same field names as the statcast CSV columns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomRoster {
+    pub name: String,
+    pub abbreviation: String,
+    #[serde(default)]
+    pub colors: TeamColors,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ballpark: Option<String>,
+    pub batters: Vec<RosterPlayer>,
+    pub pitchers: Vec<RosterPlayer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closer: Option<String>, // Player id of the pitcher saved for the 9th with a close lead
+}
+
+/// Several custom teams in one file, loaded with `--league`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct League {
+    #[serde(default)]
+    pub season: Option<u16>, // Defaults to the configured season
+    pub teams: Vec<CustomRoster>,
+}
+
+impl League {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        let league: League = serde_json::from_str(&json)?;
+        if league.teams.is_empty() {
+            return Err("the league has no teams".into());
+        }
+        for roster in &league.teams {
+            roster.validate().map_err(|e| format!("{}: {}", roster.name, e))?;
+        }
+        Ok(league)
+    }
+}
+
+/// Any color name or `#rrggbb` value the terminal understands
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamColors {
+    pub primary: String,
+    pub secondary: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterPlayer {
+    #[serde(flatten)]
+    pub stats: PlayerStats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pitching: Option<PitcherStats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bats: Option<Hand>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub throws: Option<Hand>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outs_above_average: Option<i8>,
+}
+
+impl CustomRoster {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        let roster: CustomRoster = serde_json::from_str(&json)?;
+        roster.validate()?;
+        Ok(roster)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !is_valid_abbreviation(&self.abbreviation) {
+            return Err(format!("invalid abbreviation {:?} - use 2-4 letters or digits", self.abbreviation));
+        }
+        if self.batters.is_empty() || self.pitchers.is_empty() {
+            return Err("a custom team needs at least one batter and one pitcher".to_string());
+        }
+        if let Some(closer) = &self.closer {
+            if !self.pitchers.iter().any(|p| &p.stats.id == closer) {
+                return Err(format!("closer {:?} isn't one of the team's pitchers", closer));
+            }
+        }
+        Ok(())
+    }
+
+    /// Starting point for a new team - a full lineup and staff of league-average players
+    pub fn template(abbr: &str, name: &str) -> Self {
+        let batters = (1..=TEMPLATE_BATTERS)
+            .map(|n| RosterPlayer {
+                stats: average_stats(&format!("Batter, Number {}", n), &format!("{}-B{}", abbr, n)),
+                pitching: None,
+                bats: Some(Hand::Right),
+                throws: Some(Hand::Right),
+                outs_above_average: None,
+            })
+            .collect();
+        let pitchers = (1..=TEMPLATE_PITCHERS)
+            .map(|n| RosterPlayer {
+                stats: average_stats(&format!("Pitcher, Number {}", n), &format!("{}-P{}", abbr, n)),
+                pitching: Some(PitcherStats {
+                    fastball_velocity: Some(94.0),
+                    whiff_percent: Some(25.0),
+                    k_percent: Some(22.0),
+                    bb_percent: Some(8.5),
+                }),
+                bats: Some(Hand::Right),
+                throws: Some(Hand::Right),
+                outs_above_average: None,
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            abbreviation: abbr.to_string(),
+            colors: TeamColors {
+                primary: "white".to_string(),
+                secondary: "gray".to_string(),
+            },
+            ballpark: None,
+            batters,
+            pitchers,
+            closer: None,
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    pub fn into_team(self, season: u16) -> Team {
+        let mut team = Team::new(self.name, self.abbreviation);
+        team.season = season;
+        if let Some(ballpark) = self.ballpark {
+            team.ballpark_name = ballpark;
+        }
+        team.closer = self.closer;
+        team.batters = self
+            .batters
+            .into_iter()
+            .enumerate()
+            .map(|(idx, player)| Player {
+                sprint_speed: estimate_sprint_speed(&player.stats),
+                bats: player.bats,
+                throws: player.throws,
+                outs_above_average: player.outs_above_average.unwrap_or(0),
+                stats: player.stats,
+                is_pitcher: false,
+                position: batting_position(idx),
+                arsenal: Vec::new(),
+                pitching: None,
+            })
+            .collect();
+        team.pitchers = self
+            .pitchers
+            .into_iter()
+            .map(|player| Player {
+                sprint_speed: estimate_sprint_speed(&player.stats),
+                bats: player.bats,
+                throws: player.throws,
+                outs_above_average: player.outs_above_average.unwrap_or(0),
+                stats: player.stats,
+                is_pitcher: true,
+                position: Position::Pitcher,
+                arsenal: Vec::new(),
+                pitching: Some(player.pitching.unwrap_or_default()),
+            })
+            .collect();
+        team
+    }
+}
+
+/// Abbreviations become part of file names and team keys, so no underscores or paths
+pub fn is_valid_abbreviation(abbr: &str) -> bool {
+    (2..=4).contains(&abbr.len()) && abbr.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Roughly league-average batted-ball numbers
+fn average_stats(name: &str, id: &str) -> PlayerStats {
+    PlayerStats {
+        name: name.to_string(),
+        id: id.to_string(),
+        attempts: 350,
+        avg_hit_angle: 12.5,
+        sweet_spot_percent: 33.5,
+        max_hit_speed: 110.0,
+        avg_hit_speed: 89.0,
+        ev50: 100.5,
+        fbld: 92.5,
+        gb: 84.5,
+        max_distance: 425,
+        avg_distance: 170,
+        avg_hr_distance: 398,
+        ev95plus: 140,
+        ev95_percent: 40.0,
+        barrels: 28,
+        barrel_percent: 8.0,
+        barrel_pa: 5.6,
+    }
+}
@@ -1,8 +1,12 @@
-use serde::Deserialize;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use crate::config::GameConfig;
+use crate::game::engine::PitchType;
+use crate::game::matchup::{rate_team, TeamRating};
+use crate::roster::{is_valid_abbreviation, CustomRoster, League, TeamColors};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStats {
     #[serde(rename = "last_name, first_name")]
     pub name: String,
@@ -51,14 +55,234 @@ pub struct PlayerStats {
     pub barrel_pa: f32,
 }
 
+/// Pitcher-only statcast columns. Older CSVs don't have them, so every field is optional.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PitcherStats {
+    #[serde(default, rename = "fastball_avg_speed", alias = "ff_avg_speed")]
+    pub fastball_velocity: Option<f32>,
+
+    #[serde(default)]
+    pub whiff_percent: Option<f32>,
+
+    #[serde(default)]
+    pub k_percent: Option<f32>,
+
+    #[serde(default)]
+    pub bb_percent: Option<f32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Player {
     pub stats: PlayerStats,
     pub is_pitcher: bool,
     pub position: Position,
+    pub arsenal: Vec<PitchType>, // Empty means the engine's generic pitch mix
+    pub pitching: Option<PitcherStats>, // Only set for pitchers
+    pub sprint_speed: f32, // Feet per second, from a sprint speed file or `estimate_sprint_speed`
+    pub bats: Option<Hand>,   // From a handedness file or the roster; None when unknown
+    pub throws: Option<Hand>,
+    pub outs_above_average: i8, // Fielding, from a defense file or the roster; 0 is an average glove
+}
+
+/// One row of an optional `arsenal_<TEAM>_2025.csv`
+#[derive(Debug, Clone, Deserialize)]
+struct ArsenalRow {
+    player_id: String,
+    pitch_name: String,
+    speed: u8,
+    break_amount: i8,
+    control: f32,
+    whiff: i32,
+}
+
+/// Which side a player bats or throws from, written R, L or S as on a baseball card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hand {
+    #[serde(rename = "R")]
+    Right,
+    #[serde(rename = "L")]
+    Left,
+    #[serde(rename = "S")]
+    Switch, // Hitters only - bats from whichever side is opposite the pitcher
+}
+
+impl Hand {
+    pub fn letter(&self) -> char {
+        match self {
+            Hand::Right => 'R',
+            Hand::Left => 'L',
+            Hand::Switch => 'S',
+        }
+    }
+}
+
+/// One row of an optional `handedness_<TEAM>_2025.csv`; either column may be blank
+#[derive(Debug, Clone, Deserialize)]
+struct HandednessRow {
+    player_id: String,
+    bats: Option<Hand>,
+    throws: Option<Hand>,
+}
+
+/// One row of an optional `defense_<TEAM>_2025.csv` (statcast's outs above average leaderboard)
+#[derive(Debug, Clone, Deserialize)]
+struct DefenseRow {
+    player_id: String,
+    outs_above_average: i8,
+}
+
+/// One row of an optional `sprint_speed_<TEAM>_2025.csv` (statcast's sprint speed leaderboard)
+#[derive(Debug, Clone, Deserialize)]
+struct SprintSpeedRow {
+    player_id: String,
+    sprint_speed: f32,
+}
+
+/// Guess a runner's sprint speed from the batted-ball profile when there's no real one.
+/// Slap hitters - low launch angle, short average distance - are usually the fast ones.
+pub fn estimate_sprint_speed(stats: &PlayerStats) -> f32 {
+    use crate::game::constants::*;
+    let launch = (LEAGUE_AVG_HIT_ANGLE - stats.avg_hit_angle) * SPRINT_SPEED_PER_DEGREE;
+    let distance = (LEAGUE_AVG_BATTED_DISTANCE - stats.avg_distance as f32) * SPRINT_SPEED_PER_FOOT;
+    (LEAGUE_AVG_SPRINT_SPEED + launch + distance).clamp(SPRINT_SPEED_MIN, SPRINT_SPEED_MAX)
+}
+
+/// A replacement-level hitter. The id has a `#` like the box score's empty lineup slots, so
+/// they stay out of the career stats.
+fn replacement_batter(abbreviation: &str, n: usize, position: Position) -> Player {
+    use crate::game::constants::*;
+    let stats = PlayerStats {
+        name: format!("Replacement, Player {}", n),
+        id: format!("{}#R{}", abbreviation, n),
+        attempts: MIN_PLAYER_ATTEMPTS,
+        avg_hit_angle: LEAGUE_AVG_HIT_ANGLE,
+        sweet_spot_percent: REPLACEMENT_SWEET_SPOT_PERCENT,
+        max_hit_speed: 106.0,
+        avg_hit_speed: REPLACEMENT_AVG_HIT_SPEED,
+        ev50: 97.0,
+        fbld: 90.0,
+        gb: 84.0,
+        max_distance: 400,
+        avg_distance: LEAGUE_AVG_BATTED_DISTANCE as u32,
+        avg_hr_distance: 390,
+        ev95plus: 10,
+        ev95_percent: 33.0,
+        barrels: 2,
+        barrel_percent: REPLACEMENT_BARREL_PERCENT,
+        barrel_pa: 2.8,
+    };
+    Player {
+        sprint_speed: estimate_sprint_speed(&stats),
+        stats,
+        is_pitcher: false,
+        position,
+        arsenal: Vec::new(),
+        pitching: None,
+        bats: None,
+        throws: None,
+        outs_above_average: 0,
+    }
+}
+
+/// A pitcher's turn at the plate: their name and id with a feeble synthesized stat line
+pub fn pitcher_as_batter(pitcher: &Player) -> Player {
+    use crate::game::constants::*;
+    let mut batter = replacement_batter("", 0, Position::Pitcher);
+    batter.stats.name = pitcher.stats.name.clone();
+    batter.stats.id = pitcher.stats.id.clone();
+    batter.stats.barrel_percent = PITCHER_BATTING_BARREL_PERCENT;
+    batter.stats.avg_hit_speed = PITCHER_BATTING_AVG_HIT_SPEED;
+    batter.stats.sweet_spot_percent = PITCHER_BATTING_SWEET_SPOT_PERCENT;
+    batter.stats.barrels = 0;
+    batter.sprint_speed = estimate_sprint_speed(&batter.stats);
+    batter.is_pitcher = true;
+    batter.bats = pitcher.bats.or(pitcher.throws);
+    batter.throws = pitcher.throws;
+    batter
+}
+
+/// A replacement-level arm for a staff where nobody qualified, so there's always someone on
+/// the mound. Like the filler hitters, the `#` in the id keeps them out of the career stats.
+fn replacement_pitcher(abbreviation: &str) -> Player {
+    use crate::game::constants::*;
+    let mut pitcher = replacement_batter(abbreviation, 0, Position::Pitcher);
+    pitcher.stats.name = "Replacement, Pitcher".to_string();
+    pitcher.stats.id = format!("{}#P1", abbreviation);
+    pitcher.stats.barrel_percent = REPLACEMENT_PITCHER_BARREL_PERCENT;
+    pitcher.is_pitcher = true;
+    pitcher.pitching = Some(PitcherStats {
+        fastball_velocity: Some(REPLACEMENT_PITCHER_FASTBALL_MPH),
+        whiff_percent: Some(REPLACEMENT_PITCHER_WHIFF_PERCENT),
+        ..PitcherStats::default()
+    });
+    pitcher.arsenal = pitcher_arsenal(&pitcher);
+    pitcher
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Build a plausible arsenal from what a pitcher allows on contact.
+/// Pitchers who are rarely squared up throw hard and skip the changeup, ground-ball
+/// pitchers get a sinker, and the stingiest barrel rates earn a wipeout slider.
+pub fn synthesize_arsenal(stats: &PlayerStats) -> Vec<PitchType> {
+    use crate::game::constants::*;
+    let command_bonus = if stats.sweet_spot_percent <= COMMAND_MAX_SWEET_SPOT_PERCENT {
+        COMMAND_CONTROL_BONUS
+    } else {
+        0.0
+    };
+    let pitch = |name: &str, speed: u8, break_amount: i8, control: f32, whiff: i32| PitchType {
+        control: (control + command_bonus).min(1.0),
+        whiff,
+        ..PitchType::new(name, speed, break_amount)
+    };
+
+    let power_arm = stats.ev95_percent <= POWER_ARM_MAX_EV95_PERCENT;
+    let fastball = if power_arm {
+        pitch("Fastball", 97, 0, 0.9, 4)
+    } else {
+        pitch("Fastball", 92, 0, 0.95, 0)
+    };
+    let second = if stats.avg_hit_angle <= SINKERBALLER_MAX_LAUNCH_ANGLE {
+        pitch("Sinker", 91, 2, 0.9, 1)
+    } else {
+        pitch("Curveball", 77, 5, 0.8, 3)
+    };
+    let slider = if stats.barrel_percent <= SWING_AND_MISS_MAX_BARREL_PERCENT {
+        pitch("Slider", 86, 4, 0.8, 6)
+    } else {
+        pitch("Slider", 83, 3, 0.85, 2)
+    };
+
+    let mut arsenal = vec![fastball, second, slider];
+    if !power_arm {
+        arsenal.push(pitch("Changeup", 84, 1, 0.9, 2));
+    }
+    arsenal
+}
+
+/// `synthesize_arsenal`, tuned by the pitcher's own statcast line when the file has one: the
+/// fastball comes in at its measured velocity and the walk rate moves every pitch's control.
+pub fn pitcher_arsenal(pitcher: &Player) -> Vec<PitchType> {
+    use crate::game::constants::*;
+    let mut arsenal = synthesize_arsenal(&pitcher.stats);
+    let Some(pitching) = &pitcher.pitching else {
+        return arsenal;
+    };
+    if let (Some(mph), Some(fastball)) = (pitching.fastball_velocity, arsenal.first_mut()) {
+        fastball.speed = mph.round().clamp(MIN_PITCH_SPEED_MPH, MAX_PITCH_SPEED_MPH) as u8;
+    }
+    if let Some(walks) = pitching.bb_percent {
+        let shift = (LEAGUE_AVG_BB_PERCENT - walks) * BB_PERCENT_CONTROL_SCALE;
+        for pitch in &mut arsenal {
+            pitch.control = (pitch.control + shift).clamp(MIN_PITCH_CONTROL, 1.0);
+        }
+    }
+    arsenal
+}
+
+/// Outs above average at each position, indexed by `Position as usize`
+pub type Defense = [i8; 9];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Position {
     Pitcher,
     Catcher,
@@ -71,7 +295,33 @@ pub enum Position {
     RightField,
 }
 
+/// Batters have no position data, so spread them around the diamond in lineup order
+pub fn batting_position(idx: usize) -> Position {
+    match idx % 8 {
+        0 => Position::Catcher,
+        1 => Position::FirstBase,
+        2 => Position::SecondBase,
+        3 => Position::ThirdBase,
+        4 => Position::Shortstop,
+        5 => Position::LeftField,
+        6 => Position::CenterField,
+        _ => Position::RightField,
+    }
+}
+
 impl Position {
+    /// Everyone in the field but the pitcher, in scorebook order
+    pub const FIELDERS: [Position; 8] = [
+        Position::Catcher,
+        Position::FirstBase,
+        Position::SecondBase,
+        Position::ThirdBase,
+        Position::Shortstop,
+        Position::LeftField,
+        Position::CenterField,
+        Position::RightField,
+    ];
+
     pub fn name(&self) -> &'static str {
         match self {
             Position::Pitcher => "P",
@@ -96,10 +346,20 @@ pub struct Team {
     pub current_pitcher_idx: usize,
     pub pitcher_stamina: f32,  // 0.0 to 100.0, starts at 100
     pub pitches_thrown: u32,    // Track total pitches thrown
+    pub season: u16,
+    pub skipped_rows: usize,    // Malformed CSV rows dropped while loading
+    pub ballpark_name: String,
+    pub removed_players: HashSet<String>, // Substituted out; can't come back in
+    pub closer: Option<String>, // Pitcher id who gets the ball in save situations
+    pub replacement_batters: usize, // Filler hitters added because too few qualified
+    pub replacement_pitchers: usize, // Filler arms added because no pitcher qualified
+    pub pitchers_bat: bool, // No DH - whoever is pitching hits in the order
+    pub pitcher_pinch_hitter: Option<String>, // Batted for the pitcher; leaves when the reliever comes in
 }
 
 impl Team {
     pub fn new(name: String, abbreviation: String) -> Self {
+        let ballpark_name = crate::game::ballpark::home_ballpark_name(&abbreviation).to_string();
         Self {
             name,
             abbreviation,
@@ -108,9 +368,44 @@ impl Team {
             current_pitcher_idx: 0,
             pitcher_stamina: crate::game::constants::STARTING_STAMINA,
             pitches_thrown: 0,
+            season: crate::game::constants::DEFAULT_SEASON,
+            skipped_rows: 0,
+            ballpark_name,
+            removed_players: HashSet::new(),
+            closer: None,
+            replacement_batters: 0,
+            replacement_pitchers: 0,
+            pitchers_bat: false,
+            pitcher_pinch_hitter: None,
         }
     }
 
+    /// Fill a batting order that's short of nine - too few hitters reached
+    /// `MIN_PLAYER_ATTEMPTS` - with replacement-level players, so there's always someone to
+    /// send up. Returns how many were added.
+    pub fn fill_batting_order(&mut self) -> usize {
+        let short = crate::game::constants::BATTING_ORDER_SIZE.saturating_sub(self.batters.len());
+        for _ in 0..short {
+            let idx = self.batters.len();
+            self.replacement_batters += 1;
+            let player = replacement_batter(&self.abbreviation, self.replacement_batters, batting_position(idx));
+            self.batters.push(player);
+        }
+        short
+    }
+
+    /// Give a team with no qualifying pitchers a replacement-level one, so a game never starts
+    /// with nobody on the mound. Returns how many were added.
+    pub fn fill_pitching_staff(&mut self) -> usize {
+        if !self.pitchers.is_empty() {
+            return 0;
+        }
+        self.pitchers.push(replacement_pitcher(&self.abbreviation));
+        self.current_pitcher_idx = 0;
+        self.replacement_pitchers += 1;
+        1
+    }
+
     pub fn get_current_pitcher(&self) -> Option<&Player> {
         self.pitchers.get(self.current_pitcher_idx)
     }
@@ -129,6 +424,149 @@ impl Team {
         self.batters.len().min(crate::game::constants::BATTING_ORDER_SIZE)
     }
 
+    /// The starter playing `position` in the field - the first in the order if two share it
+    pub fn fielder(&self, position: Position) -> Option<&Player> {
+        if position == Position::Pitcher {
+            return self.get_current_pitcher();
+        }
+        self.batters
+            .iter()
+            .take(self.batting_order_size())
+            .find(|p| p.position == position)
+    }
+
+    /// Outs above average of whoever is playing `position`, 0 when nobody is
+    pub fn fielding_rating(&self, position: Position) -> i8 {
+        self.fielder(position).map(|p| p.outs_above_average).unwrap_or(0)
+    }
+
+    /// Everyone's fielding rating, for the engine while this team is in the field
+    pub fn defense(&self) -> Defense {
+        let mut defense = [0; 9];
+        for position in Position::FIELDERS.into_iter().chain([Position::Pitcher]) {
+            defense[position as usize] = self.fielding_rating(position);
+        }
+        defense
+    }
+
+    /// Batters past the starting lineup who haven't been used yet
+    pub fn bench(&self) -> impl Iterator<Item = &Player> {
+        self.batters
+            .iter()
+            .skip(self.batting_order_size())
+            .filter(|p| !self.removed_players.contains(&p.stats.id))
+    }
+
+    /// Swap a bench player into a lineup spot. The player taken out can't return.
+    pub fn substitute(&mut self, out_id: &str, in_id: &str) -> bool {
+        let order_size = self.batting_order_size();
+        let slot = self.batters.iter().take(order_size).position(|p| p.stats.id == out_id);
+        let bench = self.batters.iter().skip(order_size).position(|p| p.stats.id == in_id);
+        match (slot, bench) {
+            (Some(slot), Some(bench)) => {
+                self.batters.swap(slot, order_size + bench);
+                self.removed_players.insert(out_id.to_string());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the hitter in lineup slot `from` to slot `to`, sliding everyone in between over a
+    /// spot. Slots are 0-based and limited to the starting lineup.
+    pub fn move_batting_order(&mut self, from: usize, to: usize) -> Result<(), String> {
+        let order_size = self.batting_order_size().min(self.batters.len());
+        if from >= order_size || to >= order_size {
+            return Err(format!("Lineup slots run 1-{}", order_size));
+        }
+        let hitter = self.batters.remove(from);
+        self.batters.insert(to, hitter);
+        Ok(())
+    }
+
+    pub fn set_starting_pitcher(&mut self, idx: usize) -> Result<(), String> {
+        if idx >= self.pitchers.len() {
+            return Err(format!("{} only has {} pitchers", self.name, self.pitchers.len()));
+        }
+        self.current_pitcher_idx = idx;
+        self.refresh_pitcher_slot();
+        Ok(())
+    }
+
+    /// Play without a DH: the starting pitcher bats ninth and the hitter who was there drops
+    /// to the top of the bench
+    pub fn bat_pitcher(&mut self) {
+        let Some(pitcher) = self.get_current_pitcher() else {
+            return;
+        };
+        let hitter = pitcher_as_batter(pitcher);
+        let slot = (crate::game::constants::BATTING_ORDER_SIZE - 1).min(self.batters.len());
+        self.batters.insert(slot, hitter);
+        self.pitchers_bat = true;
+    }
+
+    /// Lineup slot the pitcher hits in - or the pinch hitter who batted for them
+    fn pitcher_slot(&self) -> Option<usize> {
+        self.batters
+            .iter()
+            .take(self.batting_order_size())
+            .position(|p| p.is_pitcher || self.pitcher_pinch_hitter.as_ref() == Some(&p.stats.id))
+    }
+
+    /// Without a DH, put whoever is on the mound in the pitcher's slot. A pinch hitter who
+    /// batted for the last pitcher is out of the game.
+    fn refresh_pitcher_slot(&mut self) {
+        if !self.pitchers_bat {
+            return;
+        }
+        let (Some(slot), Some(pitcher)) = (self.pitcher_slot(), self.get_current_pitcher()) else {
+            return;
+        };
+        if self.batters[slot].stats.id == pitcher.stats.id {
+            return;
+        }
+        let hitter = pitcher_as_batter(pitcher);
+        let out = std::mem::replace(&mut self.batters[slot], hitter);
+        if self.pitcher_pinch_hitter.take().is_some() {
+            self.removed_players.insert(out.stats.id);
+        }
+    }
+
+    /// A pitcher who was hit for can't go back out - the next arm in the bullpen takes over.
+    /// Returns false when no change was needed or nobody is left.
+    pub fn relieve_removed_pitcher(&mut self) -> bool {
+        let Some(current) = self.get_current_pitcher() else {
+            return false;
+        };
+        if !self.removed_players.contains(&current.stats.id) {
+            return false;
+        }
+        let count = self.pitchers.len();
+        let Some(idx) = (1..count)
+            .map(|step| (self.current_pitcher_idx + step) % count)
+            .find(|&idx| !self.removed_players.contains(&self.pitchers[idx].stats.id))
+        else {
+            return false;
+        };
+        self.current_pitcher_idx = idx;
+        self.pitcher_stamina = crate::game::constants::STARTING_STAMINA;
+        self.pitches_thrown = 0;
+        self.refresh_pitcher_slot();
+        true
+    }
+
+    /// A game needs a full batting order and someone on the mound
+    pub fn validate_lineup(&self) -> Result<(), String> {
+        use crate::game::constants::BATTING_ORDER_SIZE;
+        if self.batters.len() < BATTING_ORDER_SIZE {
+            return Err(format!("{} needs {} hitters but only has {}", self.name, BATTING_ORDER_SIZE, self.batters.len()));
+        }
+        if self.get_current_pitcher().is_none() {
+            return Err(format!("{} has no starting pitcher", self.name));
+        }
+        Ok(())
+    }
+
     pub fn decrease_stamina(&mut self, amount: f32) {
         self.pitcher_stamina = (self.pitcher_stamina - amount).max(0.0);
         self.pitches_thrown += 1;
@@ -151,76 +589,478 @@ impl Team {
         }
     }
 
+    /// Hand the ball to the designated closer. Returns false when there's no closer on the
+    /// staff or they're already pitching.
+    pub fn bring_in_closer(&mut self) -> bool {
+        let Some(closer) = &self.closer else {
+            return false;
+        };
+        if self.removed_players.contains(closer) {
+            return false;
+        }
+        match self.pitchers.iter().position(|p| &p.stats.id == closer) {
+            Some(idx) if idx != self.current_pitcher_idx => {
+                self.current_pitcher_idx = idx;
+                self.pitcher_stamina = crate::game::constants::STARTING_STAMINA;
+                self.pitches_thrown = 0;
+                self.refresh_pitcher_slot();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Relievers who can come in - not the pitcher on the mound and nobody already taken out
+    pub fn bullpen(&self) -> impl Iterator<Item = &Player> {
+        self.pitchers
+            .iter()
+            .enumerate()
+            .filter(|(idx, p)| *idx != self.current_pitcher_idx && !self.removed_players.contains(&p.stats.id))
+            .map(|(_, p)| p)
+    }
+
+    /// Lineup slots a new pitcher can take in a double switch - every one but the pitcher's
+    /// own, and none at all with a DH or nobody left on the bench
+    pub fn double_switch_slots(&self) -> Vec<usize> {
+        if !self.pitchers_bat || self.bench().next().is_none() {
+            return Vec::new();
+        }
+        let pitcher_slot = self.pitcher_slot();
+        (0..self.batting_order_size().min(self.batters.len()))
+            .filter(|&slot| Some(slot) != pitcher_slot)
+            .collect()
+    }
+
+    /// Pitching change to `reliever_id`; the pitcher coming out is out of the game. A double
+    /// switch also sends bench player `sub_id` in for the fielder batting in `slot`: the sub
+    /// takes the old pitcher's spot in the order and the reliever bats in `slot`.
+    pub fn bring_in_reliever(&mut self, reliever_id: &str, double_switch: Option<(usize, &str)>) -> Result<(), String> {
+        if !self.bullpen().any(|p| p.stats.id == reliever_id) {
+            return Err(format!("{} isn't available to pitch", reliever_id));
+        }
+        let idx = self.pitchers.iter().position(|p| p.stats.id == reliever_id).unwrap_or(self.current_pitcher_idx);
+        if let Some((slot, sub_id)) = double_switch {
+            if !self.pitchers_bat {
+                return Err("Double switches are only for games without a DH".to_string());
+            }
+            if !self.double_switch_slots().contains(&slot) {
+                return Err(format!("The new pitcher can't bat {}", slot + 1));
+            }
+            let pitcher_slot = self.pitcher_slot().ok_or("Nobody is batting for the pitcher")?;
+            let (out_id, position) = (self.batters[slot].stats.id.clone(), self.batters[slot].position);
+            if !self.substitute(&out_id, sub_id) {
+                return Err(format!("{} isn't available to come in", sub_id));
+            }
+            self.batters[slot].position = position;
+            self.batters.swap(slot, pitcher_slot);
+        }
+        if let Some(outgoing) = self.get_current_pitcher() {
+            let id = outgoing.stats.id.clone();
+            self.removed_players.insert(id);
+        }
+        self.current_pitcher_idx = idx;
+        self.pitcher_stamina = crate::game::constants::STARTING_STAMINA;
+        self.pitches_thrown = 0;
+        self.refresh_pitcher_slot();
+        Ok(())
+    }
+
     pub fn change_pitcher(&mut self) {
         if !self.pitchers.is_empty() {
             self.current_pitcher_idx = (self.current_pitcher_idx + 1) % self.pitchers.len();
             self.pitcher_stamina = crate::game::constants::STARTING_STAMINA;
             self.pitches_thrown = 0;
+            self.refresh_pitcher_slot();
         }
     }
 }
 
+/// Statcast numbers the league leaders can be ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatCategory {
+    BarrelPercent,
+    MaxExitVelo,
+    MaxDistance,
+}
+
+impl StatCategory {
+    pub const ALL: [StatCategory; 3] = [StatCategory::BarrelPercent, StatCategory::MaxExitVelo, StatCategory::MaxDistance];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatCategory::BarrelPercent => "Barrel %",
+            StatCategory::MaxExitVelo => "Max Exit Velo",
+            StatCategory::MaxDistance => "Max Distance",
+        }
+    }
+
+    /// The short name used on the command line, e.g. `--leaders exit-velo`
+    pub fn flag(&self) -> &'static str {
+        match self {
+            StatCategory::BarrelPercent => "barrel",
+            StatCategory::MaxExitVelo => "exit-velo",
+            StatCategory::MaxDistance => "distance",
+        }
+    }
+
+    pub fn from_flag(flag: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.flag() == flag)
+    }
+
+    pub fn value(&self, stats: &PlayerStats) -> f32 {
+        match self {
+            StatCategory::BarrelPercent => stats.barrel_percent,
+            StatCategory::MaxExitVelo => stats.max_hit_speed,
+            StatCategory::MaxDistance => stats.max_distance as f32,
+        }
+    }
+}
+
+/// One row of a league leaders list
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatLeader {
+    pub name: String,
+    pub team: String, // Team key, e.g. "LAD_2024"
+    pub value: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct TeamManager {
-    pub teams: HashMap<String, Team>,
+    pub teams: HashMap<String, Team>, // Keyed by team key, e.g. "LAD_2024"
+    pub data_dir: PathBuf,
+    pub season: u16, // Season for bare abbreviations and new team templates
+    pub available: HashSet<String>, // Team keys with a JSON roster or both CSVs on disk
+    pub custom_teams: HashMap<String, CustomRoster>, // JSON rosters from the last scan or league
+    pub ratings: HashMap<String, TeamRating>, // Filled in as teams load
+}
+
+/// Key for one season of one team, so different years of a franchise can coexist
+pub fn team_key(abbr: &str, season: u16) -> String {
+    format!("{}_{}", abbr, season)
+}
+
+/// Split a team key back into abbreviation and season
+pub fn split_team_key(key: &str) -> Option<(&str, u16)> {
+    let (abbr, season) = key.rsplit_once('_')?;
+    Some((abbr, season.parse().ok()?))
+}
+
+/// Whether every character of `needle` appears in `haystack` in order
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut rest = haystack.chars();
+    needle.chars().all(|c| rest.any(|h| h == c))
+}
+
+/// Players parsed from one CSV, plus how many rows had to be skipped
+#[derive(Debug)]
+pub struct CsvLoad {
+    pub players: Vec<Player>,
+    pub skipped_rows: usize,
+}
+
+/// Arsenals parsed from one CSV keyed by player id, plus how many rows had to be skipped
+#[derive(Debug)]
+pub struct ArsenalLoad {
+    pub arsenals: HashMap<String, Vec<PitchType>>,
+    pub skipped_rows: usize,
+}
+
+/// Sprint speeds parsed from one CSV keyed by player id, plus how many rows had to be skipped
+#[derive(Debug)]
+pub struct SprintSpeedLoad {
+    pub speeds: HashMap<String, f32>,
+    pub skipped_rows: usize,
+}
+
+/// Batting and throwing hands parsed from one CSV keyed by player id, plus how many rows had
+/// to be skipped
+#[derive(Debug)]
+pub struct HandednessLoad {
+    pub hands: HashMap<String, (Option<Hand>, Option<Hand>)>,
+    pub skipped_rows: usize,
+}
+
+/// Outs above average parsed from one CSV keyed by player id, plus how many rows had to be
+/// skipped
+#[derive(Debug)]
+pub struct DefenseLoad {
+    pub defense: HashMap<String, i8>,
+    pub skipped_rows: usize,
 }
 
 impl TeamManager {
-    pub fn new() -> Self {
+    /// The teams a config points at - its league file if it has one, else whatever is in its
+    /// data directory. Never fails; problems come back as a warning for the status line.
+    pub fn from_config(config: &GameConfig) -> (Self, Option<String>) {
+        let mut team_manager = TeamManager::new(&config.data_dir, config.season);
+        team_manager.scan_available_teams(); // Teams themselves load on demand
+        let league_error = config.league_file.as_ref().and_then(|league| {
+            let e = team_manager.load_league(league).err()?;
+            Some(format!("Couldn't load league {} ({}), using the built-in teams", league, e))
+        });
+        let warning = if league_error.is_some() {
+            league_error
+        } else if team_manager.get_team_list().is_empty() {
+            Some(format!("No teams found in {} - see docs/README.md for getting data", config.data_dir))
+        } else {
+            None
+        };
+        (team_manager, warning)
+    }
+
+    pub fn new<P: Into<PathBuf>>(data_dir: P, season: u16) -> Self {
         Self {
             teams: HashMap::new(),
+            data_dir: data_dir.into(),
+            season,
+            available: HashSet::new(),
+            custom_teams: HashMap::new(),
+            ratings: HashMap::new(),
         }
     }
 
-    /// Get list of all available team abbreviations without loading them
+    pub fn batter_path(&self, key: &str) -> PathBuf {
+        self.data_dir.join(format!("batter_{}.csv", key))
+    }
+
+    pub fn pitcher_path(&self, key: &str) -> PathBuf {
+        self.data_dir.join(format!("pitcher_{}.csv", key))
+    }
+
+    pub fn custom_team_path(&self, key: &str) -> PathBuf {
+        self.data_dir.join(format!("team_{}.json", key))
+    }
+
+    pub fn is_available(&self, key: &str) -> bool {
+        self.available.contains(key)
+    }
+
+    /// Get the selectable team keys without loading them - whatever the last scan found
+    /// on disk, ordered by abbreviation and then season
     pub fn get_team_list(&self) -> Vec<String> {
-        vec![
-            "ARI", "ATL", "BAL", "BOS", "CHC", "CIN", "CLE", "COL", "CWS", "DET",
-            "HOU", "KC", "LAA", "LAD", "MIA", "MIL", "MIN", "NYM", "NYY", "OAK",
-            "PHI", "PIT", "SD", "SDG", "SEA", "SF", "STL", "TB", "TEX", "THW", "TOR", "WSH"
-        ].iter().map(|s| s.to_string()).collect()
+        let mut keys: Vec<&String> = self.available.iter().collect();
+        keys.sort_by_key(|key| split_team_key(key));
+        keys.into_iter().cloned().collect()
+    }
+
+    /// Positions in `get_team_list` whose abbreviation or name match `query`, ignoring case.
+    /// Substring matches come first, then names containing the query's letters in order.
+    pub fn search_teams(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        let labels: Vec<String> = self
+            .get_team_list()
+            .iter()
+            .map(|key| self.describe_team(key).to_lowercase())
+            .collect();
+
+        let substring = (0..labels.len()).filter(|&idx| labels[idx].contains(&query));
+        let in_order = (0..labels.len())
+            .filter(|&idx| !labels[idx].contains(&query) && is_subsequence(&query, &labels[idx]));
+        substring.chain(in_order).collect()
+    }
+
+    /// True when teams from more than one season are on disk
+    pub fn has_multiple_seasons(&self) -> bool {
+        let seasons: HashSet<u16> = self
+            .available
+            .iter()
+            .filter_map(|key| split_team_key(key).map(|(_, season)| season))
+            .collect();
+        seasons.len() > 1
     }
 
-    /// Load a specific team's data from CSV files
-    pub fn load_team(&mut self, abbr: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// Selection screen label - the season is only shown when it tells teams apart
+    pub fn describe_team(&self, key: &str) -> String {
+        let (abbr, season) = split_team_key(key).unwrap_or((key, self.season));
+        let name = self.get_team_full_name(key);
+        if self.has_multiple_seasons() {
+            format!("{} - {} ({})", abbr, name, season)
+        } else {
+            format!("{} - {}", abbr, name)
+        }
+    }
+
+    /// Load a specific team's data, preferring a JSON roster over the statcast CSVs
+    pub fn load_team(&mut self, key: &str) -> Result<(), Box<dyn std::error::Error>> {
         // Check if already loaded
-        if self.teams.contains_key(abbr) {
+        if self.teams.contains_key(key) {
             return Ok(());
         }
 
-        let team_name = self.get_team_full_name(abbr);
+        let (abbr, season) = split_team_key(key).unwrap_or((key, self.season));
+        let file_key = team_key(abbr, season); // Bare abbreviations mean the default season
+
+        let roster_path = self.custom_team_path(&file_key);
+        let roster = if let Some(roster) = self.custom_teams.get(key) {
+            Some(roster.clone())
+        } else if roster_path.exists() {
+            Some(CustomRoster::load(&roster_path)
+                .map_err(|e| format!("Failed to load custom team {} from {}: {}", key, roster_path.display(), e))?)
+        } else {
+            None
+        };
+        if let Some(roster) = roster {
+            let mut team = roster.into_team(season);
+            for pitcher in &mut team.pitchers {
+                pitcher.arsenal = pitcher_arsenal(pitcher);
+            }
+            self.insert_team(key, team);
+            return Ok(());
+        }
+
+        let team_name = self.get_team_full_name(key);
         let mut team = Team::new(team_name, abbr.to_string());
+        team.season = season;
 
         // Load batters
-        let batter_path = PathBuf::from("data_down")
-            .join("statcast_downloads")
-            .join(format!("batter_{}_2025.csv", abbr));
-        
+        let batter_path = self.batter_path(&file_key);
+        if !batter_path.exists() {
+            return Err(format!("Missing batter data for {}: expected {}", key, batter_path.display()).into());
+        }
         match Self::load_players_from_csv(&batter_path, false) {
-            Ok(batters) => team.batters = batters,
-            Err(e) => return Err(format!("Failed to load batters for {}: {}", abbr, e).into()),
+            Ok(load) => {
+                team.batters = load.players;
+                team.skipped_rows += load.skipped_rows;
+            }
+            Err(e) => return Err(format!("Failed to load batters for {} from {}: {}", key, batter_path.display(), e).into()),
         }
 
         // Load pitchers  
-        let pitcher_path = PathBuf::from("data_down")
-            .join("statcast_downloads")
-            .join(format!("pitcher_{}_2025.csv", abbr));
-        
+        let pitcher_path = self.pitcher_path(&file_key);
+        if !pitcher_path.exists() {
+            return Err(format!("Missing pitcher data for {}: expected {}", key, pitcher_path.display()).into());
+        }
         match Self::load_players_from_csv(&pitcher_path, true) {
-            Ok(pitchers) => team.pitchers = pitchers,
-            Err(e) => return Err(format!("Failed to load pitchers for {}: {}", abbr, e).into()),
+            Ok(load) => {
+                team.pitchers = load.players;
+                team.skipped_rows += load.skipped_rows;
+            }
+            Err(e) => return Err(format!("Failed to load pitchers for {} from {}: {}", key, pitcher_path.display(), e).into()),
+        }
+
+        // Arsenal file is optional - pitchers without a row get one built from their stats
+        let arsenal_path = self.data_dir.join(format!("arsenal_{}.csv", file_key));
+        let arsenals = if arsenal_path.exists() {
+            let load = Self::load_arsenals_from_csv(&arsenal_path)
+                .map_err(|e| format!("Failed to load arsenals for {}: {}", key, e))?;
+            team.skipped_rows += load.skipped_rows;
+            load.arsenals
+        } else {
+            HashMap::new()
+        };
+        for pitcher in &mut team.pitchers {
+            pitcher.arsenal = arsenals
+                .get(&pitcher.stats.id)
+                .cloned()
+                .unwrap_or_else(|| pitcher_arsenal(pitcher));
+        }
+
+        // Sprint speed file is optional too - without it speeds stay estimated from the stats
+        let sprint_speed_path = self.data_dir.join(format!("sprint_speed_{}.csv", file_key));
+        if sprint_speed_path.exists() {
+            let load = Self::load_sprint_speeds_from_csv(&sprint_speed_path)
+                .map_err(|e| format!("Failed to load sprint speeds for {}: {}", key, e))?;
+            team.skipped_rows += load.skipped_rows;
+            let speeds = load.speeds;
+            for player in team.batters.iter_mut().chain(team.pitchers.iter_mut()) {
+                if let Some(speed) = speeds.get(&player.stats.id) {
+                    player.sprint_speed = *speed;
+                }
+            }
+        }
+
+        // Handedness isn't in the statcast exports, so it comes from its own optional file
+        let handedness_path = self.data_dir.join(format!("handedness_{}.csv", file_key));
+        if handedness_path.exists() {
+            let load = Self::load_handedness_from_csv(&handedness_path)
+                .map_err(|e| format!("Failed to load handedness for {}: {}", key, e))?;
+            team.skipped_rows += load.skipped_rows;
+            let hands = load.hands;
+            for player in team.batters.iter_mut().chain(team.pitchers.iter_mut()) {
+                if let Some(&(bats, throws)) = hands.get(&player.stats.id) {
+                    player.bats = bats.or(player.bats);
+                    player.throws = throws.or(player.throws);
+                }
+            }
+        }
+
+        // Outs above average come from their own optional file; everyone else is average
+        let defense_path = self.data_dir.join(format!("defense_{}.csv", file_key));
+        if defense_path.exists() {
+            let load = Self::load_defense_from_csv(&defense_path)
+                .map_err(|e| format!("Failed to load defense for {}: {}", key, e))?;
+            team.skipped_rows += load.skipped_rows;
+            let defense = load.defense;
+            for player in team.batters.iter_mut().chain(team.pitchers.iter_mut()) {
+                if let Some(oaa) = defense.get(&player.stats.id) {
+                    player.outs_above_average = *oaa;
+                }
+            }
         }
 
         // Only add teams that have players
         if team.batters.is_empty() && team.pitchers.is_empty() {
-            return Err(format!("No player data found for team {}", abbr).into());
+            return Err(format!("No player data found for team {}", key).into());
         }
 
-        self.teams.insert(abbr.to_string(), team);
+        self.insert_team(key, team);
         Ok(())
     }
 
-    pub fn get_team_full_name(&self, abbr: &str) -> String {
+    /// Keep a loaded team and grade it while its roster is at hand
+    fn insert_team(&mut self, key: &str, mut team: Team) {
+        team.fill_batting_order();
+        team.fill_pitching_staff();
+        self.ratings.insert(key.to_string(), rate_team(&team));
+        self.teams.insert(key.to_string(), team);
+    }
+
+    /// Scouting grades for a team, once it has been loaded
+    pub fn team_rating(&self, key: &str) -> Option<TeamRating> {
+        self.ratings.get(key).copied()
+    }
+
+    /// Positions in `get_team_list`, best overall rating first. Teams that haven't been
+    /// loaded yet have no rating and keep their list order at the end.
+    pub fn teams_by_rating(&self) -> Vec<usize> {
+        let teams = self.get_team_list();
+        let mut order: Vec<usize> = (0..teams.len()).collect();
+        order.sort_by_key(|&idx| std::cmp::Reverse(self.team_rating(&teams[idx]).map(|r| r.overall())));
+        order
+    }
+
+    /// The top `n` hitters across every loaded team in `category`, best first. Ties go
+    /// by player name and then team key so the list comes out the same every time.
+    pub fn stat_leaders(&self, category: StatCategory, n: usize) -> Vec<StatLeader> {
+        let mut leaders: Vec<StatLeader> = self
+            .teams
+            .iter()
+            .flat_map(|(key, team)| {
+                team.batters
+                    .iter()
+                    .filter(|player| player.stats.attempts > 0)
+                    .map(move |player| StatLeader {
+                        name: player.stats.name.clone(),
+                        team: key.clone(),
+                        value: category.value(&player.stats),
+                    })
+            })
+            .collect();
+        leaders.sort_by(|a, b| {
+            b.value
+                .total_cmp(&a.value)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.team.cmp(&b.team))
+        });
+        leaders.truncate(n);
+        leaders
+    }
+
+    pub fn get_team_full_name(&self, key: &str) -> String {
+        if let Some(roster) = self.custom_teams.get(key) {
+            return roster.name.clone();
+        }
+        let abbr = split_team_key(key).map(|(abbr, _)| abbr).unwrap_or(key);
         let team_names = [
             ("ARI", "Arizona Diamondbacks"),
             ("ATL", "Atlanta Braves"),
@@ -262,42 +1102,125 @@ impl TeamManager {
             .unwrap_or_else(|| abbr.to_string())
     }
 
-    /// Deprecated - teams are now loaded on demand
-    pub fn load_teams(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        // This is now a no-op - teams load on demand
+    /// Colors a custom roster asked for, if the team is one
+    pub fn team_colors(&self, key: &str) -> Option<&TeamColors> {
+        self.custom_teams.get(key).map(|roster| &roster.colors)
+    }
+
+    /// Check which teams and seasons have data on disk. Teams still load on demand; this
+    /// only decides which ones the selection screen offers.
+    pub fn scan_available_teams(&mut self) {
+        self.custom_teams.clear();
+        let Ok(entries) = std::fs::read_dir(&self.data_dir) else {
+            self.available.clear();
+            return;
+        };
+        let names: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect();
+
+        let mut available: HashSet<String> = names
+            .iter()
+            .filter_map(|name| {
+                let key = name.strip_prefix("batter_")?.strip_suffix(".csv")?;
+                split_team_key(key)?;
+                Some(key.to_string())
+            })
+            .filter(|key| self.pitcher_path(key).exists())
+            .collect();
+
+        // Rosters that don't parse stay off the menu rather than failing at game start
+        for name in &names {
+            let Some(key) = name.strip_prefix("team_").and_then(|n| n.strip_suffix(".json")) else {
+                continue;
+            };
+            if split_team_key(key).is_none() {
+                continue;
+            }
+            if let Ok(roster) = CustomRoster::load(self.data_dir.join(name)) {
+                available.insert(key.to_string());
+                self.custom_teams.insert(key.to_string(), roster);
+            }
+        }
+        self.available = available;
+    }
+
+    /// Replace the team list with the teams in a league file. On error the current list is kept.
+    pub fn load_league<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let league = League::load(path)?;
+        let season = league.season.unwrap_or(self.season);
+
+        self.custom_teams.clear();
+        self.available.clear();
+        for roster in league.teams {
+            let key = team_key(&roster.abbreviation, season);
+            self.available.insert(key.clone());
+            self.custom_teams.insert(key, roster);
+        }
         Ok(())
     }
 
-    fn load_players_from_csv(path: &PathBuf, is_pitcher: bool) -> Result<Vec<Player>, Box<dyn std::error::Error>> {
+    /// Write a roster of league-average players for the user to edit. Never overwrites.
+    pub fn create_team_template(&self, abbr: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if !is_valid_abbreviation(abbr) {
+            return Err(format!("Invalid team abbreviation {:?} - use 2-4 capital letters or digits", abbr).into());
+        }
+        let path = self.custom_team_path(&team_key(abbr, self.season));
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()).into());
+        }
+        std::fs::create_dir_all(&self.data_dir)?;
+        CustomRoster::template(abbr, &format!("{} Custom Team", abbr)).save(&path)?;
+        Ok(path)
+    }
+
+    /// Parse a statcast CSV, skipping rows that don't deserialize. Only fails when the
+    /// file can't be opened or when it has rows but none of them are usable.
+    pub(crate) fn load_players_from_csv<P: AsRef<Path>>(path: P, is_pitcher: bool) -> Result<CsvLoad, Box<dyn std::error::Error>> {
         let mut rdr = csv::Reader::from_path(path)?;
+        let headers = rdr.headers()?.clone();
         let mut players = Vec::new();
+        let mut skipped_rows = 0;
+        let mut first_error: Option<csv::Error> = None;
 
-        for result in rdr.deserialize() {
-            let stats: PlayerStats = result?;
+        for result in rdr.records() {
+            let parsed = result.and_then(|record| {
+                let stats: PlayerStats = record.deserialize(Some(&headers))?;
+                // Pitcher files may carry extra columns on top of the shared batted-ball schema
+                let pitching = if is_pitcher {
+                    Some(record.deserialize::<PitcherStats>(Some(&headers))?)
+                } else {
+                    None
+                };
+                Ok((stats, pitching))
+            });
+            let (stats, pitching) = match parsed {
+                Ok(row) => row,
+                Err(e) => {
+                    skipped_rows += 1;
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
             
             // Only include players with reasonable number of attempts
             if stats.attempts >= crate::game::constants::MIN_PLAYER_ATTEMPTS {
                 let position = if is_pitcher {
                     Position::Pitcher
                 } else {
-                    // For batters, we'll assign positions based on their stats
-                    // This is a simple heuristic - in a real game you'd have position data
-                    match players.len() % 8 {
-                        0 => Position::Catcher,
-                        1 => Position::FirstBase,
-                        2 => Position::SecondBase,
-                        3 => Position::ThirdBase,
-                        4 => Position::Shortstop,
-                        5 => Position::LeftField,
-                        6 => Position::CenterField,
-                        _ => Position::RightField,
-                    }
+                    batting_position(players.len())
                 };
 
                 players.push(Player {
+                    sprint_speed: estimate_sprint_speed(&stats),
+                    bats: None,
+                    throws: None,
+                    outs_above_average: 0,
                     stats,
                     is_pitcher,
                     position,
+                    arsenal: Vec::new(),
+                    pitching,
                 });
             }
         }
@@ -310,14 +1233,142 @@ impl TeamManager {
             players.sort_by(|a, b| a.stats.barrel_percent.partial_cmp(&b.stats.barrel_percent).unwrap_or(std::cmp::Ordering::Equal));
         }
 
-        Ok(players)
+        // A file where nothing parses (e.g. a missing column) is an error, not an empty roster
+        if players.is_empty() {
+            if let Some(e) = first_error {
+                return Err(format!("all {} rows are malformed ({})", skipped_rows, e).into());
+            }
+        }
+
+        Ok(CsvLoad { players, skipped_rows })
     }
 
-    pub fn get_team(&self, abbr: &str) -> Option<&Team> {
-        self.teams.get(abbr)
+    /// Parse per-pitch rows into arsenals keyed by player id, capped at the selectable pitch
+    /// count. Rows that don't deserialize are skipped, as with the statcast CSVs.
+    pub fn load_arsenals_from_csv<P: AsRef<Path>>(path: P) -> Result<ArsenalLoad, Box<dyn std::error::Error>> {
+        let mut rdr = csv::Reader::from_path(path)?;
+        let mut arsenals: HashMap<String, Vec<PitchType>> = HashMap::new();
+        let mut skipped_rows = 0;
+        let mut first_error: Option<csv::Error> = None;
+
+        for result in rdr.deserialize() {
+            let row: ArsenalRow = match result {
+                Ok(row) => row,
+                Err(e) => {
+                    skipped_rows += 1;
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            let arsenal = arsenals.entry(row.player_id).or_default();
+            if arsenal.len() < crate::game::constants::MAX_ARSENAL_SIZE {
+                arsenal.push(PitchType {
+                    control: row.control.clamp(0.0, 1.0),
+                    whiff: row.whiff,
+                    ..PitchType::new(&row.pitch_name, row.speed, row.break_amount)
+                });
+            }
+        }
+
+        if arsenals.is_empty() {
+            if let Some(e) = first_error {
+                return Err(format!("all {} rows are malformed ({})", skipped_rows, e).into());
+            }
+        }
+
+        Ok(ArsenalLoad { arsenals, skipped_rows })
     }
 
-    pub fn get_team_mut(&mut self, abbr: &str) -> Option<&mut Team> {
-        self.teams.get_mut(abbr)
+    /// Sprint speeds keyed by player id, clamped to a believable range. Rows that don't
+    /// deserialize are skipped, and those players keep their estimated speed.
+    pub fn load_sprint_speeds_from_csv<P: AsRef<Path>>(path: P) -> Result<SprintSpeedLoad, Box<dyn std::error::Error>> {
+        use crate::game::constants::{SPRINT_SPEED_MAX, SPRINT_SPEED_MIN};
+        let mut rdr = csv::Reader::from_path(path)?;
+        let mut speeds = HashMap::new();
+        let mut skipped_rows = 0;
+        let mut first_error: Option<csv::Error> = None;
+
+        for result in rdr.deserialize() {
+            let row: SprintSpeedRow = match result {
+                Ok(row) => row,
+                Err(e) => {
+                    skipped_rows += 1;
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            speeds.insert(row.player_id, row.sprint_speed.clamp(SPRINT_SPEED_MIN, SPRINT_SPEED_MAX));
+        }
+
+        if speeds.is_empty() {
+            if let Some(e) = first_error {
+                return Err(format!("all {} rows are malformed ({})", skipped_rows, e).into());
+            }
+        }
+
+        Ok(SprintSpeedLoad { speeds, skipped_rows })
+    }
+
+    /// Batting and throwing hands keyed by player id. Rows that don't deserialize are skipped,
+    /// and those players' hands stay unknown.
+    pub fn load_handedness_from_csv<P: AsRef<Path>>(path: P) -> Result<HandednessLoad, Box<dyn std::error::Error>> {
+        let mut rdr = csv::Reader::from_path(path)?;
+        let mut hands = HashMap::new();
+        let mut skipped_rows = 0;
+        let mut first_error: Option<csv::Error> = None;
+
+        for result in rdr.deserialize() {
+            let row: HandednessRow = match result {
+                Ok(row) => row,
+                Err(e) => {
+                    skipped_rows += 1;
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            hands.insert(row.player_id, (row.bats, row.throws));
+        }
+
+        if hands.is_empty() {
+            if let Some(e) = first_error {
+                return Err(format!("all {} rows are malformed ({})", skipped_rows, e).into());
+            }
+        }
+
+        Ok(HandednessLoad { hands, skipped_rows })
+    }
+
+    /// Outs above average keyed by player id, clamped to a believable range. Rows that don't
+    /// deserialize are skipped, and those players stay average.
+    pub fn load_defense_from_csv<P: AsRef<Path>>(path: P) -> Result<DefenseLoad, Box<dyn std::error::Error>> {
+        use crate::game::constants::OUTS_ABOVE_AVERAGE_MAX;
+        let mut rdr = csv::Reader::from_path(path)?;
+        let mut defense = HashMap::new();
+        let mut skipped_rows = 0;
+        let mut first_error: Option<csv::Error> = None;
+
+        for result in rdr.deserialize() {
+            let row: DefenseRow = match result {
+                Ok(row) => row,
+                Err(e) => {
+                    skipped_rows += 1;
+                    first_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            defense.insert(row.player_id, row.outs_above_average.clamp(-OUTS_ABOVE_AVERAGE_MAX, OUTS_ABOVE_AVERAGE_MAX));
+        }
+
+        if defense.is_empty() {
+            if let Some(e) = first_error {
+                return Err(format!("all {} rows are malformed ({})", skipped_rows, e).into());
+            }
+        }
+
+        Ok(DefenseLoad { defense, skipped_rows })
+    }
+
+    pub fn get_team(&self, abbr: &str) -> Option<&Team> {
+        self.teams.get(abbr)
     }
 }
@@ -0,0 +1,555 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::constants::{BATTING_ORDER_SIZE, DEFAULT_SEASON, LEAGUE_AVG_SPRINT_SPEED, OUTS_ABOVE_AVERAGE_MAX};
+    use crate::team::{estimate_sprint_speed, pitcher_arsenal, split_team_key, synthesize_arsenal, team_key, Hand, PitcherStats, StatCategory, TeamManager};
+
+    fn fixture(name: &str) -> String {
+        format!("{}/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn test_pitcher_csv_with_pitching_columns() {
+        let pitchers = TeamManager::load_players_from_csv(fixture("pitcher_sample_2025.csv"), true).unwrap().players;
+        assert_eq!(pitchers.len(), 2);
+
+        let richardson = pitchers.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(
+            richardson.pitching,
+            Some(PitcherStats {
+                fastball_velocity: Some(98.2),
+                whiff_percent: Some(31.5),
+                k_percent: Some(29.4),
+                bb_percent: Some(6.2),
+            })
+        );
+
+        // Blank cells read as missing rather than failing the row
+        let kim = pitchers.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.pitching, Some(PitcherStats::default()));
+    }
+
+    #[test]
+    fn test_legacy_pitcher_csv_still_loads() {
+        let pitchers = TeamManager::load_players_from_csv(fixture("pitcher_legacy_2025.csv"), true).unwrap().players;
+        assert_eq!(pitchers.len(), 1);
+        assert_eq!(pitchers[0].stats.name, "Richardson, Tyler");
+        assert_eq!(pitchers[0].pitching, Some(PitcherStats::default()));
+    }
+
+    #[test]
+    fn test_pitcher_csv_line_shapes_the_arsenal() {
+        let pitchers = TeamManager::load_players_from_csv(fixture("pitcher_sample_2025.csv"), true).unwrap().players;
+        let richardson = pitchers.iter().find(|p| p.stats.id == "800301").unwrap();
+        let arsenal = pitcher_arsenal(richardson);
+        assert_eq!(arsenal[0].name, "Fastball");
+        assert_eq!(arsenal[0].speed, 98);
+        // A 6.2% walk rate is better than average, so every pitch finds its spot more often
+        let generic = synthesize_arsenal(&richardson.stats);
+        for (pitch, base) in arsenal.iter().zip(&generic) {
+            assert!(pitch.control > base.control && pitch.control <= 1.0, "{}: {}", pitch.name, pitch.control);
+        }
+
+        // Blank pitching columns leave the arsenal built from batted-ball stats alone
+        let kim = pitchers.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(pitcher_arsenal(kim), synthesize_arsenal(&kim.stats));
+    }
+
+    #[test]
+    fn test_batters_carry_no_pitching_stats() {
+        let batters = TeamManager::load_players_from_csv(fixture("pitcher_sample_2025.csv"), false).unwrap().players;
+        assert!(batters.iter().all(|b| b.pitching.is_none()));
+    }
+
+    #[test]
+    fn test_bad_row_is_skipped_not_fatal() {
+        let load = TeamManager::load_players_from_csv(fixture("batter_bad_row_2025.csv"), false).unwrap();
+        assert_eq!(load.players.len(), 2);
+        assert_eq!(load.skipped_rows, 1);
+    }
+
+    #[test]
+    fn test_missing_column_fails_with_reason() {
+        let err = TeamManager::load_players_from_csv(fixture("batter_missing_column_2025.csv"), false).unwrap_err();
+        assert!(err.to_string().contains("brl_pa"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_empty_file_has_no_players() {
+        let load = TeamManager::load_players_from_csv(fixture("batter_empty_2025.csv"), false).unwrap();
+        assert!(load.players.is_empty());
+        assert_eq!(load.skipped_rows, 0);
+    }
+
+    #[test]
+    fn test_scan_lists_only_teams_with_both_files() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+        assert!(manager.is_available("THW_2025"));
+        assert!(!manager.is_available("SDG_2025")); // Pitchers only
+        assert!(!manager.is_available("NYY_2025"));
+    }
+
+    #[test]
+    fn test_load_team_reports_expected_path_and_skipped_rows() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+
+        let err = manager.load_team("SDG_2025").unwrap_err().to_string();
+        assert!(err.contains("batter_SDG_2025.csv"), "unexpected error: {}", err);
+
+        manager.load_team("THW_2025").unwrap();
+        let team = manager.get_team("THW_2025").unwrap();
+        assert_eq!(team.batters.len() - team.replacement_batters, 2);
+        assert_eq!(team.skipped_rows, 1);
+    }
+
+    #[test]
+    fn test_bad_arsenal_row_is_skipped_not_fatal() {
+        let load = TeamManager::load_arsenals_from_csv(fixture("arsenal_bad_row_2025.csv")).unwrap();
+        assert_eq!(load.skipped_rows, 1);
+        let names: Vec<&str> = load.arsenals["800301"].iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Four-Seam Fastball", "Changeup"]);
+
+        let dir = std::env::temp_dir().join(format!("bitbatter_bad_arsenal_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("teams/batter_THW_2025.csv"), dir.join("batter_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("teams/pitcher_THW_2025.csv"), dir.join("pitcher_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("arsenal_bad_row_2025.csv"), dir.join("arsenal_TST_2025.csv")).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.skipped_rows, 2); // The batter file's bad row and the arsenal's
+        let richardson = team.pitchers.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(richardson.arsenal.len(), 2);
+        let kim = team.pitchers.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.arsenal, pitcher_arsenal(kim)); // No rows of his own
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_team_without_qualifying_pitchers_gets_a_replacement() {
+        let dir = std::env::temp_dir().join(format!("bitbatter_no_pitchers_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("teams/batter_THW_2025.csv"), dir.join("batter_TST_2025.csv")).unwrap();
+        // The pitcher file is there, but nobody in it qualified
+        let pitchers = std::fs::read_to_string(fixture("teams/pitcher_THW_2025.csv")).unwrap();
+        std::fs::write(dir.join("pitcher_TST_2025.csv"), pitchers.lines().next().unwrap()).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.replacement_pitchers, 1);
+        let pitcher = team.get_current_pitcher().unwrap();
+        assert!(pitcher.is_pitcher && pitcher.stats.id.contains('#'), "{}", pitcher.stats.id);
+        assert!(!pitcher.arsenal.is_empty());
+        assert!(team.validate_lineup().is_ok());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_short_lineup_is_filled_with_replacement_hitters() {
+        let dir = std::env::temp_dir().join(format!("bitbatter_short_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let batters = std::fs::read_to_string(fixture("teams/batter_THW_2025.csv")).unwrap();
+        let mut lines: Vec<String> = batters.lines().filter(|line| !line.contains("Broken")).map(str::to_string).collect();
+        lines.push(lines[1].replace("Richardson, Tyler", "Ortiz, Luis").replace("800301", "800303"));
+        // Too few batted balls to qualify
+        lines.push(lines[1].replace("Richardson, Tyler", "Cup, Coffee").replace("800301", "800304").replace("\"502\"", "\"12\""));
+        std::fs::write(dir.join("batter_TST_2025.csv"), lines.join("\n")).unwrap();
+        std::fs::copy(fixture("teams/pitcher_THW_2025.csv"), dir.join("pitcher_TST_2025.csv")).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.batting_order_size(), BATTING_ORDER_SIZE);
+        assert_eq!(team.replacement_batters, 6);
+        assert!((0..BATTING_ORDER_SIZE).all(|idx| team.get_batter(idx).is_some()));
+        let filler = &team.batters[BATTING_ORDER_SIZE - 1];
+        assert!(filler.stats.id.contains('#'), "{}", filler.stats.id);
+        assert!(filler.stats.barrel_percent < team.batters[0].stats.barrel_percent);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_sprint_speed_file_overrides_the_estimate() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.load_team("THW_2025").unwrap();
+        let team = manager.get_team("THW_2025").unwrap();
+
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(richardson.sprint_speed, 29.4);
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.sprint_speed, estimate_sprint_speed(&kim.stats));
+    }
+
+    #[test]
+    fn test_bad_sprint_speed_row_is_skipped_not_fatal() {
+        let load = TeamManager::load_sprint_speeds_from_csv(fixture("sprint_speed_bad_row_2025.csv")).unwrap();
+        assert_eq!(load.skipped_rows, 1);
+        assert_eq!(load.speeds.len(), 1);
+
+        let dir = std::env::temp_dir().join(format!("bitbatter_bad_sprint_speed_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("teams/batter_THW_2025.csv"), dir.join("batter_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("teams/pitcher_THW_2025.csv"), dir.join("pitcher_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("sprint_speed_bad_row_2025.csv"), dir.join("sprint_speed_TST_2025.csv")).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.skipped_rows, 2); // The batter file's bad row and the sprint speed file's
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(richardson.sprint_speed, 29.4);
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.sprint_speed, estimate_sprint_speed(&kim.stats)); // Bad row, so the estimate
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_handedness_file_sets_bats_and_throws() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.load_team("THW_2025").unwrap();
+        let team = manager.get_team("THW_2025").unwrap();
+
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!((richardson.bats, richardson.throws), (Some(Hand::Left), Some(Hand::Right)));
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!((kim.bats, kim.throws), (Some(Hand::Switch), None)); // Blank column stays unknown
+    }
+
+    #[test]
+    fn test_bad_handedness_row_is_skipped_not_fatal() {
+        let load = TeamManager::load_handedness_from_csv(fixture("handedness_bad_row_2025.csv")).unwrap();
+        assert_eq!(load.skipped_rows, 1);
+        assert_eq!(load.hands.len(), 1);
+
+        let dir = std::env::temp_dir().join(format!("bitbatter_bad_handedness_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("teams/batter_THW_2025.csv"), dir.join("batter_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("teams/pitcher_THW_2025.csv"), dir.join("pitcher_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("handedness_bad_row_2025.csv"), dir.join("handedness_TST_2025.csv")).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.skipped_rows, 2); // The batter file's bad row and the handedness file's
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!((richardson.bats, richardson.throws), (Some(Hand::Left), Some(Hand::Right)));
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!((kim.bats, kim.throws), (None, None)); // Bad row, so still unknown
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_defense_file_sets_outs_above_average() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.load_team("THW_2025").unwrap();
+        let team = manager.get_team("THW_2025").unwrap();
+
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(richardson.outs_above_average, 12);
+        assert_eq!(team.fielding_rating(richardson.position), 12);
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.outs_above_average, -OUTS_ABOVE_AVERAGE_MAX); // Clamped
+    }
+
+    #[test]
+    fn test_bad_defense_row_is_skipped_not_fatal() {
+        let load = TeamManager::load_defense_from_csv(fixture("defense_bad_row_2025.csv")).unwrap();
+        assert_eq!(load.skipped_rows, 1);
+        assert_eq!(load.defense.len(), 1);
+
+        let dir = std::env::temp_dir().join(format!("bitbatter_bad_defense_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("teams/batter_THW_2025.csv"), dir.join("batter_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("teams/pitcher_THW_2025.csv"), dir.join("pitcher_TST_2025.csv")).unwrap();
+        std::fs::copy(fixture("defense_bad_row_2025.csv"), dir.join("defense_TST_2025.csv")).unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.load_team("TST_2025").unwrap();
+        let team = manager.get_team("TST_2025").unwrap();
+        assert_eq!(team.skipped_rows, 2); // The batter file's bad row and the defense file's
+        let richardson = team.batters.iter().find(|p| p.stats.id == "800301").unwrap();
+        assert_eq!(richardson.outs_above_average, 12);
+        let kim = team.batters.iter().find(|p| p.stats.id == "800302").unwrap();
+        assert_eq!(kim.outs_above_average, 0); // Bad row, so average
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_slap_hitters_are_estimated_faster_than_sluggers() {
+        let team = crate::roster::CustomRoster::template("TST", "Test").into_team(DEFAULT_SEASON);
+        let mut slap_hitter = team.batters[0].stats.clone();
+        slap_hitter.avg_hit_angle = 4.0;
+        slap_hitter.avg_distance = 160;
+        let mut slugger = slap_hitter.clone();
+        slugger.avg_hit_angle = 20.0;
+        slugger.avg_distance = 210;
+
+        assert!(estimate_sprint_speed(&slap_hitter) > LEAGUE_AVG_SPRINT_SPEED);
+        assert!(estimate_sprint_speed(&slugger) < LEAGUE_AVG_SPRINT_SPEED);
+    }
+
+    #[test]
+    fn test_team_key_round_trips() {
+        assert_eq!(team_key("LAD", 2024), "LAD_2024");
+        assert_eq!(split_team_key("LAD_2024"), Some(("LAD", 2024)));
+        assert_eq!(split_team_key("LAD"), None);
+    }
+
+    #[test]
+    fn test_two_seasons_of_a_team_load_side_by_side() {
+        let mut manager = TeamManager::new(fixture("seasons"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+
+        let list = manager.get_team_list();
+        let lad: Vec<&String> = list.iter().filter(|key| key.starts_with("LAD_")).collect();
+        assert_eq!(lad, vec!["LAD_2024", "LAD_2025"]);
+        assert!(manager.has_multiple_seasons());
+        assert_eq!(manager.describe_team("LAD_2024"), "LAD - Los Angeles Dodgers (2024)");
+
+        manager.load_team("LAD_2024").unwrap();
+        manager.load_team("LAD_2025").unwrap();
+        assert_eq!(manager.get_team("LAD_2024").unwrap().season, 2024);
+        assert_eq!(manager.get_team("LAD_2025").unwrap().season, 2025);
+    }
+
+    #[test]
+    fn test_single_season_hides_year_and_lists_only_teams_on_disk() {
+        let mut manager = TeamManager::new(fixture("teams"), 2030);
+        manager.scan_available_teams();
+
+        assert!(!manager.has_multiple_seasons());
+        assert_eq!(manager.describe_team("THW_2025"), "THW - Thunder Hawks");
+        assert_eq!(manager.get_team_list(), vec!["THW_2025"]);
+    }
+
+    #[test]
+    fn test_team_list_is_discovered_from_the_data_directory() {
+        let dir = std::env::temp_dir().join(format!("bitbatter_discover_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let batters = std::fs::read_to_string(fixture("teams/batter_THW_2025.csv")).unwrap();
+        let pitchers = std::fs::read_to_string(fixture("teams/pitcher_THW_2025.csv")).unwrap();
+        for key in ["NYY_2025", "NYY_2024", "XYZ_2025"] {
+            std::fs::write(dir.join(format!("batter_{}.csv", key)), &batters).unwrap();
+            std::fs::write(dir.join(format!("pitcher_{}.csv", key)), &pitchers).unwrap();
+        }
+        std::fs::write(dir.join("batter_BOS_2025.csv"), &batters).unwrap(); // No pitcher file
+        std::fs::write(dir.join("notes.txt"), "not a team").unwrap();
+
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+        manager.scan_available_teams();
+        assert_eq!(manager.get_team_list(), vec!["NYY_2024", "NYY_2025", "XYZ_2025"]);
+        assert_eq!(crate::game::input_handler::team_number_range(&manager), "1-3");
+        // Unknown abbreviations are shown as they are
+        assert_eq!(manager.describe_team("XYZ_2025"), "XYZ - XYZ (2025)");
+        assert_eq!(manager.describe_team("NYY_2024"), "NYY - New York Yankees (2024)");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_empty_data_directory_has_no_teams() {
+        let mut manager = TeamManager::new(fixture("does_not_exist"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+        assert!(manager.get_team_list().is_empty());
+        assert_eq!(crate::game::input_handler::team_number_range(&manager), "no teams found");
+    }
+
+    #[test]
+    fn test_custom_json_team_is_listed_and_loads() {
+        let mut manager = TeamManager::new(fixture("custom"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+
+        // Appended after the MLB clubs; the malformed roster stays off the menu
+        let list = manager.get_team_list();
+        assert_eq!(list.last().map(String::as_str), Some("SDG_2025"));
+        assert!(!manager.is_available("BAD_2025"));
+        assert_eq!(manager.describe_team("SDG_2025"), "SDG - Storm Dragons");
+        assert_eq!(manager.team_colors("SDG_2025").unwrap().primary, "magenta");
+
+        manager.load_team("SDG_2025").unwrap();
+        let team = manager.get_team("SDG_2025").unwrap();
+        assert_eq!(team.name, "Storm Dragons");
+        assert_eq!(team.batters.len() - team.replacement_batters, 2);
+        assert_eq!(team.ballpark_name, "Dragon's Lair");
+        let ace = &team.pitchers[0];
+        assert_eq!(ace.pitching.as_ref().unwrap().fastball_velocity, Some(97.5));
+        assert!(!ace.arsenal.is_empty());
+
+        let err = manager.load_team("BAD_2025").unwrap_err().to_string();
+        assert!(err.contains("team_BAD_2025.json"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_new_team_template_round_trips() {
+        let dir = std::env::temp_dir().join(format!("bitbatter_template_{}", std::process::id()));
+        let mut manager = TeamManager::new(&dir, DEFAULT_SEASON);
+
+        let path = manager.create_team_template("ZAP").unwrap();
+        assert!(path.ends_with("team_ZAP_2025.json"));
+        assert!(manager.create_team_template("ZAP").is_err()); // Never overwrites
+        assert!(manager.create_team_template("bad_abbr").is_err());
+
+        manager.scan_available_teams();
+        assert!(manager.get_team_list().contains(&"ZAP_2025".to_string()));
+        manager.load_team("ZAP_2025").unwrap();
+        let team = manager.get_team("ZAP_2025").unwrap();
+        assert_eq!(team.batters.len(), 9);
+        assert_eq!(team.pitchers.len(), 5);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_league_file_overrides_the_team_list() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+        assert_eq!(manager.get_team_list(), vec!["THW_2025"]);
+
+        manager.load_league(fixture("league.json")).unwrap();
+        assert_eq!(manager.get_team_list(), vec!["SDG_2026", "THW_2026"]);
+        assert_eq!(manager.describe_team("THW_2026"), "THW - Thunder Hawks");
+
+        manager.load_team("THW_2026").unwrap();
+        assert_eq!(manager.get_team("THW_2026").unwrap().season, 2026);
+    }
+
+    #[test]
+    fn test_bad_league_file_keeps_the_current_teams() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.scan_available_teams();
+        assert!(manager.load_league(fixture("custom/team_BAD_2025.json")).is_err());
+        assert_eq!(manager.get_team_list(), vec!["THW_2025"]);
+    }
+
+    #[test]
+    fn test_team_search_matches_abbreviation_name_and_letters_in_order() {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        manager.load_league(fixture("league.json")).unwrap();
+        // List is SDG_2026, THW_2026
+
+        assert_eq!(manager.search_teams("thw"), vec![1]);
+        assert_eq!(manager.search_teams("DRAGON"), vec![0]);
+        assert_eq!(manager.search_teams("thk"), vec![1]); // T-h-...-k in "thunder hawks"
+        assert_eq!(manager.search_teams(""), vec![0, 1]);
+        assert!(manager.search_teams("zzz").is_empty());
+    }
+
+    #[test]
+    fn test_from_config_warns_about_an_empty_data_dir_and_a_bad_league() {
+        let dir = std::env::temp_dir().join(format!("bitbatter_empty_teams_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut config = crate::config::GameConfig {
+            data_dir: dir.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let (manager, warning) = TeamManager::from_config(&config);
+        assert!(manager.get_team_list().is_empty());
+        assert!(warning.unwrap().starts_with("No teams found"));
+
+        config.league_file = Some(fixture("missing_league.json"));
+        let (_, warning) = TeamManager::from_config(&config);
+        assert!(warning.unwrap().starts_with("Couldn't load league"));
+
+        config.league_file = Some(fixture("league.json"));
+        let (manager, warning) = TeamManager::from_config(&config);
+        assert_eq!(manager.get_team_list().len(), 2);
+        assert!(warning.is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_started_game_keeps_its_own_copy_of_each_team() {
+        let mut manager = TeamManager::new(fixture(""), DEFAULT_SEASON);
+        manager.load_league(fixture("league.json")).unwrap();
+        let keys = manager.get_team_list();
+        for key in &keys {
+            manager.load_team(key).unwrap();
+        }
+
+        let mut state = crate::game::GameState::new();
+        state.start_game(&manager, keys[0].clone(), keys[1].clone());
+        state.get_current_pitching_team_mut().unwrap().decrease_stamina(10.0);
+
+        assert_eq!(state.home_name(), manager.get_team(&keys[0]).unwrap().name);
+        assert_eq!(manager.get_team(&keys[0]).unwrap().pitcher_stamina, crate::game::constants::STARTING_STAMINA);
+        assert!(state.get_current_pitching_team().unwrap().pitcher_stamina < crate::game::constants::STARTING_STAMINA);
+    }
+
+    fn template_team() -> crate::team::Team {
+        crate::roster::CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON)
+    }
+
+    fn lineup_ids(team: &crate::team::Team) -> Vec<&str> {
+        team.batters.iter().map(|p| p.stats.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_moving_the_nine_hitter_to_leadoff_shifts_everyone_down() {
+        let mut team = template_team();
+        team.move_batting_order(8, 0).unwrap();
+        assert_eq!(
+            lineup_ids(&team),
+            ["TST-B9", "TST-B1", "TST-B2", "TST-B3", "TST-B4", "TST-B5", "TST-B6", "TST-B7", "TST-B8"]
+        );
+
+        team.move_batting_order(0, 8).unwrap();
+        assert_eq!(lineup_ids(&team)[0], "TST-B1");
+        assert_eq!(lineup_ids(&team)[8], "TST-B9");
+        assert!(team.move_batting_order(0, 9).is_err());
+    }
+
+    #[test]
+    fn test_lineup_needs_nine_hitters_and_a_starter() {
+        let mut team = template_team();
+        team.set_starting_pitcher(3).unwrap();
+        assert_eq!(team.get_current_pitcher().unwrap().stats.id, "TST-P4");
+        assert!(team.set_starting_pitcher(5).is_err());
+        assert!(team.validate_lineup().is_ok());
+
+        team.batters.pop();
+        assert_eq!(team.validate_lineup().unwrap_err(), "Test Team needs 9 hitters but only has 8");
+        let mut no_staff = template_team();
+        no_staff.pitchers.clear();
+        assert!(no_staff.validate_lineup().is_err());
+    }
+
+    fn manager_with_template_teams() -> TeamManager {
+        let mut manager = TeamManager::new(fixture("teams"), DEFAULT_SEASON);
+        for (key, bump) in [("AAA_2025", 0.0), ("BBB_2025", 5.0)] {
+            let mut team = template_team();
+            for (idx, batter) in team.batters.iter_mut().enumerate() {
+                batter.stats.max_hit_speed = 100.0 + bump + idx as f32;
+            }
+            manager.teams.insert(key.to_string(), team);
+        }
+        manager
+    }
+
+    #[test]
+    fn test_stat_leaders_are_sorted_best_first_and_limited_to_n() {
+        let manager = manager_with_template_teams();
+        let leaders = manager.stat_leaders(StatCategory::MaxExitVelo, 5);
+        assert_eq!(leaders.len(), 5);
+        assert!(leaders.windows(2).all(|pair| pair[0].value >= pair[1].value));
+        assert_eq!((leaders[0].name.as_str(), leaders[0].team.as_str(), leaders[0].value), ("Batter, Number 9", "BBB_2025", 113.0));
+
+        assert_eq!(manager.stat_leaders(StatCategory::MaxExitVelo, 100).len(), 18);
+        assert!(manager.stat_leaders(StatCategory::MaxExitVelo, 0).is_empty());
+    }
+
+    #[test]
+    fn test_stat_leader_ties_break_by_name_then_team() {
+        let manager = manager_with_template_teams();
+        // Every template hitter has the same barrel rate
+        let leaders = manager.stat_leaders(StatCategory::BarrelPercent, 4);
+        let order: Vec<(&str, &str)> = leaders.iter().map(|l| (l.name.as_str(), l.team.as_str())).collect();
+        assert_eq!(
+            order,
+            [("Batter, Number 1", "AAA_2025"), ("Batter, Number 1", "BBB_2025"), ("Batter, Number 2", "AAA_2025"), ("Batter, Number 2", "BBB_2025")]
+        );
+        assert_eq!(StatCategory::from_flag("distance"), Some(StatCategory::MaxDistance));
+        assert_eq!(StatCategory::from_flag("ops"), None);
+    }
+}
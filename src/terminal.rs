@@ -0,0 +1,58 @@
+use crossterm::{
+    cursor, execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use std::io;
+
+/// Puts the terminal back the way the shell expects it
+pub trait TerminalRestore {
+    fn restore(&mut self) -> io::Result<()>;
+}
+
+/// Leaves raw mode and the alternate screen and shows the cursor again
+pub struct CrosstermRestore;
+
+impl TerminalRestore for CrosstermRestore {
+    fn restore(&mut self) -> io::Result<()> {
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen, cursor::Show)
+    }
+}
+
+/// Restores the terminal when dropped, so an early return or a panic unwinding out of the
+/// game loop can't leave the shell in raw mode
+pub struct TerminalGuard<R: TerminalRestore> {
+    restorer: R,
+    restored: bool,
+}
+
+impl<R: TerminalRestore> TerminalGuard<R> {
+    pub fn new(restorer: R) -> Self {
+        Self { restorer, restored: false }
+    }
+
+    /// Restore now and report any error. Dropping the guard afterwards does nothing.
+    pub fn restore(&mut self) -> io::Result<()> {
+        if self.restored {
+            return Ok(());
+        }
+        self.restored = true;
+        self.restorer.restore()
+    }
+}
+
+impl<R: TerminalRestore> Drop for TerminalGuard<R> {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
+
+/// Restore the terminal before the panic message is printed, otherwise it's written to the
+/// alternate screen and lost when the guard clears it
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = CrosstermRestore.restore();
+        default_hook(info);
+    }));
+}
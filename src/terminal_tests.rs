@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::terminal::{TerminalGuard, TerminalRestore};
+    use std::cell::Cell;
+    use std::io;
+    use std::rc::Rc;
+
+    /// Counts restores instead of touching the real terminal
+    struct CountingRestore(Rc<Cell<u32>>);
+
+    impl TerminalRestore for CountingRestore {
+        fn restore(&mut self) -> io::Result<()> {
+            self.0.set(self.0.get() + 1);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dropping_the_guard_restores_the_terminal() {
+        let restores = Rc::new(Cell::new(0));
+        drop(TerminalGuard::new(CountingRestore(restores.clone())));
+        assert_eq!(restores.get(), 1);
+    }
+
+    #[test]
+    fn test_guard_restores_once_when_restored_explicitly() {
+        let restores = Rc::new(Cell::new(0));
+        let mut guard = TerminalGuard::new(CountingRestore(restores.clone()));
+        guard.restore().unwrap();
+        guard.restore().unwrap();
+        drop(guard);
+        assert_eq!(restores.get(), 1);
+    }
+
+    #[test]
+    fn test_guard_restores_while_unwinding_from_a_panic() {
+        let restores = Rc::new(Cell::new(0));
+        let counter = restores.clone();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            let _guard = TerminalGuard::new(CountingRestore(counter));
+            panic!("game loop blew up");
+        }));
+        assert!(result.is_err());
+        assert_eq!(restores.get(), 1);
+    }
+}
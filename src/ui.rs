@@ -1,22 +1,135 @@
-use crate::game::{GameMode, GameState, InningHalf, PitchState, SwingTiming};
+use crate::game::matchup::{handedness_matchup, MatchupRoll};
+use crate::game::practice::{PracticeMode, PracticeSession};
+use crate::game::spray_chart::sector_label;
+use crate::game::career::CareerStats;
+use crate::game::engine::PitchPreview;
+use crate::game::tutorial::Tutorial;
+use crate::config::{ColorScheme, GameConfig, Setting};
+use crate::game::constants::AT_THE_PLATE;
+use crate::input::{KeyPhase, KEY_BINDINGS};
+use crate::team::TeamManager;
+use crate::game::{GameMode, GameState, InningHalf, LineupEditor, PitchState, PlayResult, SwingTiming, TeamInputMode, TeamSearch, TeamSort};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
     Frame,
 };
 
-pub fn render_game(frame: &mut Frame, game_state: &GameState, engine: &crate::game::GameEngine, input_state: &crate::input::InputState) {
+/// Colors for the meanings the UI leans on. The standard scheme is red and green; the
+/// color-blind one swaps them for blue and orange, which stay apart for all common types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub good: Color,
+    pub bad: Color,
+    pub good_light: Color,
+    pub bad_light: Color,
+    pub neutral: Color, // Neither - a take, say
+}
+
+impl Palette {
+    pub fn for_scheme(scheme: ColorScheme) -> Self {
+        match scheme {
+            ColorScheme::Standard => Self {
+                good: Color::Green,
+                bad: Color::Red,
+                good_light: Color::LightGreen,
+                bad_light: Color::LightRed,
+                neutral: Color::Blue,
+            },
+            ColorScheme::ColorBlind => Self {
+                good: Color::Blue,
+                bad: Color::Indexed(208), // Orange
+                good_light: Color::LightBlue,
+                bad_light: Color::Indexed(215), // Light orange
+                neutral: Color::Gray,
+            },
+        }
+    }
+}
+
+/// Everything on screen that can change between frames. The loop only redraws when this
+/// differs from the last frame drawn - an idle pitcher costs one draw a second (for the
+/// pitcher's clock) instead of thirty, while animated states like the pitch clock, the ball
+/// approach and fielding change `pitch_state` every tick and keep redrawing continuously.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderKey {
+    mode: GameMode,
+    pitch_state: PitchState,
+    message: String,
+    situation: (u8, InningHalf, u8, u8, u8), // Inning, half, outs, balls, strikes
+    score: (u8, u8),
+    bases: [Option<String>; 3],
+    current_batter_idx: usize,
+    teams: (Option<String>, Option<String>),
+    pitcher_clock_seconds: u16,
+    aim: (bool, bool, bool, bool),
+    quit_requested: bool,
+    game_over: bool,
+    pinch_run_menu: Option<crate::game::PinchRunMenu>,
+    defensive_sub_menu: Option<crate::game::DefensiveSubMenu>,
+    bullpen_menu: Option<crate::game::BullpenMenu>,
+    practice: Option<PracticeSession>,
+    tutorial_step: Option<usize>,
+    help_page: Option<usize>,
+    leaders_tab: Option<usize>,
+    settings: Option<(usize, GameConfig)>, // Row and every value while the settings screen is up
+    pitch_preview: bool,
+    pitch_guess: Option<usize>,
+}
+
+impl RenderKey {
+    pub fn capture(state: &GameState, input_state: &crate::input::InputState) -> Self {
+        let clock_frames = state.config.pitcher_clock_frames.saturating_sub(state.pitcher_clock_used);
+        Self {
+            mode: state.mode.clone(),
+            pitch_state: state.pitch_state.clone(),
+            message: state.message.clone(),
+            situation: (state.inning, state.half, state.outs, state.balls, state.strikes),
+            score: (state.home_score, state.away_score),
+            bases: state.bases.clone(),
+            current_batter_idx: state.current_batter_idx,
+            teams: (state.home_team.clone(), state.away_team.clone()),
+            pitcher_clock_seconds: crate::game::constants::frames_to_seconds(clock_frames as u64) as u16,
+            aim: (input_state.up, input_state.down, input_state.left, input_state.right),
+            quit_requested: state.quit_requested,
+            game_over: state.game_over,
+            pinch_run_menu: state.pinch_run_menu.clone(),
+            defensive_sub_menu: state.defensive_sub_menu.clone(),
+            bullpen_menu: state.bullpen_menu.clone(),
+            practice: state.practice.clone(),
+            tutorial_step: state.tutorial.as_ref().map(|tutorial| tutorial.step),
+            help_page: state.help_page,
+            leaders_tab: state.leaders_tab,
+            settings: state.settings_row.map(|row| (row, state.config.clone())),
+            pitch_preview: state.config.pitch_preview,
+            pitch_guess: state.pitch_guess,
+        }
+    }
+}
+
+pub fn render_game(
+    frame: &mut Frame,
+    game_state: &GameState,
+    team_manager: &TeamManager,
+    career: &CareerStats,
+    engine: &crate::game::GameEngine,
+    input_state: &crate::input::InputState,
+) {
+    let palette = Palette::for_scheme(game_state.config.color_scheme);
     match &game_state.mode {
-        GameMode::TeamSelection { selected_home, selected_away, input_buffer, input_mode } => {
-            render_team_selection(frame, game_state, selected_home, selected_away, input_buffer, input_mode);
+        GameMode::TeamSelection { selected_home, selected_away, input_buffer, search, roll, sort, practice, .. } => {
+            render_team_selection(frame, team_manager, selected_home, selected_away, input_buffer, search.as_ref(), roll.as_ref(), *sort, *practice, palette);
+        }
+        GameMode::LineupEdit(editor) => {
+            render_lineup_editor(frame, game_state, editor);
         }
         GameMode::Playing => {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(8),  // Scoreboard (increased from 7 to 8)
+                    Constraint::Length(9),  // Scoreboard
                     Constraint::Length(4),  // Timing display
                     Constraint::Min(8),     // Field (reduced to make room for timing)
                     Constraint::Length(5),  // Controls/Message
@@ -24,20 +137,192 @@ pub fn render_game(frame: &mut Frame, game_state: &GameState, engine: &crate::ga
                 .split(frame.area());
 
             render_scoreboard(frame, chunks[0], game_state);
-            render_timing_display(frame, chunks[1], game_state);
-            render_field(frame, chunks[2], game_state, input_state);
+            render_timing_display(frame, chunks[1], game_state, engine);
+            if crate::game::post_game::showing_post_game(game_state) {
+                render_post_game(frame, chunks[2], game_state);
+            } else {
+                render_field(frame, chunks[2], game_state, career, engine, input_state);
+            }
             render_controls(frame, chunks[3], game_state, engine);
+            if let Some(tutorial) = game_state.tutorial.as_ref().filter(|t| t.holds(&game_state.pitch_state)) {
+                render_tutorial(frame, chunks[2], tutorial);
+            }
+        }
+    }
+    if let Some(tab) = game_state.leaders_tab {
+        render_leaders(frame, frame.area(), career, tab);
+    }
+    if let Some(row) = game_state.settings_row {
+        render_settings(frame, frame.area(), &game_state.config, row);
+    }
+    if let Some(page) = game_state.help_page {
+        render_help(frame, frame.area(), &game_state.config, page);
+    }
+}
+
+/// The settings screen: game options, then display settings, the picked one highlighted
+fn render_settings(frame: &mut Frame, area: Rect, config: &GameConfig, row: usize) {
+    let heading = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(""), Line::from(Span::styled("Game Options", heading))];
+    for (i, setting) in Setting::ALL.iter().enumerate() {
+        if setting.is_display() && (i == 0 || !Setting::ALL[i - 1].is_display()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Display", heading)));
+        }
+        let value = config.setting_value(*setting);
+        lines.push(if i == row {
+            Line::from(Span::styled(
+                format!("{}: < {} >", setting.name(), value),
+                Style::default().fg(Color::Black).bg(Color::Cyan),
+            ))
+        } else {
+            Line::from(format!("{}: {}", setting.name(), value))
+        });
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "UP/DOWN pick a setting, LEFT/RIGHT change it - any other key saves and closes",
+        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Settings")
+        .title_alignment(Alignment::Center);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).block(block).alignment(Alignment::Center), area);
+}
+
+/// The career leaders screen: one category's top players, with the categories as tabs
+fn render_leaders(frame: &mut Frame, area: Rect, career: &CareerStats, tab: usize) {
+    use crate::game::career::CareerCategory;
+    use crate::game::constants::{CAREER_AVG_MIN_AT_BATS, LEAGUE_LEADERS_SHOWN};
+
+    let category = CareerCategory::ALL[tab % CareerCategory::ALL.len()];
+    let tabs: Vec<Span> = CareerCategory::ALL
+        .iter()
+        .flat_map(|c| {
+            let style = if *c == category {
+                Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            [Span::styled(format!(" {} ", c.name()), style), Span::raw(" ")]
+        })
+        .collect();
+
+    let mut lines = vec![Line::from(tabs), Line::from("")];
+    if career.batting.is_empty() && career.pitching.is_empty() {
+        lines.push(Line::from("No games played yet"));
+    } else {
+        let leaders = career.leaders(category, LEAGUE_LEADERS_SHOWN);
+        if leaders.is_empty() {
+            lines.push(Line::from("Nobody qualifies yet"));
+        }
+        for (rank, leader) in leaders.iter().enumerate() {
+            lines.push(Line::from(format!("{:>2}. {:<24} {:>5}", rank + 1, leader.name, category.format(leader.value))));
+        }
+        if category == CareerCategory::Average {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("Minimum {} at-bats", CAREER_AVG_MIN_AT_BATS),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "LEFT/RIGHT for another category - any other key closes",
+        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Career Leaders")
+        .title_alignment(Alignment::Center);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).block(block).alignment(Alignment::Center), area);
+}
+
+/// The help screen's lines: every key binding by phase, then the current settings
+pub fn help_lines(config: &GameConfig) -> Vec<Line<'static>> {
+    use crate::game::constants::{frames_to_seconds, INNINGS_PER_GAME};
+
+    let heading = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    for phase in KeyPhase::ALL {
+        lines.push(Line::from(Span::styled(phase.name(), heading)));
+        for binding in KEY_BINDINGS.iter().filter(|b| b.phase == phase) {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16}", binding.keys), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(binding.action),
+            ]));
         }
     }
+
+    let innings = match config.max_innings {
+        0 => "extras until someone wins".to_string(),
+        max => format!("a tie after {} ends it", max),
+    };
+    let pitcher_clock = match config.pitcher_clock_frames {
+        0 => "off".to_string(),
+        frames => format!("{}s", frames_to_seconds(frames as u64)),
+    };
+    let colors = match config.color_scheme {
+        ColorScheme::Standard => "standard",
+        ColorScheme::ColorBlind => "color-blind",
+    };
+    lines.push(Line::from(Span::styled("Settings", heading)));
+    lines.push(Line::from(format!("  CPU batter aggressiveness: {:.2}", config.batter_aggressiveness)));
+    lines.push(Line::from(format!("  Innings: {}, {}", INNINGS_PER_GAME, innings)));
+    lines.push(Line::from(format!("  Pitch clock: {}s, pitcher's clock: {}", frames_to_seconds(config.pitch_clock_frames as u64), pitcher_clock)));
+    lines.push(Line::from(format!("  Momentum effects: {}", if config.momentum_effects { "on" } else { "off" })));
+    lines.push(Line::from(format!("  Pitch preview: {}", if config.pitch_preview { "on" } else { "off" })));
+    lines.push(Line::from(format!("  Batter's eye: {}", if config.batters_eye { "on" } else { "off" })));
+    lines.push(Line::from(format!("  Colors: {}", colors)));
+    lines.push(Line::from(format!("  Sound: {}", if config.sound { "on" } else { "off" })));
+    lines
 }
 
-fn render_team_selection(frame: &mut Frame, game_state: &GameState, selected_home: &Option<String>, selected_away: &Option<String>, input_buffer: &str, _input_mode: &crate::game::TeamInputMode) {
+/// Lines per help page at `height` rows, the border and footer taken out
+pub fn help_page_size(height: u16) -> usize {
+    (height.saturating_sub(3) as usize).max(1)
+}
+
+/// Full-screen key list, paged to fit. The page wraps around, so RIGHT keeps going.
+fn render_help(frame: &mut Frame, area: Rect, config: &GameConfig, page: usize) {
+    let lines = help_lines(config);
+    let per_page = help_page_size(area.height);
+    let pages = lines.len().div_ceil(per_page);
+    let page = page % pages;
+
+    let mut shown: Vec<Line> = lines.into_iter().skip(page * per_page).take(per_page).collect();
+    shown.resize(per_page, Line::from(""));
+    let footer = if pages > 1 { "LEFT/RIGHT for more - any other key closes" } else { "Any key closes" };
+    shown.push(Line::from(Span::styled(
+        footer,
+        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("Keys - page {}/{}", page + 1, pages))
+        .title_alignment(Alignment::Center);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(shown).block(block), area);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_team_selection(frame: &mut Frame, team_manager: &TeamManager, selected_home: &Option<String>, selected_away: &Option<String>, input_buffer: &str, search: Option<&TeamSearch>, roll: Option<&MatchupRoll>, sort: TeamSort, practice: Option<PracticeMode>, palette: Palette) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),   // Title
             Constraint::Min(10),     // Team selection
-            Constraint::Length(5),   // Instructions
+            Constraint::Length(6),   // Instructions
         ])
         .split(frame.area());
 
@@ -58,19 +343,8 @@ fn render_team_selection(frame: &mut Frame, game_state: &GameState, selected_hom
         .split(chunks[1]);
 
     // Away team selection
-    let away_teams: Vec<ListItem> = game_state.team_manager.get_team_list()
-        .iter()
-        .enumerate()
-        .map(|(idx, team_abbr)| {
-            let team_name = game_state.team_manager.get_team_full_name(team_abbr);
-            let style = if selected_away.as_ref() == Some(team_abbr) {
-                Style::default().fg(Color::Black).bg(Color::Yellow)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            ListItem::new(format!("{}: {} - {}", idx + 1, team_abbr, team_name)).style(style)
-        })
-        .collect();
+    let away_search = search.filter(|s| s.side == TeamInputMode::SelectingAway);
+    let away_teams = team_list_items(team_manager, selected_away, Color::Yellow, away_search, sort);
 
     let away_list = List::new(away_teams)
         .block(Block::default()
@@ -80,31 +354,42 @@ fn render_team_selection(frame: &mut Frame, game_state: &GameState, selected_hom
     frame.render_widget(away_list, team_chunks[0]);
 
     // Home team selection
-    let home_teams: Vec<ListItem> = game_state.team_manager.get_team_list()
-        .iter()
-        .enumerate()
-        .map(|(idx, team_abbr)| {
-            let team_name = game_state.team_manager.get_team_full_name(team_abbr);
-            let style = if selected_home.as_ref() == Some(team_abbr) {
-                Style::default().fg(Color::Black).bg(Color::Green)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            ListItem::new(format!("{}: {} - {}", idx + 1, team_abbr, team_name)).style(style)
-        })
-        .collect();
+    let home_search = search.filter(|s| s.side == TeamInputMode::SelectingHome);
+    let home_teams = team_list_items(team_manager, selected_home, palette.good, home_search, sort);
 
     let home_list = List::new(home_teams)
         .block(Block::default()
             .title("Home Team (Press H + Number)")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Red)));
+            .border_style(Style::default().fg(palette.bad)));
     frame.render_widget(home_list, team_chunks[1]);
 
     // Instructions
     let mut instructions = vec![
-        Line::from("Press A then enter team # (1-30) and ENTER | Press H then enter team # (1-30) and ENTER"),
+        Line::from(format!(
+            "Press A then enter team # ({0}) and ENTER | Press H then enter team # ({0}) and ENTER",
+            crate::game::input_handler::team_number_range(team_manager)
+        )),
     ];
+
+    if let Some(roll) = roll {
+        // Cycle through names until the roll locks in
+        let teams = team_manager.get_team_list();
+        let frame_idx = roll.frames_left as usize;
+        let cycling = |offset: usize| teams.get((frame_idx + offset) % teams.len().max(1)).cloned().unwrap_or_default();
+        instructions.push(Line::from(Span::styled(
+            format!("{} @ {}", cycling(0), cycling(teams.len() / 2)),
+            Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+        )));
+    } else if let Some(search) = search {
+        let side = if search.side == TeamInputMode::SelectingHome { "home" } else { "away" };
+        instructions.push(Line::from(Span::styled(
+            format!("Search {} team: {}_  (arrows move, ENTER picks, ESC cancels)", side, search.query),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        )));
+    } else {
+        instructions.push(Line::from("Press / to search teams by name | ? for a surprise matchup | B for a balanced one | T to sort by rating | P to practice | L for career leaders | F1 for help"));
+    }
     
     if !input_buffer.is_empty() {
         instructions.push(Line::from(Span::styled(
@@ -114,9 +399,14 @@ fn render_team_selection(frame: &mut Frame, game_state: &GameState, selected_hom
     }
     
     if selected_home.is_some() && selected_away.is_some() && input_buffer.is_empty() {
+        let start = match practice {
+            Some(PracticeMode::BattingCage) => "Press SPACE or ENTER for the batting cage - the away lineup hits".to_string(),
+            Some(PracticeMode::Bullpen) => "Press SPACE or ENTER for a bullpen session - the home starter throws".to_string(),
+            None => "Press SPACE or ENTER to start the game!".to_string(),
+        };
         instructions.push(Line::from(Span::styled(
-            "Press SPACE or ENTER to start the game!",
-            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+            start,
+            Style::default().fg(palette.good).add_modifier(Modifier::BOLD)
         )));
     }
 
@@ -126,7 +416,124 @@ fn render_team_selection(frame: &mut Frame, game_state: &GameState, selected_hom
     frame.render_widget(instruction_paragraph, chunks[2]);
 }
 
+fn render_lineup_editor(frame: &mut Frame, state: &GameState, editor: &LineupEditor) {
+    let palette = Palette::for_scheme(state.config.color_scheme);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),   // Title
+            Constraint::Min(10),     // Lineup and staff
+            Constraint::Length(5),   // Instructions
+        ])
+        .split(frame.area());
+
+    let (side, name) = if editor.home { ("Home", state.home_name()) } else { ("Away", state.away_name()) };
+    let title = Paragraph::new(format!("Lineup Card - {}: {}", side, name))
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(title, chunks[0]);
+
+    let Some(team) = state.lineup_team(editor.home) else {
+        return;
+    };
+    let order_size = team.batting_order_size().min(team.batters.len());
+    let row_style = |row: usize| {
+        if row == editor.cursor && editor.holding {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else if row == editor.cursor {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::White)
+        }
+    };
+
+    let card_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50),
+            Constraint::Percentage(50),
+        ])
+        .split(chunks[1]);
+
+    let lineup: Vec<ListItem> = team.batters[..order_size]
+        .iter()
+        .enumerate()
+        .map(|(slot, batter)| {
+            ListItem::new(format!("{}. {} ({})", slot + 1, batter.stats.name, batter.position.name())).style(row_style(slot))
+        })
+        .collect();
+    let lineup_list = List::new(lineup)
+        .block(Block::default().title("Batting Order").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)));
+    frame.render_widget(lineup_list, card_chunks[0]);
+
+    let staff: Vec<ListItem> = team.pitchers
+        .iter()
+        .enumerate()
+        .map(|(idx, pitcher)| {
+            let starter = if idx == team.current_pitcher_idx { "* " } else { "  " };
+            ListItem::new(format!("{}{}", starter, pitcher.stats.name)).style(row_style(order_size + idx))
+        })
+        .collect();
+    let staff_list = List::new(staff)
+        .block(Block::default().title("Pitchers (* starts)").borders(Borders::ALL).border_style(Style::default().fg(palette.bad)));
+    frame.render_widget(staff_list, card_chunks[1]);
+
+    let instructions = vec![
+        Line::from("UP/DOWN to move | SPACE grabs or drops a hitter, or picks the starting pitcher | LEFT/RIGHT switch teams"),
+        Line::from(Span::styled(
+            "Press S to play ball!",
+            Style::default().fg(palette.good).add_modifier(Modifier::BOLD)
+        )),
+        Line::from(state.message.clone()),
+    ];
+    let instruction_paragraph = Paragraph::new(instructions)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Instructions"));
+    frame.render_widget(instruction_paragraph, chunks[2]);
+}
+
+/// One side's team list. While searching, only matching teams are shown, keeping their
+/// original numbers, and the highlighted match is marked. Loaded teams show their ratings.
+fn team_list_items<'a>(team_manager: &TeamManager, selected: &Option<String>, selected_bg: Color, search: Option<&TeamSearch>, sort: TeamSort) -> Vec<ListItem<'a>> {
+    let teams = team_manager.get_team_list();
+    let shown: Vec<usize> = match (search, sort) {
+        (Some(search), _) => team_manager.search_teams(&search.query),
+        (None, TeamSort::Alphabetical) => (0..teams.len()).collect(),
+        (None, TeamSort::Rating) => team_manager.teams_by_rating(),
+    };
+
+    shown
+        .iter()
+        .enumerate()
+        .map(|(row, &idx)| {
+            let team_key = &teams[idx];
+            let highlighted = search.is_some_and(|s| s.cursor == row);
+            let style = if highlighted {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if selected.as_ref() == Some(team_key) {
+                Style::default().fg(Color::Black).bg(selected_bg)
+            } else if let Some(color) = custom_team_color(team_manager, team_key) {
+                Style::default().fg(color)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let rating = match team_manager.team_rating(team_key) {
+                Some(rating) => format!("  OFF {} / PIT {}", rating.offense, rating.pitching),
+                None => String::new(),
+            };
+            ListItem::new(format!("{}: {}{}", idx + 1, team_manager.describe_team(team_key), rating)).style(style)
+        })
+        .collect()
+}
+
+/// Primary color from a custom team's roster, when it names one the terminal knows
+fn custom_team_color(team_manager: &TeamManager, team_key: &str) -> Option<Color> {
+    team_manager.team_colors(team_key)?.primary.parse().ok()
+}
+
 fn render_scoreboard(frame: &mut Frame, area: Rect, state: &GameState) {
+    let palette = Palette::for_scheme(state.config.color_scheme);
     let inning_text = format!(
         "Inning: {} {}",
         state.inning,
@@ -136,10 +543,13 @@ fn render_scoreboard(frame: &mut Frame, area: Rect, state: &GameState) {
         }
     );
 
-    let score_text = format!(
-        "Away: {:2}  Home: {:2}",
-        state.away_score, state.home_score
-    );
+    let score_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let score_line = Line::from(vec![
+        Span::styled(format!("Away: {:2} ", state.away_score), score_style),
+        momentum_meter(state.away_momentum, palette),
+        Span::styled(format!("  Home: {:2} ", state.home_score), score_style),
+        momentum_meter(state.home_momentum, palette),
+    ]);
 
     let count_text = format!(
         "Balls: {}  Strikes: {}  Outs: {}",
@@ -147,25 +557,46 @@ fn render_scoreboard(frame: &mut Frame, area: Rect, state: &GameState) {
     );
 
     let batter_info = if let Some(batter) = state.get_current_batter() {
-        format!("Batter: {} ({})", batter.stats.name, batter.position.name())
+        let line = state.box_score.batter(&batter.stats.id).cloned().unwrap_or_default();
+        format!("Batter: {} ({}) | {}-{}, {} RBI", batter.stats.name, batter.position.name(), line.hits, line.at_bats, line.rbi)
     } else {
         format!("Batter #{} - {}", state.current_batter_idx + 1, state.batting_team())
     };
 
+    let on_deck_info = upcoming_batters_line(state);
+
     let pitcher_info = if let Some(pitcher) = state.get_current_pitcher() {
         let pitching_team = state.get_current_pitching_team();
         let stamina = pitching_team.map(|t| t.pitcher_stamina).unwrap_or(100.0);
         let pitches = pitching_team.map(|t| t.pitches_thrown).unwrap_or(0);
-        format!("Pitcher: {} | Stamina: {:.0}% | Pitches: {}", 
-                pitcher.stats.name, stamina, pitches)
+        let line = state.box_score.pitcher(&pitcher.stats.id).cloned().unwrap_or_default();
+        format!("Pitcher: {} ({} IP, {} ER, {} K, {} BB) | Stamina: {:.0}% | Pitches: {}",
+                pitcher.stats.name, line.innings_pitched(), line.earned_runs, line.strikeouts, line.walks, stamina, pitches)
     } else {
         "Pitcher: Unknown".to_string()
     };
 
+    // Platoon matchup, good news when it favors the batter
+    let matchup = handedness_matchup(
+        state.get_current_pitcher().and_then(|p| p.throws),
+        state.get_current_batter().and_then(|b| b.bats),
+    );
+    let matchup_span = match matchup {
+        Some((label, batter_edge)) => Span::styled(
+            format!(" | {}", label),
+            Style::default().fg(if batter_edge { palette.good_light } else { palette.bad_light }).add_modifier(Modifier::BOLD),
+        ),
+        None => Span::raw(""),
+    };
+
+    let ballpark = state.home_ballpark();
     let team_names = format!(
-        "{} @ {}",
-        state.away_team.as_ref().map(|t| state.team_manager.get_team(t).map(|team| team.name.as_str()).unwrap_or(t)).unwrap_or("Away"),
-        state.home_team.as_ref().map(|t| state.team_manager.get_team(t).map(|team| team.name.as_str()).unwrap_or(t)).unwrap_or("Home")
+        "{} @ {} - {} (HR x{:.2}), {}",
+        state.away_name(),
+        state.home_name(),
+        ballpark.name,
+        ballpark.park_factor * state.weather.hr_factor(),
+        state.weather.name()
     );
 
     let scoreboard = vec![
@@ -177,18 +608,16 @@ fn render_scoreboard(frame: &mut Frame, area: Rect, state: &GameState) {
             inning_text,
             Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
         )),
-        Line::from(Span::styled(
-            score_text,
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
-        )),
+        score_line,
         Line::from(Span::styled(
             count_text,
             Style::default().fg(Color::White),
         )),
-        Line::from(Span::styled(
-            batter_info,
-            Style::default().fg(Color::Green),
-        )),
+        Line::from(vec![
+            Span::styled(batter_info, Style::default().fg(palette.good)),
+            matchup_span,
+        ]),
+        Line::from(Span::styled(on_deck_info, Style::default().fg(Color::DarkGray))),
         Line::from(Span::styled(
             pitcher_info,
             Style::default().fg(Color::LightBlue),
@@ -204,16 +633,68 @@ fn render_scoreboard(frame: &mut Frame, area: Rect, state: &GameState) {
         .block(block)
         .alignment(Alignment::Center);
 
-    frame.render_widget(paragraph, area);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(BASE_DIAMOND_WIDTH + 2)])
+        .split(area);
+    frame.render_widget(paragraph, chunks[0]);
+    render_base_diamond(frame, chunks[1], state);
+}
+
+const BASE_DIAMOND_NAME_WIDTH: usize = 10;
+const BASE_DIAMOND_WIDTH: u16 = 2 * BASE_DIAMOND_NAME_WIDTH as u16 + 7;
+
+/// Runners and outs at a glance beside the scoreboard
+fn render_base_diamond(frame: &mut Frame, area: Rect, state: &GameState) {
+    let names = state.bases.each_ref().map(|id| id.as_ref().map(|id| last_name(&state.player_name(id)).to_string()));
+    let runners = names.each_ref().map(Option::as_deref);
+    let mut lines = vec![Line::from("")];
+    lines.extend(base_diamond(&runners, state.outs).into_iter().map(|line| Line::from(Span::styled(line, Style::default().fg(Color::Yellow)))));
+    let block = Block::default().borders(Borders::ALL).title("Bases");
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// "Doe" from "Doe, Jane"
+fn last_name(name: &str) -> &str {
+    name.split(',').next().unwrap_or(name).trim()
+}
+
+/// The base state as three lines: second base on top, third and first below it with each
+/// runner's name on the outside, then the outs as filled circles. Occupied bases are filled
+/// diamonds, names are cut to fit.
+pub fn base_diamond(runners: &[Option<&str>; crate::game::constants::BASES_COUNT], outs: u8) -> Vec<String> {
+    let base = |runner: Option<&str>| if runner.is_some() { '◆' } else { '◇' };
+    let name = |runner: Option<&str>| runner.unwrap_or("").chars().take(BASE_DIAMOND_NAME_WIDTH).collect::<String>();
+    let width = BASE_DIAMOND_NAME_WIDTH;
+    let outs: Vec<&str> = (0..crate::game::constants::MAX_OUTS).map(|n| if n < outs { "●" } else { "○" }).collect();
+    [
+        format!("{:pad$}{} {}", "", base(runners[1]), name(runners[1]), pad = width + 3),
+        format!("{:>width$} {}   {} {}", name(runners[2]), base(runners[2]), base(runners[0]), name(runners[0])),
+        format!("{:pad$}{}", "", outs.join(" "), pad = width + 1),
+    ]
+    .into_iter()
+    .map(|line| line.trim_end().to_string())
+    .collect()
 }
 
-fn render_field(frame: &mut Frame, area: Rect, state: &GameState, input_state: &crate::input::InputState) {
-    // Split field area to show field + strike zone side by side
+/// A team's momentum next to its score - good when it's rolling, bad when it's gone cold
+fn momentum_meter(momentum: crate::game::momentum::Momentum, palette: Palette) -> Span<'static> {
+    let color = match momentum.value {
+        v if v > 0 => palette.good_light,
+        v if v < 0 => palette.bad_light,
+        _ => Color::DarkGray,
+    };
+    Span::styled(momentum.meter(), Style::default().fg(color))
+}
+
+fn render_field(frame: &mut Frame, area: Rect, state: &GameState, career: &CareerStats, engine: &crate::game::GameEngine, input_state: &crate::input::InputState) {
+    // Split field area to show field + strike zone + batter heat map side by side
     let field_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(60),  // Field
-            Constraint::Percentage(40),  // Strike zone with aiming
+            Constraint::Percentage(55),  // Field
+            Constraint::Percentage(25),  // Strike zone with aiming
+            Constraint::Percentage(20),  // Batter heat map
         ])
         .split(area);
 
@@ -221,17 +702,208 @@ fn render_field(frame: &mut Frame, area: Rect, state: &GameState, input_state: &
     render_baseball_field(frame, field_chunks[0], state);
 
     // Render strike zone with aiming indicator
-    render_strike_zone(frame, field_chunks[1], state, input_state);
+    render_strike_zone(frame, field_chunks[1], state, engine, input_state);
+
+    match &state.practice {
+        Some(session) => render_practice(frame, field_chunks[2], session),
+        None => render_heat_map(frame, field_chunks[2], state, career),
+    }
+}
+
+/// Practice session tally in place of the heat map
+fn render_practice(frame: &mut Frame, area: Rect, session: &PracticeSession) {
+    let mut lines = vec![Line::from("")];
+    match session.mode {
+        PracticeMode::BattingCage => {
+            lines.push(Line::from(format!("Swings: {}", session.swings)));
+            lines.push(Line::from(format!("Barrels: {}", session.barrels)));
+            lines.push(Line::from(format!("Hits: {}  Outs: {}", session.hits, session.outs)));
+            lines.push(Line::from(format!("Fouls: {}  Misses: {}", session.fouls, session.misses)));
+            lines.push(Line::from(format!("Avg timing: {:+.1} frames", session.average_timing())));
+        }
+        PracticeMode::Bullpen => {
+            lines.push(Line::from(Span::styled(
+                format!("Target: {}", session.target().name()),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!("Pitches: {}", session.pitches)));
+            lines.push(Line::from(format!("On target: {} ({:.0}%)", session.on_target, session.accuracy() * 100.0)));
+            lines.push(Line::from(format!("Strikes: {}", session.strikes)));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "ESC ends the session",
+        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(session.mode.name())
+        .title_alignment(Alignment::Center);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// The tutorial's current step, boxed over the middle of the field while play waits on it
+fn render_tutorial(frame: &mut Frame, area: Rect, tutorial: &Tutorial) {
+    let step = tutorial.current();
+    let width = (area.width * 3 / 5).max(40).min(area.width);
+    let height = 7.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let lines = vec![
+        Line::from(step.text),
+        Line::from(""),
+        Line::from(Span::styled(
+            "ESC skips the tutorial",
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+        )),
+    ];
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!("Tutorial {}/{}: {}", tutorial.step + 1, tutorial.len(), step.title))
+        .title_alignment(Alignment::Center);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(paragraph, popup);
+}
+
+/// Player of the game and the plays worth another look, in place of the field once it's over
+fn render_post_game(frame: &mut Frame, area: Rect, state: &GameState) {
+    use crate::game::constants::HIGHLIGHTS_SHOWN;
+    use crate::game::post_game::{rank_players, top_highlights};
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!("Final: {} {}, {} {}", state.away_name(), state.away_score, state.home_name(), state.home_score),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    lines.push(Line::from(""));
+    if let Some((key, _)) = rank_players(&state.box_score).first() {
+        let mut parts = Vec::new();
+        if let Some(line) = state.box_score.batter(key).filter(|line| line.at_bats + line.walks > 0) {
+            parts.push(format!("{}-{}", line.hits, line.at_bats));
+            if line.home_runs > 0 {
+                parts.push(format!("{} HR", line.home_runs));
+            }
+            if line.rbi > 0 {
+                parts.push(format!("{} RBI", line.rbi));
+            }
+        }
+        if let Some(line) = state.box_score.pitcher(key) {
+            parts.push(format!("{} IP, {} K, {} ER", line.innings_pitched(), line.strikeouts, line.earned_runs));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("Player of the game: ", Style::default().fg(Color::Yellow)),
+            Span::styled(state.player_name(key), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" ({})", parts.join(", "))),
+        ]));
+        lines.push(Line::from(""));
+    }
+    let highlights = top_highlights(&state.highlights, HIGHLIGHTS_SHOWN);
+    if highlights.is_empty() {
+        lines.push(Line::from("No runs driven in"));
+    }
+    for highlight in highlights {
+        lines.push(Line::from(highlight.describe(&state.player_name(&highlight.batter))));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Press any key to exit", Style::default().fg(Color::DarkGray))));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Game Over")
+        .title_alignment(Alignment::Center);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Where the current batter does damage, to help the pitcher pick a spot, and how they've hit
+/// in the games played here
+fn render_heat_map(frame: &mut Frame, area: Rect, state: &GameState, career: &CareerStats) {
+    use crate::game::constants::{HEAT_HOT_MIN, HEAT_WARM_MIN};
+
+    let mut lines = vec![Line::from(""), Line::from(""), Line::from("")];
+    match state.get_current_batter() {
+        Some(batter) => {
+            for row in crate::game::heat_map::danger_grid(&batter.stats) {
+                let cells: Vec<Span> = row
+                    .iter()
+                    .map(|&danger| {
+                        let color = if danger >= HEAT_HOT_MIN {
+                            Color::Red
+                        } else if danger >= HEAT_WARM_MIN {
+                            Color::Yellow
+                        } else {
+                            Color::Blue
+                        };
+                        Span::styled(format!("{:^4}", danger), Style::default().fg(Color::Black).bg(color))
+                    })
+                    .collect();
+                lines.push(Line::from(cells));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Red = hot, blue = cold",
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(crate::game::scouting::scouting_report(&batter.stats)));
+            if let Some(line) = career.batting.get(&batter.stats.id) {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    format!("Career: {}", line.summary()),
+                    Style::default().fg(Color::Cyan),
+                )));
+            }
+        }
+        None => lines.push(Line::from("No scouting data")),
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Batter Heat")
+        .title_alignment(Alignment::Center);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: false }); // Keep the padding on the grid cells
+
+    frame.render_widget(paragraph, area);
 }
 
 fn render_baseball_field(frame: &mut Frame, area: Rect, state: &GameState) {
+    let palette = Palette::for_scheme(state.config.color_scheme);
     // Professional ASCII baseball field
     // Credit: https://github.com/ceejay3264/ascii_baseball
 
     // Dynamic runner indicators - show filled circle if runner present
-    let r1 = if state.bases[0] { "*" } else { " " };  // 1st base
-    let r2 = if state.bases[1] { "*" } else { " " };  // 2nd base
-    let r3 = if state.bases[2] { "*" } else { " " };  // 3rd base
+    let [on_first, on_second, on_third] = state.occupied_bases();
+    let r1 = if on_first { "*" } else { " " };  // 1st base
+    let r2 = if on_second { "*" } else { " " };  // 2nd base
+    let r3 = if on_third { "*" } else { " " };  // 3rd base
 
     // Build the field with dynamic runners
     let field_art = format!(
@@ -279,17 +951,24 @@ fn render_baseball_field(frame: &mut Frame, area: Rect, state: &GameState) {
 
     // Color based on game state
     let style = match state.pitch_state {
-        PitchState::Pitching { .. } => Style::default().fg(Color::Yellow),
-        PitchState::Swinging { .. } => Style::default().fg(Color::Red),
-        PitchState::BallInPlay { .. } | PitchState::Fielding { .. } => Style::default().fg(Color::Green),
+        PitchState::Swinging { .. } => Style::default().fg(palette.bad),
+        PitchState::Fielding { .. } => Style::default().fg(palette.good),
         _ => Style::default().fg(Color::Cyan),
     };
 
+    // Name the zone the ball is headed for while it's being fielded - foul pops included
+    let title = match &state.pitch_state {
+        PitchState::Fielding { ball_in_play, .. } => {
+            format!("Diamond - {} ({})", sector_label(ball_in_play.direction), ball_in_play.direction.name())
+        }
+        _ => "Diamond".to_string(),
+    };
+
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Diamond")
+        .title(title)
         .title_alignment(Alignment::Center)
-        .border_style(Style::default().fg(Color::Green));
+        .border_style(Style::default().fg(palette.good));
 
     let paragraph = Paragraph::new(centered_field)
         .block(block)
@@ -299,13 +978,22 @@ fn render_baseball_field(frame: &mut Frame, area: Rect, state: &GameState) {
     frame.render_widget(paragraph, area);
 }
 
-fn render_strike_zone(frame: &mut Frame, area: Rect, state: &GameState, input_state: &crate::input::InputState) {
+fn render_strike_zone(frame: &mut Frame, area: Rect, state: &GameState, engine: &crate::game::GameEngine, input_state: &crate::input::InputState) {
+    let palette = Palette::for_scheme(state.config.color_scheme);
     // Determine what to show based on pitch state
     let (title, content_style) = match &state.pitch_state {
         PitchState::Aiming { .. } => ("[P] Pitcher Aim", Style::default().fg(Color::Yellow)),
-        PitchState::WaitingForBatter => ("[B] Batter Aim", Style::default().fg(Color::Red)),
+        PitchState::UmpireCall { .. } => ("Umpire's Call", Style::default().fg(Color::Yellow)),
         _ => ("Strike Zone", Style::default().fg(Color::Gray)),
     };
+    // Where a taken pitch crossed, flashing while the umpire makes the call
+    let crossed = match &state.pitch_state {
+        PitchState::UmpireCall { frames_left, .. } => {
+            let lit = (*frames_left / crate::game::constants::UMPIRE_CALL_FLASH_FRAMES).is_multiple_of(2);
+            state.pitch_location.map(|loc| (loc, lit))
+        }
+        _ => None,
+    };
 
     // Calculate aim position (9-zone grid)
     // Center = no input, arrows move from center
@@ -332,23 +1020,47 @@ fn render_strike_zone(frame: &mut Frame, area: Rect, state: &GameState, input_st
     // Build strike zone grid
     let mut zone_lines = vec![];
 
-    // Add title info
-    zone_lines.push(Line::from(""));
-    zone_lines.push(Line::from(Span::styled(
-        "Strike Zone:",
-        Style::default().add_modifier(Modifier::BOLD),
-    )));
-    zone_lines.push(Line::from(""));
+    // Add title info - or, with the preview on, the odds of the pitch being aimed
+    match &state.pitch_state {
+        PitchState::Aiming { pitch_type } if state.config.pitch_preview => {
+            let fatigue_penalty = state
+                .get_current_pitching_team()
+                .map(|t| t.get_fatigue_penalty())
+                .unwrap_or(crate::game::constants::FATIGUE_PENALTY_FRESH);
+            let preview = engine.preview_pitch(
+                input_state.aimed_location(),
+                *pitch_type,
+                state.get_current_batter(),
+                state.get_current_pitcher(),
+                fatigue_penalty,
+                (state.balls, state.strikes),
+                state.momentum_contact_bonus(),
+            );
+            zone_lines.extend(preview_lines(&preview, area.width.saturating_sub(2), palette));
+        }
+        _ => {
+            zone_lines.push(Line::from(""));
+            zone_lines.push(Line::from(Span::styled(
+                "Strike Zone:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            zone_lines.push(Line::from(""));
+        }
+    }
 
     // Build 3x3 grid
     for row in 0..3 {
         let mut cells = vec![];
         for col in 0..3 {
+            if let Some((_, lit)) = crossed.filter(|&(loc, _)| crate::game::heat_map::location_at(row, col) == loc) {
+                let color = if lit { Color::Yellow } else { Color::White };
+                cells.push(Span::styled(" ● ", Style::default().fg(color).add_modifier(Modifier::BOLD)));
+                continue;
+            }
             let symbol = if row == aim_row && col == aim_col {
                 // Show crosshair at aim position
                 match &state.pitch_state {
                     PitchState::Aiming { .. } => "+",  // Pitcher crosshair
-                    PitchState::WaitingForBatter => "X",  // Batter crosshair
                     _ => ".",
                 }
             } else {
@@ -370,7 +1082,7 @@ fn render_strike_zone(frame: &mut Frame, area: Rect, state: &GameState, input_st
     zone_lines.push(Line::from(""));
 
     // Add legend based on state
-    if matches!(state.pitch_state, PitchState::Aiming { .. } | PitchState::WaitingForBatter) {
+    if matches!(state.pitch_state, PitchState::Aiming { .. }) {
         zone_lines.push(Line::from(Span::styled(
             "Use arrow keys to aim",
             Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC),
@@ -390,49 +1102,77 @@ fn render_strike_zone(frame: &mut Frame, area: Rect, state: &GameState, input_st
     frame.render_widget(paragraph, area);
 }
 
+/// Strike, ball and contact odds as three bars sized to fit `width` columns
+fn preview_lines(preview: &PitchPreview, width: u16, palette: Palette) -> Vec<Line<'static>> {
+    const LABEL_WIDTH: u16 = 8;
+    const PERCENT_WIDTH: u16 = 5;
+    let bar_width = width.saturating_sub(LABEL_WIDTH + PERCENT_WIDTH).clamp(2, 10) as usize;
+    [("Strike", preview.strike, palette.good), ("Ball", preview.ball, palette.bad), ("Contact", preview.contact, Color::Yellow)]
+        .into_iter()
+        .map(|(label, odds, color)| {
+            let filled = ((odds * bar_width as f32).round() as usize).min(bar_width);
+            Line::from(vec![
+                Span::raw(format!("{:<8}", label)),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled("░".repeat(bar_width - filled), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!(" {:>3.0}%", odds * 100.0)),
+            ])
+        })
+        .collect()
+}
+
 fn render_controls(frame: &mut Frame, area: Rect, state: &GameState, engine: &crate::game::GameEngine) {
+    let pitches: Vec<String> = engine
+        .arsenal(state.get_current_pitcher())
+        .iter()
+        .enumerate()
+        .map(|(i, p)| format!("{}: {}", i + 1, p.name))
+        .collect();
+    let bullpen = state.practice_mode() == Some(PracticeMode::Bullpen);
     let controls = match &state.pitch_state {
+        _ if state.attract_mode => "DEMO - press any key to pick your own teams".to_string(),
+        _ if state.pinch_run_menu.is_some() => pinch_run_label(state),
+        _ if state.defensive_sub_menu.is_some() => defensive_sub_label(state),
+        _ if state.bullpen_menu.is_some() => bullpen_label(state),
+        PitchState::ChoosePitch if state.practice.is_some() => {
+            format!("Choose Pitch: {}  |  ESC: end practice  |  Q: quit", pitches.join(" | "))
+        }
+        PitchState::BallApproaching { .. } if bullpen => "Watch it into the glove...  |  ESC: end practice  |  Q: quit".to_string(),
         PitchState::ChoosePitch => {
-            let pitches: Vec<String> = engine
-                .pitch_types
-                .iter()
-                .enumerate()
-                .map(|(i, p)| format!("{}: {}", i + 1, p.name))
-                .collect();
             format!(
-                "Choose Pitch: {}  |  Press Q to quit",
-                pitches.join(" | ")
+                "Choose Pitch: {}  |  {}S: sim inning  |  Press Q to quit",
+                pitches.join(" | "),
+                pitcher_clock_label(state)
             )
         }
         PitchState::Aiming { pitch_type } => {
             format!(
-                "Aiming {} - Use arrow keys to aim, SPACE to pitch  |  Q: quit",
-                engine.get_pitch_name(*pitch_type)
+                "Aiming {} - Use arrow keys or TAB to aim, SPACE to pitch  |  {}Q: quit",
+                engine.get_pitch_name(state.get_current_pitcher(), *pitch_type),
+                pitcher_clock_label(state)
             )
         }
         PitchState::PitchClock { .. } => {
-            "GET READY! Position yourself for the incoming pitch...  |  Q: quit".to_string()
+            "GET READY! Position yourself for the incoming pitch...  |  O: step out (strike)  |  Q: quit".to_string()
         }
         PitchState::BallApproaching { can_swing, .. } => {
             if *can_swing {
                 "⚡ SWING NOW! Use arrow keys + SPACE or SHIFT+(1-9) to swing!  |  Q: quit".to_string()
+            } else if state.config.batters_eye && state.pitch_guess.is_none() {
+                "⏳ Ball approaching... 1-4 to call the pitch, then get ready to swing!  |  Q: quit".to_string()
             } else {
                 "⏳ Ball approaching... Get ready to swing!  |  Q: quit".to_string()
             }
         }
-        PitchState::WaitingForBatter => {
-            "BATTER: Use arrow keys to position, SPACE to swing  |  Q: quit".to_string()
-        }
-        PitchState::Pitching { .. } => "Pitching...".to_string(),
         PitchState::Swinging { .. } => "Swinging...".to_string(),
-        PitchState::BallInPlay { .. } => "Ball in play!".to_string(),
         PitchState::Fielding { ball_in_play, frames_elapsed } => {
             let time_left = ball_in_play.hang_time.saturating_sub(*frames_elapsed);
             format!(
-                "FIELDING: {:?} to {:?}! Time: {} frames - Press SPACE to field!  |  Q: quit",
-                ball_in_play.ball_type, ball_in_play.direction, time_left
+                "FIELDING: {:?} to {}! Time: {} frames - Press SPACE to field!  |  Q: quit",
+                ball_in_play.ball_type, ball_in_play.direction.name(), time_left
             )
         }
+        PitchState::UmpireCall { .. } => "And the call is...  |  SPACE: skip  |  Q: quit".to_string(),
         PitchState::ShowResult { .. } => "Press SPACE to continue  |  Q: quit".to_string(),
     };
 
@@ -457,10 +1197,152 @@ fn render_controls(frame: &mut Frame, area: Rect, state: &GameState, engine: &cr
     frame.render_widget(paragraph, area);
 }
 
-fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
+/// The runner or batter being replaced and the bench to pick from, the highlighted player in brackets
+fn pinch_run_label(state: &GameState) -> String {
+    let Some(menu) = &state.pinch_run_menu else {
+        return String::new();
+    };
+    let Some(team) = state.get_current_batting_team() else {
+        return String::new();
+    };
+    let at_the_plate = menu.base == AT_THE_PLATE;
+    let replaced = if at_the_plate { Some(state.current_batter_key()) } else { state.bases[menu.base].clone() };
+    let runner = replaced
+        .and_then(|id| team.batters.iter().find(|p| p.stats.id == id))
+        .map(|p| p.stats.name.as_str())
+        .unwrap_or(if at_the_plate { "the batter" } else { "the runner" });
+    let bench: Vec<String> = team
+        .bench()
+        .enumerate()
+        .map(|(i, p)| {
+            let entry = format!("{} ({:.1} ft/s)", p.stats.name, p.sprint_speed);
+            if i == menu.cursor { format!("[{}]", entry) } else { entry }
+        })
+        .collect();
+    if at_the_plate {
+        return format!("PINCH HIT for {}: {}", runner, bench.join(" | "));
+    }
+    format!("PINCH RUN for {} on {}: {}", runner, crate::game::state::base_name(menu.base), bench.join(" | "))
+}
+
+/// The fielder being replaced with their outs above average, and the bench to pick from
+fn defensive_sub_label(state: &GameState) -> String {
+    let Some(menu) = &state.defensive_sub_menu else {
+        return String::new();
+    };
+    let Some(team) = state.get_current_pitching_team() else {
+        return String::new();
+    };
+    let fielder = team
+        .fielder(menu.position)
+        .map(|p| format!("{} ({:+} OAA)", p.stats.name, p.outs_above_average))
+        .unwrap_or_else(|| "nobody".to_string());
+    let bench: Vec<String> = team
+        .bench()
+        .enumerate()
+        .map(|(i, p)| {
+            let entry = format!("{} ({:+} OAA)", p.stats.name, p.outs_above_average);
+            if i == menu.cursor { format!("[{}]", entry) } else { entry }
+        })
+        .collect();
+    format!("DEFENSIVE SUB at {} for {}: {}", menu.position.name(), fielder, bench.join(" | "))
+}
+
+/// The bullpen to pick from, then the double switch on offer, the highlighted player in brackets
+fn bullpen_label(state: &GameState) -> String {
+    let Some(menu) = &state.bullpen_menu else {
+        return String::new();
+    };
+    let Some(team) = state.get_current_pitching_team() else {
+        return String::new();
+    };
+    let pick = |i: usize, entry: String| if i == menu.cursor { format!("[{}]", entry) } else { entry };
+    let Some(reliever) = &menu.reliever else {
+        let pitcher = team.get_current_pitcher().map(|p| p.stats.name.as_str()).unwrap_or("the pitcher");
+        let bullpen: Vec<String> = team.bullpen().enumerate().map(|(i, p)| pick(i, p.stats.name.clone())).collect();
+        return format!("BULLPEN for {}: {}", pitcher, bullpen.join(" | "));
+    };
+    let reliever = team.pitchers.iter().find(|p| &p.stats.id == reliever).map(|p| p.stats.name.as_str()).unwrap_or("the reliever");
+    let Some(fielder) = menu.slot.and_then(|slot| team.batters.get(slot)) else {
+        return format!("DOUBLE SWITCH? {} bats for the pitcher - LEFT/RIGHT for a spot in the order, SPACE for a straight change", reliever);
+    };
+    let bench: Vec<String> = team.bench().enumerate().map(|(i, p)| pick(i, p.stats.name.clone())).collect();
+    format!(
+        "DOUBLE SWITCH: {} bats {} for {}, in at {}: {}",
+        reliever,
+        menu.slot.unwrap_or_default() + 1,
+        fielder.stats.name,
+        fielder.position.name(),
+        bench.join(" | ")
+    )
+}
+
+/// Seconds left on the pitcher's clock, or nothing when it's turned off
+fn pitcher_clock_label(state: &GameState) -> String {
+    let limit = state.config.pitcher_clock_frames;
+    if limit == 0 || state.config.demo_mode {
+        return String::new();
+    }
+    let frames_left = limit.saturating_sub(state.pitcher_clock_used);
+    format!("Clock: {}s  |  ", crate::game::constants::frames_to_seconds(frames_left as u64))
+}
+
+/// The next two up, for the pitcher planning ahead - or, while the third out is on screen,
+/// the three due up for the other side
+pub fn upcoming_batters_line(state: &GameState) -> String {
+    let side_retired = !state.game_over && state.outs == 0 && matches!(state.pitch_state, PitchState::ShowResult { result: PlayResult::Out(_), .. });
+    if side_retired {
+        let due_up: Vec<&str> = (0..3).filter_map(|ahead| state.upcoming_batter(ahead)).map(|b| b.stats.name.as_str()).collect();
+        return format!("Due up: {}", due_up.join(", "));
+    }
+    let upcoming: Vec<String> = [(1, "On deck"), (2, "in the hole")]
+        .iter()
+        .filter_map(|&(ahead, label)| {
+            let batter = state.upcoming_batter(ahead)?;
+            Some(format!("{}: {} ({:.1}% brl, {:.0} mph)", label, batter.stats.name, batter.stats.barrel_percent, batter.stats.avg_hit_speed))
+        })
+        .collect();
+    upcoming.join(" | ")
+}
+
+/// How a pitch looks out of the hand: tight fastball spin, the red dot of a slider, the tumble
+/// of a curveball or the slow turn of a changeup or splitter
+pub fn pitch_cue(pitch_name: &str) -> (char, Color) {
+    let name = pitch_name.to_ascii_lowercase();
+    if name.contains("slider") || name.contains("sweeper") {
+        ('•', Color::LightRed)
+    } else if name.contains("curve") || name.contains("slurve") {
+        ('@', Color::LightMagenta)
+    } else if name.contains("change") {
+        ('0', Color::LightBlue)
+    } else if name.contains("split") || name.contains("fork") {
+        ('0', Color::LightGreen)
+    } else if name.contains("sinker") || name.contains("two-seam") {
+        ('o', Color::LightYellow)
+    } else if name.contains("cutter") {
+        ('o', Color::LightCyan)
+    } else {
+        ('o', Color::White)
+    }
+}
+
+fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState, engine: &crate::game::GameEngine) {
+    let palette = Palette::for_scheme(state.config.color_scheme);
+    // Name the pitch and its velocity while it's on the way - with the batter's eye on, only
+    // once the batter has made their call
+    let title = match &state.pitch_state {
+        PitchState::BallApproaching { .. } if state.config.batters_eye && state.pitch_guess.is_none() => {
+            "Timing - read the spin, 1-4 to call it".to_string()
+        }
+        PitchState::BallApproaching { pitch_type, .. } => engine
+            .pitch_type(state.get_current_pitcher(), *pitch_type)
+            .map(|p| format!("Timing - {} mph {}", p.speed, p.name))
+            .unwrap_or_else(|| "Timing".to_string()),
+        _ => "Timing".to_string(),
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("Timing");
+        .title(title);
 
     match &state.pitch_state {
         PitchState::PitchClock { frames_left, .. } => {
@@ -468,7 +1350,7 @@ fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
             let clock_text = format!("PITCH CLOCK: {}s", seconds_left);
             
             // Create countdown bar
-            let progress = 1.0 - (*frames_left as f32 / crate::game::constants::PITCH_CLOCK_FRAMES as f32);
+            let progress = 1.0 - (*frames_left as f32 / state.config.pitch_clock_frames.max(1) as f32);
             let bar_width = (area.width.saturating_sub(4)) as f32 * progress;
             let filled_chars = (bar_width as usize).min(area.width.saturating_sub(4) as usize);
             let empty_chars = (area.width.saturating_sub(4) as usize).saturating_sub(filled_chars);
@@ -481,7 +1363,7 @@ fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
             let text = vec![
                 Line::from(Span::styled(
                     clock_text,
-                    Style::default().fg(if seconds_left <= 3 { Color::Red } else { Color::Yellow })
+                    Style::default().fg(if seconds_left <= 3 { palette.bad } else { Color::Yellow })
                         .add_modifier(Modifier::BOLD)
                 )),
                 Line::from(clock_bar),
@@ -490,23 +1372,29 @@ fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
             frame.render_widget(paragraph, area);
         }
-        PitchState::BallApproaching { frames_left, ball_position, can_swing, .. } => {
+        PitchState::BallApproaching { frames_left, approach_frames, ball_position, can_swing, pitch_type } => {
             // Ball approach visualization
             let ball_width = area.width.saturating_sub(4) as f32;
-            let ball_pos = (*ball_position * ball_width) as usize;
-            
-            // Create ball position display
-            let mut ball_display = vec![' '; ball_width as usize];
-            if ball_pos < ball_display.len() {
-                ball_display[ball_pos] = 'O';
-            }
+            let ball_pos = ((*ball_position * ball_width) as usize).min((ball_width as usize).saturating_sub(1));
+
+            // Out of the hand the ball shows its spin for a moment
+            let out_of_hand = approach_frames.saturating_sub(*frames_left) <= crate::game::constants::PITCH_CUE_FRAMES;
+            let (glyph, ball_style) = match engine.pitch_type(state.get_current_pitcher(), *pitch_type) {
+                Some(pitch) if out_of_hand && state.config.batters_eye => {
+                    let (glyph, color) = pitch_cue(&pitch.name);
+                    (glyph, Style::default().fg(color))
+                }
+                _ => ('O', Style::default()),
+            };
             
             // Timing window indicator
             let _timing_window_start = crate::game::constants::SWING_TIMING_WINDOW_FRAMES;
             let perfect_window = crate::game::constants::PERFECT_TIMING_WINDOW_FRAMES;
             
             let timing_info = if *can_swing {
-                if *frames_left <= perfect_window {
+                if *frames_left <= crate::game::constants::TOO_LATE_END_FRAMES {
+                    "❌ TOO LATE!"
+                } else if *frames_left <= perfect_window {
                     "⚡ PERFECT TIMING! ⚡"
                 } else {
                     "🎯 Swing Zone Active"
@@ -515,15 +1403,17 @@ fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
                 "⏳ Ball Approaching..."
             };
             
-            let ball_track = ball_display.iter().collect::<String>();
-            
             let text = vec![
                 Line::from(Span::styled(
                     timing_info,
-                    Style::default().fg(if *can_swing { Color::Green } else { Color::Cyan })
+                    Style::default().fg(if *can_swing { palette.good } else { Color::Cyan })
                         .add_modifier(Modifier::BOLD)
                 )),
-                Line::from(format!("Mound [{}] Plate", ball_track)),
+                Line::from(vec![
+                    Span::raw(format!("Mound [{}", " ".repeat(ball_pos))),
+                    Span::styled(glyph.to_string(), ball_style),
+                    Span::raw(format!("{}] Plate", " ".repeat((ball_width as usize).saturating_sub(ball_pos + 1)))),
+                ]),
             ];
             
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
@@ -540,10 +1430,10 @@ fn render_timing_display(frame: &mut Frame, area: Rect, state: &GameState) {
             };
             
             let color = match swing_timing {
-                SwingTiming::Perfect => Color::Green,
+                SwingTiming::Perfect => palette.good,
                 SwingTiming::Early | SwingTiming::Late => Color::Yellow,
-                SwingTiming::TooEarly | SwingTiming::TooLate => Color::Red,
-                SwingTiming::NoSwing => Color::Blue,
+                SwingTiming::TooEarly | SwingTiming::TooLate => palette.bad,
+                SwingTiming::NoSwing => palette.neutral,
             };
             
             let text = vec![
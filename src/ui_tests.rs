@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+    use crate::game::{constants::*, GameEngine, GameMode, GameState, PitchLocation, PitchState};
+    use crate::input::{GameInput, InputState};
+    use crate::logger::GameLogger;
+    use crate::config::ColorScheme;
+    use crate::ui::{base_diamond, help_lines, help_page_size, upcoming_batters_line, Palette, RenderKey};
+
+    fn tick(state: &mut GameState, engine: &GameEngine, input_state: &mut InputState) {
+        let logger = GameLogger::with_path(std::env::temp_dir().join("bitbatter_ui_tests.log").to_string_lossy());
+        crate::game::update_game_state(state, engine, input_state, None, &logger);
+    }
+
+    fn playing_state() -> GameState {
+        let mut state = GameState::new();
+        state.mode = GameMode::Playing;
+        state
+    }
+
+    #[test]
+    fn test_idle_pitcher_only_redraws_when_the_clock_second_changes() {
+        let engine = GameEngine::with_seed(1);
+        let mut input_state = InputState::new();
+        let mut state = playing_state();
+        let mut key = RenderKey::capture(&state, &input_state);
+
+        let mut redraws = 0;
+        for _ in 0..TARGET_FPS * 3 {
+            tick(&mut state, &engine, &mut input_state);
+            let next = RenderKey::capture(&state, &input_state);
+            if next != key {
+                redraws += 1;
+                key = next;
+            }
+        }
+        assert_eq!(redraws, 3);
+    }
+
+    #[test]
+    fn test_ball_approach_redraws_every_tick() {
+        let engine = GameEngine::with_seed(2);
+        let mut input_state = InputState::new();
+        let mut state = playing_state();
+        state.pitch_location = Some(PitchLocation::Middle);
+        state.pitch_state = PitchState::BallApproaching {
+            frames_left: BALL_APPROACH_FRAMES,
+            approach_frames: BALL_APPROACH_FRAMES,
+            ball_position: 0.0,
+            pitch_type: 0,
+            can_swing: false,
+        };
+
+        for _ in 0..10 {
+            let before = RenderKey::capture(&state, &input_state);
+            tick(&mut state, &engine, &mut input_state);
+            assert_ne!(RenderKey::capture(&state, &input_state), before);
+        }
+    }
+
+    #[test]
+    fn test_aiming_changes_what_is_drawn() {
+        let mut input_state = InputState::new();
+        let state = playing_state();
+        let before = RenderKey::capture(&state, &input_state);
+        input_state.update(&GameInput::Up);
+        assert_ne!(RenderKey::capture(&state, &input_state), before);
+    }
+
+    #[test]
+    fn test_color_blind_palette_keeps_good_and_bad_apart_without_red_or_green() {
+        use ratatui::style::Color;
+
+        let palette = Palette::for_scheme(ColorScheme::ColorBlind);
+        let roles = [palette.good, palette.bad, palette.good_light, palette.bad_light, palette.neutral];
+        for (i, a) in roles.iter().enumerate() {
+            for b in &roles[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        for color in roles {
+            assert!(![Color::Red, Color::Green, Color::LightRed, Color::LightGreen].contains(&color), "{:?}", color);
+        }
+    }
+
+    #[test]
+    fn test_standard_palette_is_unchanged() {
+        use ratatui::style::Color;
+
+        let palette = Palette::for_scheme(ColorScheme::default());
+        assert_eq!((palette.good, palette.bad), (Color::Green, Color::Red));
+    }
+
+    #[test]
+    fn test_help_lists_every_phase_then_the_settings() {
+        let config = crate::config::GameConfig { max_innings: 12, ..Default::default() };
+        let text: Vec<String> = help_lines(&config).iter().map(|line| line.to_string()).collect();
+        for phase in crate::input::KeyPhase::ALL {
+            assert!(text.iter().any(|line| line == phase.name()), "{} missing", phase.name());
+        }
+        let settings = text.iter().position(|line| line == "Settings").unwrap();
+        assert!(text[settings..].iter().any(|line| line.contains("a tie after 12 ends it")));
+        assert!(text[settings..].iter().any(|line| line.contains("CPU batter aggressiveness")));
+        assert!(text[settings..].iter().any(|line| line.contains("Sound: on")));
+    }
+
+    #[test]
+    fn test_help_screen_pauses_play_and_any_key_closes_it() {
+        let engine = GameEngine::new();
+        let mut manager = crate::team::TeamManager::new(std::env::temp_dir(), DEFAULT_SEASON);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+        let mut state = playing_state();
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Help, None, &logger);
+        assert_eq!(state.help_page, Some(0));
+        tick(&mut state, &engine, &mut input_state);
+        assert_eq!(state.pitcher_clock_used, 0);
+
+        // A small terminal needs more than one page
+        assert!(help_lines(&state.config).len() > help_page_size(12));
+        for input in [GameInput::Right, GameInput::Right, GameInput::Left] {
+            crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, input, None, &logger);
+        }
+        assert_eq!(state.help_page, Some(1));
+
+        // Closing doesn't also pick a pitch
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::SelectPitch(0), None, &logger);
+        assert!(state.help_page.is_none());
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+    }
+
+    #[test]
+    fn test_leaders_screen_opens_from_team_selection_and_tabs_wrap() {
+        let engine = GameEngine::new();
+        let mut manager = crate::team::TeamManager::new(std::env::temp_dir(), DEFAULT_SEASON);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+        let mut state = GameState::new();
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Leaders, None, &logger);
+        assert_eq!(state.leaders_tab, Some(0));
+        // Sitting on it doesn't count toward the demo starting
+        tick(&mut state, &engine, &mut input_state);
+        assert_eq!(state.idle_frames, 0);
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Left, None, &logger);
+        assert_eq!(state.leaders_tab, Some(crate::game::career::CareerCategory::ALL.len() - 1));
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Right, None, &logger);
+        assert_eq!(state.leaders_tab, Some(0));
+
+        // Closing doesn't also start a search
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::StartSearch, None, &logger);
+        assert!(state.leaders_tab.is_none());
+        assert!(matches!(&state.mode, GameMode::TeamSelection { search: None, .. }));
+    }
+
+    #[test]
+    fn test_settings_screen_changes_the_picked_setting_and_pauses_play() {
+        use crate::config::{ColorScheme, Setting};
+        let engine = GameEngine::new();
+        let mut manager = crate::team::TeamManager::new(std::env::temp_dir(), DEFAULT_SEASON);
+        let mut input_state = InputState::new();
+        let logger = GameLogger::disabled();
+        let mut state = playing_state();
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Settings, None, &logger);
+        assert_eq!(state.settings_row, Some(0));
+        tick(&mut state, &engine, &mut input_state);
+        assert_eq!(state.pitcher_clock_used, 0);
+
+        let colors = Setting::ALL.iter().position(|s| *s == Setting::ColorScheme).unwrap();
+        for _ in 0..colors {
+            crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Down, None, &logger);
+        }
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Right, None, &logger);
+        assert_eq!(state.config.color_scheme, ColorScheme::ColorBlind);
+        // The arrows worked the screen, not the aim
+        assert_eq!(input_state.aimed_location(), PitchLocation::Middle);
+
+        crate::game::handle_input(&mut state, &mut manager, &engine, &mut input_state, GameInput::Pause, None, &logger);
+        assert!(state.settings_row.is_none());
+        assert_eq!(state.pitch_state, PitchState::ChoosePitch);
+    }
+
+    #[test]
+    fn test_scoreboard_shows_on_deck_then_due_up_once_the_side_is_retired() {
+        let mut teams = crate::team::TeamManager::new(DEFAULT_DATA_DIR, DEFAULT_SEASON);
+        teams.teams.insert("TST".to_string(), crate::roster::CustomRoster::template("TST", "Test Team").into_team(DEFAULT_SEASON));
+        let mut state = GameState::new();
+        state.start_game(&teams, "TST".to_string(), "TST".to_string());
+        let name = |state: &GameState, ahead| state.upcoming_batter(ahead).unwrap().stats.name.clone();
+
+        state.outs = 2;
+        let line = upcoming_batters_line(&state);
+        assert!(line.starts_with(&format!("On deck: {}", name(&state, 1))), "{}", line);
+        assert!(line.contains(&format!("in the hole: {}", name(&state, 2))), "{}", line);
+
+        // Third out: the other side's first three are due up while the play is on screen
+        state.advance_batter();
+        state.end_half_inning();
+        state.pitch_state = PitchState::ShowResult { result: crate::game::PlayResult::Out(crate::game::OutType::Flyout), frames_left: RESULT_DISPLAY_FRAMES };
+        let due_up = format!("Due up: {}, {}, {}", name(&state, 0), name(&state, 1), name(&state, 2));
+        assert_eq!(upcoming_batters_line(&state), due_up);
+
+        state.pitch_state = PitchState::ChoosePitch;
+        assert!(upcoming_batters_line(&state).starts_with("On deck"));
+    }
+
+    #[test]
+    fn test_base_diamond_golden() {
+        assert_eq!(base_diamond(&[None, None, None], 0), ["             ◇", "           ◇   ◇", "           ○ ○ ○"]);
+        assert_eq!(
+            base_diamond(&[Some("Ohtani"), None, Some("Betts")], 1),
+            ["             ◇", "     Betts ◆   ◆ Ohtani", "           ● ○ ○"]
+        );
+        assert_eq!(
+            base_diamond(&[Some("Freeman"), Some("Kiner-Falefa"), Some("Smith")], 2),
+            ["             ◆ Kiner-Fale", "     Smith ◆   ◆ Freeman", "           ● ● ○"]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use bitbatter::game::constants::{DEFAULT_SEASON, MAX_BALLS, MAX_OUTS, MAX_STRIKES, PERFECT_END_FRAMES};
+use bitbatter::game::engine::SpraySide;
+use bitbatter::game::state::{BallInPlay, BallType, FieldDirection};
+use bitbatter::game::{GameMode, InningHalf, OutType, PitchState, PlayResult};
+use bitbatter::input::{GameInput, InputState};
+use bitbatter::logger::GameLogger;
+use bitbatter::{handle_input, update_game_state, GameEngine, GameState, TeamManager};
+
+const MAX_FRAMES: u32 = 2_000_000;
+
+/// The player's next press, given the game and how many pitches have been picked so far
+type Script = fn(&GameState, u32) -> Option<GameInput>;
+
+/// Run on the game after every frame, with the frame number
+type FrameCheck = Box<dyn FnMut(&GameState, u32)>;
+
+/// A game on the fixture league, driven only through the inputs a player would give
+struct ScriptedGame {
+    state: GameState,
+    manager: TeamManager,
+    engine: GameEngine,
+    input_state: InputState,
+    logger: GameLogger,
+    pitches: u32,
+    checks: Vec<FrameCheck>,
+}
+
+impl ScriptedGame {
+    fn new(seed: u64) -> Self {
+        let mut manager = TeamManager::new(format!("{}/fixtures/teams", env!("CARGO_MANIFEST_DIR")), DEFAULT_SEASON);
+        manager.load_league(format!("{}/fixtures/league.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+        Self {
+            state: GameState::new(),
+            manager,
+            engine: GameEngine::with_seed(seed),
+            input_state: InputState::new(),
+            logger: GameLogger::disabled(),
+            pitches: 0,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Run `check` on the game after every frame played from here on
+    fn check_every_frame(&mut self, check: impl FnMut(&GameState, u32) + 'static) {
+        self.checks.push(Box::new(check));
+    }
+
+    fn press(&mut self, input: GameInput) {
+        handle_input(&mut self.state, &mut self.manager, &self.engine, &mut self.input_state, input, None, &self.logger);
+    }
+
+    fn tick(&mut self) {
+        update_game_state(&mut self.state, &self.engine, &mut self.input_state, None, &self.logger);
+    }
+
+    /// Team selection to first pitch: away team 1, home team 2, straight past the lineup card
+    fn play_ball(&mut self) {
+        for input in [
+            GameInput::SelectAwayTeam,
+            GameInput::NumberInput('1'),
+            GameInput::Action,
+            GameInput::SelectHomeTeam,
+            GameInput::NumberInput('2'),
+            GameInput::Action,
+            GameInput::Action,
+            GameInput::SimInning,
+        ] {
+            self.press(input);
+        }
+    }
+
+    /// Play the game out with `script` pressing the keys
+    fn play_to_the_end_with(&mut self, script: Script) {
+        let mut frames = 0;
+        // Until the last out is made and the final play has had its time on screen
+        while !self.state.game_over || matches!(self.state.pitch_state, PitchState::ShowResult { .. }) {
+            assert!(frames < MAX_FRAMES, "stuck in inning {} at {:?}", self.state.inning, self.state.pitch_state);
+            if let Some(input) = script(&self.state, self.pitches) {
+                if matches!(input, GameInput::SelectPitch(_)) {
+                    self.pitches += 1;
+                }
+                self.press(input);
+            }
+            self.tick();
+            frames += 1;
+            for check in &mut self.checks {
+                check(&self.state, frames);
+            }
+        }
+    }
+
+    fn play_to_the_end(&mut self) {
+        self.play_to_the_end_with(down_the_middle);
+    }
+}
+
+/// Both sides played the same way every pitch: a first-pitch fastball down the middle, a
+/// swing at the end of the perfect window, and a try at every ball in the air halfway up
+fn down_the_middle(state: &GameState, _pitch: u32) -> Option<GameInput> {
+    match &state.pitch_state {
+        PitchState::ChoosePitch if !state.game_over => Some(GameInput::SelectPitch(0)),
+        PitchState::Aiming { .. } => Some(GameInput::Action),
+        PitchState::BallApproaching { frames_left, can_swing: true, .. } if *frames_left == PERFECT_END_FRAMES => Some(GameInput::Action),
+        PitchState::Fielding { ball_in_play, frames_elapsed } if *frames_elapsed == ball_in_play.hang_time / 2 => Some(GameInput::Action),
+        _ => None,
+    }
+}
+
+/// Varied by pitch count so a game sees every pitch type, aims all over the zone, swings
+/// early, late and on time, takes, and fields well and badly
+fn all_over_the_place(state: &GameState, pitch: u32) -> Option<GameInput> {
+    match &state.pitch_state {
+        PitchState::ChoosePitch if !state.game_over => Some(GameInput::SelectPitch(pitch as usize % 4)),
+        PitchState::Aiming { .. } => Some(GameInput::DirectPosition((pitch * 7 % 9 + 1) as u8)),
+        PitchState::BallApproaching { frames_left, can_swing: true, .. } if !pitch.is_multiple_of(5) && *frames_left as u32 == 4 + pitch % 9 => {
+            Some(GameInput::Action)
+        }
+        PitchState::Fielding { ball_in_play, frames_elapsed } if *frames_elapsed as u32 == ball_in_play.hang_time as u32 * (1 + pitch % 3) / 4 => {
+            Some(GameInput::Action)
+        }
+        _ => None,
+    }
+}
+
+/// Outs recorded so far in the game - the on-field count resets every half inning, the
+/// pitchers' lines don't
+fn total_outs(state: &GameState) -> u32 {
+    state.box_score.pitching.values().map(|line| line.outs_recorded as u32).sum()
+}
+
+/// Half innings finished before the one being played
+fn halves_done(state: &GameState) -> u32 {
+    (state.inning as u32 - 1) * 2 + if state.half == InningHalf::Bottom { 1 } else { 0 }
+}
+
+/// The count, outs, score and side changes stay legal from one frame to the next
+fn state_machine_invariants(seed: u64) -> impl FnMut(&GameState, u32) {
+    let (mut away_score, mut home_score) = (0, 0);
+    let mut half = 0;
+    move |state, frame| {
+        let at = format!("seed {}, inning {} {:?}, frame {}", seed, state.inning, state.half, frame);
+        assert!(state.outs <= MAX_OUTS, "{}: {} outs", at, state.outs);
+        assert!(state.strikes <= MAX_STRIKES, "{}: {} strikes", at, state.strikes);
+        assert!(state.balls <= MAX_BALLS, "{}: {} balls", at, state.balls);
+        assert!(state.away_score >= away_score && state.home_score >= home_score, "{}: a run came off the board", at);
+        (away_score, home_score) = (state.away_score, state.home_score);
+
+        // Sides change one half at a time, and only on the third out - so every new inning
+        // starts six outs after the last
+        let now = halves_done(state);
+        assert!(now == half || now == half + 1, "{}: skipped from half {} to {}", at, half, now);
+        if now != half || state.game_over {
+            let expected = now + u32::from(state.game_over && now == half);
+            assert_eq!(total_outs(state), expected * MAX_OUTS as u32, "{}: sides changed on the wrong out", at);
+        }
+        assert!(total_outs(state) >= now * MAX_OUTS as u32);
+        half = now;
+    }
+}
+
+#[test]
+fn test_a_full_game_plays_out_from_team_selection_through_inputs_alone() {
+    let mut game = ScriptedGame::new(1608);
+    game.play_ball();
+    assert_eq!(game.state.mode, GameMode::Playing);
+    assert!(game.state.away_team.is_some() && game.state.home_team.is_some());
+    assert_ne!(game.state.away_team, game.state.home_team);
+
+    game.play_to_the_end();
+    assert!(game.state.game_over);
+    assert!(game.state.inning >= 9);
+    assert_ne!(game.state.home_score, game.state.away_score);
+    // Swinging on time at fastballs down the middle all game finds a hit somewhere
+    let hits: u32 = game.state.box_score.batting.values().map(|line| line.hits as u32).sum();
+    assert!(hits > 0);
+}
+
+#[test]
+fn test_the_same_seed_and_script_play_the_same_game() {
+    let play = |seed| {
+        let mut game = ScriptedGame::new(seed);
+        game.play_ball();
+        game.play_to_the_end();
+        (game.state.away_score, game.state.home_score, game.state.inning, game.state.box_score)
+    };
+    assert_eq!(play(1608), play(1608));
+}
+
+#[test]
+fn test_a_pop_fly_fielded_on_the_perfect_frame_is_caught_on_a_fixed_seed() {
+    let mut game = ScriptedGame::new(1613);
+    game.play_ball();
+    let ball_in_play = BallInPlay {
+        ball_type: BallType::PopFly,
+        direction: FieldDirection::SecondBase,
+        speed: 60.0,
+        hang_time: 40,
+        initial_contact_quality: 30,
+        hr_chance: 0.0,
+        speed_factor: 1.0,
+        spray: SpraySide::Center,
+    };
+    let frames_elapsed = ball_in_play.hang_time / 2;
+    game.state.pitch_state = PitchState::Fielding { ball_in_play, frames_elapsed };
+    game.press(GameInput::Action);
+    assert!(
+        matches!(game.state.pitch_state, PitchState::ShowResult { result: PlayResult::Out(OutType::Flyout), .. }),
+        "{:?}",
+        game.state.pitch_state
+    );
+    assert_eq!(game.state.outs, 1);
+}
+
+#[test]
+fn test_scripted_games_keep_the_state_machine_consistent() {
+    for seed in [1609, 1610, 1611] {
+        let mut game = ScriptedGame::new(seed);
+        game.play_ball();
+        game.check_every_frame(state_machine_invariants(seed));
+        game.play_to_the_end_with(all_over_the_place);
+        assert!(game.state.game_over);
+        assert!(game.state.inning >= 9, "seed {} ended in inning {}", seed, game.state.inning);
+        assert_ne!(game.state.home_score, game.state.away_score, "seed {} ended tied", seed);
+    }
+}